@@ -1,7 +1,9 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::path::PathBuf;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
 
 use product_common::move_history_manager::MoveHistoryManager;
 
@@ -26,4 +28,99 @@ fn main() {
 
     // Tell Cargo to rerun this build script if the Move.lock file changes.
     println!("cargo::rerun-if-changed={move_lock_path}");
+
+    let move_sources_path = "../audit-trail-move/sources";
+    write_error_constants(Path::new(move_sources_path));
+    println!("cargo::rerun-if-changed={move_sources_path}");
+}
+
+/// Writes the error constants declared in the Move modules of `sources` to `OUT_DIR`, so that aborts are always
+/// resolved with the constants of the package sources:
+///
+/// - `abort_codes.rs` holds the plain `u64` error constants as `ABORT_CODES`.
+/// - `clever_errors.rs` holds the names of the clever error constants of each module, in declaration order, as
+///   `<MODULE>_ERRORS`.
+fn write_error_constants(sources: &Path) {
+    let mut paths: Vec<PathBuf> = fs::read_dir(sources)
+        .expect("Move sources are readable")
+        .map(|entry| entry.expect("Move sources are readable").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "move"))
+        .collect();
+    paths.sort();
+
+    let mut abort_codes = String::new();
+    let mut clever_errors = String::new();
+    for path in paths {
+        let source = fs::read_to_string(&path).expect("Move sources are readable");
+        let Some(module) = source
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))
+            .and_then(|module| module.split("::").nth(1))
+            .map(|module| module.trim_end_matches([';', '{', ' ']))
+        else {
+            continue;
+        };
+
+        let mut names = Vec::new();
+        for (name, value) in source.lines().filter_map(error_constant) {
+            match value {
+                ErrorValue::Code(code) => {
+                    writeln!(abort_codes, "    ({module:?}, {code}, {name:?}),").expect("writing to a string succeeds")
+                }
+                ErrorValue::Message => names.push(format!("{name:?}")),
+            }
+        }
+        if !names.is_empty() {
+            writeln!(
+                clever_errors,
+                "/// Clever error constants of the `{module}` module, generated from its Move sources.\n\
+                 const {}_ERRORS: &[&str] = &[{}];",
+                module.to_uppercase(),
+                names.join(", ")
+            )
+            .expect("writing to a string succeeds");
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("Cargo sets OUT_DIR"));
+    let abort_codes = format!(
+        "/// Plain `u64` abort codes of the audit-trail package as `(module, code, name)`, generated from its Move\n\
+         /// sources.\n\
+         const ABORT_CODES: &[(&str, u64, &str)] = &[\n{abort_codes}];\n"
+    );
+    fs::write(out_dir.join("abort_codes.rs"), abort_codes).expect("OUT_DIR is writable");
+    fs::write(out_dir.join("clever_errors.rs"), clever_errors).expect("OUT_DIR is writable");
+}
+
+/// The value of an error constant.
+enum ErrorValue {
+    /// A plain `u64` abort code.
+    Code(u64),
+    /// A `vector<u8>` message, raised as a clever error.
+    Message,
+}
+
+/// Parses a declaration like `const ERecordNotFound: vector<u8> = ...` into the name and value of the constant.
+fn error_constant(line: &str) -> Option<(&str, ErrorValue)> {
+    let (name, value) = line.trim().strip_prefix("const ")?.split_once(':')?;
+    let name = name.trim();
+    if !name.starts_with('E') {
+        return None;
+    }
+
+    let value = value.trim();
+    if value.starts_with("vector<u8>") {
+        return Some((name, ErrorValue::Message));
+    }
+    let code = value
+        .strip_prefix("u64")?
+        .trim()
+        .strip_prefix('=')?
+        .trim()
+        .trim_end_matches(';')
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some((name, ErrorValue::Code(code)))
 }
@@ -93,6 +93,7 @@ use secret_storage::Signer;
 use serde::de::DeserializeOwned;
 
 use crate::client::read_only::{AuditTrailClientReadOnly, PackageOverrides};
+use crate::client::retry::RetryPolicy;
 use crate::core::builder::AuditTrailBuilder;
 use crate::core::trail::{AuditTrailFull, AuditTrailHandle, AuditTrailReadOnly};
 use crate::error::Error;
@@ -337,6 +338,11 @@ where
     ) -> Result<T, Error> {
         self.read_client.execute_read_only_transaction(tx).await
     }
+
+    /// Returns the retry policy of the wrapped [`AuditTrailClientReadOnly`].
+    fn retry_policy(&self) -> RetryPolicy {
+        self.read_client.retry_policy()
+    }
 }
 
 impl<S> AuditTrailFull for AuditTrailClient<S> where S: Signer<IotaKeySignature> + OptionalSync {}
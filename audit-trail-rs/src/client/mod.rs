@@ -5,7 +5,8 @@
 //!
 //! [`AuditTrailClientReadOnly`] is the entry point for read-only inspection and typed trail handles.
 //! [`AuditTrailClient`] wraps a read-only client together with a signer so it can build write
-//! transactions through the shared transaction infrastructure.
+//! transactions through the shared transaction infrastructure. Failed RPC calls of both clients are retried according
+//! to a [`RetryPolicy`].
 
 use iota_interaction::IotaClientTrait;
 use product_common::network_name::NetworkName;
@@ -19,7 +20,10 @@ pub mod full_client;
 pub mod read_only;
 
 pub use full_client::*;
+/// Retry, timeout and backoff policy for RPC calls.
+pub use iota_rpc_retry as retry;
 pub use read_only::*;
+pub use retry::RetryPolicy;
 
 /// Resolves the network name reported by the given IOTA client.
 async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Error> {
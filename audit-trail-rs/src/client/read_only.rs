@@ -20,6 +20,7 @@ use product_common::network_name::NetworkName;
 use serde::de::DeserializeOwned;
 
 use super::network_id;
+use super::retry::RetryPolicy;
 use crate::core::trail::{AuditTrailHandle, AuditTrailReadOnly};
 use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
@@ -56,6 +57,8 @@ pub struct AuditTrailClientReadOnly {
     network: NetworkName,
     /// Raw chain identifier returned by the IOTA node.
     chain_id: String,
+    /// The policy for retrying failed reads.
+    retry_policy: RetryPolicy,
 }
 
 impl Deref for AuditTrailClientReadOnly {
@@ -93,6 +96,20 @@ impl AuditTrailClientReadOnly {
         &self.iota_client
     }
 
+    /// Returns the policy for retrying failed reads.
+    pub const fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Sets the policy for retrying failed reads.
+    ///
+    /// The policy applies to read-only transactions, linked-table traversals and the object lookups made while
+    /// building transactions, including those made through an [`crate::AuditTrailClient`] wrapping this client.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Returns a typed handle bound to a specific trail object ID.
     ///
     /// Creating the handle is cheap. Reads only happen when you call methods on the returned
@@ -134,6 +151,7 @@ impl AuditTrailClientReadOnly {
             tf_components_pkg_id: package_ids.tf_components_package_id,
             network,
             chain_id,
+            retry_policy: RetryPolicy::default(),
         })
     }
 
@@ -192,11 +210,21 @@ impl AuditTrailReadOnly for AuditTrailClientReadOnly {
         tx: ProgrammableTransaction,
     ) -> Result<T, Error> {
         let inspection_result = self
-            .iota_client
-            .read_api()
-            .dev_inspect_transaction_block(Address::ZERO, TransactionKind::Programmable(tx), None, None, None)
-            .await
-            .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to inspect transaction block: {err}")))?;
+            .retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .dev_inspect_transaction_block(
+                        Address::ZERO,
+                        TransactionKind::Programmable(tx.clone()),
+                        None,
+                        None,
+                        None,
+                    )
+                    .await
+                    .map_err(|err| Error::RpcError(format!("Failed to inspect transaction block: {err}")))
+            })
+            .await?;
 
         let execution_results = inspection_result
             .results
@@ -213,4 +241,8 @@ impl AuditTrailReadOnly for AuditTrailClientReadOnly {
 
         Ok(deserialized_output)
     }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
 }
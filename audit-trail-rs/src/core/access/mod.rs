@@ -76,13 +76,16 @@ impl<'a, C> TrailAccess<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(RevokeCapability::new(
-            self.trail_id,
-            owner,
-            capability_id,
-            capability_valid_until,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            RevokeCapability::new(
+                self.trail_id,
+                owner,
+                capability_id,
+                capability_valid_until,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Destroys a capability object.
@@ -95,12 +98,10 @@ impl<'a, C> TrailAccess<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(DestroyCapability::new(
-            self.trail_id,
-            owner,
-            capability_id,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            DestroyCapability::new(self.trail_id, owner, capability_id, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Destroys an initial-admin capability without presenting another authorization capability.
@@ -115,7 +116,10 @@ impl<'a, C> TrailAccess<'a, C> {
         C: AuditTrailFull + CoreClient<S>,
         S: Signer<IotaKeySignature> + OptionalSync,
     {
-        TransactionBuilder::new(DestroyInitialAdminCapability::new(self.trail_id, capability_id))
+        TransactionBuilder::new(
+            DestroyInitialAdminCapability::new(self.trail_id, capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Revokes an initial-admin capability by ID.
@@ -132,13 +136,16 @@ impl<'a, C> TrailAccess<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(RevokeInitialAdminCapability::new(
-            self.trail_id,
-            owner,
-            capability_id,
-            capability_valid_until,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            RevokeInitialAdminCapability::new(
+                self.trail_id,
+                owner,
+                capability_id,
+                capability_valid_until,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Removes expired entries from the revoked-capability denylist.
@@ -151,11 +158,10 @@ impl<'a, C> TrailAccess<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(CleanupRevokedCapabilities::new(
-            self.trail_id,
-            owner,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            CleanupRevokedCapabilities::new(self.trail_id, owner, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 }
 
@@ -209,14 +215,17 @@ impl<'a, C> RoleHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(CreateRole::new(
-            self.trail_id,
-            owner,
-            self.name.clone(),
-            permissions,
-            role_tags,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            CreateRole::new(
+                self.trail_id,
+                owner,
+                self.name.clone(),
+                permissions,
+                role_tags,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Issues a capability for this role using optional restrictions.
@@ -232,13 +241,16 @@ impl<'a, C> RoleHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(IssueCapability::new(
-            self.trail_id,
-            owner,
-            self.name.clone(),
-            options,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            IssueCapability::new(
+                self.trail_id,
+                owner,
+                self.name.clone(),
+                options,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Updates permissions and role-tag access rules for this role.
@@ -255,14 +267,17 @@ impl<'a, C> RoleHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(UpdateRole::new(
-            self.trail_id,
-            owner,
-            self.name.clone(),
-            permissions,
-            role_tags,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            UpdateRole::new(
+                self.trail_id,
+                owner,
+                self.name.clone(),
+                permissions,
+                role_tags,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Deletes this role.
@@ -274,11 +289,9 @@ impl<'a, C> RoleHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(DeleteRole::new(
-            self.trail_id,
-            owner,
-            self.name.clone(),
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            DeleteRole::new(self.trail_id, owner, self.name.clone(), self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 }
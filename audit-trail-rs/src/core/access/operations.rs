@@ -11,6 +11,7 @@ use iota_interaction::{OptionalSync, ident_str};
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::{trail as trail_reader, tx};
 use crate::core::types::{CapabilityIssueOptions, Permission, PermissionSet, RoleTags};
 use crate::error::Error;
@@ -28,6 +29,7 @@ impl AccessOps {
     /// `role_tags`, when present, are validated against the trail tag registry
     /// before PTB construction so the
     /// Rust side fails early with `Error::InvalidArgument` instead of relying on a later Move abort.
+    #[allow(clippy::too_many_arguments)]
     pub(super) async fn create_role<C>(
         client: &C,
         trail_id: ObjectId,
@@ -36,14 +38,16 @@ impl AccessOps {
         permissions: PermissionSet,
         role_tags: Option<RoleTags>,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        assert_role_tags_defined(client, trail_id, &role_tags).await?;
+        assert_role_tags_defined(client, trail_id, &role_tags, retry_policy).await?;
 
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::AddRoles,
@@ -81,6 +85,7 @@ impl AccessOps {
     ///
     /// The same tag-registry precondition as [`AccessOps::create_role`] applies because role-tag data is stored
     /// on-chain as part of the role definition.
+    #[allow(clippy::too_many_arguments)]
     pub(super) async fn update_role<C>(
         client: &C,
         trail_id: ObjectId,
@@ -89,14 +94,16 @@ impl AccessOps {
         permissions: PermissionSet,
         role_tags: Option<RoleTags>,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        assert_role_tags_defined(client, trail_id, &role_tags).await?;
+        assert_role_tags_defined(client, trail_id, &role_tags, retry_policy).await?;
 
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateRoles,
@@ -141,12 +148,14 @@ impl AccessOps {
         owner: Address,
         name: String,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::DeleteRoles,
@@ -173,12 +182,14 @@ impl AccessOps {
         role_name: String,
         options: CapabilityIssueOptions,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::AddCapabilities,
@@ -208,12 +219,14 @@ impl AccessOps {
         capability_id: ObjectId,
         capability_valid_until: Option<u64>,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::RevokeCapabilities,
@@ -240,14 +253,16 @@ impl AccessOps {
         owner: Address,
         capability_id: ObjectId,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let capability_ref = tx::get_object_ref_by_id(client, &capability_id).await?;
+        let capability_ref = tx::get_object_ref_by_id(client, &capability_id, retry_policy).await?;
 
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::RevokeCapabilities,
@@ -273,13 +288,15 @@ impl AccessOps {
         client: &C,
         trail_id: ObjectId,
         capability_id: ObjectId,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let cap_ref = tx::get_object_ref_by_id(client, &capability_id).await?;
+        let cap_ref = tx::get_object_ref_by_id(client, &capability_id, retry_policy).await?;
         tx::build_trail_transaction_with_cap_ref(
             client,
+            retry_policy,
             trail_id,
             cap_ref,
             "destroy_initial_admin_capability",
@@ -299,12 +316,14 @@ impl AccessOps {
         capability_id: ObjectId,
         capability_valid_until: Option<u64>,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::RevokeCapabilities,
@@ -330,12 +349,14 @@ impl AccessOps {
         trail_id: ObjectId,
         owner: Address,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::RevokeCapabilities,
@@ -354,7 +375,12 @@ impl AccessOps {
 ///
 /// Roles may only reference tags that are defined on the trail itself so later record-tag checks
 /// stay consistent with the registry stored on-chain.
-async fn assert_role_tags_defined<C>(client: &C, trail_id: ObjectId, role_tags: &Option<RoleTags>) -> Result<(), Error>
+async fn assert_role_tags_defined<C>(
+    client: &C,
+    trail_id: ObjectId,
+    role_tags: &Option<RoleTags>,
+    retry_policy: &RetryPolicy,
+) -> Result<(), Error>
 where
     C: CoreClientReadOnly + OptionalSync,
 {
@@ -362,7 +388,7 @@ where
         return Ok(());
     };
 
-    let trail = trail_reader::get_audit_trail(trail_id, client).await?;
+    let trail = trail_reader::get_audit_trail(trail_id, client, retry_policy).await?;
     let undefined_tags = role_tags
         .tags
         .iter()
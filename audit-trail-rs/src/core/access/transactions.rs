@@ -15,7 +15,8 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::AccessOps;
-use crate::core::internal::tx;
+use crate::client::retry::RetryPolicy;
+use crate::core::internal::{abort, tx};
 use crate::core::types::{
     CapabilityDestroyed, CapabilityIssueOptions, CapabilityIssued, CapabilityRevoked, Event, PermissionSet,
     RawRoleCreated, RawRoleDeleted, RawRoleUpdated, RevokedCapabilitiesCleanedUp, RoleCreated, RoleDeleted, RoleTags,
@@ -41,6 +42,7 @@ pub struct CreateRole {
     permissions: PermissionSet,
     role_tags: Option<RoleTags>,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -63,10 +65,18 @@ impl CreateRole {
             permissions,
             role_tags,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -79,6 +89,7 @@ impl CreateRole {
             self.permissions.clone(),
             self.role_tags.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -99,13 +110,15 @@ impl Transaction for CreateRole {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -139,6 +152,7 @@ pub struct UpdateRole {
     permissions: PermissionSet,
     role_tags: Option<RoleTags>,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -159,10 +173,18 @@ impl UpdateRole {
             permissions,
             role_tags,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -175,6 +197,7 @@ impl UpdateRole {
             self.permissions.clone(),
             self.role_tags.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -195,13 +218,15 @@ impl Transaction for UpdateRole {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -232,6 +257,7 @@ pub struct DeleteRole {
     owner: Address,
     name: String,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -243,10 +269,18 @@ impl DeleteRole {
             owner,
             name,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -257,6 +291,7 @@ impl DeleteRole {
             self.owner,
             self.name.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -277,13 +312,15 @@ impl Transaction for DeleteRole {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -315,6 +352,7 @@ pub struct IssueCapability {
     role: String,
     options: CapabilityIssueOptions,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -333,10 +371,18 @@ impl IssueCapability {
             role,
             options,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -348,6 +394,7 @@ impl IssueCapability {
             self.role.clone(),
             self.options.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -368,13 +415,15 @@ impl Transaction for IssueCapability {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -406,6 +455,7 @@ pub struct RevokeCapability {
     capability_id: ObjectId,
     capability_valid_until: Option<u64>,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -424,10 +474,18 @@ impl RevokeCapability {
             capability_id,
             capability_valid_until,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -439,6 +497,7 @@ impl RevokeCapability {
             self.capability_id,
             self.capability_valid_until,
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -459,13 +518,15 @@ impl Transaction for RevokeCapability {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -495,6 +556,7 @@ pub struct DestroyCapability {
     owner: Address,
     capability_id: ObjectId,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -511,10 +573,18 @@ impl DestroyCapability {
             owner,
             capability_id,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -525,6 +595,7 @@ impl DestroyCapability {
             self.owner,
             self.capability_id,
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -545,13 +616,15 @@ impl Transaction for DestroyCapability {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -585,6 +658,7 @@ impl Transaction for DestroyCapability {
 pub struct DestroyInitialAdminCapability {
     trail_id: ObjectId,
     capability_id: ObjectId,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -594,15 +668,23 @@ impl DestroyInitialAdminCapability {
         Self {
             trail_id,
             capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        AccessOps::destroy_initial_admin_capability(client, self.trail_id, self.capability_id).await
+        AccessOps::destroy_initial_admin_capability(client, self.trail_id, self.capability_id, &self.retry_policy).await
     }
 }
 
@@ -621,13 +703,15 @@ impl Transaction for DestroyInitialAdminCapability {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -665,6 +749,7 @@ pub struct RevokeInitialAdminCapability {
     capability_id: ObjectId,
     capability_valid_until: Option<u64>,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -683,10 +768,18 @@ impl RevokeInitialAdminCapability {
             capability_id,
             capability_valid_until,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -698,6 +791,7 @@ impl RevokeInitialAdminCapability {
             self.capability_id,
             self.capability_valid_until,
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -718,13 +812,15 @@ impl Transaction for RevokeInitialAdminCapability {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -758,6 +854,7 @@ pub struct CleanupRevokedCapabilities {
     trail_id: ObjectId,
     owner: Address,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -768,15 +865,30 @@ impl CleanupRevokedCapabilities {
             trail_id,
             owner,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        AccessOps::cleanup_revoked_capabilities(client, self.trail_id, self.owner, self.selected_capability_id).await
+        AccessOps::cleanup_revoked_capabilities(
+            client,
+            self.trail_id,
+            self.owner,
+            self.selected_capability_id,
+            &self.retry_policy,
+        )
+        .await
     }
 }
 
@@ -795,13 +907,15 @@ impl Transaction for CleanupRevokedCapabilities {
 
     async fn apply_with_events<C>(
         self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
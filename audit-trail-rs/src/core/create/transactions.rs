@@ -10,8 +10,9 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::{CreateOps, CreateTrailArgs};
+use crate::client::retry::RetryPolicy;
 use crate::core::builder::AuditTrailBuilder;
-use crate::core::internal::{trail as trail_reader, tx};
+use crate::core::internal::{abort, trail as trail_reader, tx};
 use crate::core::types::{AuditTrailCreated, Event, OnChainAuditTrail};
 use crate::error::Error;
 
@@ -29,6 +30,9 @@ pub struct TrailCreated {
 impl TrailCreated {
     /// Loads the newly created trail object from the ledger.
     ///
+    /// `client` may be any read-only client, so failed lookups are retried according to the default
+    /// [`RetryPolicy`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the trail cannot be fetched or deserialized.
@@ -36,7 +40,7 @@ impl TrailCreated {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        trail_reader::get_audit_trail(self.trail_id, client).await
+        trail_reader::get_audit_trail(self.trail_id, client, &RetryPolicy::default()).await
     }
 }
 
@@ -116,13 +120,15 @@ impl Transaction for CreateTrail {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
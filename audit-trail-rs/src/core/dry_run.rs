@@ -0,0 +1,111 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Dry runs of audit-trail transactions.
+//!
+//! [`DryRun::dry_run`] executes the programmable transaction of a [`TransactionBuilder`] in a dev-inspect with the
+//! sender of the client, without committing it. The result carries the estimated gas costs; a transaction that
+//! would abort fails with the [`Error`] of its abort, e.g. [`Error::TrailWriteLocked`] or [`Error::RecordLocked`],
+//! instead of an opaque RPC error.
+//!
+//! ```rust,ignore
+//! # use audit_trails::core::dry_run::DryRun;
+//! let delete = client.trail(trail_id).records().delete_records_batch(100);
+//! let estimate = delete.dry_run(&client).await?;
+//! delete.with_gas_budget(estimate.gas_budget()).build_and_execute(&client).await?;
+//! ```
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI;
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSync};
+use iota_sdk_types::TransactionKind;
+use product_common::core_client::CoreClient;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+
+use crate::core::internal::abort;
+use crate::error::Error;
+
+/// Estimated gas costs of a dry-run transaction, in NANOS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DryRunResult {
+    /// Cost of the computation.
+    pub computation_cost: u64,
+    /// Cost of storing the created and mutated objects.
+    pub storage_cost: u64,
+    /// Rebate for the storage of the deleted and mutated objects.
+    pub storage_rebate: u64,
+    /// Part of the storage rebate that is not refunded.
+    pub non_refundable_storage_fee: u64,
+}
+
+impl DryRunResult {
+    /// Returns the gas budget required to submit the transaction, i.e. the computation and storage costs before
+    /// the storage rebate.
+    pub fn gas_budget(&self) -> u64 {
+        self.computation_cost + self.storage_cost
+    }
+
+    /// Returns the net amount of gas the transaction consumes after the storage rebate; negative if the rebate
+    /// exceeds the costs, e.g. when deleting records.
+    pub fn net_gas_usage(&self) -> i64 {
+        self.computation_cost as i64 + self.storage_cost as i64 - self.storage_rebate as i64
+    }
+}
+
+/// Dry-runs a transaction before it is submitted.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait DryRun {
+    /// Executes the transaction in a dev-inspect with the sender of `client` and returns its estimated gas costs.
+    ///
+    /// The programmable transaction is cached, so submitting the transaction afterwards does not build it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the abort if the transaction would abort, e.g. [`Error::TrailWriteLocked`] or
+    /// [`Error::MoveAbort`] for aborts without a dedicated variant, and [`Error::TransactionUnexpectedResponse`] if
+    /// it would fail otherwise.
+    async fn dry_run<C, S>(&self, client: &C) -> Result<DryRunResult, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> DryRun for TransactionBuilder<Tx>
+where
+    Tx: Transaction<Error = Error> + OptionalSync,
+{
+    async fn dry_run<C, S>(&self, client: &C) -> Result<DryRunResult, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let tx = self.transaction().build_programmable_transaction(client).await?;
+        let inspection_result = client
+            .client_adapter()
+            .read_api()
+            .dev_inspect_transaction_block(
+                client.sender_address(),
+                TransactionKind::Programmable(tx),
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to inspect transaction block: {err}")))?;
+
+        let effects = inspection_result.effects;
+        abort::check_execution_status(&effects, client).await?;
+
+        let gas = effects.gas_cost_summary();
+        Ok(DryRunResult {
+            computation_cost: gas.computation_cost,
+            storage_cost: gas.storage_cost,
+            storage_rebate: gas.storage_rebate,
+            non_refundable_storage_fee: gas.non_refundable_storage_fee,
+        })
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of Move aborts from the execution errors reported by IOTA nodes.
+//!
+//! The `main` module of the audit-trail package raises clever errors: their abort code encodes the index of the
+//! error constant's name in the identifier table of the module. The name is resolved from the bytecode of the
+//! module, so it stays correct across package upgrades. Plain `u64` abort codes are resolved from [`ABORT_CODES`].
+//! An abort is attributed to the audit-trail package only if the address of the aborting module is one of the
+//! versions of the package. Aborts of the audit-trail package are mapped to dedicated [`Error`] variants; all other
+//! aborts, e.g. permission checks of dependencies or aborts of other packages with colliding module names, are
+//! returned as [`Error::MoveAbort`].
+
+use std::str::FromStr;
+
+use iota_interaction::rpc_types::{
+    IotaData as _, IotaExecutionStatus, IotaObjectDataOptions, IotaTransactionBlockEffects,
+    IotaTransactionBlockEffectsAPI,
+};
+use iota_interaction::{IotaClientTrait, OptionalSync};
+use iota_sdk_types::ObjectId;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::error::{Error, MoveAbort};
+use crate::package;
+
+include!(concat!(env!("OUT_DIR"), "/abort_codes.rs"));
+
+/// Returns the name of the plain abort constant `code` of the audit-trail package module `module`.
+pub(crate) fn abort_name(module: &str, code: u64) -> Option<&'static str> {
+    ABORT_CODES
+        .iter()
+        .find(|(abort_module, abort_code, _)| *abort_module == module && *abort_code == code)
+        .map(|(_, _, name)| *name)
+}
+
+/// Returns the value of the plain abort constant `name` of the audit-trail package module `module`.
+#[cfg(all(any(test, feature = "test-utils"), not(target_arch = "wasm32")))]
+pub(crate) fn abort_code(module: &str, name: &str) -> Option<u64> {
+    ABORT_CODES
+        .iter()
+        .find(|(abort_module, _, abort_name)| *abort_module == module && *abort_name == name)
+        .map(|(_, code, _)| *code)
+}
+
+/// Marker bit of clever error codes.
+const CLEVER_ERROR_TAG: u64 = 0x8000_0000_0000_0000;
+/// Index value of clever errors that do not reference an identifier.
+const NO_IDENTIFIER: u16 = u16::MAX;
+
+/// Magic bytes of Move bytecode.
+const BYTECODE_MAGIC: [u8; 4] = [0xA1, 0x1C, 0xEB, 0x0B];
+/// Kind of the identifier table of Move bytecode.
+const IDENTIFIERS_TABLE: u8 = 0x7;
+
+/// An abort parsed from an execution error, before its name is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AbortLocation {
+    pub(crate) address: Option<ObjectId>,
+    pub(crate) module: String,
+    pub(crate) function: Option<String>,
+    pub(crate) code: u64,
+}
+
+/// Decodes the [`MoveAbort`] of an execution error, resolving the names of clever errors from the bytecode of the
+/// aborting module.
+///
+/// Returns the abort together with whether it was raised by one of `package_versions`; plain abort codes are only
+/// resolved for aborts of the audit-trail package. Returns `None` if `error` is not a Move abort.
+pub(crate) async fn decode_move_abort<C>(
+    client: &C,
+    error: &str,
+    package_versions: &[ObjectId],
+) -> Option<(MoveAbort, bool)>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let location = parse_abort_location(error)?;
+    let is_package_abort = location
+        .address
+        .is_some_and(|address| package_versions.contains(&address));
+    let name = match clever_identifier_index(location.code) {
+        Some(index) => module_identifiers(client, &location, is_package_abort)
+            .await
+            .and_then(|identifiers| identifiers.into_iter().nth(index as usize)),
+        None if is_package_abort => abort_name(&location.module, location.code).map(str::to_string),
+        None => None,
+    };
+
+    Some((
+        MoveAbort {
+            module: location.module,
+            function: location.function,
+            code: location.code,
+            name,
+        },
+        is_package_abort,
+    ))
+}
+
+/// Returns an error if the transaction of `effects` failed on-chain, decoding its abort into an [`Error`].
+pub(crate) async fn check_execution_status<C>(effects: &IotaTransactionBlockEffects, client: &C) -> Result<(), Error>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    match effects.status() {
+        IotaExecutionStatus::Failure { error } => Err(execution_error(client, error).await),
+        IotaExecutionStatus::Success => Ok(()),
+    }
+}
+
+/// Converts the execution error of a failed transaction into an [`Error`].
+pub(crate) async fn execution_error<C>(client: &C, error: &str) -> Error
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let package_versions = package::package_versions(client).await;
+    match decode_move_abort(client, error, &package_versions).await {
+        Some((abort, true)) => abort_error(abort),
+        Some((abort, false)) => Error::MoveAbort(abort),
+        None => Error::TransactionUnexpectedResponse(format!("transaction failed: {error}")),
+    }
+}
+
+/// Maps a decoded [`MoveAbort`] of the audit-trail package to the dedicated [`Error`] variant of its constant, or to [`Error::MoveAbort`] if
+/// there is none.
+pub(crate) fn abort_error(abort: MoveAbort) -> Error {
+    match (abort.module.as_str(), abort.name.as_deref()) {
+        ("main", Some("ERecordNotFound")) => Error::RecordNotFound,
+        ("main", Some("ERecordLocked")) => Error::RecordLocked,
+        ("main", Some("ERecordAlreadyReplaced")) => Error::RecordAlreadyReplaced,
+        ("main", Some("ETrailNotEmpty")) => Error::TrailNotEmpty,
+        ("main", Some("ETrailDeleteLocked")) => Error::TrailDeleteLocked,
+        ("main", Some("ETrailWriteLocked")) => Error::TrailWriteLocked,
+        ("main", Some("EPackageVersionMismatch")) => Error::PackageVersionMismatch,
+        ("main", Some("ERecordTagNotAllowed")) => Error::RecordTagNotAllowed,
+        ("main", Some("ERecordTagNotDefined")) => Error::RecordTagNotDefined,
+        ("main", Some("ERecordTagAlreadyDefined")) => Error::RecordTagAlreadyDefined,
+        ("main", Some("ERecordTagInUse")) => Error::RecordTagInUse,
+        ("locking", Some("EUntilDestroyedNotSupportedForDeleteTrail")) => {
+            Error::UntilDestroyedNotSupportedForDeleteTrail
+        }
+        ("locking", Some("ECountWindowMustBePositive")) => Error::CountWindowMustBePositive,
+        _ => Error::MoveAbort(abort),
+    }
+}
+
+/// Parses the location and code of an execution error of the form
+/// `MoveAbort(MoveLocation { module: ModuleId { address: <address>, name: Identifier("<module>") }, function: ..,
+/// instruction: .., function_name: Some("<function>") }, <code>) in command <index>`.
+pub(crate) fn parse_abort_location(error: &str) -> Option<AbortLocation> {
+    let abort = &error[error.find("MoveAbort(")?..];
+    let abort = abort.find(") in command").map_or(abort, |end| &abort[..end]);
+
+    let address = between(abort, "address: ", ",").and_then(|address| {
+        let address = address.trim().trim_start_matches("0x");
+        ObjectId::from_str(&format!("0x{address}")).ok()
+    });
+    let module = between(abort, "name: Identifier(\"", "\")")?.to_string();
+    let function = between(abort, "function_name: Some(\"", "\")").map(str::to_string);
+    let code = abort[abort.rfind("}, ")? + 3..]
+        .trim_end_matches(')')
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(AbortLocation {
+        address,
+        module,
+        function,
+        code,
+    })
+}
+
+/// Returns the identifier index of a clever error code, or `None` for plain abort codes.
+///
+/// Clever error codes are laid out as `| tag (1 bit) | reserved (15 bits) | line (16 bits) | identifier index
+/// (16 bits) | constant index (16 bits) |`.
+pub(crate) fn clever_identifier_index(code: u64) -> Option<u16> {
+    if code & CLEVER_ERROR_TAG == 0 {
+        return None;
+    }
+    let index = ((code >> 16) & 0xFFFF) as u16;
+
+    (index != NO_IDENTIFIER).then_some(index)
+}
+
+/// Loads the identifier table of the aborting module.
+///
+/// Modules of the audit-trail package are looked up in the package configured on `client` first, i.e. the current
+/// version of the package, and in the package reported by the node otherwise. Modules of other packages, e.g.
+/// dependencies, are only looked up in the package reported by the node.
+async fn module_identifiers<C>(client: &C, location: &AbortLocation, is_package_abort: bool) -> Option<Vec<String>>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let mut package_ids = Vec::new();
+    if is_package_abort {
+        package_ids.push(client.package_id());
+    }
+    if let Some(address) = location.address.filter(|address| !package_ids.contains(address)) {
+        package_ids.push(address);
+    }
+
+    for package_id in package_ids {
+        let Ok(response) = client
+            .client_adapter()
+            .read_api()
+            .get_object_with_options(package_id, IotaObjectDataOptions::bcs_lossless())
+            .await
+        else {
+            continue;
+        };
+        let Some(package) = response
+            .data
+            .and_then(|data| data.bcs)
+            .and_then(|bcs| bcs.try_into_package())
+        else {
+            continue;
+        };
+        if let Some(bytecode) = package.module_map.get(&location.module) {
+            return parse_identifiers(bytecode);
+        }
+    }
+
+    None
+}
+
+/// Parses the identifier table of serialized Move bytecode.
+pub(crate) fn parse_identifiers(bytecode: &[u8]) -> Option<Vec<String>> {
+    let mut cursor = Cursor {
+        bytes: bytecode,
+        pos: 0,
+    };
+    if cursor.take(BYTECODE_MAGIC.len())? != BYTECODE_MAGIC {
+        return None;
+    }
+    // Version
+    cursor.take(4)?;
+
+    let table_count = cursor.uleb128()?;
+    let mut identifiers_table = None;
+    for _ in 0..table_count {
+        let kind = cursor.take(1)?[0];
+        let offset = cursor.uleb128()? as usize;
+        let len = cursor.uleb128()? as usize;
+        if kind == IDENTIFIERS_TABLE {
+            identifiers_table = Some((offset, len));
+        }
+    }
+    let (offset, len) = identifiers_table?;
+    let start = cursor.pos.checked_add(offset)?;
+    let table = bytecode.get(start..start.checked_add(len)?)?;
+
+    let mut cursor = Cursor { bytes: table, pos: 0 };
+    let mut identifiers = Vec::new();
+    while cursor.pos < table.len() {
+        let len = cursor.uleb128()? as usize;
+        identifiers.push(String::from_utf8(cursor.take(len)?.to_vec()).ok()?);
+    }
+
+    Some(identifiers)
+}
+
+/// Returns the part of `text` between the first `start` and the following `end`.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+
+    Some(&text[from..to])
+}
+
+/// Minimal reader over serialized Move bytecode.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.bytes.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+
+        Some(bytes)
+    }
+
+    fn uleb128(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABORT: &str = "MoveAbort(MoveLocation { module: ModuleId { address: \
+                         1b3f4c0e0d8a6c7d2b6e1c3a9f8e7d6c5b4a39281706f5e4d3c2b1a098765432, name: \
+                         Identifier(\"main\") }, function: 21, instruction: 17, function_name: \
+                         Some(\"delete_record\") }, 9223374966022602753) in command 0";
+
+    fn bytecode(identifiers: &[&str]) -> Vec<u8> {
+        let table: Vec<u8> = identifiers
+            .iter()
+            .flat_map(|identifier| std::iter::once(identifier.len() as u8).chain(identifier.bytes()))
+            .collect();
+
+        let mut bytecode = BYTECODE_MAGIC.to_vec();
+        bytecode.extend([7, 0, 0, 5]);
+        // Two tables: an empty signature table and the identifier table
+        bytecode.extend([2, 0x5, 0, 0, IDENTIFIERS_TABLE, 0, table.len() as u8]);
+        bytecode.extend(table);
+        bytecode
+    }
+
+    #[test]
+    fn parse_abort_location_reads_module_function_and_code() {
+        let location = parse_abort_location(ABORT).unwrap();
+
+        assert_eq!(location.module, "main");
+        assert_eq!(location.function.as_deref(), Some("delete_record"));
+        assert_eq!(location.code, 9_223_374_966_022_602_753);
+        assert!(location.address.is_some());
+        assert_eq!(parse_abort_location("InsufficientGas"), None);
+    }
+
+    #[test]
+    fn clever_identifier_index_decodes_tagged_codes() {
+        // line 682, identifier 2, constant 1
+        let code = CLEVER_ERROR_TAG | (682 << 32) | (2 << 16) | 1;
+
+        assert_eq!(clever_identifier_index(code), Some(2));
+        assert_eq!(clever_identifier_index(CLEVER_ERROR_TAG | (0xFFFF << 16)), None);
+        assert_eq!(clever_identifier_index(1), None);
+    }
+
+    #[test]
+    fn abort_error_maps_package_constants_to_variants() {
+        let abort = |module: &str, name: Option<&str>| MoveAbort {
+            module: module.to_string(),
+            function: None,
+            code: 0,
+            name: name.map(str::to_string),
+        };
+
+        assert!(matches!(
+            abort_error(abort("main", Some("ERecordLocked"))),
+            Error::RecordLocked
+        ));
+        assert!(matches!(
+            abort_error(abort("main", Some("ETrailNotEmpty"))),
+            Error::TrailNotEmpty
+        ));
+        assert!(matches!(
+            abort_error(abort("locking", Some("ECountWindowMustBePositive"))),
+            Error::CountWindowMustBePositive
+        ));
+        assert!(matches!(
+            abort_error(abort("role_map", Some("ERecordLocked"))),
+            Error::MoveAbort(_)
+        ));
+        assert!(matches!(abort_error(abort("main", None)), Error::MoveAbort(_)));
+    }
+
+    #[test]
+    fn parse_identifiers_reads_identifier_table() {
+        let identifiers = parse_identifiers(&bytecode(&["main", "ERecordNotFound", "ERecordLocked"])).unwrap();
+
+        assert_eq!(identifiers, ["main", "ERecordNotFound", "ERecordLocked"]);
+        assert_eq!(parse_identifiers(b"not move bytecode"), None);
+    }
+}
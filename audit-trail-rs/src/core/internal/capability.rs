@@ -16,19 +16,22 @@ use iota_sdk_types::{Address, ObjectId, StructTag};
 use product_common::core_client::CoreClientReadOnly;
 
 use super::{linked_table, tx};
+use crate::client::retry::RetryPolicy;
 use crate::core::types::{Capability, OnChainAuditTrail, Permission};
 use crate::error::Error;
 
 /// Finds an owned capability object that grants `permission` for `trail_id` and returns its object
 /// reference.
 ///
-/// The lookup is restricted to roles on `trail` that include the requested permission.
+/// The lookup is restricted to roles on `trail` that include the requested permission. Failed RPC calls are retried
+/// according to `retry_policy`.
 pub(crate) async fn find_capable_cap<C>(
     client: &C,
     owner: Address,
     trail_id: ObjectId,
     trail: &OnChainAuditTrail,
     permission: Permission,
+    retry_policy: &RetryPolicy,
 ) -> Result<ObjectRef, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -41,7 +44,7 @@ where
         .map(|(name, _)| name.clone())
         .collect();
 
-    let cap = find_owned_capability(client, owner, trail, |cap| {
+    let cap = find_owned_capability(client, owner, trail, retry_policy, |cap| {
         cap.matches_target_and_role(trail_id, &valid_roles)
     })
     .await?
@@ -53,24 +56,25 @@ where
     })?;
 
     let object_id = *cap.id.object_id();
-    tx::get_object_ref_by_id(client, &object_id).await
+    tx::get_object_ref_by_id(client, &object_id, retry_policy).await
 }
 
 /// Searches the owner's capability objects and returns the first one matching `predicate`.
 ///
 /// Revoked capabilities are filtered out before the predicate is applied to the remaining
-/// candidates.
+/// candidates. Failed RPC calls are retried according to `retry_policy`.
 pub(crate) async fn find_owned_capability<C, P>(
     client: &C,
     owner: Address,
     trail: &OnChainAuditTrail,
+    retry_policy: &RetryPolicy,
     predicate: P,
 ) -> Result<Option<Capability>, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
     P: Fn(&Capability) -> bool + Send,
 {
-    let revoked_capability_ids = revoked_capability_ids(client, trail).await?;
+    let revoked_capability_ids = revoked_capability_ids(client, trail, retry_policy).await?;
     let now_ms = now_ms();
     let tf_components_package_id = client
         .tf_components_package_id()
@@ -86,12 +90,16 @@ where
 
     let mut cursor = None;
     loop {
-        let mut page = client
-            .client_adapter()
-            .read_api()
-            .get_owned_objects(owner, Some(query.clone()), cursor, Some(25))
-            .await
-            .map_err(|e| Error::RpcError(e.to_string()))?;
+        let mut page = retry_policy
+            .run(|| async {
+                client
+                    .client_adapter()
+                    .read_api()
+                    .get_owned_objects(owner, Some(query.clone()), cursor, Some(25))
+                    .await
+                    .map_err(|e| Error::RpcError(e.to_string()))
+            })
+            .await?;
 
         let maybe_cap = std::mem::take(&mut page.data)
             .into_iter()
@@ -121,7 +129,11 @@ where
 ///
 /// The traversal validates that the linked-table shape is acyclic and that the number of visited
 /// entries matches the size recorded on-chain.
-async fn revoked_capability_ids<C>(client: &C, trail: &OnChainAuditTrail) -> Result<HashSet<ObjectId>, Error>
+async fn revoked_capability_ids<C>(
+    client: &C,
+    trail: &OnChainAuditTrail,
+    retry_policy: &RetryPolicy,
+) -> Result<HashSet<ObjectId>, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
 {
@@ -145,6 +157,7 @@ where
                 type_: ID::get_type_tag(),
                 value: serde_json::Value::String(Address::from(key).to_string()),
             },
+            retry_policy,
         )
         .await?;
         cursor = node.next;
@@ -192,6 +205,7 @@ pub(crate) async fn find_capable_cap_for_tags<'a, C, I>(
     trail: &OnChainAuditTrail,
     permission: Permission,
     tags: I,
+    retry_policy: &RetryPolicy,
 ) -> Result<ObjectRef, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -200,7 +214,7 @@ where
     let tags = tags.into_iter().collect::<Vec<_>>();
 
     if tags.is_empty() {
-        return find_capable_cap(client, owner, trail_id, trail, permission).await;
+        return find_capable_cap(client, owner, trail_id, trail, permission, retry_policy).await;
     }
 
     let valid_roles = trail
@@ -216,7 +230,7 @@ where
         .map(|(name, _)| name.clone())
         .collect::<HashSet<_>>();
 
-    let cap = find_owned_capability(client, owner, trail, |cap| {
+    let cap = find_owned_capability(client, owner, trail, retry_policy, |cap| {
         cap.target_key == trail_id && valid_roles.contains(&cap.role)
     })
     .await?
@@ -228,7 +242,7 @@ where
     })?;
 
     let object_id = *cap.id.object_id();
-    tx::get_object_ref_by_id(client, &object_id).await
+    tx::get_object_ref_by_id(client, &object_id, retry_policy).await
 }
 
 /// Returns the current wall-clock time as milliseconds since the Unix epoch.
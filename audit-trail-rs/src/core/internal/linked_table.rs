@@ -11,16 +11,18 @@ use iota_sdk_types::ObjectId;
 use product_common::core_client::CoreClientReadOnly;
 use serde::de::DeserializeOwned;
 
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// Fetches and decodes a single linked-table node stored as a dynamic field under `table_id`.
 ///
 /// The caller provides the fully encoded Move field name so this helper can stay generic over the
-/// linked-table key and value types.
+/// linked-table key and value types. Failed lookups are retried according to `retry_policy`.
 pub(crate) async fn fetch_node<C, K, V>(
     client: &C,
     table_id: ObjectId,
     name: DynamicFieldName,
+    retry_policy: &RetryPolicy,
 ) -> Result<LinkedTableNode<K, V>, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -28,12 +30,16 @@ where
     V: DeserializeOwned,
 {
     let name_display = name.to_string();
-    let data = client
-        .client_adapter()
-        .read_api()
-        .get_dynamic_field_object_v2(table_id, name, Some(IotaObjectDataOptions::bcs_lossless()))
-        .await
-        .map_err(|err| Error::RpcError(err.to_string()))?
+    let data = retry_policy
+        .run(|| async {
+            client
+                .client_adapter()
+                .read_api()
+                .get_dynamic_field_object_v2(table_id, name.clone(), Some(IotaObjectDataOptions::bcs_lossless()))
+                .await
+                .map_err(|err| Error::RpcError(err.to_string()))
+        })
+        .await?
         .data
         .ok_or_else(|| {
             Error::UnexpectedApiResponse(format!(
@@ -4,6 +4,8 @@
 //! Internal helpers used to bridge public audit-trail APIs to low-level IOTA object access and
 //! programmable transaction construction.
 
+/// Move abort decoding for execution errors.
+pub(crate) mod abort;
 /// Capability lookup helpers for trail-scoped permission checks.
 pub(crate) mod capability;
 /// Linked-table decoding helpers for traversing on-chain Move collections.
@@ -8,20 +8,31 @@ use iota_interaction::{IotaClientTrait, OptionalSync};
 use iota_sdk_types::ObjectId;
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::types::OnChainAuditTrail;
 use crate::error::Error;
 
 /// Loads the shared audit-trail object and decodes it into [`OnChainAuditTrail`].
-pub(crate) async fn get_audit_trail<C>(trail_id: ObjectId, client: &C) -> Result<OnChainAuditTrail, Error>
+///
+/// Failed lookups are retried according to `retry_policy`.
+pub(crate) async fn get_audit_trail<C>(
+    trail_id: ObjectId,
+    client: &C,
+    retry_policy: &RetryPolicy,
+) -> Result<OnChainAuditTrail, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
 {
-    let data = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(trail_id, IotaObjectDataOptions::bcs_lossless())
-        .await
-        .map_err(|e| Error::UnexpectedApiResponse(format!("failed to fetch trail {} object; {e}", trail_id)))?
+    let data = retry_policy
+        .run(|| async {
+            client
+                .client_adapter()
+                .read_api()
+                .get_object_with_options(trail_id, IotaObjectDataOptions::bcs_lossless())
+                .await
+                .map_err(|e| Error::RpcError(format!("failed to fetch trail {} object; {e}", trail_id)))
+        })
+        .await?
         .data
         .ok_or_else(|| Error::UnexpectedApiResponse(format!("trail {} data not found", trail_id)))?;
 
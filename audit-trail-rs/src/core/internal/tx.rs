@@ -6,7 +6,7 @@
 use std::str::FromStr;
 
 use iota_interaction::rpc_types::{
-    IotaObjectDataOptions, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI,
+    IotaObjectDataOptions, IotaObjectResponse, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI,
     IotaTransactionBlockResponseOptions,
 };
 use iota_interaction::types::base_types::ObjectRef;
@@ -21,12 +21,13 @@ use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use serde::Serialize;
 
-use super::{capability, trail as trail_reader};
+use super::{abort, capability, trail as trail_reader};
+use crate::client::retry::RetryPolicy;
 use crate::core::types::Permission;
 use crate::error::Error;
 
 /// Applies a transaction whose output is decoded from events, fetching those events from the
-/// transaction digest in `effects`.
+/// transaction digest in `effects`. Failed transactions return the [`Error`] of their abort instead.
 pub(crate) async fn apply_with_events<T, C>(
     tx: T,
     effects: &mut IotaTransactionBlockEffects,
@@ -36,6 +37,8 @@ where
     T: Transaction<Error = Error> + OptionalSend,
     C: CoreClientReadOnly + OptionalSync,
 {
+    abort::check_execution_status(effects, client).await?;
+
     let response = client
         .client_adapter()
         .read_api()
@@ -101,9 +104,11 @@ pub(crate) fn option_to_move(
 }
 
 /// Builds a writable trail transaction after resolving both the trail object and a matching
-/// capability for `owner`.
+/// capability for `owner`. Failed lookups are retried according to `retry_policy`.
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn build_trail_transaction<C, F>(
     client: &C,
+    retry_policy: &RetryPolicy,
     trail_id: ObjectId,
     owner: Address,
     permission: Permission,
@@ -116,18 +121,19 @@ where
     C: CoreClientReadOnly + OptionalSync,
 {
     let cap_ref = if let Some(capability_id) = selected_capability_id {
-        get_object_ref_by_id(client, &capability_id).await?
+        get_object_ref_by_id(client, &capability_id, retry_policy).await?
     } else {
-        let trail = trail_reader::get_audit_trail(trail_id, client).await?;
-        capability::find_capable_cap(client, owner, trail_id, &trail, permission).await?
+        let trail = trail_reader::get_audit_trail(trail_id, client, retry_policy).await?;
+        capability::find_capable_cap(client, owner, trail_id, &trail, permission, retry_policy).await?
     };
-    build_trail_transaction_with_cap_ref(client, trail_id, cap_ref, method, additional_args).await
+    build_trail_transaction_with_cap_ref(client, retry_policy, trail_id, cap_ref, method, additional_args).await
 }
 
 /// Builds a writable trail transaction when the caller already has the capability object
-/// reference.
+/// reference. Failed lookups are retried according to `retry_policy`.
 pub(crate) async fn build_trail_transaction_with_cap_ref<C, F>(
     client: &C,
+    retry_policy: &RetryPolicy,
     trail_id: ObjectId,
     cap_ref: ObjectRef,
     method: impl AsRef<str>,
@@ -139,9 +145,9 @@ where
 {
     let mut ptb = ProgrammableTransactionBuilder::new();
 
-    let type_tag = get_type_tag(client, &trail_id).await?;
+    let type_tag = get_type_tag(client, &trail_id, retry_policy).await?;
     let tag = vec![type_tag.clone()];
-    let trail_arg = get_shared_object_arg(client, &trail_id, true).await?;
+    let trail_arg = get_shared_object_arg(client, &trail_id, true, retry_policy).await?;
 
     let mut args = vec![
         ptb.obj(trail_arg)
@@ -166,9 +172,11 @@ where
     Ok(ptb.finish())
 }
 
-/// Builds a read-only trail transaction that borrows the shared trail object immutably.
+/// Builds a read-only trail transaction that borrows the shared trail object immutably. Failed
+/// lookups are retried according to `retry_policy`.
 pub(crate) async fn build_read_only_transaction<C, F>(
     client: &C,
+    retry_policy: &RetryPolicy,
     trail_id: ObjectId,
     method: impl AsRef<str>,
     additional_args: F,
@@ -179,8 +187,8 @@ where
 {
     let mut ptb = ProgrammableTransactionBuilder::new();
 
-    let tag = vec![get_type_tag(client, &trail_id).await?];
-    let trail_arg = get_shared_object_arg(client, &trail_id, false).await?;
+    let tag = vec![get_type_tag(client, &trail_id, retry_policy).await?];
+    let trail_arg = get_shared_object_arg(client, &trail_id, false, retry_policy).await?;
 
     let mut args = vec![
         ptb.obj(trail_arg)
@@ -206,17 +214,23 @@ where
 /// Extracts the generic record payload type from the on-chain trail object type.
 ///
 /// Audit-trail Move entry points are generic over the record payload type, so transaction builders
-/// need this type tag to invoke the correct specialization.
-pub(crate) async fn get_type_tag<C>(client: &C, object_id: &ObjectId) -> Result<TypeTag, Error>
+/// need this type tag to invoke the correct specialization. The lookup is retried according to
+/// `retry_policy`.
+pub(crate) async fn get_type_tag<C>(
+    client: &C,
+    object_id: &ObjectId,
+    retry_policy: &RetryPolicy,
+) -> Result<TypeTag, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
 {
-    let object_response = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*object_id, IotaObjectDataOptions::new().with_type())
-        .await
-        .map_err(|err| Error::FailedToParseTag(format!("Failed to get object: {err}")))?;
+    let object_response = get_object(
+        client,
+        object_id,
+        IotaObjectDataOptions::new().with_type(),
+        retry_policy,
+    )
+    .await?;
 
     let object_data = object_response
         .data
@@ -244,17 +258,39 @@ fn parse_type(full_type: &str) -> Result<String, Error> {
     }
 }
 
-/// Fetches the current object reference for `object_id`.
+/// Fetches `object_id` with `options`, retrying failed RPC calls according to `retry_policy`.
+async fn get_object(
+    client: &impl CoreClientReadOnly,
+    object_id: &ObjectId,
+    options: IotaObjectDataOptions,
+    retry_policy: &RetryPolicy,
+) -> Result<IotaObjectResponse, Error> {
+    retry_policy
+        .run(|| async {
+            client
+                .client_adapter()
+                .read_api()
+                .get_object_with_options(*object_id, options.clone())
+                .await
+                .map_err(|err| Error::RpcError(format!("Failed to get object {object_id}: {err}")))
+        })
+        .await
+}
+
+/// Fetches the current object reference for `object_id`, retrying the lookup according to
+/// `retry_policy`.
 pub(crate) async fn get_object_ref_by_id(
     client: &impl CoreClientReadOnly,
     object_id: &ObjectId,
+    retry_policy: &RetryPolicy,
 ) -> Result<ObjectRef, Error> {
-    let res = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*object_id, IotaObjectDataOptions::new().with_content())
-        .await
-        .map_err(|err| Error::GenericError(format!("Failed to get object: {err}")))?;
+    let res = get_object(
+        client,
+        object_id,
+        IotaObjectDataOptions::new().with_content(),
+        retry_policy,
+    )
+    .await?;
 
     let Some(data) = res.data else {
         return Err(Error::InvalidArgument("no data found".to_string()));
@@ -266,18 +302,20 @@ pub(crate) async fn get_object_ref_by_id(
 /// Resolves a shared object argument for use in a programmable transaction.
 ///
 /// This validates that the fetched object is shared and returns the appropriate mutability flag for
-/// the planned call.
+/// the planned call. The lookup is retried according to `retry_policy`.
 pub(crate) async fn get_shared_object_arg(
     client: &impl CoreClientReadOnly,
     object_id: &ObjectId,
     mutable: bool,
+    retry_policy: &RetryPolicy,
 ) -> Result<CallArg, Error> {
-    let res = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*object_id, IotaObjectDataOptions::new().with_owner())
-        .await
-        .map_err(|err| Error::GenericError(format!("Failed to get object: {err}")))?;
+    let res = get_object(
+        client,
+        object_id,
+        IotaObjectDataOptions::new().with_owner(),
+        retry_policy,
+    )
+    .await?;
 
     let Some(data) = res.data else {
         return Err(Error::InvalidArgument("no data found".to_string()));
@@ -65,12 +65,10 @@ impl<'a, C> TrailLocking<'a, C> {
     {
         config.validate()?;
         let owner = self.client.sender_address();
-        Ok(TransactionBuilder::new(UpdateLockingConfig::new(
-            self.trail_id,
-            owner,
-            config,
-            self.selected_capability_id,
-        )))
+        Ok(TransactionBuilder::new(
+            UpdateLockingConfig::new(self.trail_id, owner, config, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        ))
     }
 
     /// Updates only the delete-record window.
@@ -94,12 +92,10 @@ impl<'a, C> TrailLocking<'a, C> {
     {
         window.validate()?;
         let owner = self.client.sender_address();
-        Ok(TransactionBuilder::new(UpdateDeleteRecordWindow::new(
-            self.trail_id,
-            owner,
-            window,
-            self.selected_capability_id,
-        )))
+        Ok(TransactionBuilder::new(
+            UpdateDeleteRecordWindow::new(self.trail_id, owner, window, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        ))
     }
 
     /// Updates only the delete-trail time lock.
@@ -118,12 +114,10 @@ impl<'a, C> TrailLocking<'a, C> {
     {
         lock.validate_as_delete_trail_lock()?;
         let owner = self.client.sender_address();
-        Ok(TransactionBuilder::new(UpdateDeleteTrailLock::new(
-            self.trail_id,
-            owner,
-            lock,
-            self.selected_capability_id,
-        )))
+        Ok(TransactionBuilder::new(
+            UpdateDeleteTrailLock::new(self.trail_id, owner, lock, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        ))
     }
 
     /// Updates only the write lock.
@@ -133,12 +127,10 @@ impl<'a, C> TrailLocking<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(UpdateWriteLock::new(
-            self.trail_id,
-            owner,
-            lock,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            UpdateWriteLock::new(self.trail_id, owner, lock, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Returns `true` when the given record is currently locked against deletion.
@@ -154,7 +146,8 @@ impl<'a, C> TrailLocking<'a, C> {
     where
         C: AuditTrailReadOnly,
     {
-        let tx = LockingOps::is_record_locked(self.client, self.trail_id, sequence_number).await?;
+        let tx = LockingOps::is_record_locked(self.client, self.trail_id, sequence_number, &self.client.retry_policy())
+            .await?;
         self.client.execute_read_only_transaction(tx).await
     }
 }
@@ -10,6 +10,7 @@ use iota_interaction::OptionalSync;
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::tx;
 use crate::core::types::{LockingConfig, LockingWindow, Permission, TimeLock};
 use crate::error::Error;
@@ -25,6 +26,7 @@ impl LockingOps {
         owner: Address,
         new_config: LockingConfig,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -35,6 +37,7 @@ impl LockingOps {
 
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateLockingConfig,
@@ -57,12 +60,14 @@ impl LockingOps {
         owner: Address,
         new_delete_record_window: LockingWindow,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateLockingConfigForDeleteRecord,
@@ -85,6 +90,7 @@ impl LockingOps {
         owner: Address,
         new_delete_trail_lock: TimeLock,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -94,6 +100,7 @@ impl LockingOps {
             .expect("TfComponents package ID should be present for Audit Trail clients");
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateLockingConfigForDeleteTrail,
@@ -116,6 +123,7 @@ impl LockingOps {
         owner: Address,
         new_write_lock: TimeLock,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -125,6 +133,7 @@ impl LockingOps {
             .expect("TfComponents package ID should be present for Audit Trail clients");
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateLockingConfigForWrite,
@@ -145,11 +154,12 @@ impl LockingOps {
         client: &C,
         trail_id: ObjectId,
         sequence_number: u64,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        tx::build_read_only_transaction(client, trail_id, "is_record_locked", |ptb| {
+        tx::build_read_only_transaction(client, retry_policy, trail_id, "is_record_locked", |ptb| {
             let sequence_number = tx::ptb_pure(ptb, "sequence_number", sequence_number)?;
             let clock = tx::get_clock_ref(ptb);
 
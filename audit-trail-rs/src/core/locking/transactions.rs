@@ -12,6 +12,8 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::LockingOps;
+use crate::client::retry::RetryPolicy;
+use crate::core::internal::abort;
 use crate::core::types::{LockingConfig, LockingWindow, TimeLock};
 use crate::error::Error;
 
@@ -28,6 +30,7 @@ pub struct UpdateLockingConfig {
     owner: Address,
     config: LockingConfig,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -44,10 +47,18 @@ impl UpdateLockingConfig {
             owner,
             config,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -58,6 +69,7 @@ impl UpdateLockingConfig {
             self.owner,
             self.config.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -76,11 +88,11 @@ impl Transaction for UpdateLockingConfig {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -97,6 +109,7 @@ pub struct UpdateDeleteRecordWindow {
     owner: Address,
     window: LockingWindow,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -113,10 +126,18 @@ impl UpdateDeleteRecordWindow {
             owner,
             window,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -127,6 +148,7 @@ impl UpdateDeleteRecordWindow {
             self.owner,
             self.window.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -145,11 +167,11 @@ impl Transaction for UpdateDeleteRecordWindow {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -166,6 +188,7 @@ pub struct UpdateDeleteTrailLock {
     owner: Address,
     lock: TimeLock,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -177,10 +200,18 @@ impl UpdateDeleteTrailLock {
             owner,
             lock,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -191,6 +222,7 @@ impl UpdateDeleteTrailLock {
             self.owner,
             self.lock.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -209,11 +241,11 @@ impl Transaction for UpdateDeleteTrailLock {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -229,6 +261,7 @@ pub struct UpdateWriteLock {
     owner: Address,
     lock: TimeLock,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -240,10 +273,18 @@ impl UpdateWriteLock {
             owner,
             lock,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -254,6 +295,7 @@ impl UpdateWriteLock {
             self.owner,
             self.lock.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -272,10 +314,10 @@ impl Transaction for UpdateWriteLock {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
@@ -8,6 +8,7 @@
 //! - [`crate::core::access`] exposes role and capability management
 //! - [`crate::core::builder`] configures trail creation
 //! - [`crate::core::create`] contains the creation transaction types
+//! - [`crate::core::dry_run`] simulates transactions and estimates their gas costs
 //! - [`crate::core::locking`] manages trail locking rules
 //! - [`crate::core::records`] reads and mutates trail records
 //! - [`crate::core::tags`] manages the trail-owned record-tag registry
@@ -20,6 +21,8 @@ pub mod access;
 pub mod builder;
 /// Trail-creation transaction types.
 pub mod create;
+/// Dry runs and gas estimation for transactions.
+pub mod dry_run;
 pub(crate) mod internal;
 /// Locking configuration APIs.
 pub mod locking;
@@ -17,6 +17,7 @@ use product_common::transaction::transaction_builder::TransactionBuilder;
 use secret_storage::Signer;
 use serde::de::DeserializeOwned;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::{linked_table, trail as trail_reader};
 use crate::core::trail::{AuditTrailFull, AuditTrailReadOnly};
 use crate::core::types::{Data, PaginatedRecord, Record, RecordInput};
@@ -67,7 +68,8 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         C: AuditTrailReadOnly,
         D: DeserializeOwned,
     {
-        let tx = RecordsOps::get_record(self.client, self.trail_id, sequence_number).await?;
+        let tx =
+            RecordsOps::get_record(self.client, self.trail_id, sequence_number, &self.client.retry_policy()).await?;
         self.client.execute_read_only_transaction(tx).await
     }
 
@@ -82,14 +84,17 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         D: Into<Data>,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(AddRecord::new(
-            self.trail_id,
-            owner,
-            data.into(),
-            metadata,
-            tag,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            AddRecord::new(
+                self.trail_id,
+                owner,
+                data.into(),
+                metadata,
+                tag,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Builds a transaction that deletes a single record.
@@ -101,12 +106,10 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(DeleteRecord::new(
-            self.trail_id,
-            owner,
-            sequence_number,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            DeleteRecord::new(self.trail_id, owner, sequence_number, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Builds a transaction that deletes up to `limit` records in one operation.
@@ -140,12 +143,10 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(DeleteRecordsBatch::new(
-            self.trail_id,
-            owner,
-            limit,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            DeleteRecordsBatch::new(self.trail_id, owner, limit, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Builds a transaction that appends a correction record to the trail.
@@ -166,15 +167,18 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         D: Into<Data>,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(CorrectRecord::new(
-            self.trail_id,
-            owner,
-            sequence_number,
-            record.data.into(),
-            record.metadata,
-            record.tag,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            CorrectRecord::new(
+                self.trail_id,
+                owner,
+                sequence_number,
+                record.data.into(),
+                record.metadata,
+                record.tag,
+                self.selected_capability_id,
+            )
+            .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Loads the current version of a record by following correction links.
@@ -223,7 +227,7 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
     where
         C: AuditTrailReadOnly,
     {
-        let tx = RecordsOps::record_count(self.client, self.trail_id).await?;
+        let tx = RecordsOps::record_count(self.client, self.trail_id, &self.client.retry_policy()).await?;
         self.client.execute_read_only_transaction(tx).await
     }
 
@@ -237,7 +241,7 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         D: DeserializeOwned,
     {
         let records_table = self.load_records_table().await?;
-        list_linked_table::<_, Record<D>>(self.client, &records_table, None).await
+        list_linked_table::<_, Record<D>>(self.client, &records_table, None, &self.client.retry_policy()).await
     }
 
     /// Lists all records with a hard cap to protect against expensive traversals.
@@ -247,7 +251,13 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         D: DeserializeOwned,
     {
         let records_table = self.load_records_table().await?;
-        list_linked_table::<_, Record<D>>(self.client, &records_table, Some(max_entries)).await
+        list_linked_table::<_, Record<D>>(
+            self.client,
+            &records_table,
+            Some(max_entries),
+            &self.client.retry_policy(),
+        )
+        .await
     }
 
     /// Lists one page of linked-table records starting from `cursor`.
@@ -265,8 +275,14 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
         }
 
         let records_table = self.load_records_table().await?;
-        let (records, next_cursor) =
-            list_linked_table_page::<_, Record<D>>(self.client, &records_table, cursor, limit).await?;
+        let (records, next_cursor) = list_linked_table_page::<_, Record<D>>(
+            self.client,
+            &records_table,
+            cursor,
+            limit,
+            &self.client.retry_policy(),
+        )
+        .await?;
 
         Ok(PaginatedRecord {
             has_next_page: next_cursor.is_some(),
@@ -279,7 +295,7 @@ impl<'a, C, D> TrailRecords<'a, C, D> {
     where
         C: AuditTrailReadOnly,
     {
-        trail_reader::get_audit_trail(self.trail_id, self.client)
+        trail_reader::get_audit_trail(self.trail_id, self.client, &self.client.retry_policy())
             .await
             .map(|on_chain_trail| on_chain_trail.records)
     }
@@ -290,6 +306,7 @@ async fn list_linked_table_page<C, V>(
     table: &LinkedTable<u64>,
     start_key: Option<u64>,
     limit: usize,
+    retry_policy: &RetryPolicy,
 ) -> Result<(BTreeMap<u64, V>, Option<u64>), Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -320,6 +337,7 @@ where
                 type_: TypeTag::U64,
                 value: IotaMoveValue::from(MoveValue::U64(key)).to_json_value(),
             },
+            retry_policy,
         )
         .await?;
 
@@ -334,6 +352,7 @@ async fn list_linked_table<C, V>(
     client: &C,
     table: &LinkedTable<u64>,
     max_entries: Option<usize>,
+    retry_policy: &RetryPolicy,
 ) -> Result<HashMap<u64, V>, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -349,7 +368,7 @@ where
         )));
     }
 
-    let (entries, next_key) = list_linked_table_page(client, table, None, expected).await?;
+    let (entries, next_key) = list_linked_table_page(client, table, None, expected, retry_policy).await?;
 
     if entries.len() != expected {
         return Err(Error::UnexpectedApiResponse(format!(
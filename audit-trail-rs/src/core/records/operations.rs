@@ -13,6 +13,7 @@ use iota_interaction::types::dynamic_field::DynamicFieldName;
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction, TypeTag};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::capability::find_capable_cap_for_tags;
 use crate::core::internal::{linked_table, trail as trail_reader, tx};
 use crate::core::types::{Data, Permission, Record, RecordInput};
@@ -32,6 +33,7 @@ impl RecordsOps {
         owner: Address,
         record: RecordInput,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -40,28 +42,44 @@ impl RecordsOps {
         let record_tag = record.tag.clone();
 
         if let Some(tag) = record_tag {
-            let trail = trail_reader::get_audit_trail(trail_id, client).await?;
+            let trail = trail_reader::get_audit_trail(trail_id, client, retry_policy).await?;
             if !trail.tags.contains_key(&tag) {
                 return Err(Error::InvalidArgument(format!(
                     "record tag '{tag}' is not defined for trail {trail_id}"
                 )));
             }
             let cap_ref = if let Some(capability_id) = selected_capability_id {
-                tx::get_object_ref_by_id(client, &capability_id).await?
+                tx::get_object_ref_by_id(client, &capability_id, retry_policy).await?
             } else {
-                find_capable_cap_for_tags(client, owner, trail_id, &trail, Permission::AddRecord, [tag.as_str()])
-                    .await?
+                find_capable_cap_for_tags(
+                    client,
+                    owner,
+                    trail_id,
+                    &trail,
+                    Permission::AddRecord,
+                    [tag.as_str()],
+                    retry_policy,
+                )
+                .await?
             };
 
-            tx::build_trail_transaction_with_cap_ref(client, trail_id, cap_ref, "add_record", |ptb, trail_tag| {
-                let [data, metadata, tag] = record.into_record_args(ptb, package_id, trail_tag)?;
-                let clock = tx::get_clock_ref(ptb);
-                Ok(vec![data, metadata, tag, clock])
-            })
+            tx::build_trail_transaction_with_cap_ref(
+                client,
+                retry_policy,
+                trail_id,
+                cap_ref,
+                "add_record",
+                |ptb, trail_tag| {
+                    let [data, metadata, tag] = record.into_record_args(ptb, package_id, trail_tag)?;
+                    let clock = tx::get_clock_ref(ptb);
+                    Ok(vec![data, metadata, tag, clock])
+                },
+            )
             .await
         } else {
             tx::build_trail_transaction(
                 client,
+                retry_policy,
                 trail_id,
                 owner,
                 Permission::AddRecord,
@@ -89,13 +107,14 @@ impl RecordsOps {
         sequence_number: u64,
         record: RecordInput,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         let package_id = client.package_id();
         let record_tag = record.tag.clone();
-        let trail = trail_reader::get_audit_trail(trail_id, client).await?;
+        let trail = trail_reader::get_audit_trail(trail_id, client, retry_policy).await?;
 
         let replaced = linked_table::fetch_node::<_, u64, Record<Data>>(
             client,
@@ -104,6 +123,7 @@ impl RecordsOps {
                 type_: TypeTag::U64,
                 value: IotaMoveValue::from(MoveValue::U64(sequence_number)).to_json_value(),
             },
+            retry_policy,
         )
         .await?
         .value;
@@ -117,7 +137,7 @@ impl RecordsOps {
         }
 
         let cap_ref = if let Some(capability_id) = selected_capability_id {
-            tx::get_object_ref_by_id(client, &capability_id).await?
+            tx::get_object_ref_by_id(client, &capability_id, retry_policy).await?
         } else {
             let mut required_tags = Vec::new();
             if let Some(tag) = replaced.tag.as_deref() {
@@ -136,16 +156,24 @@ impl RecordsOps {
                 &trail,
                 Permission::CorrectRecord,
                 required_tags,
+                retry_policy,
             )
             .await?
         };
 
-        tx::build_trail_transaction_with_cap_ref(client, trail_id, cap_ref, "correct_record", |ptb, trail_tag| {
-            let seq = tx::ptb_pure(ptb, "sequence_number", sequence_number)?;
-            let [data, metadata, tag] = record.into_record_args(ptb, package_id, trail_tag)?;
-            let clock = tx::get_clock_ref(ptb);
-            Ok(vec![seq, data, metadata, tag, clock])
-        })
+        tx::build_trail_transaction_with_cap_ref(
+            client,
+            retry_policy,
+            trail_id,
+            cap_ref,
+            "correct_record",
+            |ptb, trail_tag| {
+                let seq = tx::ptb_pure(ptb, "sequence_number", sequence_number)?;
+                let [data, metadata, tag] = record.into_record_args(ptb, package_id, trail_tag)?;
+                let clock = tx::get_clock_ref(ptb);
+                Ok(vec![seq, data, metadata, tag, clock])
+            },
+        )
         .await
     }
 
@@ -158,12 +186,14 @@ impl RecordsOps {
         owner: Address,
         sequence_number: u64,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::DeleteRecord,
@@ -192,12 +222,14 @@ impl RecordsOps {
         owner: Address,
         limit: u64,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::DeleteAllRecords,
@@ -217,11 +249,12 @@ impl RecordsOps {
         client: &C,
         trail_id: ObjectId,
         sequence_number: u64,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        tx::build_read_only_transaction(client, trail_id, "get_record", |ptb| {
+        tx::build_read_only_transaction(client, retry_policy, trail_id, "get_record", |ptb| {
             let seq = tx::ptb_pure(ptb, "sequence_number", sequence_number)?;
             Ok(vec![seq])
         })
@@ -229,10 +262,14 @@ impl RecordsOps {
     }
 
     /// Builds the read-only `record_count` call.
-    pub(super) async fn record_count<C>(client: &C, trail_id: ObjectId) -> Result<ProgrammableTransaction, Error>
+    pub(super) async fn record_count<C>(
+        client: &C,
+        trail_id: ObjectId,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        tx::build_read_only_transaction(client, trail_id, "record_count", |_| Ok(vec![])).await
+        tx::build_read_only_transaction(client, retry_policy, trail_id, "record_count", |_| Ok(vec![])).await
     }
 }
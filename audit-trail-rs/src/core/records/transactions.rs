@@ -15,7 +15,8 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::RecordsOps;
-use crate::core::internal::tx;
+use crate::client::retry::RetryPolicy;
+use crate::core::internal::{abort, tx};
 use crate::core::types::{Data, Event, RecordAdded, RecordDeleted, RecordInput};
 use crate::error::Error;
 
@@ -43,6 +44,7 @@ pub struct AddRecord {
     pub tag: Option<String>,
     /// Explicit capability to use instead of auto-selecting one from the owner's wallet.
     pub selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -63,10 +65,18 @@ impl AddRecord {
             metadata,
             tag,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -77,6 +87,7 @@ impl AddRecord {
             self.owner,
             RecordInput::new(self.data.clone(), self.metadata.clone(), self.tag.clone()),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -97,13 +108,15 @@ impl Transaction for AddRecord {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -151,6 +164,7 @@ pub struct CorrectRecord {
     pub tag: Option<String>,
     /// Explicit capability to use instead of auto-selecting one from the owner's wallet.
     pub selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -177,10 +191,18 @@ impl CorrectRecord {
             metadata,
             tag,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -192,6 +214,7 @@ impl CorrectRecord {
             self.sequence_number,
             RecordInput::new(self.data.clone(), self.metadata.clone(), self.tag.clone()),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -212,13 +235,15 @@ impl Transaction for CorrectRecord {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -256,6 +281,7 @@ pub struct DeleteRecord {
     pub sequence_number: u64,
     /// Explicit capability to use instead of auto-selecting one from the owner's wallet.
     pub selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -272,10 +298,18 @@ impl DeleteRecord {
             owner,
             sequence_number,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -286,6 +320,7 @@ impl DeleteRecord {
             self.owner,
             self.sequence_number,
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -306,13 +341,15 @@ impl Transaction for DeleteRecord {
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
@@ -359,6 +396,7 @@ pub struct DeleteRecordsBatch {
     pub limit: u64,
     /// Explicit capability to use instead of auto-selecting one from the owner's wallet.
     pub selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -370,10 +408,18 @@ impl DeleteRecordsBatch {
             owner,
             limit,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -384,6 +430,7 @@ impl DeleteRecordsBatch {
             self.owner,
             self.limit,
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -404,13 +451,15 @@ impl Transaction for DeleteRecordsBatch {
 
     async fn apply_with_events<C>(
         self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let deleted = events
             .data
             .iter()
@@ -50,12 +50,10 @@ impl<'a, C> TrailTags<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(AddRecordTag::new(
-            self.trail_id,
-            owner,
-            tag.into(),
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            AddRecordTag::new(self.trail_id, owner, tag.into(), self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Removes a tag from the trail-owned record-tag registry.
@@ -67,11 +65,9 @@ impl<'a, C> TrailTags<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(RemoveRecordTag::new(
-            self.trail_id,
-            owner,
-            tag.into(),
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            RemoveRecordTag::new(self.trail_id, owner, tag.into(), self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 }
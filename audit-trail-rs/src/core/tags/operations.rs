@@ -10,6 +10,7 @@ use iota_interaction::OptionalSync;
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::tx;
 use crate::core::types::Permission;
 use crate::error::Error;
@@ -25,12 +26,14 @@ impl TagsOps {
         owner: Address,
         tag: String,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::AddRecordTags,
@@ -52,12 +55,14 @@ impl TagsOps {
         owner: Address,
         tag: String,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::DeleteRecordTags,
@@ -12,6 +12,8 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::TagsOps;
+use crate::client::retry::RetryPolicy;
+use crate::core::internal::abort;
 use crate::error::Error;
 
 /// Transaction that adds a record tag to the trail registry.
@@ -26,6 +28,7 @@ pub struct AddRecordTag {
     owner: Address,
     tag: String,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -37,10 +40,18 @@ impl AddRecordTag {
             owner,
             tag,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -51,6 +62,7 @@ impl AddRecordTag {
             self.owner,
             self.tag.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -69,11 +81,11 @@ impl Transaction for AddRecordTag {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -90,6 +102,7 @@ pub struct RemoveRecordTag {
     owner: Address,
     tag: String,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -101,10 +114,18 @@ impl RemoveRecordTag {
             owner,
             tag,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -115,6 +136,7 @@ impl RemoveRecordTag {
             self.owner,
             self.tag.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -133,10 +155,10 @@ impl Transaction for RemoveRecordTag {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
@@ -10,6 +10,7 @@ use product_common::transaction::transaction_builder::TransactionBuilder;
 use secret_storage::Signer;
 use serde::de::DeserializeOwned;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::access::TrailAccess;
 use crate::core::internal::trail as trail_reader;
 use crate::core::locking::TrailLocking;
@@ -31,6 +32,11 @@ pub trait AuditTrailReadOnly: CoreClientReadOnly + OptionalSync {
     /// Executes a read-only programmable transaction and decodes the first return value.
     async fn execute_read_only_transaction<T: DeserializeOwned>(&self, tx: ProgrammableTransaction)
     -> Result<T, Error>;
+
+    /// Returns the policy for retrying failed reads.
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
 }
 
 /// Marker trait for full audit-trail clients.
@@ -70,7 +76,7 @@ impl<'a, C> AuditTrailHandle<'a, C> {
     where
         C: AuditTrailReadOnly,
     {
-        trail_reader::get_audit_trail(self.trail_id, self.client).await
+        trail_reader::get_audit_trail(self.trail_id, self.client, &self.client.retry_policy()).await
     }
 
     /// Updates the trail's mutable metadata field.
@@ -82,12 +88,10 @@ impl<'a, C> AuditTrailHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(UpdateMetadata::new(
-            self.trail_id,
-            owner,
-            metadata,
-            self.selected_capability_id,
-        ))
+        TransactionBuilder::new(
+            UpdateMetadata::new(self.trail_id, owner, metadata, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Migrates the trail to the latest package version supported by this crate.
@@ -97,7 +101,10 @@ impl<'a, C> AuditTrailHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(Migrate::new(self.trail_id, owner, self.selected_capability_id))
+        TransactionBuilder::new(
+            Migrate::new(self.trail_id, owner, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Deletes the trail object.
@@ -111,7 +118,10 @@ impl<'a, C> AuditTrailHandle<'a, C> {
         S: Signer<IotaKeySignature> + OptionalSync,
     {
         let owner = self.client.sender_address();
-        TransactionBuilder::new(DeleteAuditTrail::new(self.trail_id, owner, self.selected_capability_id))
+        TransactionBuilder::new(
+            DeleteAuditTrail::new(self.trail_id, owner, self.selected_capability_id)
+                .with_retry_policy(self.client.retry_policy()),
+        )
     }
 
     /// Returns the record API scoped to this trail.
@@ -10,6 +10,7 @@ use iota_interaction::OptionalSync;
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::retry::RetryPolicy;
 use crate::core::internal::tx;
 use crate::core::types::Permission;
 use crate::error::Error;
@@ -24,12 +25,14 @@ impl TrailOps {
         trail_id: ObjectId,
         owner: Address,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::Migrate,
@@ -50,12 +53,14 @@ impl TrailOps {
         owner: Address,
         metadata: Option<String>,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::UpdateMetadata,
@@ -76,12 +81,14 @@ impl TrailOps {
         trail_id: ObjectId,
         owner: Address,
         selected_capability_id: Option<ObjectId>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         tx::build_trail_transaction(
             client,
+            retry_policy,
             trail_id,
             owner,
             Permission::DeleteAuditTrail,
@@ -12,7 +12,8 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use super::operations::TrailOps;
-use crate::core::internal::tx;
+use crate::client::retry::RetryPolicy;
+use crate::core::internal::{abort, tx};
 use crate::core::types::{AuditTrailDeleted, Event};
 use crate::error::Error;
 
@@ -28,6 +29,7 @@ pub struct Migrate {
     trail_id: ObjectId,
     owner: Address,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -38,15 +40,30 @@ impl Migrate {
             trail_id,
             owner,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        TrailOps::migrate(client, self.trail_id, self.owner, self.selected_capability_id).await
+        TrailOps::migrate(
+            client,
+            self.trail_id,
+            self.owner,
+            self.selected_capability_id,
+            &self.retry_policy,
+        )
+        .await
     }
 }
 
@@ -63,11 +80,11 @@ impl Transaction for Migrate {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -83,6 +100,7 @@ pub struct UpdateMetadata {
     owner: Address,
     metadata: Option<String>,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -99,10 +117,18 @@ impl UpdateMetadata {
             owner,
             metadata,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
@@ -113,6 +139,7 @@ impl UpdateMetadata {
             self.owner,
             self.metadata.clone(),
             self.selected_capability_id,
+            &self.retry_policy,
         )
         .await
     }
@@ -131,11 +158,11 @@ impl Transaction for UpdateMetadata {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        abort::check_execution_status(effects, client).await
     }
 }
 
@@ -151,6 +178,7 @@ pub struct DeleteAuditTrail {
     trail_id: ObjectId,
     owner: Address,
     selected_capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -161,15 +189,30 @@ impl DeleteAuditTrail {
             trail_id,
             owner,
             selected_capability_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the lookups made while building the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        TrailOps::delete_audit_trail(client, self.trail_id, self.owner, self.selected_capability_id).await
+        TrailOps::delete_audit_trail(
+            client,
+            self.trail_id,
+            self.owner,
+            self.selected_capability_id,
+            &self.retry_policy,
+        )
+        .await
     }
 }
 
@@ -188,13 +231,15 @@ impl Transaction for DeleteAuditTrail {
 
     async fn apply_with_events<C>(
         self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
-        _: &C,
+        client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let event = events
             .data
             .iter()
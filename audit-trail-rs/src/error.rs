@@ -3,6 +3,10 @@
 
 //! Error types returned by the audit-trail public API.
 
+use std::time::Duration;
+
+use iota_rpc_retry::RetryableError;
+
 use crate::iota_interaction_adapter::AdapterError;
 
 /// Errors that can occur when reading or mutating an `AuditTrail` object.
@@ -42,6 +46,85 @@ pub enum Error {
     /// The transaction response from the IOTA node API was not in the expected format.
     #[error("unexpected transaction response: {0}")]
     TransactionUnexpectedResponse(String),
+    /// Returned when a record does not exist at the given sequence number.
+    #[error("record not found at the given sequence number")]
+    RecordNotFound,
+    /// Returned when a record is locked by the delete-record window and cannot be deleted.
+    #[error("the record is locked and cannot be deleted")]
+    RecordLocked,
+    /// Returned when a record has already been replaced by a correction.
+    #[error("the record has already been replaced")]
+    RecordAlreadyReplaced,
+    /// Returned when a trail is deleted while records still exist.
+    #[error("the audit trail cannot be deleted while records still exist")]
+    TrailNotEmpty,
+    /// Returned when a trail is deleted while its delete-trail lock is active.
+    #[error("the audit trail is delete-locked")]
+    TrailDeleteLocked,
+    /// Returned when a record is written while the write lock of the trail is active.
+    #[error("the audit trail is write-locked")]
+    TrailWriteLocked,
+    /// Returned when the package version of a trail does not match the version expected by the package.
+    #[error("the package version of the trail does not match the expected version")]
+    PackageVersionMismatch,
+    /// Returned when the capability's role does not allow the requested record tag.
+    #[error("the capability cannot create records with the requested tag")]
+    RecordTagNotAllowed,
+    /// Returned when the requested record tag is not defined for the trail.
+    #[error("the requested tag is not defined for this audit trail")]
+    RecordTagNotDefined,
+    /// Returned when a record tag that is already defined for the trail is added again.
+    #[error("the requested tag is already defined for this audit trail")]
+    RecordTagAlreadyDefined,
+    /// Returned when a record tag that is used by a record or role is removed.
+    #[error("the requested tag is in use by an existing record or role")]
+    RecordTagInUse,
+    /// Returned when the delete-trail lock is configured as `TimeLock::UntilDestroyed`.
+    #[error("`UntilDestroyed` cannot be used for the delete-trail lock")]
+    UntilDestroyedNotSupportedForDeleteTrail,
+    /// Returned when a count-based locking window does not protect at least one record.
+    #[error("a count-based locking window must protect at least one record")]
+    CountWindowMustBePositive,
+    /// Returned when a transaction aborts in a Move function with an abort that has no dedicated variant.
+    #[error("{0}")]
+    MoveAbort(MoveAbort),
+}
+
+impl RetryableError for Error {
+    /// RPC failures are transient.
+    fn is_transient(&self) -> bool {
+        matches!(self, Error::RpcError(_))
+    }
+
+    fn timed_out(timeout: Duration) -> Self {
+        Error::RpcError(format!("call timed out after {timeout:?}"))
+    }
+}
+
+/// A Move abort raised while executing a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveAbort {
+    /// Move module that aborted, e.g. `main`.
+    pub module: String,
+    /// Move function that aborted, if reported by the node.
+    pub function: Option<String>,
+    /// Raw abort code; for clever errors this encodes the source line and the constant of the error.
+    pub code: u64,
+    /// Name of the abort constant, e.g. `ERecordLocked`, if it could be resolved.
+    pub name: Option<String>,
+}
+
+impl std::fmt::Display for MoveAbort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "transaction aborted in {}", self.module)?;
+        if let Some(function) = &self.function {
+            write!(f, "::{function}")?;
+        }
+        match &self.name {
+            Some(name) => write!(f, " with {name} (code {})", self.code),
+            None => write!(f, " with code {}", self.code),
+        }
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -12,6 +12,9 @@ pub mod core;
 pub mod error;
 pub(crate) mod iota_interaction_adapter;
 pub(crate) mod package;
+/// In-memory mock ledger for testing without an IOTA node.
+#[cfg(all(any(test, feature = "test-utils"), not(target_arch = "wasm32")))]
+pub mod test_utils;
 
 /// A signing audit-trail client that can build write transactions.
 pub use client::full_client::AuditTrailClient;
@@ -10,7 +10,9 @@
 
 use std::sync::LazyLock;
 
+use iota_interaction::OptionalSync;
 use iota_sdk_types::ObjectId;
+use product_common::core_client::CoreClientReadOnly;
 use product_common::network_name::NetworkName;
 use product_common::package_registry::{Env, PackageRegistry};
 use product_common::tf_components_registry;
@@ -64,6 +66,27 @@ pub(crate) fn blocking_audit_trail_registry_mut() -> PackageRegistryLockMut {
     AUDIT_TRAIL_PACKAGE_REGISTRY.blocking_write()
 }
 
+/// Returns the IDs of all versions of the Audit Trails Package known for the network
+/// of `client`, oldest first.
+///
+/// Includes the package used by `client`, also if it is missing from the
+/// registry, e.g. for clients created with a custom package ID.
+pub(crate) async fn package_versions<C>(client: &C) -> Vec<ObjectId>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let mut versions = audit_trail_package_registry()
+        .await
+        .history(client.network_name())
+        .map(<[ObjectId]>::to_vec)
+        .unwrap_or_default();
+    if !versions.contains(&client.package_id()) {
+        versions.push(client.package_id());
+    }
+
+    versions
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ResolvedPackageIds {
     pub audit_trail_package_id: ObjectId,
@@ -0,0 +1,313 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-memory mock ledger for testing code built on this crate without an IOTA node.
+//!
+//! [`MockLedger`] simulates the Move functions of the audit-trail package and of the `TfComponents` modules it depends
+//! on in-process and answers the JSON-RPC API of an IOTA node from the simulated state. As the `IotaClient` of the
+//! IOTA SDK only speaks JSON-RPC over HTTP, the requests of its clients reach the simulation through the loopback
+//! transport of [`iota_mock_rpc`]; no other network access is needed. Clients created via
+//! [`MockLedger::read_only_client`] and [`MockLedger::client`] are regular [`AuditTrailClientReadOnly`]s and
+//! [`AuditTrailClient`]s, so the code under test runs unchanged. [`MockLedger`] itself implements
+//! [`CoreClientReadOnly`] and can be passed wherever a read-only client is expected, e.g. to build transactions.
+//!
+//! The simulation covers records, tags, locking, roles and capabilities, including capability discovery,
+//! linked-table traversal and the decoding of aborts into typed [`Error`] variants. The chain clock only moves via
+//! [`MockLedger::set_clock_ms`] and [`MockLedger::advance_clock`], which makes locking windows and capability
+//! validity periods deterministic to test. Signatures are not verified.
+//!
+//! Requires the `test-utils` feature.
+//!
+//! ```rust,ignore
+//! use audit_trails::core::types::Data;
+//! use audit_trails::test_utils::MockLedger;
+//! use product_common::test_utils::InMemSigner;
+//!
+//! let ledger = MockLedger::start().await?;
+//! let client = ledger.client(InMemSigner::new()).await?;
+//!
+//! let trail_id = client
+//!     .create_trail()
+//!     .with_initial_record_parts(Data::text("Initial record"), None, None)
+//!     .finish()?
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output
+//!     .trail_id;
+//! ledger.advance_clock(Duration::from_secs(60));
+//! ```
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iota_interaction::{IotaClient, IotaClientBuilder, IotaKeySignature, OptionalSync};
+use iota_mock_rpc::Params;
+use iota_sdk_types::{Address, ObjectId};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::network_name::NetworkName;
+use secret_storage::Signer;
+
+use crate::client::full_client::AuditTrailClient;
+use crate::client::read_only::{AuditTrailClientReadOnly, PackageOverrides};
+use crate::error::Error;
+use crate::iota_interaction_adapter::IotaClientAdapter;
+
+mod package;
+
+use self::package::{Ledger, PackageIds};
+
+/// The [`ObjectId`] of the audit-trail package simulated by [`MockLedger`].
+pub const MOCK_PACKAGE_ID: ObjectId = ObjectId::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x6d, 0x6f, 0x63, 0x6b,
+]);
+
+/// The [`ObjectId`] of the `TfComponents` package simulated by [`MockLedger`].
+pub const MOCK_TF_COMPONENTS_PACKAGE_ID: ObjectId = ObjectId::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x74, 0x66, 0x63, 0x70,
+]);
+
+/// The chain identifier reported by [`MockLedger`].
+pub const MOCK_CHAIN_ID: &str = "6d6f636b";
+
+/// Balance of the gas coin minted by [`MockLedger::client`], in NANOS.
+pub const DEFAULT_FUNDING: u64 = 1_000_000_000_000;
+
+/// An in-memory ledger simulating an IOTA node with the audit-trail and `TfComponents` packages published.
+///
+/// Cloning a [`MockLedger`] yields another handle to the same ledger.
+#[derive(Clone)]
+pub struct MockLedger {
+    ledger: Arc<Mutex<Ledger>>,
+    address: SocketAddr,
+    iota_client: IotaClientAdapter,
+    network: NetworkName,
+}
+
+impl MockLedger {
+    /// Starts a mock ledger and serves its JSON-RPC API to the clients created from it.
+    ///
+    /// The chain clock starts at the current system time. The transport runs on the current Tokio runtime until the
+    /// runtime shuts down.
+    pub async fn start() -> Result<Self, Error> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.as_millis() as u64);
+        let packages = PackageIds {
+            audit_trail: MOCK_PACKAGE_ID,
+            tf_components: MOCK_TF_COMPONENTS_PACKAGE_ID,
+        };
+        let ledger = Arc::new(Mutex::new(Ledger::new(packages, MOCK_CHAIN_ID.to_string(), now_ms)));
+        let handler = {
+            let ledger = ledger.clone();
+            move |method: &str, params: &Params| {
+                iota_mock_rpc::dispatch(&mut ledger.lock().expect("mock ledger is not poisoned"), method, params)
+            }
+        };
+        let address = iota_mock_rpc::serve(handler)
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to serve the mock ledger: {e}")))?;
+
+        let iota_client = IotaClientAdapter::new(connect(address).await?);
+        let network = NetworkName::try_from(MOCK_CHAIN_ID).expect("mock chain ID is a valid network name");
+
+        Ok(Self {
+            ledger,
+            address,
+            iota_client,
+            network,
+        })
+    }
+
+    /// Returns the URL of the JSON-RPC API.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates an [`IotaClient`] connected to this ledger.
+    pub async fn iota_client(&self) -> Result<IotaClient, Error> {
+        connect(self.address).await
+    }
+
+    /// Creates an [`AuditTrailClientReadOnly`] connected to this ledger.
+    pub async fn read_only_client(&self) -> Result<AuditTrailClientReadOnly, Error> {
+        AuditTrailClientReadOnly::new_with_package_overrides(
+            self.iota_client().await?,
+            PackageOverrides {
+                audit_trail: Some(MOCK_PACKAGE_ID),
+                tf_component: Some(MOCK_TF_COMPONENTS_PACKAGE_ID),
+            },
+        )
+        .await
+    }
+
+    /// Creates an [`AuditTrailClient`] connected to this ledger and funds the address of `signer` with
+    /// [`DEFAULT_FUNDING`].
+    pub async fn client<S>(&self, signer: S) -> Result<AuditTrailClient<S>, Error>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let client = AuditTrailClient::new(self.read_only_client().await?, signer).await?;
+        self.fund(client.sender_address(), DEFAULT_FUNDING);
+
+        Ok(client)
+    }
+
+    /// Mints a gas coin with `amount` NANOS owned by `address`.
+    pub fn fund(&self, address: Address, amount: u64) -> ObjectId {
+        self.lock().mint_gas(address, amount)
+    }
+
+    /// Returns the time of the chain clock, in milliseconds since the Unix epoch.
+    pub fn clock_ms(&self) -> u64 {
+        self.lock().clock_ms
+    }
+
+    /// Sets the time of the chain clock, in milliseconds since the Unix epoch.
+    pub fn set_clock_ms(&self, clock_ms: u64) {
+        self.lock().clock_ms = clock_ms;
+    }
+
+    /// Moves the chain clock forward by `duration`.
+    pub fn advance_clock(&self, duration: Duration) {
+        self.lock().clock_ms += duration.as_millis() as u64;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ledger> {
+        self.ledger.lock().expect("mock ledger is not poisoned")
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait::async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait::async_trait)]
+impl CoreClientReadOnly for MockLedger {
+    fn package_id(&self) -> ObjectId {
+        MOCK_PACKAGE_ID
+    }
+
+    fn tf_components_package_id(&self) -> Option<ObjectId> {
+        Some(MOCK_TF_COMPONENTS_PACKAGE_ID)
+    }
+
+    fn network_name(&self) -> &NetworkName {
+        &self.network
+    }
+
+    fn client_adapter(&self) -> &IotaClientAdapter {
+        &self.iota_client
+    }
+}
+
+async fn connect(address: SocketAddr) -> Result<IotaClient, Error> {
+    IotaClientBuilder::default()
+        .build(format!("http://{address}"))
+        .await
+        .map_err(|e| Error::RpcError(format!("failed to connect to the mock ledger: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use product_common::test_utils::InMemSigner;
+
+    use super::*;
+    use crate::core::dry_run::DryRun;
+    use crate::core::types::{
+        CapabilityIssueOptions, Data, LockingConfig, LockingWindow, Permission, PermissionSet, TimeLock,
+    };
+
+    async fn create_trail(client: &AuditTrailClient<InMemSigner>, write_lock: TimeLock) -> anyhow::Result<ObjectId> {
+        Ok(client
+            .create_trail()
+            .with_initial_record_parts(Data::text("Initial record"), None, None)
+            .with_locking_config(LockingConfig {
+                delete_record_window: LockingWindow::None,
+                delete_trail_lock: TimeLock::None,
+                write_lock,
+            })
+            .finish()?
+            .build_and_execute(client)
+            .await?
+            .output
+            .trail_id)
+    }
+
+    async fn grant_role(
+        client: &AuditTrailClient<InMemSigner>,
+        trail_id: ObjectId,
+        role: &str,
+        permissions: PermissionSet,
+    ) -> anyhow::Result<()> {
+        let role = client.trail(trail_id).access().for_role(role);
+        role.create(permissions, None).build_and_execute(client).await?;
+        role.issue_capability(CapabilityIssueOptions::default())
+            .build_and_execute(client)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn records_are_read_back() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let trail_id = create_trail(&client, TimeLock::None).await?;
+        grant_role(&client, trail_id, "Writer", PermissionSet::record_admin_permissions()).await?;
+
+        let records = client.trail(trail_id).records();
+        records
+            .add(Data::text("Follow-up record"), None, None)
+            .build_and_execute(&client)
+            .await?;
+
+        let trail = client.trail(trail_id).get().await?;
+        assert_eq!(client.package_id(), ledger.package_id());
+        assert_eq!(trail.creator, client.sender_address());
+        assert_eq!(records.record_count().await?, 2);
+        assert_eq!(records.get(1).await?.data.as_text()?, "Follow-up record");
+        assert_eq!(records.list().await?.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_lock_follows_the_clock() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let trail_id = create_trail(&client, TimeLock::UnlockAtMs(ledger.clock_ms() + 60_000)).await?;
+        grant_role(&client, trail_id, "Writer", PermissionSet::record_admin_permissions()).await?;
+
+        let records = client.trail(trail_id).records();
+        let result = records
+            .add(Data::text("Too early"), None, None)
+            .dry_run(&client)
+            .await;
+        assert!(matches!(result, Err(Error::TrailWriteLocked)), "got {result:?}");
+
+        ledger.advance_clock(Duration::from_secs(61));
+        records
+            .add(Data::text("Late enough"), None, None)
+            .build_and_execute(&client)
+            .await?;
+        assert_eq!(records.record_count().await?, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trails_with_records_cannot_be_deleted() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let trail_id = create_trail(&client, TimeLock::None).await?;
+        let permissions = PermissionSet {
+            permissions: HashSet::from([Permission::DeleteAuditTrail]),
+        };
+        grant_role(&client, trail_id, "Deleter", permissions).await?;
+
+        let result = client.trail(trail_id).delete_audit_trail().dry_run(&client).await;
+        assert!(matches!(result, Err(Error::TrailNotEmpty)), "got {result:?}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2409 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Simulation of the Move functions of the audit-trail package and of the `TfComponents` modules it depends on.
+//!
+//! The functions mirror the authorization checks, state transitions, abort codes and events of the `main`,
+//! `record`, `locking`, `permission` and `record_tags` modules and of the `timelock`, `role_map` and `capability`
+//! modules of `TfComponents`. Aborts are raised as clever errors whose names are resolved from synthesized module
+//! bytecode, so they decode into the same [`Error`](crate::error::Error) variants as on a real network.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use iota_interaction::types::collection_types::LinkedTable;
+use iota_interaction::types::id::UID;
+use iota_mock_rpc::{DynamicField, Failure, Owner, Packages, uleb128};
+use iota_sdk_types::{Address, ObjectId, TypeTag};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+
+use crate::core::internal::abort;
+use crate::core::types::{
+    AuditTrailCreated, AuditTrailDeleted, Capability, CapabilityAdminPermissions, CapabilityDestroyed,
+    CapabilityIssued, CapabilityRevoked, Data, ImmutableMetadata, InitialRecord, LockingConfig, LockingConfigUpdated,
+    LockingWindow, MetadataUpdated, OnChainAuditTrail, Permission, PermissionSet, Record, RecordAdded,
+    RecordCorrection, RecordDeleted, RecordTagAdded, RecordTagRemoved, RevokedCapabilitiesCleanedUp, Role,
+    RoleAdminPermissions, RoleMap, RoleTags, TagRegistry, TimeLock,
+};
+
+/// Version of the simulated audit-trail package.
+const PACKAGE_VERSION: u64 = 1;
+/// Name of the role of the initial-admin capability.
+const INITIAL_ADMIN_ROLE_NAME: &str = "Admin";
+/// Marker bit of clever error codes.
+const CLEVER_ERROR_TAG: u64 = 0x8000_0000_0000_0000;
+/// Kind of the identifier table of Move bytecode.
+const IDENTIFIERS_TABLE: u8 = 0x7;
+
+// The clever error constants of the audit-trail package modules, generated from its Move sources.
+include!(concat!(env!("OUT_DIR"), "/clever_errors.rs"));
+
+// The sources of `TfComponents` are not part of this repository, so its error constants are listed here in the
+// declaration order of the published package.
+/// Error constants of the `role_map` module of `TfComponents`.
+const ROLE_MAP_ERRORS: [&str; 12] = [
+    "ERoleDoesNotExist",
+    "ERoleAlreadyExists",
+    "ECapabilityPermissionDenied",
+    "ECapabilityHasBeenRevoked",
+    "ECapabilityIssuedToMismatch",
+    "ECapabilityTimeConstraintsNotMet",
+    "ECapabilityTargetKeyMismatch",
+    "ECapabilityIsNotInitialAdmin",
+    "ECapabilityToRevokeHasAlreadyBeenRevoked",
+    "EInitialAdminCapabilityMustBeExplicitlyDestroyed",
+    "EInitialAdminPermissionsInconsistent",
+    "EInitialAdminRoleCannotBeDeleted",
+];
+
+/// Error constants of the `capability` module of `TfComponents`.
+const CAPABILITY_ERRORS: [&str; 1] = ["EValidityPeriodInconsistent"];
+/// Abort code of `timelock::EPastTimestamp` of `TfComponents`.
+const TIMELOCK_E_PAST_TIMESTAMP: u64 = 0;
+
+/// All permissions, to resolve the constructor functions of the `permission` module.
+const PERMISSIONS: [Permission; 19] = [
+    Permission::DeleteAuditTrail,
+    Permission::DeleteAllRecords,
+    Permission::AddRecord,
+    Permission::DeleteRecord,
+    Permission::CorrectRecord,
+    Permission::UpdateLockingConfig,
+    Permission::UpdateLockingConfigForDeleteRecord,
+    Permission::UpdateLockingConfigForDeleteTrail,
+    Permission::UpdateLockingConfigForWrite,
+    Permission::AddRoles,
+    Permission::UpdateRoles,
+    Permission::DeleteRoles,
+    Permission::AddCapabilities,
+    Permission::RevokeCapabilities,
+    Permission::UpdateMetadata,
+    Permission::DeleteMetadata,
+    Permission::Migrate,
+    Permission::AddRecordTags,
+    Permission::DeleteRecordTags,
+];
+
+/// A mock ledger with the audit-trail package and `TfComponents` published.
+pub(super) type Ledger = iota_mock_rpc::Ledger<PackageIds>;
+
+type Session = iota_mock_rpc::Session<PackageIds>;
+
+type Value = iota_mock_rpc::Value<Infallible>;
+
+/// The IDs of the packages simulated by the mock ledger.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PackageIds {
+    pub(super) audit_trail: ObjectId,
+    pub(super) tf_components: ObjectId,
+}
+
+impl Packages for PackageIds {
+    type Object = MoveObject;
+    type Value = Infallible;
+
+    fn modules(&self) -> Vec<(ObjectId, BTreeMap<String, Vec<u8>>)> {
+        vec![
+            (self.audit_trail, audit_trail_modules()),
+            (self.tf_components, tf_components_modules()),
+        ]
+    }
+
+    fn call(
+        session: &mut Session,
+        package_id: ObjectId,
+        module: &str,
+        function: &str,
+        type_arguments: &[String],
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, Failure> {
+        call(session, package_id, module, function, type_arguments, arguments)
+    }
+
+    fn object_type(&self, object: &MoveObject) -> String {
+        object.type_(self)
+    }
+
+    fn has_public_transfer(&self, object: &MoveObject) -> bool {
+        object.has_public_transfer()
+    }
+
+    fn object_bcs(&self, _id: ObjectId, object: &MoveObject) -> Vec<u8> {
+        object.bcs()
+    }
+
+    fn object_json(&self, _id: ObjectId, object: &MoveObject) -> Json {
+        object.fields_json()
+    }
+
+    fn dynamic_fields(&self, _id: ObjectId, object: &MoveObject) -> Vec<DynamicField> {
+        object.dynamic_fields(self)
+    }
+
+    fn value_type(&self, value: &Infallible) -> String {
+        match *value {}
+    }
+
+    fn value_bcs(&self, value: &Infallible) -> Vec<u8> {
+        match *value {}
+    }
+}
+
+/// An object of a simulated package.
+#[derive(Debug, Clone)]
+pub(super) enum MoveObject {
+    /// A `main::AuditTrail<D>`.
+    Trail(Box<Trail>),
+    /// A `capability::Capability` of `TfComponents`.
+    Capability(Capability),
+}
+
+/// A `main::AuditTrail<D>`.
+///
+/// The records and the revoked capabilities are kept next to the trail; the `LinkedTable`s of
+/// [`OnChainAuditTrail`] are derived from them whenever the trail is read.
+#[derive(Debug, Clone)]
+pub(super) struct Trail {
+    type_arg: String,
+    state: OnChainAuditTrail,
+    records: BTreeMap<u64, Record>,
+    /// Revoked capability IDs with their `valid_until`, in insertion order.
+    revoked: Vec<(ObjectId, u64)>,
+}
+
+impl MoveObject {
+    /// Returns the Move type of the object.
+    fn type_(&self, packages: &PackageIds) -> String {
+        match self {
+            MoveObject::Trail(trail) => format!("{}::main::AuditTrail<{}>", packages.audit_trail, trail.type_arg),
+            MoveObject::Capability(_) => format!("{}::capability::Capability", packages.tf_components),
+        }
+    }
+
+    /// Returns whether the object has the `store` ability.
+    fn has_public_transfer(&self) -> bool {
+        matches!(self, MoveObject::Capability(_))
+    }
+
+    /// Returns the BCS of the object.
+    fn bcs(&self) -> Vec<u8> {
+        match self {
+            MoveObject::Trail(trail) => to_bcs(&trail.on_chain()),
+            MoveObject::Capability(capability) => to_bcs(capability),
+        }
+    }
+
+    /// Returns the fields of the object as rendered in the parsed content of object responses.
+    fn fields_json(&self) -> Json {
+        match self {
+            MoveObject::Trail(trail) => serde_json::to_value(trail.on_chain()),
+            MoveObject::Capability(capability) => serde_json::to_value(capability),
+        }
+        .expect("objects are serializable")
+    }
+
+    /// Returns the dynamic fields of the collections owned by the object: the nodes of the `LinkedTable`s of the
+    /// records and of the revoked capabilities of a trail.
+    fn dynamic_fields(&self, packages: &PackageIds) -> Vec<DynamicField> {
+        let MoveObject::Trail(trail) = self else {
+            return Vec::new();
+        };
+
+        let record_type = format!(
+            "0x2::linked_table::Node<u64, {}::record::Record<{}>>",
+            packages.audit_trail, trail.type_arg
+        );
+        let records = trail.records.iter().map(|(&key, record)| {
+            let prev = trail.records.range(..key).next_back().map(|(seq, _)| *seq);
+            let next = trail.records.range(key + 1..).next().map(|(seq, _)| *seq);
+            DynamicField {
+                parent: trail.state.records.id,
+                name_type: TypeTag::U64,
+                name: to_bcs(&key),
+                value_type: record_type.clone(),
+                value: to_bcs(&(prev, next, record)),
+            }
+        });
+
+        let id_type = TypeTag::from_str("0x2::object::ID").expect("the type tag of `ID` is valid");
+        let revoked = trail.revoked.iter().enumerate().map(|(position, (key, valid_until))| {
+            let prev = position.checked_sub(1).map(|prev| trail.revoked[prev].0);
+            let next = trail.revoked.get(position + 1).map(|(id, _)| *id);
+            DynamicField {
+                parent: trail.state.roles.revoked_capabilities.id,
+                name_type: id_type.clone(),
+                name: to_bcs(key),
+                value_type: "0x2::linked_table::Node<0x2::object::ID, u64>".to_string(),
+                value: to_bcs(&(prev, next, valid_until)),
+            }
+        });
+
+        records.chain(revoked).collect()
+    }
+}
+
+impl Trail {
+    /// Returns the trail as stored on-chain, with its `LinkedTable`s in sync with the records and revoked
+    /// capabilities.
+    fn on_chain(&self) -> OnChainAuditTrail {
+        let mut state = self.state.clone();
+        state.records.size = self.records.len() as u64;
+        state.records.head = self.records.keys().next().copied();
+        state.records.tail = self.records.keys().next_back().copied();
+
+        let revoked = &mut state.roles.revoked_capabilities;
+        revoked.size = self.revoked.len() as u64;
+        revoked.head = self.revoked.first().map(|(id, _)| *id);
+        revoked.tail = self.revoked.last().map(|(id, _)| *id);
+
+        state
+    }
+
+    fn id(&self) -> ObjectId {
+        *self.state.id.object_id()
+    }
+
+    /// Mirrors `RoleMap::assert_capability_valid`.
+    fn assert_capability_valid(&self, ctx: &Context, cap: &Capability, permission: Permission) -> Result<(), Failure> {
+        let fail = |name| ctx.role_map_error("assert_capability_valid", name);
+        let roles = &self.state.roles;
+
+        if cap.target_key != roles.target_key {
+            return Err(fail("ECapabilityTargetKeyMismatch"));
+        }
+        if self.revoked.iter().any(|(id, _)| id == cap.id.object_id()) {
+            return Err(fail("ECapabilityHasBeenRevoked"));
+        }
+        let role = roles.roles.get(&cap.role).ok_or_else(|| fail("ERoleDoesNotExist"))?;
+        if !role.permissions.contains(&permission) {
+            return Err(fail("ECapabilityPermissionDenied"));
+        }
+        if cap.issued_to.is_some_and(|issued_to| issued_to != ctx.sender) {
+            return Err(fail("ECapabilityIssuedToMismatch"));
+        }
+        if cap.valid_from.is_some_and(|valid_from| ctx.now_ms < valid_from)
+            || cap.valid_until.is_some_and(|valid_until| ctx.now_ms > valid_until)
+        {
+            return Err(fail("ECapabilityTimeConstraintsNotMet"));
+        }
+
+        Ok(())
+    }
+
+    /// Mirrors `is_record_tag_allowed` of `main`, aborting with `ERecordTagNotDefined` for unknown tags.
+    fn is_record_tag_allowed(&self, ctx: &Context, cap: &Capability, tag: Option<&String>) -> Result<bool, Failure> {
+        let Some(tag) = tag else {
+            return Ok(true);
+        };
+        if !self.state.tags.contains_key(tag) {
+            return Err(ctx.main_error("ERecordTagNotDefined"));
+        }
+
+        Ok(self
+            .state
+            .roles
+            .roles
+            .get(&cap.role)
+            .and_then(|role| role.data.as_ref())
+            .is_some_and(|role_tags| role_tags.allows(tag)))
+    }
+
+    /// Returns the lowest sequence number locked by a count-based delete window.
+    fn count_lock_threshold(&self) -> Option<u64> {
+        match self.state.locking_config.delete_record_window {
+            LockingWindow::CountBased { count } if count > 0 => Some(
+                self.records
+                    .keys()
+                    .rev()
+                    .take(count as usize)
+                    .last()
+                    .copied()
+                    .unwrap_or(0),
+            ),
+            _ => None,
+        }
+    }
+
+    fn is_record_locked(&self, sequence_number: u64, threshold: Option<u64>, now_ms: u64) -> bool {
+        if threshold.is_some_and(|threshold| sequence_number >= threshold) {
+            return true;
+        }
+        match (
+            &self.state.locking_config.delete_record_window,
+            self.records.get(&sequence_number),
+        ) {
+            (LockingWindow::TimeBased { seconds }, Some(record)) => {
+                now_ms.saturating_sub(record.added_at) < seconds.saturating_mul(1000)
+            }
+            _ => false,
+        }
+    }
+
+    fn change_tag_usage(&mut self, tag: &str, increment: bool) {
+        if let Some(count) = self.state.tags.tag_map.get_mut(tag) {
+            *count = if increment { *count + 1 } else { count.saturating_sub(1) };
+        }
+    }
+
+    fn change_role_tag_usage(&mut self, role_tags: Option<&RoleTags>, increment: bool) {
+        for tag in role_tags.into_iter().flat_map(|role_tags| role_tags.tags.iter()) {
+            self.change_tag_usage(tag, increment);
+        }
+    }
+
+    /// Appends a record and returns its sequence number.
+    fn push_record(&mut self, ctx: &Context, record: InitialRecord, correction: RecordCorrection) -> u64 {
+        let sequence_number = self.state.sequence_number;
+        if let Some(tag) = &record.tag {
+            self.change_tag_usage(tag, true);
+        }
+        self.records.insert(
+            sequence_number,
+            Record {
+                data: record.data,
+                metadata: record.metadata,
+                tag: record.tag,
+                sequence_number,
+                added_by: ctx.sender,
+                added_at: ctx.now_ms,
+                correction,
+            },
+        );
+        self.state.sequence_number += 1;
+
+        sequence_number
+    }
+
+    fn remove_record(&mut self, sequence_number: u64) {
+        if let Some(tag) = self.records.remove(&sequence_number).and_then(|record| record.tag) {
+            self.change_tag_usage(&tag, false);
+        }
+    }
+}
+
+/// Returns the modules of the audit-trail package, as bytecode by module name.
+fn audit_trail_modules() -> BTreeMap<String, Vec<u8>> {
+    BTreeMap::from([("main".to_string(), bytecode("main", MAIN_ERRORS))])
+}
+
+/// Returns the modules of the `TfComponents` package, as bytecode by module name.
+fn tf_components_modules() -> BTreeMap<String, Vec<u8>> {
+    BTreeMap::from([
+        ("role_map".to_string(), bytecode("role_map", &ROLE_MAP_ERRORS)),
+        ("capability".to_string(), bytecode("capability", &CAPABILITY_ERRORS)),
+    ])
+}
+
+/// Serializes a module holding only an identifier table with the name of the module and its error constants,
+/// which is all that is needed to resolve clever errors.
+fn bytecode(module: &str, errors: &[&str]) -> Vec<u8> {
+    let identifiers: Vec<u8> = std::iter::once(module)
+        .chain(errors.iter().copied())
+        .flat_map(|identifier| [uleb128(identifier.len() as u64), identifier.as_bytes().to_vec()].concat())
+        .collect();
+
+    let mut bytecode = vec![0xA1, 0x1C, 0xEB, 0x0B, 6, 0, 0, 0];
+    bytecode.extend(uleb128(1));
+    bytecode.push(IDENTIFIERS_TABLE);
+    bytecode.extend(uleb128(0));
+    bytecode.extend(uleb128(identifiers.len() as u64));
+    bytecode.extend(identifiers);
+
+    bytecode
+}
+
+/// Returns the clever error code of the constant `name` of a module created by [`bytecode`].
+fn clever_code(errors: &[&str], name: &str) -> u64 {
+    let index = errors
+        .iter()
+        .position(|error| *error == name)
+        .expect("error constants are declared") as u64;
+
+    // The identifier table starts with the name of the module.
+    CLEVER_ERROR_TAG | ((index + 1) << 16) | index
+}
+
+/// The context of a Move call.
+struct Context<'a> {
+    packages: PackageIds,
+    sender: Address,
+    now_ms: u64,
+    function: &'a str,
+}
+
+impl Context<'_> {
+    fn main_error(&self, name: &str) -> Failure {
+        abort(
+            self.packages.audit_trail,
+            "main",
+            self.function,
+            clever_code(MAIN_ERRORS, name),
+        )
+    }
+
+    fn role_map_error(&self, function: &str, name: &str) -> Failure {
+        abort(
+            self.packages.tf_components,
+            "role_map",
+            function,
+            clever_code(&ROLE_MAP_ERRORS, name),
+        )
+    }
+
+    fn audit_type(&self, name: &str) -> String {
+        format!("{}::{name}", self.packages.audit_trail)
+    }
+
+    fn tf_type(&self, name: &str) -> String {
+        format!("{}::{name}", self.packages.tf_components)
+    }
+
+    fn emit_main(&self, session: &mut Session, name: &str, event: &impl Serialize) {
+        session.emit(self.packages.audit_trail, "main", name, event);
+    }
+
+    fn emit_role_map(&self, session: &mut Session, name: &str, event: &impl Serialize) {
+        session.emit(self.packages.tf_components, "role_map", name, event);
+    }
+}
+
+/// Executes the Move function `module::function` of the package `package_id`.
+fn call(
+    session: &mut Session,
+    package_id: ObjectId,
+    module: &str,
+    function: &str,
+    type_arguments: &[String],
+    arguments: Vec<Value>,
+) -> Result<Vec<Value>, Failure> {
+    let mut args = Args(arguments.into_iter());
+    let ctx = Context {
+        packages: session.packages,
+        sender: session.sender,
+        now_ms: session.now_ms,
+        function,
+    };
+
+    if package_id == MOVE_STDLIB_PACKAGE_ID {
+        let type_ = format!(
+            "{MOVE_STDLIB_PACKAGE_ID}::option::Option<{}>",
+            type_argument(type_arguments)?
+        );
+        return match (module, function) {
+            ("option", "some") => Ok(vec![Value::Move {
+                bcs: [vec![1], args.pure_bytes()?].concat(),
+                type_,
+            }]),
+            ("option", "none") => Ok(vec![Value::Move { bcs: vec![0], type_ }]),
+            _ => Err(unsupported(module, function)),
+        };
+    }
+    if package_id == ctx.packages.tf_components {
+        let lock = match (module, function) {
+            ("timelock", "none") => TimeLock::None,
+            ("timelock", "infinite") => TimeLock::Infinite,
+            ("timelock", "until_destroyed") => TimeLock::UntilDestroyed,
+            ("timelock", "unlock_at") => {
+                let time: u32 = args.pure()?;
+                if u64::from(time) <= ctx.now_ms / 1000 {
+                    return Err(abort(package_id, module, function, TIMELOCK_E_PAST_TIMESTAMP));
+                }
+                TimeLock::UnlockAt(time)
+            }
+            ("timelock", "unlock_at_ms") => {
+                let time_ms: u64 = args.pure()?;
+                if time_ms <= ctx.now_ms {
+                    return Err(abort(package_id, module, function, TIMELOCK_E_PAST_TIMESTAMP));
+                }
+                TimeLock::UnlockAtMs(time_ms)
+            }
+            _ => return Err(unsupported(module, function)),
+        };
+        return Ok(vec![value(&lock, ctx.tf_type("timelock::TimeLock"))]);
+    }
+    if package_id != ctx.packages.audit_trail {
+        return Err(Failure::Other(format!("package {package_id} is not available")));
+    }
+
+    match (module, function) {
+        ("record", "new_bytes") => {
+            let data = Data::Bytes(args.pure()?);
+            Ok(vec![value(&data, ctx.audit_type("record::Data"))])
+        }
+        ("record", "new_text") => {
+            let data = Data::Text(args.pure()?);
+            Ok(vec![value(&data, ctx.audit_type("record::Data"))])
+        }
+        ("record", "new_initial_record") => {
+            let record = InitialRecord {
+                data: args.pure::<Data>()?,
+                metadata: args.pure()?,
+                tag: args.pure()?,
+            };
+            let type_ = format!(
+                "{}<{}>",
+                ctx.audit_type("record::InitialRecord"),
+                type_argument(type_arguments)?
+            );
+            Ok(vec![value(&record, type_)])
+        }
+
+        ("locking", "window_none") => Ok(vec![value(
+            &LockingWindow::None,
+            ctx.audit_type("locking::LockingWindow"),
+        )]),
+        ("locking", "window_time_based") => {
+            let window = LockingWindow::TimeBased { seconds: args.pure()? };
+            Ok(vec![value(&window, ctx.audit_type("locking::LockingWindow"))])
+        }
+        ("locking", "window_count_based") => {
+            let count: u64 = args.pure()?;
+            if count == 0 {
+                return Err(locking_error(package_id, function, "ECountWindowMustBePositive"));
+            }
+            let window = LockingWindow::CountBased { count };
+            Ok(vec![value(&window, ctx.audit_type("locking::LockingWindow"))])
+        }
+        ("locking", "new") => {
+            let config = LockingConfig {
+                delete_record_window: args.pure()?,
+                delete_trail_lock: args.pure()?,
+                write_lock: args.pure()?,
+            };
+            if config.delete_trail_lock == TimeLock::UntilDestroyed {
+                return Err(locking_error(
+                    package_id,
+                    function,
+                    "EUntilDestroyedNotSupportedForDeleteTrail",
+                ));
+            }
+            Ok(vec![value(&config, ctx.audit_type("locking::LockingConfig"))])
+        }
+
+        ("permission", "from_vec") => {
+            let mut permissions: Vec<Permission> = args.pure()?;
+            let mut seen = HashSet::new();
+            permissions.retain(|permission| seen.insert(*permission));
+            let type_ = format!("0x2::vec_set::VecSet<{}>", ctx.audit_type("permission::Permission"));
+            Ok(vec![value(&permissions, type_)])
+        }
+        ("permission", _) => {
+            let permission = PERMISSIONS
+                .into_iter()
+                .find(|permission| permission.function_name() == function)
+                .ok_or_else(|| unsupported(module, function))?;
+            Ok(vec![value(&permission, ctx.audit_type("permission::Permission"))])
+        }
+
+        ("record_tags", "new_role_tags") => {
+            let tags: Vec<String> = args.pure()?;
+            let role_tags = RoleTags::new(tags);
+            Ok(vec![value(&role_tags, ctx.audit_type("record_tags::RoleTags"))])
+        }
+
+        ("main", "new_trail_metadata") => {
+            let metadata = ImmutableMetadata {
+                name: args.pure()?,
+                description: args.pure()?,
+            };
+            Ok(vec![value(&metadata, ctx.audit_type("main::ImmutableMetadata"))])
+        }
+        ("main", "create") => create(session, &ctx, type_argument(type_arguments)?, args),
+        ("main", _) => call_main(session, &ctx, args),
+
+        _ => Err(unsupported(module, function)),
+    }
+}
+
+/// Mirrors `main::create`.
+fn create(session: &mut Session, ctx: &Context, type_arg: String, mut args: Args) -> Result<Vec<Value>, Failure> {
+    let initial_record: Option<InitialRecord> = args.pure()?;
+    let locking_config: LockingConfig = args.pure()?;
+    let immutable_metadata: Option<ImmutableMetadata> = args.pure()?;
+    let updatable_metadata: Option<String> = args.pure()?;
+    let tags: Vec<String> = args.pure()?;
+
+    let trail_id = session.new_id();
+    let admin_cap_id = session.new_id();
+    let admin_cap = Capability {
+        id: UID::new(admin_cap_id),
+        target_key: trail_id,
+        role: INITIAL_ADMIN_ROLE_NAME.to_string(),
+        issued_to: None,
+        valid_from: None,
+        valid_until: None,
+    };
+    let state = OnChainAuditTrail {
+        id: UID::new(trail_id),
+        creator: ctx.sender,
+        created_at: ctx.now_ms,
+        sequence_number: 0,
+        records: LinkedTable {
+            id: session.new_id(),
+            size: 0,
+            head: None,
+            tail: None,
+        },
+        tags: TagRegistry {
+            tag_map: tags.into_iter().map(|tag| (tag, 0)).collect(),
+        },
+        locking_config,
+        roles: RoleMap {
+            target_key: trail_id,
+            roles: HashMap::from([(
+                INITIAL_ADMIN_ROLE_NAME.to_string(),
+                Role {
+                    permissions: PermissionSet::admin_permissions().permissions,
+                    data: None,
+                },
+            )]),
+            initial_admin_role_name: INITIAL_ADMIN_ROLE_NAME.to_string(),
+            revoked_capabilities: LinkedTable {
+                id: session.new_id(),
+                size: 0,
+                head: None,
+                tail: None,
+            },
+            initial_admin_cap_ids: HashSet::from([admin_cap_id]),
+            role_admin_permissions: RoleAdminPermissions {
+                add: Permission::AddRoles,
+                delete: Permission::DeleteRoles,
+                update: Permission::UpdateRoles,
+            },
+            capability_admin_permissions: CapabilityAdminPermissions {
+                add: Permission::AddCapabilities,
+                revoke: Permission::RevokeCapabilities,
+            },
+        },
+        immutable_metadata,
+        updatable_metadata,
+        version: PACKAGE_VERSION,
+    };
+    let mut trail = Trail {
+        type_arg,
+        state,
+        records: BTreeMap::new(),
+        revoked: Vec::new(),
+    };
+    if let Some(initial_record) = initial_record {
+        if initial_record
+            .tag
+            .as_ref()
+            .is_some_and(|tag| !trail.state.tags.contains_key(tag))
+        {
+            return Err(ctx.main_error("ERecordTagNotDefined"));
+        }
+        trail.push_record(ctx, initial_record, RecordCorrection::default());
+    }
+
+    session.create(
+        admin_cap_id,
+        Owner::Address(ctx.sender),
+        MoveObject::Capability(admin_cap),
+    );
+    session.create(trail_id, Owner::Shared(0), MoveObject::Trail(Box::new(trail)));
+    ctx.emit_main(
+        session,
+        "AuditTrailCreated",
+        &AuditTrailCreated {
+            trail_id,
+            creator: ctx.sender,
+            timestamp: ctx.now_ms,
+        },
+    );
+
+    Ok(vec![
+        Value::Object(admin_cap_id),
+        value(&trail_id, "0x2::object::ID".to_string()),
+    ])
+}
+
+/// Executes the functions of `main` taking the trail as their first argument.
+fn call_main(session: &mut Session, ctx: &Context, mut args: Args) -> Result<Vec<Value>, Failure> {
+    let trail_id = args.object()?;
+
+    // Read-only functions, which do not take a capability.
+    match ctx.function {
+        "record_count" => {
+            return Ok(vec![value(
+                &(trail(session, trail_id)?.records.len() as u64),
+                "u64".to_string(),
+            )]);
+        }
+        "sequence_number" => {
+            return Ok(vec![value(
+                &trail(session, trail_id)?.state.sequence_number,
+                "u64".to_string(),
+            )]);
+        }
+        "is_empty" => {
+            return Ok(vec![value(
+                &trail(session, trail_id)?.records.is_empty(),
+                "bool".to_string(),
+            )]);
+        }
+        "has_record" => {
+            let sequence_number: u64 = args.pure()?;
+            let exists = trail(session, trail_id)?.records.contains_key(&sequence_number);
+            return Ok(vec![value(&exists, "bool".to_string())]);
+        }
+        "get_record" => {
+            let sequence_number: u64 = args.pure()?;
+            let trail = trail(session, trail_id)?;
+            let record = trail
+                .records
+                .get(&sequence_number)
+                .ok_or_else(|| ctx.main_error("ERecordNotFound"))?;
+            let type_ = format!("{}<{}>", ctx.audit_type("record::Record"), trail.type_arg);
+            return Ok(vec![value(record, type_)]);
+        }
+        "is_record_locked" => {
+            let sequence_number: u64 = args.pure()?;
+            let trail = trail(session, trail_id)?;
+            if !trail.records.contains_key(&sequence_number) {
+                return Err(ctx.main_error("ERecordNotFound"));
+            }
+            let locked = trail.is_record_locked(sequence_number, trail.count_lock_threshold(), ctx.now_ms);
+            return Ok(vec![value(&locked, "bool".to_string())]);
+        }
+        "destroy_initial_admin_capability" => {
+            let cap_to_destroy = args.object()?;
+            return destroy_capability(session, ctx, trail_id, None, cap_to_destroy);
+        }
+        _ => {}
+    }
+
+    let cap_id = args.object()?;
+    let cap = capability(session, cap_id)?;
+    let version = trail(session, trail_id)?.state.version;
+    if ctx.function == "migrate" {
+        // Trails are always created at the current version, so there is nothing to migrate.
+        return Err(ctx.main_error("EPackageVersionMismatch"));
+    }
+    if version != PACKAGE_VERSION {
+        return Err(ctx.main_error("EPackageVersionMismatch"));
+    }
+
+    match ctx.function {
+        "add_record" => {
+            let record = InitialRecord {
+                data: args.pure()?,
+                metadata: args.pure()?,
+                tag: args.pure()?,
+            };
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::AddRecord)?;
+            if is_timelocked(&trail.state.locking_config.write_lock, ctx.now_ms) {
+                return Err(ctx.main_error("ETrailWriteLocked"));
+            }
+            if !trail.is_record_tag_allowed(ctx, &cap, record.tag.as_ref())? {
+                return Err(ctx.main_error("ERecordTagNotAllowed"));
+            }
+            let sequence_number = trail.push_record(ctx, record, RecordCorrection::default());
+
+            Ok(vec![record_added(session, ctx, trail_id, sequence_number)])
+        }
+        "correct_record" => {
+            let replaced: u64 = args.pure()?;
+            let record = InitialRecord {
+                data: args.pure()?,
+                metadata: args.pure()?,
+                tag: args.pure()?,
+            };
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::CorrectRecord)?;
+            if is_timelocked(&trail.state.locking_config.write_lock, ctx.now_ms) {
+                return Err(ctx.main_error("ETrailWriteLocked"));
+            }
+            let old = trail
+                .records
+                .get(&replaced)
+                .ok_or_else(|| ctx.main_error("ERecordNotFound"))?;
+            if old.correction.is_replaced() {
+                return Err(ctx.main_error("ERecordAlreadyReplaced"));
+            }
+            if !trail.is_record_tag_allowed(ctx, &cap, old.tag.as_ref())?
+                || !trail.is_record_tag_allowed(ctx, &cap, record.tag.as_ref())?
+            {
+                return Err(ctx.main_error("ERecordTagNotAllowed"));
+            }
+            let correction = RecordCorrection::with_replaces(HashSet::from([replaced]));
+            let sequence_number = trail.push_record(ctx, record, correction);
+            if let Some(old) = trail.records.get_mut(&replaced) {
+                old.correction.is_replaced_by = Some(sequence_number);
+            }
+
+            Ok(vec![record_added(session, ctx, trail_id, sequence_number)])
+        }
+        "delete_record" => {
+            let sequence_number: u64 = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::DeleteRecord)?;
+            let record = trail
+                .records
+                .get(&sequence_number)
+                .ok_or_else(|| ctx.main_error("ERecordNotFound"))?;
+            if !trail.is_record_tag_allowed(ctx, &cap, record.tag.as_ref())? {
+                return Err(ctx.main_error("ERecordTagNotAllowed"));
+            }
+            if trail.is_record_locked(sequence_number, trail.count_lock_threshold(), ctx.now_ms) {
+                return Err(ctx.main_error("ERecordLocked"));
+            }
+            trail.remove_record(sequence_number);
+            record_deleted(session, ctx, trail_id, sequence_number);
+
+            Ok(Vec::new())
+        }
+        "delete_records_batch" => {
+            let limit: u64 = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::DeleteAllRecords)?;
+            let threshold = trail.count_lock_threshold();
+            let mut deleted = Vec::new();
+            for (sequence_number, record) in &trail.records {
+                if deleted.len() as u64 >= limit {
+                    break;
+                }
+                if trail.is_record_locked(*sequence_number, threshold, ctx.now_ms)
+                    || !trail.is_record_tag_allowed(ctx, &cap, record.tag.as_ref())?
+                {
+                    continue;
+                }
+                deleted.push(*sequence_number);
+            }
+            for sequence_number in &deleted {
+                trail.remove_record(*sequence_number);
+            }
+            for sequence_number in &deleted {
+                record_deleted(session, ctx, trail_id, *sequence_number);
+            }
+
+            Ok(vec![value(&deleted, "vector<u64>".to_string())])
+        }
+        "delete_audit_trail" => {
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::DeleteAuditTrail)?;
+            if is_timelocked(&trail.state.locking_config.delete_trail_lock, ctx.now_ms) {
+                return Err(ctx.main_error("ETrailDeleteLocked"));
+            }
+            if !trail.records.is_empty() {
+                return Err(ctx.main_error("ETrailNotEmpty"));
+            }
+            session.delete(trail_id)?;
+            ctx.emit_main(
+                session,
+                "AuditTrailDeleted",
+                &AuditTrailDeleted {
+                    trail_id,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+
+        "update_locking_config" | "update_delete_record_window" | "update_delete_trail_lock" | "update_write_lock" => {
+            let trail = trail_mut(session, trail_id)?;
+            let config = &trail.state.locking_config;
+            let (permission, config) = match ctx.function {
+                "update_locking_config" => (Permission::UpdateLockingConfig, args.pure()?),
+                "update_delete_record_window" => (
+                    Permission::UpdateLockingConfigForDeleteRecord,
+                    LockingConfig {
+                        delete_record_window: args.pure()?,
+                        ..config.clone()
+                    },
+                ),
+                "update_delete_trail_lock" => (
+                    Permission::UpdateLockingConfigForDeleteTrail,
+                    LockingConfig {
+                        delete_trail_lock: args.pure()?,
+                        ..config.clone()
+                    },
+                ),
+                _ => (
+                    Permission::UpdateLockingConfigForWrite,
+                    LockingConfig {
+                        write_lock: args.pure()?,
+                        ..config.clone()
+                    },
+                ),
+            };
+            trail.assert_capability_valid(ctx, &cap, permission)?;
+            if config.delete_trail_lock == TimeLock::UntilDestroyed {
+                return Err(locking_error(
+                    ctx.packages.audit_trail,
+                    "set_delete_trail_lock",
+                    "EUntilDestroyedNotSupportedForDeleteTrail",
+                ));
+            }
+            trail.state.locking_config = config;
+            ctx.emit_main(
+                session,
+                "LockingConfigUpdated",
+                &LockingConfigUpdated {
+                    trail_id,
+                    updated_by: ctx.sender,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+        "update_metadata" => {
+            let metadata: Option<String> = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::UpdateMetadata)?;
+            trail.state.updatable_metadata = metadata;
+            ctx.emit_main(
+                session,
+                "MetadataUpdated",
+                &MetadataUpdated {
+                    trail_id,
+                    updated_by: ctx.sender,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+
+        "add_record_tag" => {
+            let tag: String = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::AddRecordTags)?;
+            if trail.state.tags.contains_key(&tag) {
+                return Err(ctx.main_error("ERecordTagAlreadyDefined"));
+            }
+            trail.state.tags.tag_map.insert(tag, 0);
+            ctx.emit_main(
+                session,
+                "RecordTagAdded",
+                &RecordTagAdded {
+                    trail_id,
+                    added_by: ctx.sender,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+        "remove_record_tag" => {
+            let tag: String = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            trail.assert_capability_valid(ctx, &cap, Permission::DeleteRecordTags)?;
+            match trail.state.tags.get(&tag) {
+                None => return Err(ctx.main_error("ERecordTagNotDefined")),
+                Some(usage) if *usage > 0 => return Err(ctx.main_error("ERecordTagInUse")),
+                Some(_) => {}
+            }
+            trail.state.tags.tag_map.remove(&tag);
+            ctx.emit_main(
+                session,
+                "RecordTagRemoved",
+                &RecordTagRemoved {
+                    trail_id,
+                    removed_by: ctx.sender,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+
+        "create_role" | "update_role_permissions" => {
+            let role: String = args.pure()?;
+            let permissions: Vec<Permission> = args.pure()?;
+            let role_tags: Option<RoleTags> = args.pure()?;
+            let creates = ctx.function == "create_role";
+            let function = if creates { "create_role" } else { "update_role" };
+            let trail = trail_mut(session, trail_id)?;
+            if role_tags
+                .iter()
+                .flat_map(|role_tags| role_tags.tags.iter())
+                .any(|tag| !trail.state.tags.contains_key(tag))
+            {
+                return Err(ctx.main_error("ERecordTagNotDefined"));
+            }
+            let existing = trail.state.roles.roles.get(&role).cloned();
+            if creates {
+                let add = trail.state.roles.role_admin_permissions.add;
+                trail.assert_capability_valid(ctx, &cap, add)?;
+                if existing.is_some() {
+                    return Err(ctx.role_map_error(function, "ERoleAlreadyExists"));
+                }
+            } else {
+                let existing = existing
+                    .as_ref()
+                    .ok_or_else(|| ctx.role_map_error("get_role_data", "ERoleDoesNotExist"))?;
+                let update = trail.state.roles.role_admin_permissions.update;
+                trail.assert_capability_valid(ctx, &cap, update)?;
+                let roles = &trail.state.roles;
+                let required = [
+                    roles.role_admin_permissions.add,
+                    roles.role_admin_permissions.delete,
+                    roles.role_admin_permissions.update,
+                    roles.capability_admin_permissions.add,
+                    roles.capability_admin_permissions.revoke,
+                ];
+                if role == roles.initial_admin_role_name
+                    && required.iter().any(|permission| !permissions.contains(permission))
+                {
+                    return Err(ctx.role_map_error(function, "EInitialAdminPermissionsInconsistent"));
+                }
+                trail.change_role_tag_usage(existing.data.as_ref(), false);
+            }
+            trail.change_role_tag_usage(role_tags.as_ref(), true);
+            trail.state.roles.roles.insert(
+                role.clone(),
+                Role {
+                    permissions: permissions.iter().copied().collect(),
+                    data: role_tags.clone(),
+                },
+            );
+            let event = RoleEvent {
+                target_key: trail_id,
+                role,
+                permissions,
+                data: role_tags,
+                by: ctx.sender,
+                timestamp: ctx.now_ms,
+            };
+            ctx.emit_role_map(session, if creates { "RoleCreated" } else { "RoleUpdated" }, &event);
+
+            Ok(Vec::new())
+        }
+        "delete_role" => {
+            let role: String = args.pure()?;
+            let trail = trail_mut(session, trail_id)?;
+            let existing = trail
+                .state
+                .roles
+                .roles
+                .get(&role)
+                .cloned()
+                .ok_or_else(|| ctx.role_map_error("get_role_data", "ERoleDoesNotExist"))?;
+            let delete = trail.state.roles.role_admin_permissions.delete;
+            trail.assert_capability_valid(ctx, &cap, delete)?;
+            if role == trail.state.roles.initial_admin_role_name {
+                return Err(ctx.role_map_error("delete_role", "EInitialAdminRoleCannotBeDeleted"));
+            }
+            trail.state.roles.roles.remove(&role);
+            trail.change_role_tag_usage(existing.data.as_ref(), false);
+            ctx.emit_role_map(
+                session,
+                "RoleDeleted",
+                &RoleDeleted {
+                    target_key: trail_id,
+                    role,
+                    deleted_by: ctx.sender,
+                    timestamp: ctx.now_ms,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+
+        "new_capability" => {
+            let role: String = args.pure()?;
+            let issued_to: Option<Address> = args.pure()?;
+            let valid_from: Option<u64> = args.pure()?;
+            let valid_until: Option<u64> = args.pure()?;
+            let capability_id = session.new_id();
+            let trail = trail_mut(session, trail_id)?;
+            let add = trail.state.roles.capability_admin_permissions.add;
+            trail.assert_capability_valid(ctx, &cap, add)?;
+            if !trail.state.roles.roles.contains_key(&role) {
+                return Err(ctx.role_map_error("new_capability", "ERoleDoesNotExist"));
+            }
+            if valid_from
+                .zip(valid_until)
+                .is_some_and(|(valid_from, valid_until)| valid_from > valid_until)
+            {
+                return Err(abort(
+                    ctx.packages.tf_components,
+                    "capability",
+                    "new_capability",
+                    clever_code(&CAPABILITY_ERRORS, "EValidityPeriodInconsistent"),
+                ));
+            }
+            if role == trail.state.roles.initial_admin_role_name {
+                trail.state.roles.initial_admin_cap_ids.insert(capability_id);
+            }
+            let capability = Capability {
+                id: UID::new(capability_id),
+                target_key: trail_id,
+                role,
+                issued_to,
+                valid_from,
+                valid_until,
+            };
+            let issued = CapabilityIssued {
+                target_key: trail_id,
+                capability_id,
+                role: capability.role.clone(),
+                issued_to,
+                valid_from,
+                valid_until,
+            };
+            let recipient = issued_to.unwrap_or(ctx.sender);
+            session.create(
+                capability_id,
+                Owner::Address(recipient),
+                MoveObject::Capability(capability),
+            );
+            ctx.emit_role_map(session, "CapabilityIssued", &issued);
+
+            Ok(vec![value(&issued, ctx.audit_type("main::CapabilityIssuedReceipt"))])
+        }
+        "revoke_capability" | "revoke_initial_admin_capability" => {
+            let capability_id: ObjectId = args.pure()?;
+            let valid_until: Option<u64> = args.pure()?;
+            let initial_admin = ctx.function == "revoke_initial_admin_capability";
+            let trail = trail_mut(session, trail_id)?;
+            let revoke = trail.state.roles.capability_admin_permissions.revoke;
+            trail.assert_capability_valid(ctx, &cap, revoke)?;
+            let is_initial_admin = trail.state.roles.initial_admin_cap_ids.contains(&capability_id);
+            if initial_admin && !is_initial_admin {
+                return Err(ctx.role_map_error(ctx.function, "ECapabilityIsNotInitialAdmin"));
+            }
+            if !initial_admin && is_initial_admin {
+                return Err(ctx.role_map_error(ctx.function, "EInitialAdminCapabilityMustBeExplicitlyDestroyed"));
+            }
+            if trail.revoked.iter().any(|(id, _)| *id == capability_id) {
+                return Err(ctx.role_map_error(ctx.function, "ECapabilityToRevokeHasAlreadyBeenRevoked"));
+            }
+            let valid_until = valid_until.unwrap_or(0);
+            trail.revoked.push((capability_id, valid_until));
+            ctx.emit_role_map(
+                session,
+                "CapabilityRevoked",
+                &CapabilityRevoked {
+                    target_key: trail_id,
+                    capability_id,
+                    valid_until,
+                },
+            );
+
+            Ok(Vec::new())
+        }
+        "destroy_capability" => {
+            let cap_to_destroy = args.object()?;
+            destroy_capability(session, ctx, trail_id, Some(&cap), cap_to_destroy)
+        }
+        "cleanup_revoked_capabilities" => {
+            let trail = trail_mut(session, trail_id)?;
+            let revoke = trail.state.roles.capability_admin_permissions.revoke;
+            trail.assert_capability_valid(ctx, &cap, revoke)?;
+            let before = trail.revoked.len();
+            trail
+                .revoked
+                .retain(|(_, valid_until)| *valid_until == 0 || *valid_until >= ctx.now_ms);
+            let cleaned_up = RevokedCapabilitiesCleanedUp {
+                trail_id,
+                cleaned_count: (before - trail.revoked.len()) as u64,
+                cleaned_by: ctx.sender,
+                timestamp: ctx.now_ms,
+            };
+            ctx.emit_main(session, "RevokedCapabilitiesCleanedUp", &cleaned_up);
+
+            Ok(vec![value(
+                &cleaned_up,
+                ctx.audit_type("main::RevokedCapabilitiesCleanedUp"),
+            )])
+        }
+
+        function => Err(unsupported("main", function)),
+    }
+}
+
+/// Mirrors `main::destroy_capability` if `cap` is given, and `main::destroy_initial_admin_capability` otherwise.
+fn destroy_capability(
+    session: &mut Session,
+    ctx: &Context,
+    trail_id: ObjectId,
+    cap: Option<&Capability>,
+    cap_to_destroy: ObjectId,
+) -> Result<Vec<Value>, Failure> {
+    let destroyed = capability(session, cap_to_destroy)?;
+    let trail = trail_mut(session, trail_id)?;
+    if trail.state.version != PACKAGE_VERSION {
+        return Err(ctx.main_error("EPackageVersionMismatch"));
+    }
+    if let Some(cap) = cap {
+        let revoke = trail.state.roles.capability_admin_permissions.revoke;
+        trail.assert_capability_valid(ctx, cap, revoke)?;
+    }
+    if destroyed.target_key != trail_id {
+        return Err(ctx.role_map_error(ctx.function, "ECapabilityTargetKeyMismatch"));
+    }
+    let is_initial_admin = trail.state.roles.initial_admin_cap_ids.remove(&cap_to_destroy);
+    match (cap.is_some(), is_initial_admin) {
+        (true, true) => {
+            return Err(ctx.role_map_error(ctx.function, "EInitialAdminCapabilityMustBeExplicitlyDestroyed"));
+        }
+        (false, false) => return Err(ctx.role_map_error(ctx.function, "ECapabilityIsNotInitialAdmin")),
+        _ => {}
+    }
+    trail.revoked.retain(|(id, _)| *id != cap_to_destroy);
+    session.delete(cap_to_destroy)?;
+    ctx.emit_role_map(
+        session,
+        "CapabilityDestroyed",
+        &CapabilityDestroyed {
+            target_key: trail_id,
+            capability_id: cap_to_destroy,
+            role: destroyed.role,
+            issued_to: destroyed.issued_to,
+            valid_from: destroyed.valid_from,
+            valid_until: destroyed.valid_until,
+        },
+    );
+
+    Ok(Vec::new())
+}
+
+/// Mirrors `timelock::is_timelocked`.
+fn is_timelocked(lock: &TimeLock, now_ms: u64) -> bool {
+    match lock {
+        TimeLock::UnlockAt(time) => now_ms / 1000 < u64::from(*time),
+        TimeLock::UnlockAtMs(time_ms) => now_ms < *time_ms,
+        TimeLock::UntilDestroyed | TimeLock::Infinite => true,
+        TimeLock::None => false,
+    }
+}
+
+/// Emits `RecordAdded` and returns it as the value returned by `main::add_record`.
+fn record_added(session: &mut Session, ctx: &Context, trail_id: ObjectId, sequence_number: u64) -> Value {
+    let added = RecordAdded {
+        trail_id,
+        sequence_number,
+        added_by: ctx.sender,
+        timestamp: ctx.now_ms,
+    };
+    ctx.emit_main(session, "RecordAdded", &added);
+
+    value(&added, ctx.audit_type("main::RecordAdded"))
+}
+
+fn record_deleted(session: &mut Session, ctx: &Context, trail_id: ObjectId, sequence_number: u64) {
+    ctx.emit_main(
+        session,
+        "RecordDeleted",
+        &RecordDeleted {
+            trail_id,
+            sequence_number,
+            deleted_by: ctx.sender,
+            timestamp: ctx.now_ms,
+        },
+    );
+}
+
+/// Layout of the `role_map::RoleCreated` and `role_map::RoleUpdated` events.
+#[derive(Serialize)]
+struct RoleEvent {
+    target_key: ObjectId,
+    role: String,
+    permissions: Vec<Permission>,
+    data: Option<RoleTags>,
+    by: Address,
+    timestamp: u64,
+}
+
+/// Layout of the `role_map::RoleDeleted` event.
+#[derive(Serialize)]
+struct RoleDeleted {
+    target_key: ObjectId,
+    role: String,
+    deleted_by: Address,
+    timestamp: u64,
+}
+
+fn trail(session: &Session, id: ObjectId) -> Result<&Trail, Failure> {
+    match session.borrow(id)? {
+        MoveObject::Trail(trail) => Ok(trail),
+        _ => Err(Failure::Other(format!("object {id} is not an audit trail"))),
+    }
+}
+
+fn trail_mut(session: &mut Session, id: ObjectId) -> Result<&mut Trail, Failure> {
+    match session.borrow_mut(id)? {
+        MoveObject::Trail(trail) => Ok(trail),
+        _ => Err(Failure::Other(format!("object {id} is not an audit trail"))),
+    }
+}
+
+fn capability(session: &Session, id: ObjectId) -> Result<Capability, Failure> {
+    match session.borrow(id)? {
+        MoveObject::Capability(capability) => Ok(capability.clone()),
+        _ => Err(Failure::Other(format!("object {id} is not a capability"))),
+    }
+}
+
+fn abort(package_id: ObjectId, module: &str, function: &str, code: u64) -> Failure {
+    Failure::Abort {
+        package_id,
+        module: module.to_string(),
+        function: function.to_string(),
+        code,
+    }
+}
+
+/// Aborts `function` of the `locking` module with its plain error constant `name`.
+fn locking_error(package_id: ObjectId, function: &str, name: &str) -> Failure {
+    let code = abort::abort_code("locking", name).expect("abort constants are declared in the Move sources");
+    abort(package_id, "locking", function, code)
+}
+
+fn unsupported(module: &str, function: &str) -> Failure {
+    Failure::Other(format!(
+        "function {module}::{function} is not simulated by the mock ledger"
+    ))
+}
+
+fn type_argument(type_arguments: &[String]) -> Result<String, Failure> {
+    type_arguments
+        .first()
+        .cloned()
+        .ok_or_else(|| Failure::Other("missing type argument".to_string()))
+}
+
+fn value<T: Serialize + ?Sized>(value: &T, type_: String) -> Value {
+    Value::Move {
+        bcs: to_bcs(value),
+        type_,
+    }
+}
+
+fn to_bcs<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    bcs::to_bytes(value).expect("values of the simulated packages are serializable")
+}
+
+/// The arguments of a Move call.
+struct Args(std::vec::IntoIter<Value>);
+
+impl Args {
+    fn next(&mut self) -> Result<Value, Failure> {
+        self.0
+            .next()
+            .ok_or_else(|| Failure::Other("missing argument".to_string()))
+    }
+
+    fn pure_bytes(&mut self) -> Result<Vec<u8>, Failure> {
+        match self.next()? {
+            Value::Pure(bcs) | Value::Move { bcs, .. } => Ok(bcs),
+            _ => Err(Failure::Other("expected a pure argument".to_string())),
+        }
+    }
+
+    fn pure<T: DeserializeOwned>(&mut self) -> Result<T, Failure> {
+        let bcs = self.pure_bytes()?;
+        bcs::from_bytes(&bcs).map_err(|e| Failure::Other(format!("invalid argument: {e}")))
+    }
+
+    fn object(&mut self) -> Result<ObjectId, Failure> {
+        match self.next()? {
+            Value::Object(id) => Ok(id),
+            _ => Err(Failure::Other("expected an object argument".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_mock_rpc::{Contents, Event};
+
+    use super::super::{MOCK_CHAIN_ID, MOCK_PACKAGE_ID, MOCK_TF_COMPONENTS_PACKAGE_ID};
+    use super::*;
+    use crate::error::{Error, MoveAbort};
+
+    const NOW_MS: u64 = 1_750_000_000_000;
+    const ADMIN: Address = Address::new([0xa; 32]);
+    const WRITER: Address = Address::new([0xb; 32]);
+    const PACKAGES: PackageIds = PackageIds {
+        audit_trail: MOCK_PACKAGE_ID,
+        tf_components: MOCK_TF_COMPONENTS_PACKAGE_ID,
+    };
+
+    /// A trail with the initial-admin capability of its creator.
+    struct TestTrail {
+        id: ObjectId,
+        admin_cap: ObjectId,
+    }
+
+    fn ledger() -> Ledger {
+        Ledger::new(PACKAGES, MOCK_CHAIN_ID.to_string(), NOW_MS)
+    }
+
+    fn pure<T: Serialize + ?Sized>(value: &T) -> Value {
+        Value::Pure(to_bcs(value))
+    }
+
+    fn decode<T: DeserializeOwned>(value: &Value) -> T {
+        match value {
+            Value::Move { bcs, .. } | Value::Pure(bcs) => {
+                bcs::from_bytes(bcs).expect("values decode as their Move type")
+            }
+            value => panic!("expected a Move value, got {value:?}"),
+        }
+    }
+
+    fn text(text: &str) -> Data {
+        Data::Text(text.to_string())
+    }
+
+    fn config(delete_record_window: LockingWindow, delete_trail_lock: TimeLock, write_lock: TimeLock) -> LockingConfig {
+        LockingConfig {
+            delete_record_window,
+            delete_trail_lock,
+            write_lock,
+        }
+    }
+
+    /// Decodes `failure` into the [`Error`] a client reports for it, resolving the names of clever errors from the
+    /// bytecode of the simulated packages.
+    fn error(ledger: &Ledger, failure: Failure) -> Error {
+        let Failure::Abort {
+            package_id,
+            module,
+            function,
+            code,
+        } = failure
+        else {
+            panic!("expected an abort, got {failure:?}");
+        };
+        let is_package_abort = package_id == PACKAGES.audit_trail;
+        let name = match abort::clever_identifier_index(code) {
+            Some(index) => match ledger.object(&package_id).map(|package| &package.contents) {
+                Some(Contents::Package(modules)) => modules
+                    .get(&module)
+                    .and_then(|bytecode| abort::parse_identifiers(bytecode))
+                    .and_then(|identifiers| identifiers.into_iter().nth(index as usize)),
+                _ => None,
+            },
+            None if is_package_abort => abort::abort_name(&module, code).map(str::to_string),
+            None => None,
+        };
+        let abort = MoveAbort {
+            module,
+            function: Some(function),
+            code,
+            name,
+        };
+
+        if is_package_abort {
+            abort::abort_error(abort)
+        } else {
+            Error::MoveAbort(abort)
+        }
+    }
+
+    /// Calls `package_id::module::function` as `sender` in a transaction of its own.
+    fn run(
+        ledger: &mut Ledger,
+        sender: Address,
+        package_id: ObjectId,
+        (module, function): (&str, &str),
+        arguments: Vec<Value>,
+    ) -> Result<(Vec<Value>, Vec<Event>), Error> {
+        let type_arguments = [format!("{MOCK_PACKAGE_ID}::record::Data")];
+        let result = ledger.transact(sender, |session| {
+            call(session, package_id, module, function, &type_arguments, arguments)
+        });
+
+        result.map_err(|failure| error(ledger, failure))
+    }
+
+    /// Calls the function `function` of `main` on `trail` with the capability `cap` as `sender`.
+    fn call_trail(
+        ledger: &mut Ledger,
+        sender: Address,
+        trail: &TestTrail,
+        cap: ObjectId,
+        function: &str,
+        arguments: Vec<Value>,
+    ) -> Result<(Vec<Value>, Vec<Event>), Error> {
+        let arguments = [vec![Value::Object(trail.id), Value::Object(cap)], arguments].concat();
+        run(ledger, sender, MOCK_PACKAGE_ID, ("main", function), arguments)
+    }
+
+    /// Calls the read-only function `function` of `main` on `trail`.
+    fn read<T: DeserializeOwned>(ledger: &mut Ledger, trail: &TestTrail, function: &str, arguments: Vec<Value>) -> T {
+        let arguments = [vec![Value::Object(trail.id)], arguments].concat();
+        let (values, _) = run(ledger, ADMIN, MOCK_PACKAGE_ID, ("main", function), arguments).expect("reads succeed");
+        decode(&values[0])
+    }
+
+    fn create_trail(
+        ledger: &mut Ledger,
+        initial_record: Option<InitialRecord>,
+        locking_config: LockingConfig,
+        tags: &[&str],
+    ) -> Result<TestTrail, Error> {
+        let arguments = vec![
+            pure(&initial_record),
+            pure(&locking_config),
+            pure(&Some(ImmutableMetadata {
+                name: "trail".to_string(),
+                description: None,
+            })),
+            pure(&None::<String>),
+            pure(tags),
+        ];
+        let (values, events) = run(ledger, ADMIN, MOCK_PACKAGE_ID, ("main", "create"), arguments)?;
+        assert_eq!(event_types(&events), ["main::AuditTrailCreated"]);
+
+        let [Value::Object(admin_cap), id] = values.as_slice() else {
+            panic!("create returned {values:?}");
+        };
+        Ok(TestTrail {
+            id: decode(id),
+            admin_cap: *admin_cap,
+        })
+    }
+
+    fn add_record(
+        ledger: &mut Ledger,
+        sender: Address,
+        trail: &TestTrail,
+        cap: ObjectId,
+        tag: Option<&str>,
+    ) -> Result<u64, Error> {
+        let arguments = vec![pure(&text("entry")), pure(&None::<String>), pure(&tag)];
+        let (values, events) = call_trail(ledger, sender, trail, cap, "add_record", arguments)?;
+        assert_eq!(event_types(&events), ["main::RecordAdded"]);
+
+        Ok(decode::<RecordAdded>(&values[0]).sequence_number)
+    }
+
+    /// Creates the role `role` with `permissions` and `tags` and issues a capability of it to `issued_to`.
+    fn grant(
+        ledger: &mut Ledger,
+        trail: &TestTrail,
+        role: &str,
+        permissions: &[Permission],
+        role_tags: Option<RoleTags>,
+        issued_to: Address,
+    ) -> ObjectId {
+        let arguments = vec![pure(role), pure(permissions), pure(&role_tags)];
+        let (_, events) = call_trail(ledger, ADMIN, trail, trail.admin_cap, "create_role", arguments).unwrap();
+        assert_eq!(event_types(&events), ["role_map::RoleCreated"]);
+
+        issue(ledger, trail, role, Some(issued_to), None).unwrap()
+    }
+
+    fn issue(
+        ledger: &mut Ledger,
+        trail: &TestTrail,
+        role: &str,
+        issued_to: Option<Address>,
+        validity: Option<(u64, u64)>,
+    ) -> Result<ObjectId, Error> {
+        let arguments = vec![
+            pure(role),
+            pure(&issued_to),
+            pure(&validity.map(|(valid_from, _)| valid_from)),
+            pure(&validity.map(|(_, valid_until)| valid_until)),
+        ];
+        let (values, events) = call_trail(ledger, ADMIN, trail, trail.admin_cap, "new_capability", arguments)?;
+        assert_eq!(event_types(&events), ["role_map::CapabilityIssued"]);
+
+        Ok(decode::<CapabilityIssued>(&values[0]).capability_id)
+    }
+
+    fn stored<'a>(ledger: &'a Ledger, trail: &TestTrail) -> &'a Trail {
+        match ledger.object(&trail.id).map(|object| &object.contents) {
+            Some(Contents::Move(MoveObject::Trail(stored))) => stored,
+            contents => panic!("expected an audit trail, got {contents:?}"),
+        }
+    }
+
+    /// Returns the events as `module::Name`.
+    fn event_types(events: &[Event]) -> Vec<&str> {
+        events
+            .iter()
+            .map(|event| {
+                event
+                    .type_
+                    .split_once("::")
+                    .map_or(event.type_.as_str(), |(_, type_)| type_)
+            })
+            .collect()
+    }
+
+    fn abort_name<T: std::fmt::Debug>(result: Result<T, Error>) -> String {
+        match result {
+            Err(Error::MoveAbort(abort)) => abort.name.unwrap_or_default(),
+            result => panic!("expected an abort of a dependency, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn constructors_return_their_move_values() {
+        let mut ledger = ledger();
+        let mut constructed = |package_id, module_function, arguments| {
+            let (values, _) = run(&mut ledger, ADMIN, package_id, module_function, arguments).unwrap();
+            values[0].clone()
+        };
+
+        let some: Option<String> = decode(&constructed(
+            MOVE_STDLIB_PACKAGE_ID,
+            ("option", "some"),
+            vec![pure("tag")],
+        ));
+        assert_eq!(some.as_deref(), Some("tag"));
+        let none: Option<String> = decode(&constructed(MOVE_STDLIB_PACKAGE_ID, ("option", "none"), Vec::new()));
+        assert_eq!(none, None);
+
+        for (function, arguments, expected) in [
+            ("none", Vec::new(), TimeLock::None),
+            ("infinite", Vec::new(), TimeLock::Infinite),
+            ("until_destroyed", Vec::new(), TimeLock::UntilDestroyed),
+            (
+                "unlock_at",
+                vec![pure(&((NOW_MS / 1000 + 60) as u32))],
+                TimeLock::UnlockAt((NOW_MS / 1000 + 60) as u32),
+            ),
+            (
+                "unlock_at_ms",
+                vec![pure(&(NOW_MS + 1))],
+                TimeLock::UnlockAtMs(NOW_MS + 1),
+            ),
+        ] {
+            let lock: TimeLock = decode(&constructed(
+                MOCK_TF_COMPONENTS_PACKAGE_ID,
+                ("timelock", function),
+                arguments,
+            ));
+            assert_eq!(lock, expected);
+        }
+
+        let data: Data = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("record", "new_text"),
+            vec![pure("entry")],
+        ));
+        assert_eq!(data, text("entry"));
+        let data: Data = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("record", "new_bytes"),
+            vec![pure(&vec![1u8, 2])],
+        ));
+        assert_eq!(data, Data::Bytes(vec![1, 2]));
+        let record: InitialRecord = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("record", "new_initial_record"),
+            vec![pure(&text("entry")), pure(&Some("metadata")), pure(&None::<String>)],
+        ));
+        assert_eq!(record.metadata.as_deref(), Some("metadata"));
+
+        let window: LockingWindow = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("locking", "window_count_based"),
+            vec![pure(&2u64)],
+        ));
+        assert_eq!(window, LockingWindow::CountBased { count: 2 });
+        let window: LockingWindow = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("locking", "window_time_based"),
+            vec![pure(&60u64)],
+        ));
+        assert_eq!(window, LockingWindow::TimeBased { seconds: 60 });
+        let window: LockingWindow = decode(&constructed(MOCK_PACKAGE_ID, ("locking", "window_none"), Vec::new()));
+        assert_eq!(window, LockingWindow::None);
+        let expected = config(LockingWindow::None, TimeLock::Infinite, TimeLock::None);
+        let locking: LockingConfig = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("locking", "new"),
+            vec![
+                pure(&expected.delete_record_window),
+                pure(&expected.delete_trail_lock),
+                pure(&expected.write_lock),
+            ],
+        ));
+        assert_eq!(locking, expected);
+
+        for permission in PERMISSIONS {
+            let constructed: Permission = decode(&constructed(
+                MOCK_PACKAGE_ID,
+                ("permission", permission.function_name()),
+                Vec::new(),
+            ));
+            assert_eq!(constructed, permission);
+        }
+        let permissions: Vec<Permission> = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("permission", "from_vec"),
+            vec![pure(&vec![Permission::AddRecord, Permission::AddRecord])],
+        ));
+        assert_eq!(permissions, [Permission::AddRecord]);
+
+        let role_tags: RoleTags = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("record_tags", "new_role_tags"),
+            vec![pure(&vec!["finance"])],
+        ));
+        assert!(role_tags.allows("finance"));
+        let metadata: ImmutableMetadata = decode(&constructed(
+            MOCK_PACKAGE_ID,
+            ("main", "new_trail_metadata"),
+            vec![pure("trail"), pure(&None::<String>)],
+        ));
+        assert_eq!(metadata.name, "trail");
+    }
+
+    #[test]
+    fn constructors_reject_invalid_arguments() {
+        let mut ledger = ledger();
+
+        let result = run(
+            &mut ledger,
+            ADMIN,
+            MOCK_TF_COMPONENTS_PACKAGE_ID,
+            ("timelock", "unlock_at"),
+            vec![pure(&((NOW_MS / 1000) as u32))],
+        );
+        let Err(Error::MoveAbort(abort)) = result else {
+            panic!("expected an abort of timelock, got {result:?}");
+        };
+        assert_eq!(
+            (abort.module.as_str(), abort.code),
+            ("timelock", TIMELOCK_E_PAST_TIMESTAMP)
+        );
+
+        let result = run(
+            &mut ledger,
+            ADMIN,
+            MOCK_PACKAGE_ID,
+            ("locking", "window_count_based"),
+            vec![pure(&0u64)],
+        );
+        assert!(
+            matches!(result, Err(Error::CountWindowMustBePositive)),
+            "got {result:?}"
+        );
+
+        let result = run(
+            &mut ledger,
+            ADMIN,
+            MOCK_PACKAGE_ID,
+            ("locking", "new"),
+            vec![
+                pure(&LockingWindow::None),
+                pure(&TimeLock::UntilDestroyed),
+                pure(&TimeLock::None),
+            ],
+        );
+        assert!(
+            matches!(result, Err(Error::UntilDestroyedNotSupportedForDeleteTrail)),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn created_trails_hold_the_initial_record() {
+        let mut ledger = ledger();
+        let initial_record = InitialRecord {
+            data: text("initial"),
+            metadata: None,
+            tag: None,
+        };
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, Some(initial_record), none.clone(), &[]).unwrap();
+        assert!(matches!(ledger.object(&trail.id).unwrap().owner, Owner::Shared(_)));
+        assert_eq!(ledger.object(&trail.admin_cap).unwrap().owner, Owner::Address(ADMIN));
+
+        assert_eq!(read::<u64>(&mut ledger, &trail, "record_count", Vec::new()), 1);
+        assert_eq!(read::<u64>(&mut ledger, &trail, "sequence_number", Vec::new()), 1);
+        assert!(!read::<bool>(&mut ledger, &trail, "is_empty", Vec::new()));
+        assert!(read::<bool>(&mut ledger, &trail, "has_record", vec![pure(&0u64)]));
+        assert!(!read::<bool>(&mut ledger, &trail, "has_record", vec![pure(&1u64)]));
+        let record: Record = read(&mut ledger, &trail, "get_record", vec![pure(&0u64)]);
+        assert_eq!((record.data, record.added_by), (text("initial"), ADMIN));
+        assert!(!read::<bool>(
+            &mut ledger,
+            &trail,
+            "is_record_locked",
+            vec![pure(&0u64)]
+        ));
+
+        let arguments = vec![Value::Object(trail.id), pure(&1u64)];
+        let result = run(&mut ledger, ADMIN, MOCK_PACKAGE_ID, ("main", "get_record"), arguments);
+        assert!(matches!(result, Err(Error::RecordNotFound)), "got {result:?}");
+
+        let initial_record = InitialRecord {
+            data: text("initial"),
+            metadata: None,
+            tag: Some("undefined".to_string()),
+        };
+        let result = create_trail(&mut ledger, Some(initial_record), none, &[]);
+        assert!(matches!(result, Err(Error::RecordTagNotDefined)), "got {result:?}");
+    }
+
+    #[test]
+    fn records_are_added_corrected_and_deleted() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none, &[]).unwrap();
+        assert_eq!(
+            add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None).unwrap(),
+            0
+        );
+
+        let arguments = vec![
+            pure(&0u64),
+            pure(&text("fixed")),
+            pure(&None::<String>),
+            pure(&None::<String>),
+        ];
+        let (values, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "correct_record",
+            arguments.clone(),
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::RecordAdded"]);
+        assert_eq!(decode::<RecordAdded>(&values[0]).sequence_number, 1);
+        let replaced: Record = read(&mut ledger, &trail, "get_record", vec![pure(&0u64)]);
+        assert_eq!(replaced.correction.is_replaced_by, Some(1));
+        let correction: Record = read(&mut ledger, &trail, "get_record", vec![pure(&1u64)]);
+        assert!(correction.correction.replaces.contains(&0));
+
+        let result = call_trail(&mut ledger, ADMIN, &trail, trail.admin_cap, "correct_record", arguments);
+        assert!(matches!(result, Err(Error::RecordAlreadyReplaced)), "got {result:?}");
+
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_record",
+            vec![pure(&0u64)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::RecordDeleted"]);
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_record",
+            vec![pure(&0u64)],
+        );
+        assert!(matches!(result, Err(Error::RecordNotFound)), "got {result:?}");
+        assert_eq!(
+            add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn delete_windows_lock_records() {
+        let mut ledger = ledger();
+        let count_based = config(LockingWindow::CountBased { count: 1 }, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, count_based, &[]).unwrap();
+        for _ in 0..3 {
+            add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None).unwrap();
+        }
+
+        assert!(read::<bool>(&mut ledger, &trail, "is_record_locked", vec![pure(&2u64)]));
+        assert!(!read::<bool>(
+            &mut ledger,
+            &trail,
+            "is_record_locked",
+            vec![pure(&1u64)]
+        ));
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_record",
+            vec![pure(&2u64)],
+        );
+        assert!(matches!(result, Err(Error::RecordLocked)), "got {result:?}");
+
+        let (values, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_records_batch",
+            vec![pure(&10u64)],
+        )
+        .unwrap();
+        assert_eq!(decode::<Vec<u64>>(&values[0]), [0, 1]);
+        assert_eq!(event_types(&events), ["main::RecordDeleted", "main::RecordDeleted"]);
+
+        let time_based = LockingWindow::TimeBased { seconds: 60 };
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_delete_record_window",
+            vec![pure(&time_based)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::LockingConfigUpdated"]);
+        assert!(read::<bool>(&mut ledger, &trail, "is_record_locked", vec![pure(&2u64)]));
+        ledger.clock_ms += 60_000;
+        assert!(!read::<bool>(
+            &mut ledger,
+            &trail,
+            "is_record_locked",
+            vec![pure(&2u64)]
+        ));
+        call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_record",
+            vec![pure(&2u64)],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn locking_config_updates_apply_their_locks() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none.clone(), &[]).unwrap();
+
+        let write_lock = TimeLock::UnlockAtMs(NOW_MS + 1_000);
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_write_lock",
+            vec![pure(&write_lock)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::LockingConfigUpdated"]);
+        let result = add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None);
+        assert!(matches!(result, Err(Error::TrailWriteLocked)), "got {result:?}");
+        ledger.clock_ms += 1_000;
+        add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None).unwrap();
+
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_delete_trail_lock",
+            vec![pure(&TimeLock::UntilDestroyed)],
+        );
+        assert!(
+            matches!(result, Err(Error::UntilDestroyedNotSupportedForDeleteTrail)),
+            "got {result:?}"
+        );
+        call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_delete_trail_lock",
+            vec![pure(&TimeLock::Infinite)],
+        )
+        .unwrap();
+        call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_locking_config",
+            vec![pure(&none)],
+        )
+        .unwrap();
+
+        assert_eq!(stored(&ledger, &trail).state.locking_config, none);
+    }
+
+    #[test]
+    fn trails_are_deleted_only_when_empty_and_unlocked() {
+        let mut ledger = ledger();
+        let delete_locked = config(
+            LockingWindow::None,
+            TimeLock::UnlockAtMs(NOW_MS + 1_000),
+            TimeLock::None,
+        );
+        let trail = create_trail(&mut ledger, None, delete_locked, &[]).unwrap();
+        add_record(&mut ledger, ADMIN, &trail, trail.admin_cap, None).unwrap();
+
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_audit_trail",
+            Vec::new(),
+        );
+        assert!(matches!(result, Err(Error::TrailDeleteLocked)), "got {result:?}");
+        ledger.clock_ms += 1_000;
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_audit_trail",
+            Vec::new(),
+        );
+        assert!(matches!(result, Err(Error::TrailNotEmpty)), "got {result:?}");
+
+        call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_record",
+            vec![pure(&0u64)],
+        )
+        .unwrap();
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_audit_trail",
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::AuditTrailDeleted"]);
+        assert!(ledger.object(&trail.id).is_none());
+    }
+
+    #[test]
+    fn metadata_and_record_tags_are_managed() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none, &["finance"]).unwrap();
+
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_metadata",
+            vec![pure(&Some("reviewed"))],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::MetadataUpdated"]);
+
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "add_record_tag",
+            vec![pure("legal")],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::RecordTagAdded"]);
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "add_record_tag",
+            vec![pure("legal")],
+        );
+        assert!(matches!(result, Err(Error::RecordTagAlreadyDefined)), "got {result:?}");
+
+        let finance = grant(
+            &mut ledger,
+            &trail,
+            "Finance",
+            &[Permission::AddRecord],
+            Some(RoleTags::new(["finance"])),
+            WRITER,
+        );
+        add_record(&mut ledger, WRITER, &trail, finance, Some("finance")).unwrap();
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "remove_record_tag",
+            vec![pure("finance")],
+        );
+        assert!(matches!(result, Err(Error::RecordTagInUse)), "got {result:?}");
+
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "remove_record_tag",
+            vec![pure("legal")],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::RecordTagRemoved"]);
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "remove_record_tag",
+            vec![pure("legal")],
+        );
+        assert!(matches!(result, Err(Error::RecordTagNotDefined)), "got {result:?}");
+    }
+
+    #[test]
+    fn roles_grant_permissions_to_capabilities() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none, &["finance", "legal"]).unwrap();
+        let writer = grant(
+            &mut ledger,
+            &trail,
+            "Writer",
+            &[Permission::AddRecord],
+            Some(RoleTags::new(["finance"])),
+            WRITER,
+        );
+        assert_eq!(ledger.object(&writer).unwrap().owner, Owner::Address(WRITER));
+
+        add_record(&mut ledger, WRITER, &trail, writer, None).unwrap();
+        add_record(&mut ledger, WRITER, &trail, writer, Some("finance")).unwrap();
+        let result = add_record(&mut ledger, WRITER, &trail, writer, Some("legal"));
+        assert!(matches!(result, Err(Error::RecordTagNotAllowed)), "got {result:?}");
+        let result = add_record(&mut ledger, ADMIN, &trail, writer, None);
+        assert_eq!(abort_name(result), "ECapabilityIssuedToMismatch");
+        let result = call_trail(&mut ledger, WRITER, &trail, writer, "delete_record", vec![pure(&0u64)]);
+        assert_eq!(abort_name(result), "ECapabilityPermissionDenied");
+
+        let arguments = vec![
+            pure("Writer"),
+            pure(&vec![Permission::AddRecord, Permission::DeleteRecord]),
+            pure(&None::<RoleTags>),
+        ];
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_role_permissions",
+            arguments,
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::RoleUpdated"]);
+        call_trail(&mut ledger, WRITER, &trail, writer, "delete_record", vec![pure(&0u64)]).unwrap();
+
+        let arguments = vec![
+            pure("Auditor"),
+            pure(&vec![Permission::AddRecord]),
+            pure(&Some(RoleTags::new(["undefined"]))),
+        ];
+        let result = call_trail(&mut ledger, ADMIN, &trail, trail.admin_cap, "create_role", arguments);
+        assert!(matches!(result, Err(Error::RecordTagNotDefined)), "got {result:?}");
+        let arguments = vec![
+            pure("Writer"),
+            pure(&vec![Permission::AddRecord]),
+            pure(&None::<RoleTags>),
+        ];
+        let result = call_trail(&mut ledger, ADMIN, &trail, trail.admin_cap, "create_role", arguments);
+        assert_eq!(abort_name(result), "ERoleAlreadyExists");
+        let arguments = vec![
+            pure(INITIAL_ADMIN_ROLE_NAME),
+            pure(&vec![Permission::AddRecord]),
+            pure(&None::<RoleTags>),
+        ];
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "update_role_permissions",
+            arguments,
+        );
+        assert_eq!(abort_name(result), "EInitialAdminPermissionsInconsistent");
+
+        let result = issue(&mut ledger, &trail, "Writer", None, Some((NOW_MS + 1, NOW_MS)));
+        assert_eq!(abort_name(result), "EValidityPeriodInconsistent");
+        let scheduled = issue(
+            &mut ledger,
+            &trail,
+            "Writer",
+            None,
+            Some((NOW_MS + 1_000, NOW_MS + 2_000)),
+        )
+        .unwrap();
+        let result = add_record(&mut ledger, ADMIN, &trail, scheduled, None);
+        assert_eq!(abort_name(result), "ECapabilityTimeConstraintsNotMet");
+
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_role",
+            vec![pure("Writer")],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::RoleDeleted"]);
+        let result = add_record(&mut ledger, WRITER, &trail, writer, None);
+        assert_eq!(abort_name(result), "ERoleDoesNotExist");
+        let result = issue(&mut ledger, &trail, "Writer", None, None);
+        assert_eq!(abort_name(result), "ERoleDoesNotExist");
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "delete_role",
+            vec![pure(INITIAL_ADMIN_ROLE_NAME)],
+        );
+        assert_eq!(abort_name(result), "EInitialAdminRoleCannotBeDeleted");
+    }
+
+    #[test]
+    fn capabilities_are_revoked_and_destroyed() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none, &[]).unwrap();
+        let writer = grant(&mut ledger, &trail, "Writer", &[Permission::AddRecord], None, WRITER);
+        let expiring = issue(&mut ledger, &trail, "Writer", None, None).unwrap();
+        let second_admin = issue(&mut ledger, &trail, INITIAL_ADMIN_ROLE_NAME, None, None).unwrap();
+
+        let revoke = |capability: ObjectId, valid_until: Option<u64>| vec![pure(&capability), pure(&valid_until)];
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_capability",
+            revoke(writer, None),
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::CapabilityRevoked"]);
+        let result = add_record(&mut ledger, WRITER, &trail, writer, None);
+        assert_eq!(abort_name(result), "ECapabilityHasBeenRevoked");
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_capability",
+            revoke(writer, None),
+        );
+        assert_eq!(abort_name(result), "ECapabilityToRevokeHasAlreadyBeenRevoked");
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_capability",
+            revoke(second_admin, None),
+        );
+        assert_eq!(abort_name(result), "EInitialAdminCapabilityMustBeExplicitlyDestroyed");
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_initial_admin_capability",
+            revoke(expiring, None),
+        );
+        assert_eq!(abort_name(result), "ECapabilityIsNotInitialAdmin");
+
+        call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_capability",
+            revoke(expiring, Some(NOW_MS + 1_000)),
+        )
+        .unwrap();
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "revoke_initial_admin_capability",
+            revoke(second_admin, None),
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::CapabilityRevoked"]);
+
+        ledger.clock_ms += 2_000;
+        let (values, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "cleanup_revoked_capabilities",
+            Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["main::RevokedCapabilitiesCleanedUp"]);
+        assert_eq!(decode::<RevokedCapabilitiesCleanedUp>(&values[0]).cleaned_count, 1);
+
+        let result = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "destroy_capability",
+            vec![Value::Object(second_admin)],
+        );
+        assert_eq!(abort_name(result), "EInitialAdminCapabilityMustBeExplicitlyDestroyed");
+        let (_, events) = call_trail(
+            &mut ledger,
+            ADMIN,
+            &trail,
+            trail.admin_cap,
+            "destroy_capability",
+            vec![Value::Object(writer)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::CapabilityDestroyed"]);
+        assert!(ledger.object(&writer).is_none());
+
+        let arguments = vec![Value::Object(trail.id), Value::Object(expiring)];
+        let result = run(
+            &mut ledger,
+            ADMIN,
+            MOCK_PACKAGE_ID,
+            ("main", "destroy_initial_admin_capability"),
+            arguments,
+        );
+        assert_eq!(abort_name(result), "ECapabilityIsNotInitialAdmin");
+        let arguments = vec![Value::Object(trail.id), Value::Object(second_admin)];
+        let (_, events) = run(
+            &mut ledger,
+            ADMIN,
+            MOCK_PACKAGE_ID,
+            ("main", "destroy_initial_admin_capability"),
+            arguments,
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["role_map::CapabilityDestroyed"]);
+    }
+
+    #[test]
+    fn migrating_current_trails_is_rejected() {
+        let mut ledger = ledger();
+        let none = config(LockingWindow::None, TimeLock::None, TimeLock::None);
+        let trail = create_trail(&mut ledger, None, none, &[]).unwrap();
+
+        let result = call_trail(&mut ledger, ADMIN, &trail, trail.admin_cap, "migrate", Vec::new());
+        assert!(matches!(result, Err(Error::PackageVersionMismatch)), "got {result:?}");
+    }
+
+    #[test]
+    fn clever_errors_are_generated_from_the_move_sources() {
+        assert_eq!(MAIN_ERRORS.first(), Some(&"ERecordNotFound"));
+        assert_eq!(abort::abort_code("locking", "ECountWindowMustBePositive"), Some(1));
+    }
+}
@@ -1,9 +1,11 @@
 // Copyright 2020-2026 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use audit_trails::core::dry_run::DryRun;
 use audit_trails::core::types::{
     CapabilityIssueOptions, Data, InitialRecord, LockingConfig, LockingWindow, Permission, TimeLock,
 };
+use audit_trails::error::Error;
 use iota_sdk_types::ObjectId;
 
 use crate::client::{TestClient, get_funded_test_client};
@@ -201,6 +203,50 @@ async fn update_write_lock_roundtrip_and_blocks_add_record() -> anyhow::Result<(
     Ok(())
 }
 
+#[tokio::test]
+async fn dry_run_decodes_write_lock_abort() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let trail_id = client
+        .create_test_trail(Data::text("trail-dry-run-write-lock-e2e"))
+        .await?;
+    let trail = client.trail(trail_id);
+
+    grant_role_capability(
+        &client,
+        trail_id,
+        "DryRunWriteLockAdmin",
+        [Permission::UpdateLockingConfigForWrite, Permission::AddRecord],
+    )
+    .await?;
+
+    let add = trail.records().add(Data::text("dry-run-before-lock"), None, None);
+    let estimate = add.dry_run(&client).await?;
+    assert!(estimate.gas_budget() > 0);
+    assert_eq!(
+        trail.records().record_count().await?,
+        1,
+        "a dry run must not add the record"
+    );
+
+    trail
+        .locking()
+        .update_write_lock(TimeLock::Infinite)
+        .build_and_execute(&client)
+        .await?;
+
+    let result = trail
+        .records()
+        .add(Data::text("dry-run-write-locked"), None, None)
+        .dry_run(&client)
+        .await;
+    assert!(
+        matches!(result, Err(Error::TrailWriteLocked)),
+        "expected a write-lock abort, got {result:?}"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn update_locking_config_requires_permission() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
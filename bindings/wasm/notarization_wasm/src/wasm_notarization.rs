@@ -8,10 +8,13 @@ use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use iota_interaction_ts::wasm_error::Result;
 use notarization::core::builder::{Dynamic, Locked};
 use notarization::core::transactions::{
-    CreateNotarization, DestroyNotarization, TransferNotarization, UpdateMetadata, UpdateState,
+    CreateNotarization, DestroyNotarization, SealNotarization, TransferNotarization, UpdateMetadata, UpdateState,
 };
 use notarization::core::types::irl_integration::NotarizationResourceBuilder;
-use notarization::core::types::OnChainNotarization;
+use notarization::core::types::{
+    DynamicNotarizationTransferred, NotarizationDestroyed, NotarizationMetadataUpdated, NotarizationSealed,
+    NotarizationUpdated, OnChainNotarization,
+};
 use product_common::bindings::utils::{
     apply_with_events, build_programmable_transaction, parse_wasm_iota_address, parse_wasm_object_id,
 };
@@ -20,7 +23,12 @@ use product_common::network_name::NetworkName;
 use wasm_bindgen::prelude::*;
 
 use crate::wasm_notarization_builder::{WasmNotarizationBuilderDynamic, WasmNotarizationBuilderLocked};
-use crate::wasm_types::{WasmEmpty, WasmImmutableMetadata, WasmNotarizationMethod, WasmState};
+use crate::wasm_time_lock::WasmTimeLock;
+use crate::wasm_types::{
+    WasmDynamicNotarizationTransferred, WasmImmutableMetadata, WasmNotarizationDestroyed,
+    WasmNotarizationMetadataUpdated, WasmNotarizationMethod, WasmNotarizationSealed, WasmNotarizationUpdated,
+    WasmState,
+};
 
 /// The on-chain representation of a notarization.
 ///
@@ -321,7 +329,9 @@ impl WasmCreateNotarizationDynamic {
 /// refreshes `lastStateChangeAt` to the on-chain clock timestamp.
 ///
 /// Behavior depends on the Notarization Method:
-/// * `Dynamic`: always permitted — the underlying `updateLock` is fixed to {@link TimeLockType.None}.
+/// * `Dynamic`: aborts on-chain while the optional `updateLock` is active, i.e. before an {@link
+///   TimeLockType.UnlockAt} embargo expires or from the timestamp of a {@link TimeLockType.LockAt} freeze on;
+///   permitted otherwise.
 /// * `Locked`: always aborts on-chain, because the underlying `updateLock` is pinned to {@link
 ///   TimeLockType.UntilDestroyed}.
 ///
@@ -367,6 +377,8 @@ impl WasmUpdateState {
     /// @param events - The transaction block events produced on-chain.
     /// @param client - A read-only client connected to the target network.
     ///
+    /// @returns The decoded {@link NotarizationUpdated} event.
+    ///
     /// @throws When the effects/events are inconsistent with this transaction.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
@@ -374,8 +386,9 @@ impl WasmUpdateState {
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client).await
+    ) -> Result<WasmNotarizationUpdated> {
+        let updated: NotarizationUpdated = apply_with_events(self.0, wasm_effects, wasm_events, client).await?;
+        Ok(updated.into())
     }
 }
 
@@ -386,7 +399,9 @@ impl WasmUpdateState {
 /// `lastStateChangeAt` timestamp, or the immutable description.
 ///
 /// Behavior depends on the Notarization Method:
-/// * `Dynamic`: always permitted — the underlying `updateLock` is fixed to {@link TimeLockType.None}.
+/// * `Dynamic`: aborts on-chain while the optional `updateLock` is active, i.e. before an {@link
+///   TimeLockType.UnlockAt} embargo expires or from the timestamp of a {@link TimeLockType.LockAt} freeze on;
+///   permitted otherwise.
 /// * `Locked`: always aborts on-chain, because the underlying `updateLock` is pinned to {@link
 ///   TimeLockType.UntilDestroyed}.
 #[wasm_bindgen(js_name = UpdateMetadata, inspectable)]
@@ -430,6 +445,8 @@ impl WasmUpdateMetadata {
     /// @param events - The transaction block events produced on-chain.
     /// @param client - A read-only client connected to the target network.
     ///
+    /// @returns The decoded {@link NotarizationMetadataUpdated} event.
+    ///
     /// @throws When the effects/events are inconsistent with this transaction.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
@@ -437,8 +454,9 @@ impl WasmUpdateMetadata {
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client).await
+    ) -> Result<WasmNotarizationMetadataUpdated> {
+        let updated: NotarizationMetadataUpdated = apply_with_events(self.0, wasm_effects, wasm_events, client).await?;
+        Ok(updated.into())
     }
 }
 
@@ -491,6 +509,8 @@ impl WasmDestroyNotarization {
     /// @param events - The transaction block events produced on-chain.
     /// @param client - A read-only client connected to the target network.
     ///
+    /// @returns The decoded {@link NotarizationDestroyed} event.
+    ///
     /// @throws When the effects/events are inconsistent with this transaction.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
@@ -498,8 +518,9 @@ impl WasmDestroyNotarization {
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client).await
+    ) -> Result<WasmNotarizationDestroyed> {
+        let destroyed: NotarizationDestroyed = apply_with_events(self.0, wasm_effects, wasm_events, client).await?;
+        Ok(destroyed.into())
     }
 }
 
@@ -556,6 +577,8 @@ impl WasmTransferNotarization {
     /// @param events - The transaction block events produced on-chain.
     /// @param client - A read-only client connected to the target network.
     ///
+    /// @returns The decoded {@link DynamicNotarizationTransferred} event.
+    ///
     /// @throws When the effects/events are inconsistent with this transaction.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
@@ -563,7 +586,76 @@ impl WasmTransferNotarization {
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client).await
+    ) -> Result<WasmDynamicNotarizationTransferred> {
+        let transferred: DynamicNotarizationTransferred =
+            apply_with_events(self.0, wasm_effects, wasm_events, client).await?;
+        Ok(transferred.into())
+    }
+}
+
+/// Transaction that seals a Dynamic-Notarization into a Locked-Notarization.
+///
+/// @remarks
+/// The object ID, state, updatable metadata, `createdAt` and
+/// `stateVersionCount` are preserved. The given `deleteLock` becomes the
+/// delete lock of the Locked-Notarization and must not unlock before an
+/// active `UnlockAt` delete or transfer lock of the Dynamic-Notarization.
+/// Submitting against a Locked-Notarization or while the update lock is
+/// engaged aborts on-chain.
+///
+/// Emits a `NotarizationSealed` event on success.
+#[wasm_bindgen(js_name = SealNotarization, inspectable)]
+pub struct WasmSealNotarization(pub(crate) SealNotarization);
+
+#[wasm_bindgen(js_class = SealNotarization)]
+impl WasmSealNotarization {
+    /// Constructs the transaction.
+    ///
+    /// @param objectId - The notarization object's ID.
+    /// @param deleteLock - The delete lock of the sealed notarization.
+    ///
+    /// @throws When the ID is malformed.
+    #[wasm_bindgen(constructor)]
+    pub fn new(object_id: WasmObjectID, delete_lock: WasmTimeLock) -> Result<Self> {
+        let obj_id = parse_wasm_object_id(&object_id)?;
+        Ok(WasmSealNotarization(SealNotarization::new(obj_id, delete_lock.0)))
+    }
+
+    /// Builds the programmable transaction bytes.
+    ///
+    /// @param client - A read-only client connected to the target network.
+    ///
+    /// @returns The BCS-serialized programmable transaction, ready to be
+    /// signed and submitted.
+    ///
+    /// @throws When the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        build_programmable_transaction(&self.0, client).await
+    }
+
+    /// Reads the on-chain effects and events of the submitted transaction.
+    ///
+    /// @remarks
+    /// Invoked automatically by the {@link TransactionBuilder} machinery
+    /// after the transaction has been submitted; calling it directly is
+    /// normally not necessary.
+    ///
+    /// @param effects - The transaction block effects produced on-chain.
+    /// @param events - The transaction block events produced on-chain.
+    /// @param client - A read-only client connected to the target network.
+    ///
+    /// @returns The decoded {@link NotarizationSealed} event.
+    ///
+    /// @throws When the effects/events are inconsistent with this transaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<WasmNotarizationSealed> {
+        let sealed: NotarizationSealed = apply_with_events(self.0, wasm_effects, wasm_events, client).await?;
+        Ok(sealed.into())
     }
 }
@@ -8,7 +8,7 @@ use product_common::bindings::transaction::WasmTransactionBuilder;
 use wasm_bindgen::prelude::*;
 
 use crate::wasm_notarization::{WasmCreateNotarizationDynamic, WasmCreateNotarizationLocked};
-use crate::wasm_time_lock::WasmTimeLock;
+use crate::wasm_time_lock::{WasmTimeLock, WasmUpdateLock};
 
 /// Builder for a "create Locked-Notarization" transaction.
 ///
@@ -226,6 +226,40 @@ impl WasmNotarizationBuilderDynamic {
         self.0.with_transfer_lock(lock.0).into()
     }
 
+    /// Sets the delete lock for the notarization.
+    ///
+    /// @remarks
+    /// While the delete lock is active, {@link NotarizationClient.destroy}
+    /// aborts on-chain; `state` and `updatableMetadata` stay updatable.
+    /// `deleteLock` cannot be {@link TimeLockType.UntilDestroyed} and must
+    /// not unlock before an `UnlockAt` transfer lock.
+    ///
+    /// @param lock - The {@link TimeLock} controlling when destruction is
+    /// permitted.
+    ///
+    /// @returns The same builder, with the delete lock configured.
+    #[wasm_bindgen(js_name = withDeleteLock)]
+    pub fn with_delete_lock(self, lock: WasmTimeLock) -> Self {
+        self.0.with_delete_lock(lock.0).into()
+    }
+
+    /// Sets the update lock for the notarization.
+    ///
+    /// @remarks
+    /// Restricts the window in which `state` and `updatableMetadata` can be
+    /// updated: {@link TimeLockType.UnlockAt} embargoes updates until a time,
+    /// {@link UpdateLockType.LockAt} freezes them from a time on. `updateLock`
+    /// cannot be {@link TimeLockType.UntilDestroyed}.
+    ///
+    /// @param lock - The {@link UpdateLock} controlling when updates are
+    /// permitted.
+    ///
+    /// @returns The same builder, with the update lock configured.
+    #[wasm_bindgen(js_name = withUpdateLock)]
+    pub fn with_update_lock(self, lock: WasmUpdateLock) -> Self {
+        self.0.with_update_lock(lock.0).into()
+    }
+
     /// Finalizes the configuration and produces the transaction builder.
     ///
     /// @returns A {@link TransactionBuilder} wrapping the
@@ -12,10 +12,11 @@ use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use wasm_bindgen::prelude::*;
 
 use crate::wasm_notarization::{
-    WasmDestroyNotarization, WasmTransferNotarization, WasmUpdateMetadata, WasmUpdateState,
+    WasmDestroyNotarization, WasmSealNotarization, WasmTransferNotarization, WasmUpdateMetadata, WasmUpdateState,
 };
 use crate::wasm_notarization_builder::{WasmNotarizationBuilderDynamic, WasmNotarizationBuilderLocked};
 use crate::wasm_notarization_client_read_only::WasmNotarizationClientReadOnly;
+use crate::wasm_time_lock::WasmTimeLock;
 use crate::wasm_types::WasmState;
 
 /// Read-write client for creating and modifying notarizations on the IOTA
@@ -28,7 +29,8 @@ use crate::wasm_types::WasmState;
 /// the mutation methods ({@link NotarizationClient.updateState},
 /// {@link NotarizationClient.updateMetadata},
 /// {@link NotarizationClient.destroy},
-/// {@link NotarizationClient.transferNotarization}) to operate on existing
+/// {@link NotarizationClient.transferNotarization},
+/// {@link NotarizationClient.seal}) to operate on existing
 /// ones. For pure read access, prefer {@link NotarizationClientReadOnly}.
 #[derive(Clone)]
 #[wasm_bindgen(js_name = NotarizationClient)]
@@ -159,7 +161,7 @@ impl WasmNotarizationClient {
     /// Unix epoch).
     ///
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: always permitted — the underlying `updateLock` is fixed to {@link TimeLockType.None}.
+    /// * `Dynamic`: permitted unless the optional `updateLock` is currently active.
     /// * `Locked`: always aborts on-chain, because the underlying `updateLock` is pinned to {@link
     ///   TimeLockType.UntilDestroyed}.
     ///
@@ -187,7 +189,7 @@ impl WasmNotarizationClient {
     /// `lastStateChangeAt`, or the immutable description.
     ///
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: always permitted — the underlying `updateLock` is fixed to {@link TimeLockType.None}.
+    /// * `Dynamic`: permitted unless the optional `updateLock` is currently active.
     /// * `Locked`: always aborts on-chain, because the underlying `updateLock` is pinned to {@link
     ///   TimeLockType.UntilDestroyed}.
     ///
@@ -270,4 +272,33 @@ impl WasmNotarizationClient {
             .into_inner();
         Ok(into_transaction_builder(WasmTransferNotarization(tx)))
     }
+
+    /// Creates a transaction that seals a Dynamic-Notarization into a
+    /// Locked-Notarization.
+    ///
+    /// @remarks
+    /// The object ID, state, updatable metadata, `createdAt` and
+    /// `stateVersionCount` are preserved. Afterwards the notarization can
+    /// neither be updated nor transferred.
+    ///
+    /// Behaviour depends on the Notarization Method:
+    /// * `Dynamic`: sealed on success. Submitting while the `updateLock` is engaged, or with a `deleteLock`
+    ///   unlocking before an active `UnlockAt` `deleteLock` or `transferLock`, aborts on-chain.
+    /// * `Locked`: always aborts on-chain — the notarization is already sealed.
+    ///
+    /// @param notarizationId - The notarization object's ID.
+    /// @param deleteLock - The delete lock of the sealed notarization; {@link TimeLockType.UntilDestroyed} is not
+    /// permitted.
+    ///
+    /// @returns A {@link TransactionBuilder} wrapping the
+    /// {@link SealNotarization} transaction.
+    ///
+    /// @throws When the ID is malformed.
+    ///
+    /// Emits a `NotarizationSealed` event on success.
+    pub fn seal(&self, notarization_id: WasmObjectID, delete_lock: WasmTimeLock) -> Result<WasmTransactionBuilder> {
+        let notarization_id = parse_wasm_object_id(&notarization_id)?;
+        let tx = self.0.seal(notarization_id, delete_lock.0).into_inner();
+        Ok(into_transaction_builder(WasmSealNotarization(tx)))
+    }
 }
@@ -5,11 +5,11 @@ use std::str::FromStr;
 
 use anyhow::anyhow;
 use iota_interaction_ts::bindings::WasmIotaClient;
-use iota_interaction_ts::wasm_error::{wasm_error, Result, WasmResult};
+use iota_interaction_ts::wasm_error::{Result, WasmResult, wasm_error};
 use iota_sdk_types::ObjectId;
 use notarization::NotarizationClientReadOnly;
-use product_common::bindings::utils::parse_wasm_object_id;
 use product_common::bindings::WasmObjectID;
+use product_common::bindings::utils::parse_wasm_object_id;
 use product_common::core_client::CoreClientReadOnly;
 use wasm_bindgen::prelude::*;
 
@@ -279,7 +279,7 @@ impl WasmNotarizationClientReadOnly {
     ///
     /// @remarks
     /// Result depends on the Notarization Method:
-    /// * `Dynamic`: always `false`.
+    /// * `Dynamic`: `true` while the optional `updateLock` is engaged.
     /// * `Locked`: `true` while the configured `updateLock` is engaged.
     ///
     /// @param notarizedObjectId - The notarization object's ID.
@@ -302,8 +302,8 @@ impl WasmNotarizationClientReadOnly {
     ///
     /// @remarks
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: destruction is gated only on the `transferLock`. The notarization is destroy-allowed unless
-    ///   `transferLock` is currently `UnlockAt`-locked.
+    /// * `Dynamic`: destruction is gated on the optional `updateLock`, `deleteLock`, and `transferLock`. The
+    ///   notarization is destroy-allowed unless one of them is currently `UnlockAt`-locked.
     /// * `Locked`: destruction is gated on `updateLock`, `deleteLock`, and `transferLock`. The notarization is
     ///   destroy-allowed only when none of them is currently `UnlockAt`-locked.
     ///
@@ -1,7 +1,7 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use notarization::core::types::TimeLock;
+use notarization::core::types::{TimeLock, UpdateLock};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -87,3 +87,76 @@ impl WasmTimeLock {
         }
     }
 }
+
+/// Discriminator for the variants of {@link UpdateLock}.
+///
+/// @remarks
+/// Returned by the {@link UpdateLock.type} getter so callers can branch on the
+/// kind of lock without inspecting its arguments.
+#[wasm_bindgen(js_name = UpdateLockType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmUpdateLockType {
+    /// A {@link TimeLock}.
+    TimeLock = "TimeLock",
+    /// Locks at a specific timestamp expressed in seconds since the Unix epoch
+    /// and stays locked until the notarization is destroyed.
+    /// Can only be used for Dynamic-Notarizations.
+    LockAt = "LockAt",
+}
+
+/// The lock applied to the `updateLock` field of a notarization.
+///
+/// @remarks
+/// Construct one with the static factory methods ({@link UpdateLock.withTimeLock},
+/// {@link UpdateLock.withLockAt}) and inspect it via the {@link UpdateLock.type}
+/// and {@link UpdateLock.args} getters.
+#[wasm_bindgen(js_name = UpdateLock, inspectable)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WasmUpdateLock(pub(crate) UpdateLock);
+
+#[wasm_bindgen(js_class = UpdateLock)]
+impl WasmUpdateLock {
+    /// Creates an update lock from a {@link TimeLock}.
+    ///
+    /// @param lock - The {@link TimeLock} guarding updates.
+    ///
+    /// @returns An {@link UpdateLock} of type {@link UpdateLockType.TimeLock}.
+    #[wasm_bindgen(js_name = withTimeLock)]
+    pub fn with_time_lock(lock: WasmTimeLock) -> Self {
+        Self(UpdateLock::TimeLock(lock.0))
+    }
+
+    /// Creates a lock that engages at a specific timestamp in seconds.
+    ///
+    /// @remarks
+    /// Freezes a Dynamic-Notarization from `timeSec` on.
+    ///
+    /// @param timeSec - Lock time, in seconds since the Unix epoch.
+    ///
+    /// @returns An {@link UpdateLock} of type {@link UpdateLockType.LockAt}.
+    #[wasm_bindgen(js_name = withLockAt)]
+    pub fn with_lock_at(time_sec: u32) -> Self {
+        Self(UpdateLock::LockAt(time_sec))
+    }
+
+    /// The discriminator for which kind of lock this is.
+    #[wasm_bindgen(js_name = "type", getter)]
+    pub fn lock_type(&self) -> WasmUpdateLockType {
+        match &self.0 {
+            UpdateLock::TimeLock(_) => WasmUpdateLockType::TimeLock,
+            UpdateLock::LockAt(_) => WasmUpdateLockType::LockAt,
+        }
+    }
+
+    /// The argument carried by the lock variant.
+    ///
+    /// @returns The {@link TimeLock} for `TimeLock` and the lock timestamp
+    /// (`number`) for `LockAt` (seconds).
+    #[wasm_bindgen(js_name = "args", getter)]
+    pub fn args(&self) -> JsValue {
+        match &self.0 {
+            UpdateLock::TimeLock(lock) => WasmTimeLock(lock.clone()).into(),
+            UpdateLock::LockAt(lock_time) => JsValue::from(*lock_time),
+        }
+    }
+}
@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use js_sys::Uint8Array;
-use notarization::core::types::{Data, ImmutableMetadata, LockMetadata, NotarizationMethod, State};
+use notarization::core::types::{
+    Data, DynamicNotarizationTransferred, ImmutableMetadata, LockMetadata, NotarizationDestroyed,
+    NotarizationMetadataUpdated, NotarizationMethod, NotarizationSealed, NotarizationUpdated, State,
+};
+use product_common::bindings::WasmAddress;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
-use crate::wasm_time_lock::WasmTimeLock;
+use crate::wasm_time_lock::{WasmTimeLock, WasmUpdateLock};
 
 /// An empty placeholder value returned by transaction-apply methods that have
 /// no observable result.
@@ -97,7 +101,7 @@ impl From<Data> for WasmData {
 
 impl From<WasmData> for Data {
     fn from(value: WasmData) -> Self {
-        serde_wasm_bindgen::from_value(value.into()).expect("From implementation works")
+        value.0
     }
 }
 
@@ -117,6 +121,7 @@ impl From<WasmData> for Data {
 /// increments the underlying notarization's `stateVersionCount` and updates
 /// its `lastStateChangeAt` timestamp, even when only the `metadata` changes.
 #[wasm_bindgen(js_name = State, inspectable)]
+#[derive(Clone)]
 pub struct WasmState(pub(crate) State);
 
 #[wasm_bindgen(js_class = State)]
@@ -176,8 +181,8 @@ impl From<WasmState> for State {
 /// `transferLock` — on-chain creation aborts otherwise.
 ///
 /// Permitted lock configurations depend on the {@link NotarizationMethod}:
-/// * `Dynamic`: `updateLock` is fixed to {@link TimeLockType.None}; `transferLock` may carry any {@link TimeLock}
-///   variant.
+/// * `Dynamic`: `updateLock` may be {@link TimeLockType.None}, {@link TimeLockType.UnlockAt} or
+///   {@link UpdateLockType.LockAt}; `transferLock` may carry any {@link TimeLock} variant.
 /// * `Locked`: both `updateLock` and `transferLock` are fixed to {@link TimeLockType.UntilDestroyed} —
 ///   Locked-Notarizations are non-transferable and their state is immutable.
 #[wasm_bindgen(js_name = LockMetadata, getter_with_clone, inspectable)]
@@ -186,11 +191,12 @@ pub struct WasmLockMetadata {
     /// Lock gating state and metadata updates.
     ///
     /// Value depends on the Notarization Method:
-    /// * `Dynamic`: fixed to {@link TimeLockType.None} — state and updatable metadata are always replaceable via
-    ///   {@link NotarizationClient.updateState} and {@link NotarizationClient.updateMetadata}.
+    /// * `Dynamic`: an optional update window — {@link TimeLockType.UnlockAt} embargoes
+    ///   {@link NotarizationClient.updateState} and {@link NotarizationClient.updateMetadata} until a time,
+    ///   {@link UpdateLockType.LockAt} freezes them from a time on.
     /// * `Locked`: fixed to {@link TimeLockType.UntilDestroyed}.
     #[wasm_bindgen(js_name = updateLock)]
-    pub update_lock: WasmTimeLock,
+    pub update_lock: WasmUpdateLock,
     /// Lock gating destruction. Cannot be {@link TimeLockType.UntilDestroyed};
     /// its unlock time must be ≥ both other locks' unlock times.
     #[wasm_bindgen(js_name = deleteLock)]
@@ -207,7 +213,7 @@ pub struct WasmLockMetadata {
 impl From<LockMetadata> for WasmLockMetadata {
     fn from(value: LockMetadata) -> Self {
         WasmLockMetadata {
-            update_lock: WasmTimeLock(value.update_lock),
+            update_lock: WasmUpdateLock(value.update_lock),
             delete_lock: WasmTimeLock(value.delete_lock),
             transfer_lock: WasmTimeLock(value.transfer_lock),
         }
@@ -248,7 +254,7 @@ impl WasmImmutableMetadata {
     /// @remarks
     /// Presence depends on the Notarization Method:
     /// * `Dynamic`: absent when the Dynamic-Notarization carries no transfer lock; present otherwise.
-    /// * `Locked`: always present.
+    /// * `Locked` and `Shared`: always present.
     ///
     /// @returns The {@link LockMetadata}, or `null` when none is attached.
     #[wasm_bindgen(getter)]
@@ -276,6 +282,9 @@ pub enum WasmNotarizationMethod {
     /// Method whose state and updatable metadata are immutable after
     /// creation and whose destruction is gated by a `deleteLock`.
     Locked = "Locked",
+    /// Method of shared objects whose state and updatable metadata can be
+    /// updated by every holder of a valid capability issued for them.
+    Shared = "Shared",
 }
 
 impl From<NotarizationMethod> for WasmNotarizationMethod {
@@ -283,6 +292,7 @@ impl From<NotarizationMethod> for WasmNotarizationMethod {
         match value {
             NotarizationMethod::Dynamic => WasmNotarizationMethod::Dynamic,
             NotarizationMethod::Locked => WasmNotarizationMethod::Locked,
+            NotarizationMethod::Shared => WasmNotarizationMethod::Shared,
         }
     }
 }
@@ -292,7 +302,113 @@ impl From<WasmNotarizationMethod> for NotarizationMethod {
         match value {
             WasmNotarizationMethod::Dynamic => NotarizationMethod::Dynamic,
             WasmNotarizationMethod::Locked => NotarizationMethod::Locked,
+            WasmNotarizationMethod::Shared => NotarizationMethod::Shared,
             WasmNotarizationMethod::__Invalid => panic!("The NotarizationMethod {value:?} is not known"),
         }
     }
 }
+
+/// Event payload emitted when the state of a notarization is updated.
+#[wasm_bindgen(js_name = NotarizationUpdated, getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmNotarizationUpdated {
+    /// Object ID of the updated notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+    /// Value of `stateVersionCount` after the update.
+    #[wasm_bindgen(js_name = stateVersionCount)]
+    pub state_version_count: u64,
+    /// The new state after the update.
+    #[wasm_bindgen(js_name = updatedState)]
+    pub updated_state: WasmState,
+}
+
+impl From<NotarizationUpdated> for WasmNotarizationUpdated {
+    fn from(value: NotarizationUpdated) -> Self {
+        Self {
+            notarization_id: value.notarization_id.to_string(),
+            state_version_count: value.state_version_count,
+            updated_state: value.updated_state.into(),
+        }
+    }
+}
+
+/// Event payload emitted when the updatable metadata of a notarization is replaced.
+#[wasm_bindgen(js_name = NotarizationMetadataUpdated, getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmNotarizationMetadataUpdated {
+    /// Object ID of the updated notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+    /// The new updatable metadata.
+    #[wasm_bindgen(js_name = updatedMetadata)]
+    pub updated_metadata: Option<String>,
+}
+
+impl From<NotarizationMetadataUpdated> for WasmNotarizationMetadataUpdated {
+    fn from(value: NotarizationMetadataUpdated) -> Self {
+        Self {
+            notarization_id: value.notarization_id.to_string(),
+            updated_metadata: value.updated_metadata,
+        }
+    }
+}
+
+/// Event payload emitted when a notarization is destroyed.
+#[wasm_bindgen(js_name = NotarizationDestroyed, getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmNotarizationDestroyed {
+    /// Object ID of the destroyed notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+}
+
+impl From<NotarizationDestroyed> for WasmNotarizationDestroyed {
+    fn from(value: NotarizationDestroyed) -> Self {
+        Self {
+            notarization_id: value.notarization_id.to_string(),
+        }
+    }
+}
+
+/// Event payload emitted when a Dynamic-Notarization is transferred.
+#[wasm_bindgen(js_name = DynamicNotarizationTransferred, getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmDynamicNotarizationTransferred {
+    /// Object ID of the transferred notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+    /// Address of the new owner.
+    pub recipient: WasmAddress,
+}
+
+impl From<DynamicNotarizationTransferred> for WasmDynamicNotarizationTransferred {
+    fn from(value: DynamicNotarizationTransferred) -> Self {
+        Self {
+            notarization_id: value.notarization_id.to_string(),
+            recipient: value.recipient.to_string(),
+        }
+    }
+}
+
+/// Event payload emitted when a Dynamic-Notarization is sealed into a
+/// Locked-Notarization.
+#[wasm_bindgen(js_name = NotarizationSealed, getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmNotarizationSealed {
+    /// Object ID of the sealed notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+    /// Value of `stateVersionCount` of the sealed state.
+    #[wasm_bindgen(js_name = stateVersionCount)]
+    pub state_version_count: u64,
+}
+
+impl From<NotarizationSealed> for WasmNotarizationSealed {
+    fn from(value: NotarizationSealed) -> Self {
+        Self {
+            notarization_id: value.notarization_id.to_string(),
+            state_version_count: value.state_version_count,
+        }
+    }
+}
@@ -21,6 +21,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use anyhow::Result;
 use examples::get_funded_notarization_client;
 use notarization::core::types::{State, TimeLock};
+use product_common::core_client::CoreClient;
 use serde_json::json;
 use sha2::{Digest, Sha256};
 
@@ -176,6 +177,32 @@ async fn main() -> Result<()> {
         println!("❌ CRITICAL ERROR: Contract hash has been tampered with!");
     }
 
+    // Both parties endorse the notarized contract version. Endorsements only reference the
+    // notarization, so the employee can endorse it although the employer owns it.
+    println!("\n✍️  Endorsing the notarized contract version...");
+    let employee_client = get_funded_notarization_client().await?;
+    for client in [&notarization_client, &employee_client] {
+        let endorsed = client.endorse(*notarization_id).build_and_execute(client).await?.output;
+        println!(
+            "✅ {} endorsed version {}",
+            client.sender_address(),
+            endorsed.state_version_count
+        );
+    }
+
+    let endorsements = notarization_client.verify_endorsements(*notarization_id).await?;
+    for verified in &endorsements {
+        println!(
+            "🖋️  Endorsement by {}: {}",
+            verified.endorsement.endorser,
+            if verified.is_current {
+                "valid for the current contract version"
+            } else {
+                "outdated"
+            }
+        );
+    }
+
     println!("\n🎯 Example Complete!");
     println!("\n💡 Key Takeaways:");
     println!("• 🔒 Locked notarizations are completely immutable after creation");
@@ -184,6 +211,7 @@ async fn main() -> Result<()> {
     println!("• 📝 immutable_description: Human-readable contract identification");
     println!("• 📁 updatable_metadata: Administrative info (but immutable for locked!)");
     println!("• ⏰ delete_lock: Enforces legal retention periods");
+    println!("• ✍️  endorsements: Record that a party endorsed the notarized version");
     println!("\nLocked notarizations provide tamper-proof legal document attestation!");
 
     Ok(())
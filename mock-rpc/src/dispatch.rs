@@ -0,0 +1,594 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The JSON-RPC methods of an IOTA node, answered from an in-memory [`Ledger`].
+//!
+//! Covers the methods used by the clients of the `notarization` and `audit_trails` crates and by the transaction
+//! helpers of `product_common`. Responses are rendered in the JSON format of the IOTA node, so the `IotaClient` of
+//! the IOTA SDK decodes them as usual.
+
+use std::str::FromStr;
+
+use iota_sdk_types::{ObjectId, TypeTag};
+use serde_json::{Value as Json, json};
+
+use crate::discover;
+use crate::encoding::{base58, base64};
+use crate::filter::{all_of, any_of, matches_module, matches_type, normalize_type, tagged, type_package};
+use crate::ledger::{Contents, Event, Ledger, Object, ObjectRef, Outcome, Owner, Packages, REFERENCE_GAS_PRICE};
+use crate::params::{Params, RpcError, json_u64, parse_address, parse_object_id};
+
+/// Coin type of the gas coins of the mock ledger.
+const IOTA_COIN_TYPE: &str = "0x2::iota::IOTA";
+
+const METHODS: [&str; 19] = [
+    "rpc.discover",
+    "iota_getChainIdentifier",
+    "iota_getLatestCheckpointSequenceNumber",
+    "iota_getObject",
+    "iota_multiGetObjects",
+    "iota_getTransactionBlock",
+    "iota_devInspectTransactionBlock",
+    "iota_dryRunTransactionBlock",
+    "iota_executeTransactionBlock",
+    "iotax_getOwnedObjects",
+    "iotax_queryEvents",
+    "iotax_getReferenceGasPrice",
+    "iotax_getCoins",
+    "iotax_getAllCoins",
+    "iotax_getBalance",
+    "iotax_getAllBalances",
+    "iotax_getDynamicFields",
+    "iotax_getDynamicFieldObject",
+    "iotax_getDynamicFieldObjectV2",
+];
+
+/// Answers the JSON-RPC call `method` with `params` from `ledger`.
+pub fn dispatch<P: Packages>(ledger: &mut Ledger<P>, method: &str, params: &Params) -> Result<Json, RpcError> {
+    match method {
+        "rpc.discover" => Ok(discover(&METHODS)),
+        "iota_getChainIdentifier" => Ok(json!(ledger.chain_id)),
+        "iota_getLatestCheckpointSequenceNumber" => Ok(json!(ledger.events().len().to_string())),
+        "iotax_getReferenceGasPrice" => Ok(json!(REFERENCE_GAS_PRICE.to_string())),
+
+        "iota_getObject" => {
+            let id = params.object_id(0)?;
+            Ok(object_response(ledger, &id, &params.get(1)))
+        }
+        "iota_multiGetObjects" => {
+            let ids = params
+                .get(0)
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .map(parse_object_id)
+                .collect::<Result<Vec<_>, _>>()?;
+            let options = params.get(1);
+            Ok(json!(
+                ids.iter()
+                    .map(|id| object_response(ledger, id, &options))
+                    .collect::<Vec<_>>()
+            ))
+        }
+        "iotax_getOwnedObjects" => get_owned_objects(ledger, params),
+        "iotax_getDynamicFields" => Ok(json!({ "data": [], "nextCursor": Json::Null, "hasNextPage": false })),
+        "iotax_getDynamicFieldObject" | "iotax_getDynamicFieldObjectV2" => get_dynamic_field_object(ledger, params),
+
+        "iotax_getCoins" | "iotax_getAllCoins" => {
+            let owner = params.address(0)?;
+            let coins: Vec<Json> = ledger
+                .objects()
+                .filter(|object| object.owner == Owner::Address(owner))
+                .filter_map(coin_json)
+                .collect();
+            Ok(json!({ "data": coins, "nextCursor": Json::Null, "hasNextPage": false }))
+        }
+        "iotax_getBalance" | "iotax_getAllBalances" => {
+            let owner = params.address(0)?;
+            let coins: Vec<u64> = ledger
+                .objects()
+                .filter(|object| object.owner == Owner::Address(owner))
+                .filter_map(|object| match object.contents {
+                    Contents::Coin(balance) => Some(balance),
+                    _ => None,
+                })
+                .collect();
+            let balance = json!({
+                "coinType": IOTA_COIN_TYPE,
+                "coinObjectCount": coins.len(),
+                "totalBalance": coins.iter().sum::<u64>().to_string(),
+            });
+            if method == "iotax_getBalance" {
+                Ok(balance)
+            } else {
+                Ok(json!([balance]))
+            }
+        }
+
+        "iota_devInspectTransactionBlock" => {
+            let sender = params.address(0)?;
+            let kind_bytes = params.base64(1)?;
+            let outcome = ledger
+                .dev_inspect(sender, &kind_bytes)
+                .map_err(RpcError::invalid_params)?;
+            Ok(dev_inspect_json(&outcome))
+        }
+        "iota_dryRunTransactionBlock" => {
+            let tx_bytes = params.base64(0)?;
+            let outcome = ledger.dry_run(&tx_bytes).map_err(RpcError::rejected)?;
+            Ok(json!({
+                "effects": effects_json(&outcome),
+                "events": events_json(&outcome.events),
+                "objectChanges": object_changes_json(&outcome),
+                "balanceChanges": [],
+                "input": {
+                    "messageVersion": "v1",
+                    "transaction": { "kind": "ProgrammableTransaction", "inputs": [], "transactions": [] },
+                    "sender": outcome.sender.to_string(),
+                    "gasData": {
+                        "payment": [],
+                        "owner": outcome.sender.to_string(),
+                        "price": REFERENCE_GAS_PRICE.to_string(),
+                        "budget": (outcome.computation_cost + outcome.storage_cost).to_string(),
+                    },
+                },
+            }))
+        }
+        "iota_executeTransactionBlock" => {
+            let tx_bytes = params.base64(0)?;
+            let outcome = ledger.execute(&tx_bytes).map_err(RpcError::rejected)?;
+            Ok(transaction_json(&outcome))
+        }
+        "iota_getTransactionBlock" => {
+            let digest = params.string(0)?;
+            ledger
+                .transaction(&digest)
+                .map(transaction_json)
+                .ok_or_else(|| RpcError::invalid_params(format!("could not find the referenced transaction {digest}")))
+        }
+
+        "iotax_queryEvents" => query_events(ledger, params),
+
+        _ => Err(RpcError::method_not_found(method)),
+    }
+}
+
+fn get_owned_objects<P: Packages>(ledger: &Ledger<P>, params: &Params) -> Result<Json, RpcError> {
+    let owner = params.address(0)?;
+    let query = params.get(1);
+    let filter = query.get("filter").cloned().unwrap_or(Json::Null);
+    let options = query.get("options").cloned().unwrap_or(Json::Null);
+    let cursor = match params.get(2) {
+        Json::Null => None,
+        cursor => Some(parse_object_id(&cursor)?),
+    };
+    let limit = params.limit(3);
+
+    let mut matching = Vec::new();
+    for object in ledger.objects() {
+        if object.owner != Owner::Address(owner) || cursor.is_some_and(|cursor| object.id <= cursor) {
+            continue;
+        }
+        if filter.is_null() || matches_object_filter(ledger, object, &filter)? {
+            matching.push(object);
+        }
+    }
+
+    let has_next_page = matching.len() > limit;
+    matching.truncate(limit);
+    let next_cursor = matching.last().map(|object| json!(object.id.to_string()));
+
+    Ok(json!({
+        "data": matching.iter().map(|object| json!({ "data": object_data(ledger, object, &options) })).collect::<Vec<_>>(),
+        "nextCursor": next_cursor.unwrap_or(Json::Null),
+        "hasNextPage": has_next_page,
+    }))
+}
+
+fn get_dynamic_field_object<P: Packages>(ledger: &Ledger<P>, params: &Params) -> Result<Json, RpcError> {
+    let parent = params.object_id(0)?;
+    let name = params.get(1);
+    let type_ = name.get("type").and_then(Json::as_str).unwrap_or_default();
+    let name_type = TypeTag::from_str(type_)
+        .map_err(|_| RpcError::invalid_params(format!("invalid dynamic field name type `{type_}`")))?;
+    let name_bcs = name_bcs(&name_type, name.get("value").unwrap_or(&Json::Null))?;
+    let options = match params.get(2) {
+        Json::Null => json!({ "showType": true, "showOwner": true, "showContent": true, "showBcs": true }),
+        options => options,
+    };
+
+    Ok(match ledger.dynamic_field(parent, &name_type, &name_bcs) {
+        Some(object) => json!({ "data": object_data(ledger, object, &options) }),
+        None => json!({
+            "error": { "code": "dynamicFieldNotFound", "parent_object_id": parent.to_string() },
+        }),
+    })
+}
+
+/// Encodes the dynamic field name `value` of type `name_type` as BCS.
+///
+/// Supports the name types used by the simulated packages: `u64`, `address`, `0x2::object::ID` and
+/// `0x1::string::String`.
+fn name_bcs(name_type: &TypeTag, value: &Json) -> Result<Vec<u8>, RpcError> {
+    let type_ = normalize_type(&name_type.to_string());
+    let bcs = if type_ == "u64" {
+        let value = match value {
+            Json::String(value) => value.parse::<u64>().ok(),
+            value => value.as_u64(),
+        }
+        .ok_or_else(|| RpcError::invalid_params(format!("invalid u64 dynamic field name {value}")))?;
+        bcs::to_bytes(&value)
+    } else if type_ == "address" {
+        bcs::to_bytes(&parse_address(value)?)
+    } else if type_ == normalize_type("0x2::object::ID") {
+        bcs::to_bytes(&parse_object_id(value)?)
+    } else if type_ == normalize_type("0x1::string::String") {
+        let value = value
+            .as_str()
+            .ok_or_else(|| RpcError::invalid_params(format!("invalid string dynamic field name {value}")))?;
+        bcs::to_bytes(value)
+    } else {
+        return Err(RpcError::invalid_params(format!(
+            "unsupported dynamic field name type `{type_}`"
+        )));
+    };
+
+    Ok(bcs.expect("dynamic field names are serializable"))
+}
+
+fn matches_object_filter<P: Packages>(
+    ledger: &Ledger<P>,
+    object: &Object<P::Object>,
+    filter: &Json,
+) -> Result<bool, RpcError> {
+    let (kind, value) = tagged(filter)?;
+    let type_ = object.type_(&ledger.packages);
+
+    Ok(match kind {
+        "MatchAll" => all_of(value, |filter| matches_object_filter(ledger, object, filter))?,
+        "MatchAny" => any_of(value, |filter| matches_object_filter(ledger, object, filter))?,
+        "MatchNone" => !any_of(value, |filter| matches_object_filter(ledger, object, filter))?,
+        "StructType" => matches_type(&type_, value.as_str().unwrap_or_default()),
+        "Package" => type_package(&type_) == normalize_type(value.as_str().unwrap_or_default()),
+        "MoveModule" => matches_module(&type_, value),
+        "ObjectId" => object.id == parse_object_id(value)?,
+        "ObjectIds" => value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(parse_object_id)
+            .collect::<Result<Vec<_>, _>>()?
+            .contains(&object.id),
+        "AddressOwner" => object.owner == Owner::Address(parse_address(value)?),
+        _ => return Err(RpcError::invalid_params(format!("unsupported object filter `{kind}`"))),
+    })
+}
+
+fn query_events<P: Packages>(ledger: &Ledger<P>, params: &Params) -> Result<Json, RpcError> {
+    let filter = params.get(0);
+    let cursor = match params.get(1) {
+        Json::Null => None,
+        cursor => Some((
+            cursor
+                .get("txDigest")
+                .and_then(Json::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            json_u64(cursor.get("eventSeq").unwrap_or(&Json::Null)),
+        )),
+    };
+    let limit = params.limit(2);
+    let descending = params.get(3).as_bool().unwrap_or(false);
+
+    let mut events: Vec<&Event> = Vec::new();
+    for event in ledger.events() {
+        if matches_event_filter(event, &filter)? {
+            events.push(event);
+        }
+    }
+    if descending {
+        events.reverse();
+    }
+    if let Some((tx_digest, seq)) = cursor {
+        let position = events
+            .iter()
+            .position(|event| event.tx_digest == tx_digest && event.seq == seq)
+            .map_or(0, |position| position + 1);
+        events.drain(..position);
+    }
+
+    let has_next_page = events.len() > limit;
+    events.truncate(limit);
+    let next_cursor = events
+        .last()
+        .map(|event| json!({ "txDigest": event.tx_digest, "eventSeq": event.seq.to_string() }));
+
+    Ok(json!({
+        "data": events.into_iter().map(event_json).collect::<Vec<_>>(),
+        "nextCursor": next_cursor.unwrap_or(Json::Null),
+        "hasNextPage": has_next_page,
+    }))
+}
+
+fn matches_event_filter(event: &Event, filter: &Json) -> Result<bool, RpcError> {
+    let (kind, value) = tagged(filter)?;
+
+    Ok(match kind {
+        "All" => all_of(value, |filter| matches_event_filter(event, filter))?,
+        "Any" => any_of(value, |filter| matches_event_filter(event, filter))?,
+        "And" => all_of(value, |filter| matches_event_filter(event, filter))?,
+        "Or" => any_of(value, |filter| matches_event_filter(event, filter))?,
+        "MoveEventType" => matches_type(&event.type_, value.as_str().unwrap_or_default()),
+        "MoveEventModule" | "MoveModule" => matches_module(&event.type_, value),
+        "Package" => parse_object_id(value)? == event.package_id,
+        "Sender" => parse_address(value)? == event.sender,
+        "Transaction" => value.as_str() == Some(event.tx_digest.as_str()),
+        _ => return Err(RpcError::invalid_params(format!("unsupported event filter `{kind}`"))),
+    })
+}
+
+fn object_response<P: Packages>(ledger: &Ledger<P>, id: &ObjectId, options: &Json) -> Json {
+    match ledger.object(id) {
+        Some(object) => json!({ "data": object_data(ledger, object, options) }),
+        None => json!({
+            "error": { "code": "notExists", "object_id": id.to_string(), "objectId": id.to_string() },
+        }),
+    }
+}
+
+fn object_data<P: Packages>(ledger: &Ledger<P>, object: &Object<P::Object>, options: &Json) -> Json {
+    let show = |option: &str| options.get(option).and_then(Json::as_bool).unwrap_or(false);
+    let type_ = object.type_(&ledger.packages);
+    let mut data = json!({
+        "objectId": object.id.to_string(),
+        "version": object.version.to_string(),
+        "digest": object.digest(),
+    });
+
+    if show("showType") {
+        data["type"] = json!(type_);
+    }
+    if show("showOwner") {
+        data["owner"] = owner_json(object.owner);
+    }
+    if show("showPreviousTransaction") {
+        data["previousTransaction"] = json!(object.previous_transaction);
+    }
+    if show("showStorageRebate") {
+        data["storageRebate"] = json!(object.storage_rebate.to_string());
+    }
+    let move_object = match &object.contents {
+        Contents::Move(move_object) => Some(move_object),
+        _ => None,
+    };
+    let modules = match &object.contents {
+        Contents::Package(modules) => Some(modules),
+        _ => None,
+    };
+    if show("showContent") {
+        data["content"] = if modules.is_some() {
+            json!({ "dataType": "package", "disassembled": {} })
+        } else {
+            let fields = match (&object.contents, move_object) {
+                (_, Some(move_object)) => ledger.packages.object_json(object.id, move_object),
+                (Contents::DynamicField { .. }, _) => json!({ "id": { "id": object.id.to_string() } }),
+                (Contents::Coin(balance), _) => {
+                    json!({ "id": { "id": object.id.to_string() }, "balance": balance.to_string() })
+                }
+                _ => json!({ "id": { "id": object.id.to_string() }, "timestamp_ms": ledger.clock_ms.to_string() }),
+            };
+            json!({
+                "dataType": "moveObject",
+                "type": type_,
+                "hasPublicTransfer": move_object.is_none_or(|object| ledger.packages.has_public_transfer(object)),
+                "fields": fields,
+            })
+        };
+    }
+    if show("showBcs") {
+        data["bcs"] = if let Some(modules) = modules {
+            json!({
+                "dataType": "package",
+                "id": object.id.to_string(),
+                "version": object.version,
+                "moduleMap": modules
+                    .iter()
+                    .map(|(name, bytecode)| (name.clone(), json!(base64(bytecode))))
+                    .collect::<serde_json::Map<_, _>>(),
+                "typeOriginTable": [],
+                "linkageTable": {},
+            })
+        } else {
+            json!({
+                "dataType": "moveObject",
+                "type": type_,
+                "hasPublicTransfer": move_object.is_none_or(|object| ledger.packages.has_public_transfer(object)),
+                "version": object.version,
+                "bcsBytes": base64(&object.bcs(&ledger.packages, ledger.clock_ms)),
+            })
+        };
+    }
+
+    data
+}
+
+fn coin_json<O>(object: &Object<O>) -> Option<Json> {
+    let Contents::Coin(balance) = object.contents else {
+        return None;
+    };
+
+    Some(json!({
+        "coinType": IOTA_COIN_TYPE,
+        "coinObjectId": object.id.to_string(),
+        "version": object.version.to_string(),
+        "digest": object.digest(),
+        "balance": balance.to_string(),
+        "previousTransaction": object.previous_transaction,
+    }))
+}
+
+fn transaction_json(outcome: &Outcome) -> Json {
+    json!({
+        "digest": outcome.digest,
+        "effects": effects_json(outcome),
+        "events": events_json(&outcome.events),
+        "objectChanges": object_changes_json(outcome),
+        "balanceChanges": [],
+        "timestampMs": outcome.timestamp_ms.to_string(),
+        "confirmedLocalExecution": true,
+        "checkpoint": "1",
+        "errors": [],
+    })
+}
+
+fn dev_inspect_json(outcome: &Outcome) -> Json {
+    let results = outcome.status.is_ok().then(|| {
+        outcome
+            .results
+            .iter()
+            .map(|values| {
+                json!({
+                    "mutableReferenceOutputs": [],
+                    "returnValues": values.iter().map(|(bcs, type_)| json!([bcs, type_])).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    json!({
+        "effects": effects_json(outcome),
+        "events": events_json(&outcome.events),
+        "results": results,
+        "error": outcome.status.as_ref().err(),
+        "rawTxnData": [],
+        "rawEffects": [],
+    })
+}
+
+fn effects_json(outcome: &Outcome) -> Json {
+    let status = match &outcome.status {
+        Ok(()) => json!({ "status": "success" }),
+        Err(error) => json!({ "status": "failure", "error": error }),
+    };
+    let owned = |objects: &[(ObjectRef, Owner, String)]| {
+        objects
+            .iter()
+            .map(
+                |(reference, owner, _)| json!({ "owner": owner_json(*owner), "reference": object_ref_json(reference) }),
+            )
+            .collect::<Vec<_>>()
+    };
+    let gas_object = outcome.gas_object.as_ref().map_or_else(
+        || {
+            json!({
+                "owner": owner_json(Owner::Address(outcome.sender)),
+                "reference": object_ref_json(&ObjectRef {
+                    id: ObjectId::ZERO,
+                    version: 0,
+                    digest: base58(&[0; 32]),
+                }),
+            })
+        },
+        |(reference, owner)| json!({ "owner": owner_json(*owner), "reference": object_ref_json(reference) }),
+    );
+
+    json!({
+        "messageVersion": "v1",
+        "status": status,
+        "executedEpoch": "0",
+        "gasUsed": {
+            "computationCost": outcome.computation_cost.to_string(),
+            "computationCostBurned": outcome.computation_cost.to_string(),
+            "storageCost": outcome.storage_cost.to_string(),
+            "storageRebate": outcome.storage_rebate.to_string(),
+            "nonRefundableStorageFee": "0",
+        },
+        "modifiedAtVersions": [],
+        "sharedObjects": outcome.shared_objects.iter().map(object_ref_json).collect::<Vec<_>>(),
+        "transactionDigest": outcome.digest,
+        "created": owned(&outcome.created),
+        "mutated": owned(&outcome.mutated),
+        "unwrapped": [],
+        "deleted": outcome.deleted.iter().map(|(reference, _)| object_ref_json(reference)).collect::<Vec<_>>(),
+        "unwrappedThenDeleted": [],
+        "wrapped": [],
+        "gasObject": gas_object,
+        "dependencies": [],
+    })
+}
+
+fn object_changes_json(outcome: &Outcome) -> Json {
+    let sender = outcome.sender.to_string();
+    let created = outcome.created.iter().map(|(reference, owner, type_)| {
+        json!({
+            "type": "created",
+            "sender": sender,
+            "owner": owner_json(*owner),
+            "objectType": type_,
+            "objectId": reference.id.to_string(),
+            "version": reference.version.to_string(),
+            "digest": reference.digest,
+        })
+    });
+    let mutated = outcome.mutated.iter().map(|(reference, owner, type_)| {
+        json!({
+            "type": "mutated",
+            "sender": sender,
+            "owner": owner_json(*owner),
+            "objectType": type_,
+            "objectId": reference.id.to_string(),
+            "version": reference.version.to_string(),
+            "previousVersion": reference.version.saturating_sub(1).to_string(),
+            "digest": reference.digest,
+        })
+    });
+    let deleted = outcome.deleted.iter().map(|(reference, type_)| {
+        json!({
+            "type": "deleted",
+            "sender": sender,
+            "objectType": type_,
+            "objectId": reference.id.to_string(),
+            "version": reference.version.to_string(),
+        })
+    });
+
+    json!(created.chain(mutated).chain(deleted).collect::<Vec<_>>())
+}
+
+fn events_json(events: &[Event]) -> Json {
+    json!(events.iter().map(event_json).collect::<Vec<_>>())
+}
+
+fn event_json(event: &Event) -> Json {
+    json!({
+        "id": { "txDigest": event.tx_digest, "eventSeq": event.seq.to_string() },
+        "packageId": event.package_id.to_string(),
+        "transactionModule": event.module,
+        "sender": event.sender.to_string(),
+        "type": event.type_,
+        "parsedJson": event.json,
+        "bcsEncoding": "base64",
+        "bcs": base64(&event.bcs),
+        "timestampMs": event.timestamp_ms.to_string(),
+    })
+}
+
+fn owner_json(owner: Owner) -> Json {
+    match owner {
+        Owner::Address(address) => json!({ "AddressOwner": address.to_string() }),
+        Owner::Object(parent) => json!({ "ObjectOwner": parent.to_string() }),
+        Owner::Shared(initial_shared_version) => {
+            json!({ "Shared": { "initial_shared_version": initial_shared_version } })
+        }
+        Owner::Immutable => json!("Immutable"),
+    }
+}
+
+fn object_ref_json(reference: &ObjectRef) -> Json {
+    json!({
+        "objectId": reference.id.to_string(),
+        "version": reference.version,
+        "digest": reference.digest,
+    })
+}
@@ -0,0 +1,110 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The encodings of the node API: Base58 for digests and Base64 for BCS payloads, plus the ULEB128 encoding of
+//! lengths in BCS and Move bytecode.
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `bytes` in Base58, the encoding of transaction and object digests.
+pub fn base58(bytes: &[u8]) -> String {
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in bytes {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+    std::iter::repeat_n(BASE58_ALPHABET[0], zeros)
+        .chain(digits.iter().rev().map(|digit| BASE58_ALPHABET[usize::from(*digit)]))
+        .map(char::from)
+        .collect()
+}
+
+/// Encodes `bytes` in padded standard Base64.
+pub fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let block = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |block, (i, byte)| block | (u32::from(*byte) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(BASE64_ALPHABET[((block >> (18 - 6 * i)) & 0x3f) as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes padded or unpadded standard Base64.
+pub fn from_base64(encoded: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    let mut block = 0u32;
+    let mut bits = 0;
+    for c in encoded.trim_end_matches('=').bytes() {
+        let value = BASE64_ALPHABET.iter().position(|a| *a == c)? as u32;
+        block = (block << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push(((block >> bits) & 0xff) as u8);
+        }
+    }
+
+    Some(decoded)
+}
+
+/// Encodes `value` as ULEB128, the encoding of lengths in BCS and Move bytecode.
+pub fn uleb128(mut value: u64) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            encoded.push(byte);
+            return encoded;
+        }
+        encoded.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58_keeps_leading_zeros() {
+        assert_eq!(base58(&[0, 0, 1]), "112");
+        assert_eq!(base58(b"hello"), "Cn8eVZg");
+    }
+
+    #[test]
+    fn base64_round_trips() {
+        for bytes in [b"".as_slice(), b"a", b"ab", b"abc", b"abcd"] {
+            assert_eq!(from_base64(&base64(bytes)).as_deref(), Some(bytes));
+        }
+        assert_eq!(base64(b"ab"), "YWI=");
+        assert_eq!(from_base64("YWI"), Some(b"ab".to_vec()));
+    }
+
+    #[test]
+    fn uleb128_continues_on_the_high_bit() {
+        assert_eq!(uleb128(0), [0]);
+        assert_eq!(uleb128(0x7f), [0x7f]);
+        assert_eq!(uleb128(300), [0xac, 0x02]);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The matching of objects and events against the filters of the node API.
+
+use serde_json::Value as Json;
+
+use crate::params::RpcError;
+
+/// Splits an externally tagged filter into its variant and value.
+pub fn tagged(filter: &Json) -> Result<(&str, &Json), RpcError> {
+    filter
+        .as_object()
+        .and_then(|filter| filter.iter().next())
+        .map(|(kind, value)| (kind.as_str(), value))
+        .ok_or_else(|| RpcError::invalid_params(format!("invalid filter {filter}")))
+}
+
+/// Returns whether all `filters` match, stopping at the first that does not.
+pub fn all_of(filters: &Json, mut matches: impl FnMut(&Json) -> Result<bool, RpcError>) -> Result<bool, RpcError> {
+    for filter in filters.as_array().into_iter().flatten() {
+        if !matches(filter)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns whether any of `filters` matches, stopping at the first that does.
+pub fn any_of(filters: &Json, mut matches: impl FnMut(&Json) -> Result<bool, RpcError>) -> Result<bool, RpcError> {
+    for filter in filters.as_array().into_iter().flatten() {
+        if matches(filter)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Returns whether the Move type `type_` matches the type `filter`; a filter without type parameters matches all
+/// instantiations of a generic type.
+pub fn matches_type(type_: &str, filter: &str) -> bool {
+    let type_ = normalize_type(type_);
+    let filter = normalize_type(filter);
+    type_ == filter || (!filter.contains('<') && type_.split('<').next() == Some(filter.as_str()))
+}
+
+/// Returns whether the Move type `type_` is defined in the module `filter`, given as
+/// `{ "package": .., "module": .. }`.
+pub fn matches_module(type_: &str, filter: &Json) -> bool {
+    let package = normalize_type(filter.get("package").and_then(Json::as_str).unwrap_or_default());
+    let module = filter.get("module").and_then(Json::as_str).unwrap_or_default();
+    normalize_type(type_).starts_with(&format!("{package}::{module}::"))
+}
+
+/// Returns the normalized address of the package defining the Move type `type_`.
+pub fn type_package(type_: &str) -> String {
+    normalize_type(type_).split("::").next().unwrap_or_default().to_string()
+}
+
+/// Pads all addresses in the Move type `type_` to 64 hex digits, so that `0x2::clock::Clock` and
+/// `0x00..02::clock::Clock` compare equal.
+pub fn normalize_type(type_: &str) -> String {
+    let mut normalized = String::with_capacity(type_.len());
+    let mut rest = type_;
+    while let Some(start) = rest.find("0x") {
+        normalized.push_str(&rest[..start]);
+        let hex = &rest[start + 2..];
+        let end = hex.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(hex.len());
+        normalized.push_str(&format!("0x{:0>64}", hex[..end].to_ascii_lowercase()));
+        rest = &hex[end..];
+    }
+    normalized.push_str(rest);
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn types_match_regardless_of_address_padding() {
+        assert!(matches_type(
+            "0x0000000000000000000000000000000000000000000000000000000000000002::clock::Clock",
+            "0x2::clock::Clock"
+        ));
+        assert!(matches_type("0x2::coin::Coin<0x2::iota::IOTA>", "0x2::coin::Coin"));
+        assert!(!matches_type("0x2::coin::Coin<0x2::iota::IOTA>", "0x2::coin::Coin<0x3::iota::IOTA>"));
+    }
+
+    #[test]
+    fn modules_and_packages_are_matched_by_prefix() {
+        let type_ = "0x2::coin::Coin<0x2::iota::IOTA>";
+
+        assert!(matches_module(type_, &json!({ "package": "0x2", "module": "coin" })));
+        assert!(!matches_module(type_, &json!({ "package": "0x2", "module": "iota" })));
+        assert_eq!(type_package(type_), normalize_type("0x2"));
+    }
+
+    #[test]
+    fn filters_are_combined() -> Result<(), RpcError> {
+        let filter = json!({ "MatchAll": [{ "A": 1 }, { "B": 2 }] });
+        let (kind, filters) = tagged(&filter)?;
+        let is_a = |filter: &Json| tagged(filter).map(|(kind, _)| kind == "A");
+
+        assert_eq!(kind, "MatchAll");
+        assert!(!all_of(filters, is_a)?);
+        assert!(any_of(filters, is_a)?);
+        assert!(tagged(&json!("MatchAll")).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1072 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The in-memory object store of a mock ledger and the executor of programmable transactions.
+//!
+//! Transactions are decoded from their BCS wire format and executed command by command against a working copy of
+//! the objects. Move calls are handed to the [`Packages`] simulated by the ledger; a failing call rolls the working
+//! copy back, like an abort on-chain.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Debug;
+
+use blake2::Blake2b;
+use blake2::digest::consts::U32;
+use iota_sdk_types::{Address, ObjectId, TypeTag};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+use sha2::{Digest as _, Sha256};
+
+use crate::encoding::{base58, uleb128};
+
+/// Computation cost charged for every transaction, in NANOS.
+pub const COMPUTATION_COST: u64 = 1_000_000;
+/// Storage cost of a byte of object data, in NANOS.
+pub const STORAGE_PRICE_PER_BYTE: u64 = 7_600;
+/// Fixed overhead of every stored object, in bytes.
+const OBJECT_OVERHEAD_BYTES: u64 = 100;
+/// Reference gas price reported by the mock ledger.
+pub const REFERENCE_GAS_PRICE: u64 = 1_000;
+
+/// The ID of the `0x2::clock::Clock` singleton.
+const CLOCK_OBJECT_ID: ObjectId = ObjectId::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+]);
+/// Domain separator of the IDs of child objects, e.g. dynamic fields.
+const CHILD_OBJECT_ID_SCOPE: u8 = 0xf0;
+
+/// The Move packages simulated by a [`Ledger`].
+///
+/// Implementations hold the IDs the packages are published at and simulate their functions and objects; object
+/// ownership, versions, gas and the bookkeeping of events are handled by the ledger.
+pub trait Packages: Debug + Clone + Send + 'static {
+    /// An object of the simulated packages.
+    type Object: Debug + Clone + Send;
+    /// A value of the simulated packages that is passed between Move calls without being encoded as BCS.
+    type Value: Debug + Clone + Send;
+
+    /// Returns the IDs of the simulated packages with the bytecode of their modules, by module name.
+    fn modules(&self) -> Vec<(ObjectId, BTreeMap<String, Vec<u8>>)>;
+
+    /// Executes the Move function `module::function` of the package `package_id`.
+    fn call(
+        session: &mut Session<Self>,
+        package_id: ObjectId,
+        module: &str,
+        function: &str,
+        type_arguments: &[String],
+        arguments: Vec<Value<Self::Value>>,
+    ) -> Result<Vec<Value<Self::Value>>, Failure>;
+
+    /// Returns the Move type of `object`.
+    fn object_type(&self, object: &Self::Object) -> String;
+
+    /// Returns whether `object` has the `store` ability.
+    fn has_public_transfer(&self, object: &Self::Object) -> bool;
+
+    /// Returns the BCS of `object`, whose ID is `id`.
+    fn object_bcs(&self, id: ObjectId, object: &Self::Object) -> Vec<u8>;
+
+    /// Returns the fields of `object`, whose ID is `id`, as rendered in the parsed content of object responses.
+    fn object_json(&self, id: ObjectId, object: &Self::Object) -> Json;
+
+    /// Returns the dynamic fields held by `object`, whose ID is `id`.
+    fn dynamic_fields(&self, id: ObjectId, object: &Self::Object) -> Vec<DynamicField>;
+
+    /// Returns the Move type of `value`.
+    fn value_type(&self, value: &Self::Value) -> String;
+
+    /// Returns the BCS of `value`.
+    fn value_bcs(&self, value: &Self::Value) -> Vec<u8>;
+}
+
+/// A dynamic field held by an object of the simulated [`Packages`].
+///
+/// Dynamic fields are not stored by the simulation; after every transaction, the [`Ledger`] derives them from the
+/// objects holding them and stores them as `0x2::dynamic_field::Field<K, V>` objects, under the IDs a node derives
+/// for them.
+#[derive(Debug, Clone)]
+pub struct DynamicField {
+    /// The ID of the `UID` the field is attached to.
+    pub parent: ObjectId,
+    /// The Move type of the name of the field.
+    pub name_type: TypeTag,
+    /// The BCS of the name of the field.
+    pub name: Vec<u8>,
+    /// The Move type of the value of the field.
+    pub value_type: String,
+    /// The BCS of the value of the field.
+    pub value: Vec<u8>,
+}
+
+/// The in-memory state of a mock ledger.
+#[derive(Clone)]
+pub struct Ledger<P: Packages> {
+    pub packages: P,
+    pub chain_id: String,
+    pub clock_ms: u64,
+    objects: BTreeMap<ObjectId, Object<P::Object>>,
+    events: Vec<Event>,
+    transactions: HashMap<String, Outcome>,
+    next_object: u64,
+    lamport: u64,
+}
+
+/// An object stored on the mock ledger.
+#[derive(Debug, Clone)]
+pub struct Object<O> {
+    pub id: ObjectId,
+    pub version: u64,
+    pub owner: Owner,
+    pub previous_transaction: String,
+    pub storage_rebate: u64,
+    pub contents: Contents<O>,
+}
+
+/// The owner of an [`Object`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owner {
+    Address(Address),
+    Object(ObjectId),
+    Shared(u64),
+    Immutable,
+}
+
+/// The contents of an [`Object`].
+#[derive(Debug, Clone)]
+pub enum Contents<O> {
+    /// The `0x2::clock::Clock` singleton, reading the time of the ledger.
+    Clock,
+    /// A `0x2::coin::Coin<0x2::iota::IOTA>` with its balance.
+    Coin(u64),
+    /// A simulated Move package with the bytecode of its modules, by module name.
+    Package(BTreeMap<String, Vec<u8>>),
+    /// An object of the simulated packages.
+    Move(O),
+    /// A `0x2::dynamic_field::Field<K, V>` derived from a [`DynamicField`], with its Move type and complete BCS.
+    DynamicField { type_: String, bcs: Vec<u8> },
+}
+
+/// A Move event emitted by an executed transaction.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub tx_digest: String,
+    pub seq: u64,
+    pub package_id: ObjectId,
+    pub module: String,
+    pub sender: Address,
+    pub type_: String,
+    pub json: Json,
+    pub bcs: Vec<u8>,
+    pub timestamp_ms: u64,
+}
+
+/// An event emitted by a [`Session`], before the transaction is committed.
+#[derive(Debug, Clone)]
+struct PendingEvent {
+    package_id: ObjectId,
+    module: String,
+    type_: String,
+    json: Json,
+    bcs: Vec<u8>,
+}
+
+/// A reference to an object version, as reported in transaction effects.
+#[derive(Debug, Clone)]
+pub struct ObjectRef {
+    pub id: ObjectId,
+    pub version: u64,
+    pub digest: String,
+}
+
+/// The outcome of an executed or inspected transaction.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub digest: String,
+    pub sender: Address,
+    pub status: Result<(), String>,
+    pub computation_cost: u64,
+    pub storage_cost: u64,
+    pub storage_rebate: u64,
+    pub created: Vec<(ObjectRef, Owner, String)>,
+    pub mutated: Vec<(ObjectRef, Owner, String)>,
+    pub deleted: Vec<(ObjectRef, String)>,
+    pub shared_objects: Vec<ObjectRef>,
+    pub gas_object: Option<(ObjectRef, Owner)>,
+    pub events: Vec<Event>,
+    /// BCS and Move type of the values returned by each command.
+    pub results: Vec<Vec<(Vec<u8>, String)>>,
+    pub timestamp_ms: u64,
+}
+
+/// A failure while executing a command.
+#[derive(Debug)]
+pub enum Failure {
+    /// A Move abort raised by a simulated package.
+    Abort {
+        package_id: ObjectId,
+        module: String,
+        function: String,
+        code: u64,
+    },
+    /// Any other execution error.
+    Other(String),
+}
+
+impl Failure {
+    /// Renders the failure like the execution error a node reports for the command at index `command`.
+    pub fn describe(&self, command: usize) -> String {
+        match self {
+            Failure::Abort {
+                package_id,
+                module,
+                function,
+                code,
+            } => format!(
+                "MoveAbort(MoveLocation {{ module: ModuleId {{ address: {package_id}, name: Identifier(\"{module}\") \
+                 }}, function: 0, instruction: 0, function_name: Some(\"{function}\") }}, {code}) in command {command}"
+            ),
+            Failure::Other(error) => format!("{error} in command {command}"),
+        }
+    }
+}
+
+/// A value produced while executing a programmable transaction.
+#[derive(Debug, Clone)]
+pub enum Value<V> {
+    /// A pure input, as BCS.
+    Pure(Vec<u8>),
+    /// A value returned by a Move call, as BCS with its Move type.
+    Move { bcs: Vec<u8>, type_: String },
+    /// An object, by ID.
+    Object(ObjectId),
+    /// A [`Packages::Value`] passed on to other Move calls.
+    Package(V),
+}
+
+/// The objects and events of a transaction being executed.
+///
+/// Works on a copy of the objects of the [`Ledger`], which is committed only if the transaction succeeds.
+pub struct Session<P: Packages> {
+    pub packages: P,
+    pub sender: Address,
+    pub now_ms: u64,
+    objects: BTreeMap<ObjectId, Object<P::Object>>,
+    created: BTreeSet<ObjectId>,
+    written: BTreeSet<ObjectId>,
+    deleted: BTreeSet<ObjectId>,
+    events: Vec<PendingEvent>,
+    next_object: u64,
+}
+
+impl<P: Packages> Session<P> {
+    /// Returns the object `id` of a simulated package.
+    pub fn borrow(&self, id: ObjectId) -> Result<&P::Object, Failure> {
+        match self.objects.get(&id).map(|object| &object.contents) {
+            Some(Contents::Move(object)) => Ok(object),
+            Some(_) => Err(Failure::Other(format!("object {id} has an unexpected type"))),
+            None => Err(Failure::Other(format!("object {id} does not exist"))),
+        }
+    }
+
+    /// Returns the object `id` of a simulated package for mutation.
+    pub fn borrow_mut(&mut self, id: ObjectId) -> Result<&mut P::Object, Failure> {
+        self.written.insert(id);
+        match self.objects.get_mut(&id).map(|object| &mut object.contents) {
+            Some(Contents::Move(object)) => Ok(object),
+            Some(_) => Err(Failure::Other(format!("object {id} has an unexpected type"))),
+            None => Err(Failure::Other(format!("object {id} does not exist"))),
+        }
+    }
+
+    /// Returns a fresh object ID, e.g. for the `UID` of a new object or collection.
+    pub fn new_id(&mut self) -> ObjectId {
+        let id = object_id(b"object", self.next_object);
+        self.next_object += 1;
+
+        id
+    }
+
+    /// Creates the object `id` owned by `owner`; `id` must come from [`Session::new_id`].
+    pub fn create(&mut self, id: ObjectId, owner: Owner, object: P::Object) {
+        self.created.insert(id);
+        self.objects.insert(
+            id,
+            Object {
+                id,
+                version: 0,
+                owner,
+                previous_transaction: String::new(),
+                storage_rebate: 0,
+                contents: Contents::Move(object),
+            },
+        );
+    }
+
+    /// Changes the owner of the object `id`.
+    pub fn transfer(&mut self, id: ObjectId, owner: Owner) -> Result<(), Failure> {
+        let object = self
+            .objects
+            .get_mut(&id)
+            .ok_or_else(|| Failure::Other(format!("object {id} does not exist")))?;
+        if matches!(object.owner, Owner::Shared(_) | Owner::Immutable) {
+            return Err(Failure::Other(format!("object {id} cannot be transferred")));
+        }
+        object.owner = owner;
+        self.written.insert(id);
+
+        Ok(())
+    }
+
+    /// Deletes the object `id` and returns its contents.
+    pub fn delete(&mut self, id: ObjectId) -> Result<P::Object, Failure> {
+        match self.objects.remove(&id).map(|object| object.contents) {
+            Some(Contents::Move(object)) => {
+                if !self.created.remove(&id) {
+                    self.deleted.insert(id);
+                }
+                self.written.remove(&id);
+                Ok(object)
+            }
+            _ => Err(Failure::Other(format!("object {id} cannot be deleted"))),
+        }
+    }
+
+    /// Emits `event` as the event `name` of `module` of the package `package_id`.
+    pub fn emit(&mut self, package_id: ObjectId, module: &str, name: &str, event: &impl Serialize) {
+        let json = serde_json::to_value(event).expect("events are serializable");
+        let bcs = bcs::to_bytes(event).expect("events are serializable");
+        self.push_event(package_id, module, name, json, bcs);
+    }
+
+    /// Emits the event `name` of `module` of the package `package_id` with the parsed JSON `json` and no BCS, for
+    /// events whose Move type has no Rust counterpart.
+    pub fn emit_json(&mut self, package_id: ObjectId, module: &str, name: &str, json: Json) {
+        self.push_event(package_id, module, name, json, Vec::new());
+    }
+
+    /// Emits the event `name` of `module` of the package `package_id` with the parsed JSON `json` and the BCS `bcs`,
+    /// for events whose Move type has no Rust counterpart but whose content is read back from its BCS.
+    pub fn emit_raw(&mut self, package_id: ObjectId, module: &str, name: &str, json: Json, bcs: Vec<u8>) {
+        self.push_event(package_id, module, name, json, bcs);
+    }
+
+    fn push_event(&mut self, package_id: ObjectId, module: &str, name: &str, json: Json, bcs: Vec<u8>) {
+        self.events.push(PendingEvent {
+            package_id,
+            module: module.to_string(),
+            type_: format!("{package_id}::{module}::{name}"),
+            json,
+            bcs,
+        });
+    }
+
+    /// Returns the BCS and the Move type of `value`.
+    fn encode(&self, value: &Value<P::Value>) -> (Vec<u8>, String) {
+        match value {
+            Value::Pure(bcs) => (bcs.clone(), "vector<u8>".to_string()),
+            Value::Move { bcs, type_ } => (bcs.clone(), type_.clone()),
+            Value::Object(id) => self.objects.get(id).map_or_else(
+                || (Vec::new(), String::new()),
+                |object| (object.bcs(&self.packages, self.now_ms), object.type_(&self.packages)),
+            ),
+            Value::Package(value) => (self.packages.value_bcs(value), self.packages.value_type(value)),
+        }
+    }
+
+    /// Returns the storage cost of the objects written by the transaction and the rebate of the storage they
+    /// occupied before.
+    fn storage(&self, ledger: &Ledger<P>) -> (u64, u64) {
+        let cost = self
+            .written
+            .iter()
+            .chain(&self.created)
+            .filter_map(|id| self.objects.get(id))
+            .map(|object| object.storage_cost(&self.packages, self.now_ms))
+            .sum();
+        let rebate = self
+            .written
+            .iter()
+            .chain(&self.deleted)
+            .filter_map(|id| ledger.objects.get(id))
+            .map(|object| object.storage_rebate)
+            .sum();
+
+        (cost, rebate)
+    }
+}
+
+impl<O> Object<O> {
+    /// Returns the Move type of the object.
+    pub fn type_<P: Packages<Object = O>>(&self, packages: &P) -> String {
+        match &self.contents {
+            Contents::Clock => "0x2::clock::Clock".to_string(),
+            Contents::Coin(_) => "0x2::coin::Coin<0x2::iota::IOTA>".to_string(),
+            Contents::Package(_) => "package".to_string(),
+            Contents::Move(object) => packages.object_type(object),
+            Contents::DynamicField { type_, .. } => type_.clone(),
+        }
+    }
+
+    /// Returns the BCS of the object; `now_ms` is the time of the clock.
+    pub fn bcs<P: Packages<Object = O>>(&self, packages: &P, now_ms: u64) -> Vec<u8> {
+        let id = bcs::to_bytes(&self.id).expect("IDs are serializable");
+        match &self.contents {
+            Contents::Clock => [id, bcs::to_bytes(&now_ms).expect("u64 is serializable")].concat(),
+            Contents::Coin(balance) => [id, bcs::to_bytes(balance).expect("u64 is serializable")].concat(),
+            Contents::Package(_) => Vec::new(),
+            Contents::Move(object) => packages.object_bcs(self.id, object),
+            Contents::DynamicField { bcs, .. } => bcs.clone(),
+        }
+    }
+
+    /// Returns the digest of the current version of the object.
+    pub fn digest(&self) -> String {
+        digest_of(&[self.id.to_string().as_bytes(), self.version.to_le_bytes().as_slice()])
+    }
+
+    /// Returns the reference to the current version of the object.
+    pub fn object_ref(&self) -> ObjectRef {
+        ObjectRef {
+            id: self.id,
+            version: self.version,
+            digest: self.digest(),
+        }
+    }
+
+    fn storage_cost<P: Packages<Object = O>>(&self, packages: &P, now_ms: u64) -> u64 {
+        (self.bcs(packages, now_ms).len() as u64 + OBJECT_OVERHEAD_BYTES) * STORAGE_PRICE_PER_BYTE
+    }
+}
+
+impl<P: Packages> Ledger<P> {
+    /// Creates a ledger holding the clock and the simulated `packages`.
+    pub fn new(packages: P, chain_id: String, clock_ms: u64) -> Self {
+        let mut ledger = Self {
+            packages,
+            chain_id,
+            clock_ms,
+            objects: BTreeMap::new(),
+            events: Vec::new(),
+            transactions: HashMap::new(),
+            next_object: 0,
+            lamport: 1,
+        };
+        let genesis = digest_of(&[b"genesis".as_slice()]);
+        let clock = (CLOCK_OBJECT_ID, Owner::Shared(1), Contents::Clock);
+        let packages = ledger
+            .packages
+            .modules()
+            .into_iter()
+            .map(|(id, modules)| (id, Owner::Immutable, Contents::Package(modules)));
+        for (id, owner, contents) in std::iter::once(clock).chain(packages) {
+            ledger.objects.insert(
+                id,
+                Object {
+                    id,
+                    version: 1,
+                    owner,
+                    previous_transaction: genesis.clone(),
+                    storage_rebate: 0,
+                    contents,
+                },
+            );
+        }
+
+        ledger
+    }
+
+    /// Returns the object `id`, including the dynamic fields derived from the objects of the simulated packages.
+    pub fn object(&self, id: &ObjectId) -> Option<&Object<P::Object>> {
+        self.objects.get(id)
+    }
+
+    /// Returns all objects, ordered by ID.
+    pub fn objects(&self) -> impl Iterator<Item = &Object<P::Object>> {
+        self.objects.values()
+    }
+
+    /// Returns the dynamic field of `parent` whose name of type `name_type` has the BCS `name`.
+    pub fn dynamic_field(&self, parent: ObjectId, name_type: &TypeTag, name: &[u8]) -> Option<&Object<P::Object>> {
+        self.objects
+            .get(&dynamic_field_id(parent, name_type, name))
+            .filter(|object| object.owner == Owner::Object(parent))
+    }
+
+    /// Returns all events, in the order they were emitted.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the outcome of the executed transaction `digest`.
+    pub fn transaction(&self, digest: &str) -> Option<&Outcome> {
+        self.transactions.get(digest)
+    }
+
+    /// Creates a gas coin with `balance` owned by `owner`.
+    pub fn mint_gas(&mut self, owner: Address, balance: u64) -> ObjectId {
+        let id = object_id(b"gas", self.next_object);
+        self.next_object += 1;
+        self.lamport += 1;
+        let coin = Object {
+            id,
+            version: self.lamport,
+            owner: Owner::Address(owner),
+            previous_transaction: digest_of(&[b"mint".as_slice(), id.to_string().as_bytes()]),
+            storage_rebate: 0,
+            contents: Contents::Coin(balance),
+        };
+        self.objects.insert(id, coin);
+
+        id
+    }
+
+    /// Executes the BCS-encoded `TransactionData` `tx_bytes` and commits its effects.
+    ///
+    /// Transactions a node would reject before execution, e.g. because an owned input is stale or not owned by the
+    /// sender, return an error and leave the ledger untouched.
+    pub fn execute(&mut self, tx_bytes: &[u8]) -> Result<Outcome, String> {
+        let TransactionData::V1(tx) =
+            bcs::from_bytes(tx_bytes).map_err(|e| format!("invalid transaction data: {e}"))?;
+        let TransactionKind::ProgrammableTransaction(ptb) = tx.kind;
+        let (gas_coin, ..) = tx
+            .gas_data
+            .payment
+            .first()
+            .cloned()
+            .ok_or_else(|| "missing gas payment".to_string())?;
+
+        match self.objects.get(&gas_coin) {
+            Some(Object {
+                owner: Owner::Address(owner),
+                contents: Contents::Coin(balance),
+                ..
+            }) if *owner == tx.sender => {
+                if *balance < tx.gas_data.budget {
+                    return Err(format!(
+                        "balance of gas object {gas_coin} is lower than the needed amount: {}",
+                        tx.gas_data.budget
+                    ));
+                }
+            }
+            _ => return Err(format!("gas object {gas_coin} is not a coin owned by {}", tx.sender)),
+        }
+        for input in &ptb.inputs {
+            if let CallArg::Object(ObjectArg::ImmOrOwnedObject((id, version, _))) = input {
+                let object = self
+                    .objects
+                    .get(id)
+                    .ok_or_else(|| format!("object {id} does not exist"))?;
+                if object.version != *version {
+                    return Err(format!(
+                        "object {id} version {version} is unavailable for consumption, current version: {}",
+                        object.version
+                    ));
+                }
+                if !matches!(object.owner, Owner::Immutable) && object.owner != Owner::Address(tx.sender) {
+                    return Err(format!("object {id} is not owned by the sender {}", tx.sender));
+                }
+            }
+        }
+
+        let outcome = self.run(tx.sender, &ptb, Some((gas_coin, tx.gas_data.budget)));
+        self.transactions.insert(outcome.digest.clone(), outcome.clone());
+
+        Ok(outcome)
+    }
+
+    /// Executes the BCS-encoded `TransactionData` `tx_bytes` without committing its effects.
+    pub fn dry_run(&self, tx_bytes: &[u8]) -> Result<Outcome, String> {
+        self.clone().execute(tx_bytes)
+    }
+
+    /// Executes the BCS-encoded `TransactionKind` `kind_bytes` as `sender` without committing its effects,
+    /// skipping ownership and gas checks.
+    pub fn dev_inspect(&self, sender: Address, kind_bytes: &[u8]) -> Result<Outcome, String> {
+        let TransactionKind::ProgrammableTransaction(ptb) =
+            bcs::from_bytes(kind_bytes).map_err(|e| format!("invalid transaction kind: {e}"))?;
+
+        Ok(self.clone().run(sender, &ptb, None))
+    }
+
+    /// Runs `calls` as `sender` in a transaction of their own and commits its effects, without going through the
+    /// wire format of transactions. Meant for the unit tests of [`Packages`] implementations.
+    ///
+    /// A failing transaction leaves the ledger untouched and returns its [`Failure`].
+    pub fn transact<T>(
+        &mut self,
+        sender: Address,
+        calls: impl FnOnce(&mut Session<P>) -> Result<T, Failure>,
+    ) -> Result<(T, Vec<Event>), Failure> {
+        let mut session = self.session(sender, &ProgrammableTransaction::default(), None);
+        let value = calls(&mut session)?;
+
+        self.lamport += 1;
+        let digest = digest_of(&[b"transaction".as_slice(), self.lamport.to_le_bytes().as_slice()]);
+        let outcome = self.commit(session, digest, sender, Ok(()), 0, 0, Vec::new(), None);
+
+        Ok((value, outcome.events))
+    }
+
+    /// Runs `ptb` and commits its effects; failed transactions only pay for gas.
+    fn run(&mut self, sender: Address, ptb: &ProgrammableTransaction, gas: Option<(ObjectId, u64)>) -> Outcome {
+        self.lamport += 1;
+        let digest = digest_of(&[b"transaction".as_slice(), self.lamport.to_le_bytes().as_slice()]);
+
+        let mut session = self.session(sender, ptb, gas.map(|(coin, _)| coin));
+        let mut results = Vec::new();
+        let mut status = self.execute_commands(&mut session, ptb, gas.map(|(coin, _)| coin), &mut results);
+        let (mut storage_cost, mut storage_rebate) = session.storage(self);
+
+        let over_budget = gas.is_some_and(|(_, budget)| COMPUTATION_COST + storage_cost > budget);
+        if status.is_ok() && over_budget {
+            status = Err("InsufficientGas".to_string());
+        }
+        if status.is_err() {
+            session = self.session(sender, &ProgrammableTransaction::default(), gas.map(|(coin, _)| coin));
+            (storage_cost, storage_rebate) = session.storage(self);
+        }
+        if let Some((coin, _)) = gas
+            && let Some(Object {
+                contents: Contents::Coin(balance),
+                ..
+            }) = session.objects.get_mut(&coin)
+        {
+            *balance = (*balance + storage_rebate).saturating_sub(COMPUTATION_COST + storage_cost);
+        }
+
+        self.commit(
+            session,
+            digest,
+            sender,
+            status,
+            storage_cost,
+            storage_rebate,
+            results,
+            gas,
+        )
+    }
+
+    /// Creates a [`Session`] for `ptb`, marking its mutable inputs and the gas coin as written.
+    fn session(&self, sender: Address, ptb: &ProgrammableTransaction, gas: Option<ObjectId>) -> Session<P> {
+        let written = ptb
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                CallArg::Object(ObjectArg::ImmOrOwnedObject((id, ..))) => Some(*id),
+                CallArg::Object(ObjectArg::SharedObject { id, mutable: true, .. }) => Some(*id),
+                _ => None,
+            })
+            .filter(|id| {
+                self.objects
+                    .get(id)
+                    .is_some_and(|object| object.owner != Owner::Immutable)
+            })
+            .chain(gas)
+            .collect();
+
+        Session {
+            packages: self.packages.clone(),
+            sender,
+            now_ms: self.clock_ms,
+            objects: self.objects.clone(),
+            created: BTreeSet::new(),
+            written,
+            deleted: BTreeSet::new(),
+            events: Vec::new(),
+            next_object: self.next_object,
+        }
+    }
+
+    /// Executes the commands of `ptb`, collecting the values they return.
+    fn execute_commands(
+        &self,
+        session: &mut Session<P>,
+        ptb: &ProgrammableTransaction,
+        gas: Option<ObjectId>,
+        returned: &mut Vec<Vec<(Vec<u8>, String)>>,
+    ) -> Result<(), String> {
+        let inputs: Vec<Value<P::Value>> = ptb
+            .inputs
+            .iter()
+            .map(|input| match input {
+                CallArg::Pure(bcs) => Value::Pure(bcs.clone()),
+                CallArg::Object(ObjectArg::ImmOrOwnedObject((id, ..)) | ObjectArg::Receiving((id, ..))) => {
+                    Value::Object(*id)
+                }
+                CallArg::Object(ObjectArg::SharedObject { id, .. }) => Value::Object(*id),
+            })
+            .collect();
+        let mut results: Vec<Vec<Value<P::Value>>> = Vec::new();
+
+        for (index, command) in ptb.commands.iter().enumerate() {
+            let values = self
+                .execute_command(session, command, &inputs, &results, gas)
+                .map_err(|failure| failure.describe(index))?;
+            returned.push(values.iter().map(|value| session.encode(value)).collect());
+            results.push(values);
+        }
+
+        Ok(())
+    }
+
+    fn execute_command(
+        &self,
+        session: &mut Session<P>,
+        command: &Command,
+        inputs: &[Value<P::Value>],
+        results: &[Vec<Value<P::Value>>],
+        gas: Option<ObjectId>,
+    ) -> Result<Vec<Value<P::Value>>, Failure> {
+        let argument = |argument: &Argument| {
+            match argument {
+                Argument::GasCoin => gas.map(Value::Object),
+                Argument::Input(index) => inputs.get(usize::from(*index)).cloned(),
+                Argument::Result(index) => results.get(usize::from(*index)).and_then(|r| r.first()).cloned(),
+                Argument::NestedResult(index, nested) => results
+                    .get(usize::from(*index))
+                    .and_then(|r| r.get(usize::from(*nested)))
+                    .cloned(),
+            }
+            .ok_or_else(|| Failure::Other(format!("invalid argument {argument:?}")))
+        };
+
+        match command {
+            Command::MoveCall(call) => {
+                let type_arguments: Vec<String> = call.type_arguments.iter().map(TypeTag::to_string).collect();
+                let arguments = call.arguments.iter().map(argument).collect::<Result<Vec<_>, _>>()?;
+
+                P::call(
+                    session,
+                    call.package,
+                    &call.module,
+                    &call.function,
+                    &type_arguments,
+                    arguments,
+                )
+            }
+            Command::TransferObjects(objects, recipient) => {
+                let Value::Pure(recipient) = argument(recipient)? else {
+                    return Err(Failure::Other("recipient must be a pure address".to_string()));
+                };
+                let recipient: Address =
+                    bcs::from_bytes(&recipient).map_err(|e| Failure::Other(format!("invalid recipient: {e}")))?;
+                for object in objects {
+                    let Value::Object(id) = argument(object)? else {
+                        return Err(Failure::Other("only objects can be transferred".to_string()));
+                    };
+                    session.transfer(id, Owner::Address(recipient))?;
+                }
+
+                Ok(Vec::new())
+            }
+            Command::MakeMoveVec(type_, elements) => {
+                let mut element_type = type_.as_ref().map(TypeTag::to_string);
+                let mut bcs = uleb128(elements.len() as u64);
+                for element in elements {
+                    match argument(element)? {
+                        Value::Pure(element) => bcs.extend(element),
+                        Value::Move { bcs: element, type_ } => {
+                            element_type.get_or_insert(type_);
+                            bcs.extend(element);
+                        }
+                        Value::Package(element) => {
+                            element_type.get_or_insert_with(|| session.packages.value_type(&element));
+                            bcs.extend(session.packages.value_bcs(&element));
+                        }
+                        Value::Object(_) => {
+                            return Err(Failure::Other(
+                                "vectors of objects are not supported by the mock ledger".to_string(),
+                            ));
+                        }
+                    }
+                }
+                let element_type =
+                    element_type.ok_or_else(|| Failure::Other("missing type of the vector elements".to_string()))?;
+
+                Ok(vec![Value::Move {
+                    bcs,
+                    type_: format!("vector<{element_type}>"),
+                }])
+            }
+            _ => Err(Failure::Other(
+                "command is not supported by the mock ledger".to_string(),
+            )),
+        }
+    }
+
+    /// Commits `session` and returns the [`Outcome`] of the transaction.
+    #[allow(clippy::too_many_arguments)]
+    fn commit(
+        &mut self,
+        mut session: Session<P>,
+        digest: String,
+        sender: Address,
+        status: Result<(), String>,
+        storage_cost: u64,
+        storage_rebate: u64,
+        results: Vec<Vec<(Vec<u8>, String)>>,
+        gas: Option<(ObjectId, u64)>,
+    ) -> Outcome {
+        let packages = &self.packages;
+        let now_ms = self.clock_ms;
+
+        for id in session.written.iter().chain(&session.created) {
+            if let Some(object) = session.objects.get_mut(id) {
+                object.version = self.lamport;
+                // Objects shared by this transaction start out as `Shared(0)`.
+                if matches!(object.owner, Owner::Shared(0)) {
+                    object.owner = Owner::Shared(self.lamport);
+                }
+                object.previous_transaction = digest.clone();
+                object.storage_rebate = object.storage_cost(packages, now_ms);
+            }
+        }
+
+        let describe = |object: &Object<P::Object>| (object.object_ref(), object.owner, object.type_(packages));
+        let created = session
+            .created
+            .iter()
+            .filter_map(|id| session.objects.get(id))
+            .map(describe)
+            .collect();
+        let mutated = session
+            .written
+            .iter()
+            .filter(|id| gas.is_none_or(|(coin, _)| **id != coin))
+            .filter_map(|id| session.objects.get(id))
+            .map(describe)
+            .collect();
+        let deleted = session
+            .deleted
+            .iter()
+            .filter_map(|id| self.objects.get(id))
+            .map(|object| {
+                let mut deleted = object.object_ref();
+                deleted.version = self.lamport;
+                (deleted, object.type_(packages))
+            })
+            .collect();
+        let shared_objects = session
+            .objects
+            .values()
+            .filter(|object| matches!(object.owner, Owner::Shared(_)) && !session.created.contains(&object.id))
+            .filter(|object| session.written.contains(&object.id) || object.id == CLOCK_OBJECT_ID)
+            .map(Object::object_ref)
+            .collect();
+        let gas_object = gas
+            .and_then(|(coin, _)| session.objects.get(&coin))
+            .map(|object| (object.object_ref(), object.owner));
+
+        let events: Vec<Event> = session
+            .events
+            .drain(..)
+            .enumerate()
+            .map(|(seq, event)| Event {
+                tx_digest: digest.clone(),
+                seq: seq as u64,
+                package_id: event.package_id,
+                module: event.module,
+                sender,
+                type_: event.type_,
+                json: event.json,
+                bcs: event.bcs,
+                timestamp_ms: now_ms,
+            })
+            .collect();
+
+        self.objects = session.objects;
+        self.next_object = session.next_object;
+        self.events.extend(events.iter().cloned());
+        self.derive_dynamic_fields();
+
+        Outcome {
+            digest,
+            sender,
+            status,
+            computation_cost: COMPUTATION_COST,
+            storage_cost,
+            storage_rebate,
+            created,
+            mutated,
+            deleted,
+            shared_objects,
+            gas_object,
+            events,
+            results,
+            timestamp_ms: now_ms,
+        }
+    }
+
+    /// Replaces the stored dynamic fields by the ones the objects of the simulated packages currently hold.
+    ///
+    /// A field has the version and previous transaction of the object holding it.
+    fn derive_dynamic_fields(&mut self) {
+        self.objects
+            .retain(|_, object| !matches!(object.contents, Contents::DynamicField { .. }));
+
+        let fields: Vec<Object<P::Object>> = self
+            .objects
+            .values()
+            .filter_map(|object| match &object.contents {
+                Contents::Move(move_object) => Some((object, move_object)),
+                _ => None,
+            })
+            .flat_map(|(object, move_object)| {
+                self.packages
+                    .dynamic_fields(object.id, move_object)
+                    .into_iter()
+                    .map(move |field| {
+                        let id = dynamic_field_id(field.parent, &field.name_type, &field.name);
+                        let type_ = format!("0x2::dynamic_field::Field<{}, {}>", field.name_type, field.value_type);
+                        let bcs = [
+                            bcs::to_bytes(&id).expect("IDs are serializable"),
+                            field.name,
+                            field.value,
+                        ]
+                        .concat();
+
+                        Object {
+                            id,
+                            version: object.version,
+                            owner: Owner::Object(field.parent),
+                            previous_transaction: object.previous_transaction.clone(),
+                            storage_rebate: 0,
+                            contents: Contents::DynamicField { type_, bcs },
+                        }
+                    })
+            })
+            .collect();
+
+        self.objects.extend(fields.into_iter().map(|field| (field.id, field)));
+    }
+}
+
+/// Derives the ID of the dynamic field of `parent` whose name of type `name_type` has the BCS `name`, like
+/// `0x2::dynamic_field::hash_type_and_key` does on-chain.
+pub fn dynamic_field_id(parent: ObjectId, name_type: &TypeTag, name: &[u8]) -> ObjectId {
+    let hash = Blake2b::<U32>::new()
+        .chain_update([CHILD_OBJECT_ID_SCOPE])
+        .chain_update(bcs::to_bytes(&parent).expect("IDs are serializable"))
+        .chain_update((name.len() as u64).to_le_bytes())
+        .chain_update(name)
+        .chain_update(bcs::to_bytes(name_type).expect("type tags are serializable"))
+        .finalize();
+    let mut id = [0; 32];
+    id.copy_from_slice(&hash);
+
+    ObjectId::new(id)
+}
+
+/// Derives a deterministic object ID from `domain` and `counter`.
+fn object_id(domain: &[u8], counter: u64) -> ObjectId {
+    let hash = Sha256::new()
+        .chain_update(domain)
+        .chain_update(counter.to_le_bytes())
+        .finalize();
+    let mut id = [0; 32];
+    id.copy_from_slice(&hash);
+
+    ObjectId::new(id)
+}
+
+/// Returns the Base58-encoded SHA-256 digest of `parts`.
+fn digest_of(parts: &[&[u8]]) -> String {
+    let hash = parts
+        .iter()
+        .fold(Sha256::new(), |hasher, part| hasher.chain_update(part))
+        .finalize();
+
+    base58(&hash)
+}
+
+// BCS wire format of transactions. Only programmable transactions are supported; unread fields are decoded to
+// consume the wire format.
+
+#[derive(Deserialize)]
+enum TransactionData {
+    V1(TransactionDataV1),
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct TransactionDataV1 {
+    kind: TransactionKind,
+    sender: Address,
+    gas_data: GasData,
+    expiration: TransactionExpiration,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct GasData {
+    payment: Vec<(ObjectId, u64, Vec<u8>)>,
+    owner: Address,
+    price: u64,
+    budget: u64,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+enum TransactionExpiration {
+    None,
+    Epoch(u64),
+}
+
+#[derive(Deserialize)]
+enum TransactionKind {
+    ProgrammableTransaction(ProgrammableTransaction),
+}
+
+#[derive(Default, Deserialize)]
+struct ProgrammableTransaction {
+    inputs: Vec<CallArg>,
+    commands: Vec<Command>,
+}
+
+#[derive(Deserialize)]
+enum CallArg {
+    Pure(Vec<u8>),
+    Object(ObjectArg),
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+enum ObjectArg {
+    ImmOrOwnedObject((ObjectId, u64, Vec<u8>)),
+    SharedObject {
+        id: ObjectId,
+        initial_shared_version: u64,
+        mutable: bool,
+    },
+    Receiving((ObjectId, u64, Vec<u8>)),
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+enum Command {
+    MoveCall(Box<MoveCall>),
+    TransferObjects(Vec<Argument>, Argument),
+    SplitCoins(Argument, Vec<Argument>),
+    MergeCoins(Argument, Vec<Argument>),
+    Publish(Vec<Vec<u8>>, Vec<ObjectId>),
+    MakeMoveVec(Option<TypeTag>, Vec<Argument>),
+    Upgrade(Vec<Vec<u8>>, Vec<ObjectId>, ObjectId, Argument),
+}
+
+#[derive(Deserialize)]
+struct MoveCall {
+    package: ObjectId,
+    module: String,
+    function: String,
+    type_arguments: Vec<TypeTag>,
+    arguments: Vec<Argument>,
+}
+
+#[derive(Debug, Deserialize)]
+enum Argument {
+    GasCoin,
+    Input(u16),
+    Result(u16),
+    NestedResult(u16, u16),
+}
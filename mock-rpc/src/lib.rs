@@ -0,0 +1,135 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # IOTA Mock RPC
+//!
+//! The in-memory mock ledger of the `notarization` and `audit_trails` crates and its JSON-RPC transport.
+//!
+//! ## Overview
+//!
+//! A [`Ledger`] stores objects and events, executes programmable transactions from their BCS wire format and hands
+//! Move calls to the [`Packages`] it simulates; each crate only simulates the functions and objects of its own Move
+//! packages. [`dispatch`] answers the methods of the IOTA node API from a ledger.
+//!
+//! Methods are answered by a [`Handler`], which receives the method name and its positional [`Params`] and returns
+//! the JSON result or an [`RpcError`]. This crate wraps handlers into the JSON-RPC envelope:
+//!
+//! - [`handle_request`] answers a serialized request in-process, without opening a socket.
+//! - [`serve`] answers the requests of an `IotaClient`, which only speaks JSON-RPC over HTTP, on a free port of the
+//!   loopback interface.
+//!
+//! The remaining items are the building blocks shared by the handlers: the encodings of digests and BCS payloads,
+//! and the matching of Move types against the filters of the node API.
+
+mod dispatch;
+mod encoding;
+mod filter;
+mod ledger;
+mod params;
+mod server;
+
+use serde_json::{Value as Json, json};
+
+pub use self::dispatch::dispatch;
+pub use self::encoding::{base58, base64, from_base64, uleb128};
+pub use self::filter::{all_of, any_of, matches_module, matches_type, normalize_type, tagged, type_package};
+pub use self::ledger::{
+    COMPUTATION_COST, Contents, DynamicField, Event, Failure, Ledger, Object, ObjectRef, Outcome, Owner, Packages,
+    REFERENCE_GAS_PRICE, STORAGE_PRICE_PER_BYTE, Session, Value, dynamic_field_id,
+};
+pub use self::params::{DEFAULT_PAGE_SIZE, Params, RpcError, json_u64, parse_address, parse_object_id};
+pub use self::server::serve;
+
+/// Version of the node API reported by [`discover`].
+pub const API_VERSION: &str = "1.0.0";
+
+/// Answers the JSON-RPC method calls of a mock ledger.
+///
+/// Implemented for all closures taking the method name and its parameters.
+pub trait Handler: Send + Sync + 'static {
+    /// Answers the call of `method` with `params`.
+    fn handle(&self, method: &str, params: &Params) -> Result<Json, RpcError>;
+}
+
+impl<F> Handler for F
+where
+    F: Fn(&str, &Params) -> Result<Json, RpcError> + Send + Sync + 'static,
+{
+    fn handle(&self, method: &str, params: &Params) -> Result<Json, RpcError> {
+        self(method, params)
+    }
+}
+
+/// Answers the JSON-RPC request `body` with `handler` and returns the serialized response.
+pub fn handle_request<H>(handler: &H, body: &[u8]) -> String
+where
+    H: Handler + ?Sized,
+{
+    let request: Json = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(e) => return error_response(Json::Null, RpcError::parse_error(e.to_string())),
+    };
+    let id = request.get("id").cloned().unwrap_or(Json::Null);
+    let method = request.get("method").and_then(Json::as_str).unwrap_or_default();
+    let params = match request.get("params") {
+        Some(Json::Array(params)) => params.clone(),
+        _ => Vec::new(),
+    };
+
+    match handler.handle(method, &Params::new(params)) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+        Err(error) => error_response(id, error),
+    }
+}
+
+/// Returns the result of `rpc.discover` for a node supporting `methods`.
+pub fn discover(methods: &[&str]) -> Json {
+    json!({
+        "openrpc": "1.2.6",
+        "info": { "title": "IOTA JSON-RPC (mock ledger)", "version": API_VERSION },
+        "methods": methods.iter().map(|name| json!({ "name": name, "params": [] })).collect::<Vec<_>>(),
+    })
+}
+
+fn error_response(id: Json, RpcError { code, message }: RpcError) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn echo(method: &str, params: &Params) -> Result<Json, RpcError> {
+        match method {
+            "echo" => Ok(params.get(0)),
+            _ => Err(RpcError::method_not_found(method)),
+        }
+    }
+
+    fn respond(body: &str) -> Json {
+        serde_json::from_str(&handle_request(&echo, body.as_bytes())).expect("responses are valid JSON")
+    }
+
+    #[test]
+    fn results_keep_the_request_id() {
+        let response = respond(r#"{ "jsonrpc": "2.0", "id": 7, "method": "echo", "params": ["hello"] }"#);
+
+        assert_eq!(response, json!({ "jsonrpc": "2.0", "id": 7, "result": "hello" }));
+    }
+
+    #[test]
+    fn handler_errors_are_returned_as_json_rpc_errors() {
+        let response = respond(r#"{ "jsonrpc": "2.0", "id": 1, "method": "iota_unknown", "params": [] }"#);
+
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["error"]["code"], -32601);
+    }
+
+    #[test]
+    fn malformed_requests_are_parse_errors() {
+        let response = respond("{ not json");
+
+        assert_eq!(response["id"], Json::Null);
+        assert_eq!(response["error"]["code"], -32700);
+    }
+}
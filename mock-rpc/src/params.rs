@@ -0,0 +1,125 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The positional parameters of JSON-RPC requests and the errors returned for them.
+
+use iota_sdk_types::{Address, ObjectId};
+use serde_json::Value as Json;
+
+use crate::encoding::from_base64;
+
+/// Page size used if a request does not set a limit.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// A JSON-RPC error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    /// The JSON-RPC error code.
+    pub code: i64,
+    /// The human-readable error message.
+    pub message: String,
+}
+
+impl RpcError {
+    /// The request is not valid JSON.
+    pub fn parse_error(message: impl Into<String>) -> Self {
+        Self {
+            code: -32700,
+            message: message.into(),
+        }
+    }
+
+    /// The method is not answered by the mock ledger.
+    pub fn method_not_found(method: &str) -> Self {
+        Self {
+            code: -32601,
+            message: format!("method `{method}` is not supported by the mock ledger"),
+        }
+    }
+
+    /// The parameters of the request are invalid.
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: -32602,
+            message: message.into(),
+        }
+    }
+
+    /// The node rejected the transaction before executing it.
+    pub fn rejected(message: impl Into<String>) -> Self {
+        Self {
+            code: -32002,
+            message: message.into(),
+        }
+    }
+}
+
+/// The positional parameters of a request.
+#[derive(Debug, Clone, Default)]
+pub struct Params(Vec<Json>);
+
+impl Params {
+    /// Wraps the positional parameters `params`.
+    pub fn new(params: Vec<Json>) -> Self {
+        Self(params)
+    }
+
+    /// Returns the parameter `index`, or `null` if it is missing.
+    pub fn get(&self, index: usize) -> Json {
+        self.0.get(index).cloned().unwrap_or(Json::Null)
+    }
+
+    /// Returns the string parameter `index`.
+    pub fn string(&self, index: usize) -> Result<String, RpcError> {
+        self.get(index)
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| RpcError::invalid_params(format!("missing string parameter {index}")))
+    }
+
+    /// Returns the object ID parameter `index`.
+    pub fn object_id(&self, index: usize) -> Result<ObjectId, RpcError> {
+        parse_object_id(&self.get(index))
+    }
+
+    /// Returns the address parameter `index`.
+    pub fn address(&self, index: usize) -> Result<Address, RpcError> {
+        parse_address(&self.get(index))
+    }
+
+    /// Returns the Base64-encoded parameter `index`, decoded.
+    pub fn base64(&self, index: usize) -> Result<Vec<u8>, RpcError> {
+        from_base64(&self.string(index)?).ok_or_else(|| RpcError::invalid_params("invalid Base64"))
+    }
+
+    /// Returns the page size parameter `index`, or [`DEFAULT_PAGE_SIZE`] if it is missing.
+    pub fn limit(&self, index: usize) -> usize {
+        self.get(index)
+            .as_u64()
+            .map_or(DEFAULT_PAGE_SIZE, |limit| limit as usize)
+    }
+}
+
+/// Parses an object ID given as a hex string.
+pub fn parse_object_id(value: &Json) -> Result<ObjectId, RpcError> {
+    value
+        .as_str()
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| RpcError::invalid_params(format!("invalid object ID {value}")))
+}
+
+/// Parses an address given as a hex string.
+pub fn parse_address(value: &Json) -> Result<Address, RpcError> {
+    value
+        .as_str()
+        .and_then(|address| address.parse().ok())
+        .ok_or_else(|| RpcError::invalid_params(format!("invalid address {value}")))
+}
+
+/// Reads a `u64` given as a number or, like the node API renders them, as a decimal string.
+pub fn json_u64(value: &Json) -> u64 {
+    match value {
+        Json::String(value) => value.parse().unwrap_or_default(),
+        value => value.as_u64().unwrap_or_default(),
+    }
+}
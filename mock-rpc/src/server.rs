@@ -0,0 +1,81 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal HTTP/1.1 server answering the JSON-RPC requests of an `IotaClient` with a [`Handler`].
+//!
+//! Only what the JSON-RPC client of the IOTA SDK sends is supported: `POST` requests with a `Content-Length` body,
+//! on keep-alive connections.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::{Handler, handle_request};
+
+/// Serves the requests answered by `handler` on a free port of the loopback interface and returns its address.
+///
+/// The server runs on the current Tokio runtime until the runtime shuts down.
+pub async fn serve<H: Handler>(handler: H) -> std::io::Result<SocketAddr> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+    let address = listener.local_addr()?;
+    tokio::spawn(accept(listener, Arc::new(handler)));
+
+    Ok(address)
+}
+
+/// Accepts connections on `listener` until the listener fails.
+async fn accept<H: Handler>(listener: TcpListener, handler: Arc<H>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        let handler = handler.clone();
+        tokio::spawn(async move {
+            // A connection error only ends the connection, the client reconnects.
+            let _ = serve_connection(stream, handler.as_ref()).await;
+        });
+    }
+}
+
+/// Answers the requests of a single connection until it is closed.
+async fn serve_connection<H: Handler>(stream: TcpStream, handler: &H) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(());
+        }
+
+        let mut content_length = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).await? == 0 {
+                return Ok(());
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((_, value)) = header
+                .split_once(':')
+                .filter(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+            {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+
+        let response = handle_request(handler, &body);
+        let head = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+            response.len()
+        );
+        writer.write_all(head.as_bytes()).await?;
+        writer.write_all(response.as_bytes()).await?;
+        writer.flush().await?;
+    }
+}
@@ -4,11 +4,12 @@
 /// Public entry surface for Dynamic-Notarizations: `Notarization<D>` objects
 /// configured with the `Dynamic` Notarization Method, whose `state` and
 /// `updatable_metadata` can be updated after creation and which may
-/// optionally carry a transfer lock.
+/// optionally carry a transfer lock. A Dynamic-Notarization can be sealed into
+/// a Locked-Notarization via `seal`.
 module iota_notarization::dynamic_notarization;
 
 use iota::{clock::Clock, event};
-use iota_notarization::{notarization, timelock::TimeLock};
+use iota_notarization::{lock_at::LockAt, notarization, timelock::{Self, TimeLock}};
 use std::string::String;
 
 // ===== Constants =====
@@ -31,6 +32,15 @@ public struct DynamicNotarizationTransferred has copy, drop {
     recipient: address,
 }
 
+/// Emitted by `seal` after a Dynamic-Notarization is converted into a
+/// Locked-Notarization.
+public struct NotarizationSealed has copy, drop {
+    /// Id of the sealed `Notarization` object.
+    notarization_id: ID,
+    /// Value of `state_version_count` of the sealed `state`.
+    state_version_count: u64,
+}
+
 /// Creates a new Dynamic-Notarization `Notarization<D>` without transferring
 /// it.
 ///
@@ -59,6 +69,117 @@ public fun new<D: store + drop + copy>(
     )
 }
 
+/// Creates a new Dynamic-Notarization `Notarization<D>` carrying a delete
+/// lock without transferring it.
+///
+/// The `delete_lock` acts as a retention lock: the notarization stays
+/// updatable but cannot be destroyed while the lock is active.
+///
+/// Delegates to `notarization::new_dynamic_notarization_with_locks`; see
+/// that function for the full contract.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_locks`.
+///
+/// Returns the constructed `Notarization<D>`.
+public fun new_with_delete_lock<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): notarization::Notarization<D> {
+    notarization::new_dynamic_notarization_with_locks(
+        state,
+        immutable_description,
+        updatable_metadata,
+        timelock::none(),
+        delete_lock,
+        transfer_lock,
+        clock,
+        ctx,
+    )
+}
+
+/// Creates a new Dynamic-Notarization `Notarization<D>` carrying an update
+/// lock and a delete lock without transferring it.
+///
+/// The `update_lock` restricts the window in which `state` and
+/// `updatable_metadata` can be updated: `timelock::unlock_at` embargoes
+/// updates until a time. Use `new_with_lock_at` to freeze them from a time
+/// on instead. See `new_with_delete_lock` for the semantics of `delete_lock`.
+///
+/// Delegates to `notarization::new_dynamic_notarization_with_locks`; see
+/// that function for the full contract.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_locks`.
+///
+/// Returns the constructed `Notarization<D>`.
+public fun new_with_locks<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): notarization::Notarization<D> {
+    notarization::new_dynamic_notarization_with_locks(
+        state,
+        immutable_description,
+        updatable_metadata,
+        update_lock,
+        delete_lock,
+        transfer_lock,
+        clock,
+        ctx,
+    )
+}
+
+/// Creates a new Dynamic-Notarization `Notarization<D>` whose `state` and
+/// `updatable_metadata` are frozen from the lock time of `update_lock_at` on,
+/// without transferring it.
+///
+/// Until then the notarization can be updated like any other
+/// Dynamic-Notarization. See `new_with_delete_lock` for the semantics of
+/// `delete_lock`.
+///
+/// Delegates to `notarization::new_dynamic_notarization_with_lock_at`; see
+/// that function for the full contract.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_lock_at`.
+///
+/// Returns the constructed `Notarization<D>`.
+public fun new_with_lock_at<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock_at: LockAt,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): notarization::Notarization<D> {
+    notarization::new_dynamic_notarization_with_lock_at(
+        state,
+        immutable_description,
+        updatable_metadata,
+        update_lock_at,
+        delete_lock,
+        transfer_lock,
+        clock,
+        ctx,
+    )
+}
+
 /// Creates a new Dynamic-Notarization `Notarization<D>` and transfers it to
 /// the transaction sender.
 ///
@@ -73,17 +194,125 @@ public fun create<D: store + drop + copy>(
     transfer_lock: TimeLock,
     clock: &Clock,
     ctx: &mut TxContext,
+) {
+    create_with_delete_lock(
+        state,
+        immutable_description,
+        updatable_metadata,
+        timelock::none(),
+        transfer_lock,
+        clock,
+        ctx,
+    );
+}
+
+/// Creates a new Dynamic-Notarization `Notarization<D>` carrying a delete
+/// lock and transfers it to the transaction sender.
+///
+/// See `new_with_delete_lock` for the semantics of `delete_lock`.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_locks`.
+///
+/// Emits a `DynamicNotarizationCreated` event on success.
+public fun create_with_delete_lock<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    create_with_locks(
+        state,
+        immutable_description,
+        updatable_metadata,
+        timelock::none(),
+        delete_lock,
+        transfer_lock,
+        clock,
+        ctx,
+    );
+}
+
+/// Creates a new Dynamic-Notarization `Notarization<D>` carrying an update
+/// lock and a delete lock and transfers it to the transaction sender.
+///
+/// See `new_with_locks` for the semantics of the locks.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_locks`.
+///
+/// Emits a `DynamicNotarizationCreated` event on success.
+public fun create_with_locks<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
 ) {
     // Use the core module to create and transfer the notarization
-    let notarization = new(
+    let notarization = new_with_locks(
         state,
         immutable_description,
         updatable_metadata,
+        update_lock,
+        delete_lock,
         transfer_lock,
         clock,
         ctx,
     );
 
+    transfer_to_sender(notarization, ctx);
+}
+
+/// Creates a new Dynamic-Notarization `Notarization<D>` whose `state` and
+/// `updatable_metadata` are frozen from the lock time of `update_lock_at` on,
+/// and transfers it to the transaction sender.
+///
+/// See `new_with_lock_at` for the semantics of the locks.
+///
+/// Aborts with:
+/// * any error documented by
+///   `notarization::new_dynamic_notarization_with_lock_at`.
+///
+/// Emits a `DynamicNotarizationCreated` event on success.
+public fun create_with_lock_at<D: store + drop + copy>(
+    state: notarization::State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock_at: LockAt,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    let notarization = new_with_lock_at(
+        state,
+        immutable_description,
+        updatable_metadata,
+        update_lock_at,
+        delete_lock,
+        transfer_lock,
+        clock,
+        ctx,
+    );
+
+    transfer_to_sender(notarization, ctx);
+}
+
+/// Emits a `DynamicNotarizationCreated` event for the newly created
+/// `notarization` and transfers it to the transaction sender.
+fun transfer_to_sender<D: store + drop + copy>(
+    notarization: notarization::Notarization<D>,
+    ctx: &TxContext,
+) {
     let id = object::uid_to_inner(notarization.id());
     event::emit(DynamicNotarizationCreated { notarization_id: id });
 
@@ -109,15 +338,41 @@ public fun transfer<D: store + drop + copy>(
     // Ensure this notarization is transferrable
     assert!(is_transferable(&self, clock), ECannotTransferLocked);
 
+    let id = object::uid_to_inner(self.id());
     notarization::transfer_notarization(self, recipient);
 
-    let id = object::id_from_address(recipient);
     event::emit(DynamicNotarizationTransferred {
         notarization_id: id,
         recipient,
     });
 }
 
+/// Seals `self`, converting it into a Locked-Notarization.
+///
+/// The object id, `state`, `updatable_metadata`, `created_at` and
+/// `state_version_count` are preserved; afterwards `self` can neither be
+/// updated nor transferred, and its destruction is gated by `delete_lock`.
+///
+/// Delegates to `notarization::seal_notarization`; see that function for the
+/// full contract.
+///
+/// Aborts with:
+/// * any error documented by `notarization::seal_notarization`.
+///
+/// Emits a `NotarizationSealed` event on success.
+public fun seal<D: store + drop + copy>(
+    self: &mut notarization::Notarization<D>,
+    delete_lock: TimeLock,
+    clock: &Clock,
+) {
+    notarization::seal_notarization(self, delete_lock, clock);
+
+    event::emit(NotarizationSealed {
+        notarization_id: object::uid_to_inner(self.id()),
+        state_version_count: self.version_count(),
+    });
+}
+
 /// Checks whether `self` may currently be transferred.
 ///
 /// Returns `true` when `self` has no `LockMetadata` or when its
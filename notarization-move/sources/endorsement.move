@@ -0,0 +1,140 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Endorsements of notarizations: evidence that an address endorsed a
+/// specific version of the `state` of a `Notarization<D>`.
+///
+/// The endorser of an `Endorsement` is always the sender of the transaction
+/// that created it. The endorsed version is passed by its
+/// `state_version_count` and the hash of its `state`, so any address can
+/// endorse any notarization, including owned ones, without access to the
+/// object. The package cannot check these values on-chain; readers verify
+/// them against the notarization, e.g. by comparing them with its current
+/// `state_version_count` and `hash_state`.
+///
+/// Endorsements are immutable and are transferred to the address of the
+/// endorsed notarization, so all endorsements of a notarization can be listed
+/// by querying the objects owned by it. Destroying the notarization does not
+/// destroy its endorsements: they stay listed under its address, but can
+/// neither be received nor deleted anymore.
+module iota_notarization::endorsement;
+
+use iota::{clock::Clock, event};
+use iota_notarization::notarization::Notarization;
+use std::{bcs, hash};
+
+// ===== Errors =====
+/// Raised when the endorsed state hash is not a SHA2-256 hash.
+const EInvalidStateHash: u64 = 0;
+
+// ===== Constants =====
+/// Length of a SHA2-256 hash in bytes.
+const STATE_HASH_LENGTH: u64 = 32;
+
+/// An endorsement of version `state_version_count` of the `state` of the
+/// notarization `notarization_id` by `endorser`.
+public struct Endorsement has key {
+    id: UID,
+    /// Id of the endorsed `Notarization` object.
+    notarization_id: ID,
+    /// Address that endorsed the notarization.
+    endorser: address,
+    /// `state_version_count` of the endorsed version of the `state`.
+    state_version_count: u64,
+    /// SHA2-256 hash of the BCS of the endorsed `state`.
+    state_hash: vector<u8>,
+    /// Timestamp of the endorsement, in milliseconds since the Unix epoch.
+    endorsed_at: u64,
+}
+
+/// Emitted by `endorse` after an `Endorsement` was created.
+public struct NotarizationEndorsed has copy, drop {
+    /// Id of the created `Endorsement` object.
+    endorsement_id: ID,
+    /// Id of the endorsed `Notarization` object.
+    notarization_id: ID,
+    /// Address that endorsed the notarization.
+    endorser: address,
+    /// `state_version_count` of the endorsed version of the `state`.
+    state_version_count: u64,
+    /// SHA2-256 hash of the BCS of the endorsed `state`.
+    state_hash: vector<u8>,
+    /// Timestamp of the endorsement, in milliseconds since the Unix epoch.
+    endorsed_at: u64,
+}
+
+/// Endorses version `state_version_count` of the `state` of the notarization
+/// `notarization_id`, whose hash is `state_hash`, on behalf of the
+/// transaction sender.
+///
+/// The notarization is not accessed, so it may be owned by another address.
+/// Neither its existence nor the endorsed version are checked; see the module
+/// documentation. The `Endorsement` is transferred to the address of the
+/// notarization.
+///
+/// Aborts with:
+/// * `EInvalidStateHash` when `state_hash` is not 32 bytes long.
+///
+/// Emits a `NotarizationEndorsed` event on success.
+public fun endorse(
+    notarization_id: ID,
+    state_version_count: u64,
+    state_hash: vector<u8>,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    assert!(state_hash.length() == STATE_HASH_LENGTH, EInvalidStateHash);
+
+    let endorsement = Endorsement {
+        id: object::new(ctx),
+        notarization_id,
+        endorser: tx_context::sender(ctx),
+        state_version_count,
+        state_hash,
+        endorsed_at: clock.timestamp_ms(),
+    };
+
+    event::emit(NotarizationEndorsed {
+        endorsement_id: object::id(&endorsement),
+        notarization_id,
+        endorser: endorsement.endorser,
+        state_version_count,
+        state_hash,
+        endorsed_at: endorsement.endorsed_at,
+    });
+
+    transfer::transfer(endorsement, notarization_id.to_address());
+}
+
+/// Returns the SHA2-256 hash of the BCS of the current `state` of
+/// `notarization`, as recorded by endorsements of that `state`.
+public fun hash_state<D: store + drop + copy>(notarization: &Notarization<D>): vector<u8> {
+    hash::sha2_256(bcs::to_bytes(notarization.state()))
+}
+
+// ===== Getter Functions =====
+/// Returns the id of the endorsed `Notarization` object.
+public fun notarization_id(self: &Endorsement): ID {
+    self.notarization_id
+}
+
+/// Returns the address that endorsed the notarization.
+public fun endorser(self: &Endorsement): address {
+    self.endorser
+}
+
+/// Returns the `state_version_count` of the endorsed version of the `state`.
+public fun state_version_count(self: &Endorsement): u64 {
+    self.state_version_count
+}
+
+/// Returns the SHA2-256 hash of the BCS of the endorsed `state`.
+public fun state_hash(self: &Endorsement): vector<u8> {
+    self.state_hash
+}
+
+/// Returns the timestamp of the endorsement, in milliseconds since the Unix
+/// epoch.
+public fun endorsed_at(self: &Endorsement): u64 {
+    self.endorsed_at
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// # Lock-At Module
+///
+/// This module implements an update lock that becomes locked at a specific
+/// Unix timestamp and stays locked until the notarization is destroyed.
+///
+/// `LockAt` is not a variant of `timelock::TimeLock`, as variants cannot be
+/// added to the enum of a published package. A Dynamic-Notarization
+/// carrying a `LockAt` stores it as a dynamic field of its object id under
+/// the `UPDATE_LOCK_AT_KEY` name; the `update_lock` of its `LockMetadata` is
+/// set to `TimeLock::UntilDestroyed`, so that package versions unaware of
+/// `LockAt` reject updates instead of ignoring the lock.
+module iota_notarization::lock_at;
+
+use iota::{clock::{Self, Clock}, dynamic_field};
+use std::string::String;
+
+// ===== Errors =====
+/// Error when attempting to create a `LockAt` with a timestamp in the past
+const EPastTimestamp: u64 = 0;
+
+// ===== Constants =====
+/// Name of the dynamic field storing the `LockAt` of a notarization.
+const UPDATE_LOCK_AT_KEY: vector<u8> = b"update_lock_at";
+
+/// A lock that becomes locked at a specific Unix timestamp (seconds since
+/// epoch) and stays locked until the notarization object is destroyed.
+public struct LockAt has copy, drop, store {
+    lock_time: u32,
+}
+
+/// Creates a new `LockAt` that becomes locked at a specific Unix timestamp.
+public fun new(unix_time: u32, clock: &Clock): LockAt {
+    let now = (clock::timestamp_ms(clock) / 1000) as u32;
+
+    assert!(unix_time > now, EPastTimestamp);
+
+    LockAt { lock_time: unix_time }
+}
+
+/// Gets the Unix timestamp from which on `self` is locked.
+public fun lock_time(self: &LockAt): u32 {
+    self.lock_time
+}
+
+/// Checks if `self` is currently locked, i.e. whether the current time has
+/// reached its lock time.
+public fun is_locked(self: &LockAt, clock: &Clock): bool {
+    !(self.lock_time > ((clock::timestamp_ms(clock) / 1000) as u32))
+}
+
+/// Attaches `lock` to the object `id`.
+public(package) fun attach(id: &mut UID, lock: LockAt) {
+    dynamic_field::add(id, key(), lock);
+}
+
+/// Checks if a `LockAt` is attached to the object `id`.
+public(package) fun is_attached(id: &UID): bool {
+    dynamic_field::exists_with_type<String, LockAt>(id, key())
+}
+
+/// Returns the `LockAt` attached to the object `id`.
+public(package) fun borrow(id: &UID): &LockAt {
+    dynamic_field::borrow(id, key())
+}
+
+/// Detaches the `LockAt` of the object `id`, if any.
+public(package) fun detach(id: &mut UID) {
+    if (is_attached(id)) {
+        let _: LockAt = dynamic_field::remove(id, key());
+    }
+}
+
+fun key(): String {
+    UPDATE_LOCK_AT_KEY.to_string()
+}
@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 /// Implements the core `Notarization<D>` object and the shared state, metadata
-/// and locking primitives reused by the `dynamic_notarization` and
-/// `locked_notarization` wrapper modules.
+/// and locking primitives reused by the `dynamic_notarization`,
+/// `locked_notarization` and `shared_notarization` wrapper modules.
 #[allow(lint(self_transfer))]
 module iota_notarization::notarization;
 
-use iota::{clock::{Self, Clock}, event};
+use iota::{clock::{Self, Clock}, dynamic_field, event};
 use iota_notarization::{
+    lock_at::{Self, LockAt},
     method::{NotarizationMethod, new_dynamic, new_locked},
     timelock::{Self, TimeLock}
 };
@@ -32,6 +33,27 @@ const EDynamicNotarizationInvariants: u64 = 4;
 /// Notarization Method violates the invariants of that method (see
 /// `are_locked_notarization_invariants_ok`).
 const ELockedNotarizationInvariants: u64 = 5;
+/// Raised when `seal_notarization` is called on a notarization that does not
+/// use the `Dynamic` Notarization Method.
+const ENotDynamicNotarization: u64 = 6;
+/// Raised when `seal_notarization` would allow destroying the notarization
+/// earlier than its current locks do.
+const ESealShortensRetention: u64 = 7;
+/// Raised when `update_state`, `update_metadata` or `destroy` is called on a
+/// Shared-Notarization; such notarizations are only modifiable through the
+/// capability-gated functions of `shared_notarization`.
+const ECapabilityRequired: u64 = 8;
+/// Raised when the `LockMetadata` of a Shared-Notarization violates the
+/// invariants of Shared-Notarizations (see
+/// `are_shared_notarization_invariants_ok`).
+const ESharedNotarizationInvariants: u64 = 9;
+/// Raised when `seal_shared_notarization` is called on a notarization that is
+/// not a Shared-Notarization.
+const ENotSharedNotarization: u64 = 10;
+
+/// Name of the dynamic field storing the `LockMetadata` of a
+/// Shared-Notarization.
+const SHARED_LOCK_METADATA_KEY: vector<u8> = b"shared_lock_metadata";
 
 // ===== Core Type =====
 /// On-chain notarization object. Stores user-defined data together with
@@ -45,6 +67,8 @@ public struct Notarization<D: store + drop + copy> has key {
     /// on the Notarization Method:
     /// * `Dynamic`: updatable after creation via `update_state`.
     /// * `Locked`: immutable after creation.
+    /// * Shared-Notarizations: updatable after creation via
+    ///   `shared_notarization::update_state`.
     state: State<D>,
     /// Provenance fixed at creation time (creation timestamp, description,
     /// optional `LockMetadata`).
@@ -53,6 +77,8 @@ public struct Notarization<D: store + drop + copy> has key {
     /// * `Dynamic`: updatable after creation via `update_metadata`; updates
     ///   do not bump `state_version_count` nor `last_state_change_at`.
     /// * `Locked`: immutable after creation.
+    /// * Shared-Notarizations: updatable after creation via
+    ///   `shared_notarization::update_metadata`.
     ///
     /// NOTE:
     /// - `updatable_metadata` can be updated independently of `state`
@@ -66,7 +92,8 @@ public struct Notarization<D: store + drop + copy> has key {
     /// Number of times `state` has been updated since creation.
     state_version_count: u64,
     /// Notarization Method governing the mutation and destruction rules of
-    /// this `Notarization`.
+    /// this `Notarization`. Shared-Notarizations use the `Locked`
+    /// Notarization Method (see `new_shared_notarization`).
     method: NotarizationMethod,
 }
 
@@ -78,8 +105,8 @@ public struct ImmutableMetadata has store {
     /// Human-readable description of the `Notarization`.
     description: Option<String>,
     /// Optional lock metadata. Presence depends on the Notarization Method:
-    /// * `Dynamic`: absent when the Dynamic-Notarization carries no transfer
-    ///   lock; present otherwise.
+    /// * `Dynamic`: absent when the Dynamic-Notarization carries neither a
+    ///   transfer lock nor a delete lock; present otherwise.
     /// * `Locked`: always present.
     locking: Option<LockMetadata>,
 }
@@ -87,9 +114,17 @@ public struct ImmutableMetadata has store {
 /// Bundle of three `TimeLock`s controlling whether a `Notarization` can be
 /// updated, destroyed, or transferred.
 public struct LockMetadata has store {
-    /// Lock guarding `update_state` and `update_metadata`.
+    /// Lock guarding `update_state` and `update_metadata`. Its role depends on
+    /// the Notarization Method:
+    /// * `Dynamic`: an optional update window; `TimeLock::UnlockAt` embargoes
+    ///   updates until a time. A Dynamic-Notarization freezing its state from
+    ///   a time on carries `TimeLock::UntilDestroyed` here and its
+    ///   `lock_at::LockAt` as a dynamic field (see the `lock_at` module).
+    /// * `Locked`: pinned to `TimeLock::UntilDestroyed`.
     update_lock: TimeLock,
     /// Lock guarding `destroy`. Must not be `TimeLock::UntilDestroyed`.
+    /// Used by both Notarization Methods; for `Dynamic` it acts as a
+    /// retention lock that still permits updates.
     delete_lock: TimeLock,
     /// Lock guarding transfer. Its role depends on the Notarization Method:
     /// * `Dynamic`: gates `dynamic_notarization::transfer`.
@@ -127,6 +162,14 @@ public struct NotarizationUpdated<D: store + drop + copy> has copy, drop {
     updated_state: State<D>,
 }
 
+/// Emitted by `update_metadata` after the `updatable_metadata` was replaced.
+public struct NotarizationMetadataUpdated has copy, drop {
+    /// Id of the updated `Notarization` object.
+    notarization_id: ID,
+    /// The new `updatable_metadata`.
+    updated_metadata: Option<String>,
+}
+
 /// Emitted by `destroy` after a successful destruction.
 public struct NotarizationDestroyed has copy, drop {
     /// Id of the destroyed `Notarization` object.
@@ -160,8 +203,8 @@ public fun new_state_from_generic<D: store + drop + copy>(
 /// `UnlockAt` `update_lock` or `transfer_lock`.
 ///
 /// In the current implementation the legal combinations are further narrowed
-/// by the method-specific invariants enforced in `new_dynamic_notarization`
-/// and `new_locked_notarization`; edge cases where `delete_lock` is
+/// by the method-specific invariants enforced in `new_dynamic_notarization`,
+/// `new_locked_notarization` and `new_shared_notarization`; edge cases where `delete_lock` is
 /// `TimeLock::None` while other locks are `UnlockAt` are therefore not
 /// reachable here.
 ///
@@ -238,35 +281,72 @@ public(package) fun new_immutable_metadata(
 }
 
 // ===== Notarization Creation Functions =====
+/// Creates a new `Notarization<D>` using the `Dynamic` Notarization Method
+/// without an update or delete lock.
+///
+/// Equivalent to `new_dynamic_notarization_with_locks` with
+/// `update_lock = delete_lock = TimeLock::None`; see that function for the
+/// full contract.
+public(package) fun new_dynamic_notarization<D: store + drop + copy>(
+    state: State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): Notarization<D> {
+    new_dynamic_notarization_with_locks(
+        state,
+        immutable_description,
+        updatable_metadata,
+        timelock::none(),
+        timelock::none(),
+        transfer_lock,
+        clock,
+        ctx,
+    )
+}
+
 /// Creates a new `Notarization<D>` using the `Dynamic` Notarization Method.
 ///
-/// When `transfer_lock` is `TimeLock::None`, the resulting object has no
-/// `LockMetadata` and is freely transferable. Otherwise a `LockMetadata` is
-/// built with `update_lock = delete_lock = TimeLock::None` and the supplied
-/// `transfer_lock`. `state_version_count` starts at `0` and
-/// `last_state_change_at` is set to the current clock timestamp.
+/// When `update_lock`, `delete_lock` and `transfer_lock` are all
+/// `TimeLock::None`, the resulting object has no `LockMetadata` and is
+/// freely updatable, transferable and destroyable. Otherwise a
+/// `LockMetadata` is built from the supplied locks: `update_lock` restricts
+/// the window in which the object is updatable, while `delete_lock` and
+/// `transfer_lock` restrict its destruction and transfer.
+/// `state_version_count` starts at `0` and `last_state_change_at` is set to
+/// the current clock timestamp.
 ///
 /// Aborts with:
-/// * any error documented by `new_lock_metadata` when `transfer_lock` is not
+/// * any error documented by `new_lock_metadata` when any lock is not
 ///   `TimeLock::None`.
 /// * `EDynamicNotarizationInvariants` when the resulting `ImmutableMetadata`
 ///   violates the invariants of the `Dynamic` Notarization Method (see
 ///   `are_dynamic_notarization_invariants_ok`).
 ///
 /// Returns the constructed `Notarization<D>`.
-public(package) fun new_dynamic_notarization<D: store + drop + copy>(
+public(package) fun new_dynamic_notarization_with_locks<D: store + drop + copy>(
     state: State<D>,
     immutable_description: Option<String>,
     updatable_metadata: Option<String>,
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
     transfer_lock: TimeLock,
     clock: &Clock,
     ctx: &mut TxContext,
 ): Notarization<D> {
-    let locking = if (timelock::is_none(&transfer_lock)) {
+    let locking = if (
+        timelock::is_none(&update_lock) &&
+        timelock::is_none(&delete_lock) &&
+        timelock::is_none(&transfer_lock)
+    ) {
+        timelock::destroy(update_lock, clock);
+        timelock::destroy(delete_lock, clock);
         timelock::destroy(transfer_lock, clock);
         option::none()
     } else {
-        option::some(new_lock_metadata(timelock::none(), timelock::none(), transfer_lock))
+        option::some(new_lock_metadata(update_lock, delete_lock, transfer_lock))
     };
 
     let immutable_metadata = ImmutableMetadata {
@@ -275,7 +355,7 @@ public(package) fun new_dynamic_notarization<D: store + drop + copy>(
         locking,
     };
     assert!(
-        are_dynamic_notarization_invariants_ok(&immutable_metadata),
+        are_dynamic_notarization_invariants_ok(&immutable_metadata, false),
         EDynamicNotarizationInvariants,
     );
 
@@ -290,6 +370,58 @@ public(package) fun new_dynamic_notarization<D: store + drop + copy>(
     }
 }
 
+/// Creates a new `Notarization<D>` using the `Dynamic` Notarization Method
+/// whose `state` and `updatable_metadata` are frozen from the lock time of
+/// `update_lock_at` on.
+///
+/// The `update_lock` of the resulting `LockMetadata` is
+/// `TimeLock::UntilDestroyed` and `update_lock_at` is attached to the object
+/// id (see the `lock_at` module). `delete_lock` and `transfer_lock` have the
+/// same semantics as for `new_dynamic_notarization_with_locks`.
+///
+/// Aborts with:
+/// * any error documented by `new_lock_metadata`.
+/// * `EDynamicNotarizationInvariants` when the resulting `ImmutableMetadata`
+///   violates the invariants of the `Dynamic` Notarization Method (see
+///   `are_dynamic_notarization_invariants_ok`).
+///
+/// Returns the constructed `Notarization<D>`.
+public(package) fun new_dynamic_notarization_with_lock_at<D: store + drop + copy>(
+    state: State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock_at: LockAt,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): Notarization<D> {
+    let immutable_metadata = ImmutableMetadata {
+        created_at: clock::timestamp_ms(clock),
+        description: immutable_description,
+        locking: option::some(
+            new_lock_metadata(timelock::until_destroyed(), delete_lock, transfer_lock),
+        ),
+    };
+    assert!(
+        are_dynamic_notarization_invariants_ok(&immutable_metadata, true),
+        EDynamicNotarizationInvariants,
+    );
+
+    let mut id = object::new(ctx);
+    lock_at::attach(&mut id, update_lock_at);
+
+    Notarization<D> {
+        id,
+        state,
+        immutable_metadata,
+        updatable_metadata,
+        last_state_change_at: clock::timestamp_ms(clock),
+        state_version_count: 0,
+        method: new_dynamic(),
+    }
+}
+
 /// Creates a new `Notarization<D>` using the `Locked` Notarization Method.
 ///
 /// The resulting object always carries `LockMetadata` with both `update_lock`
@@ -340,6 +472,81 @@ public(package) fun new_locked_notarization<D: store + drop + copy>(
     }
 }
 
+/// Creates a new Shared-Notarization `Notarization<D>`.
+///
+/// The supplied `update_lock` and `delete_lock` form the `LockMetadata` of the
+/// Shared-Notarization, with `transfer_lock` pinned to
+/// `TimeLock::UntilDestroyed`. It is stored as a dynamic field of the object
+/// under the `SHARED_LOCK_METADATA_KEY` name.
+///
+/// The object itself uses the `Locked` Notarization Method, as variants
+/// cannot be added to the `NotarizationMethod` enum of a published package.
+/// Its own `LockMetadata` pins `update_lock` and `transfer_lock` to
+/// `TimeLock::UntilDestroyed` and `delete_lock` to an `UnlockAt` lock that
+/// never expires, so that package versions unaware of Shared-Notarizations
+/// reject updating and destroying the shared object.
+///
+/// `state_version_count` starts at `0` and `last_state_change_at` is set to
+/// the current clock timestamp.
+///
+/// The caller is responsible for sharing the object via `share_notarization`
+/// and for issuing the capabilities authorizing its updates.
+///
+/// A Shared-Notarization cannot be created frozen: `update_lock` must not be
+/// `TimeLock::UntilDestroyed`. Use `seal_shared_notarization` to freeze it
+/// later.
+///
+/// Aborts with:
+/// * any error documented by `new_lock_metadata` for the chosen locks.
+/// * `ESharedNotarizationInvariants` when the resulting `LockMetadata`
+///   violates the invariants of Shared-Notarizations (see
+///   `are_shared_notarization_invariants_ok`) or when `update_lock` is
+///   `TimeLock::UntilDestroyed`.
+///
+/// Returns the constructed `Notarization<D>`.
+public(package) fun new_shared_notarization<D: store + drop + copy>(
+    state: State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+): Notarization<D> {
+    let shared_lock_metadata = new_lock_metadata(
+        update_lock,
+        delete_lock,
+        timelock::until_destroyed(),
+    );
+
+    assert!(
+        are_shared_notarization_invariants_ok(&shared_lock_metadata) &&
+        !timelock::is_until_destroyed(&shared_lock_metadata.update_lock),
+        ESharedNotarizationInvariants,
+    );
+
+    let mut id = object::new(ctx);
+    dynamic_field::add(&mut id, shared_lock_metadata_key(), shared_lock_metadata);
+
+    Notarization<D> {
+        id,
+        state,
+        immutable_metadata: ImmutableMetadata {
+            created_at: clock::timestamp_ms(clock),
+            description: immutable_description,
+            locking: option::some(LockMetadata {
+                update_lock: timelock::until_destroyed(),
+                delete_lock: timelock::unlock_at_max(),
+                transfer_lock: timelock::until_destroyed(),
+            }),
+        },
+        updatable_metadata,
+        last_state_change_at: clock::timestamp_ms(clock),
+        state_version_count: 0,
+        method: new_locked(),
+    }
+}
+
 // ===== State Management Functions =====
 /// Replaces the `state` of `self` with `new_state`.
 ///
@@ -347,11 +554,15 @@ public(package) fun new_locked_notarization<D: store + drop + copy>(
 /// the current clock timestamp.
 ///
 /// Behaviour depends on the Notarization Method:
-/// * `Dynamic`: allways permitted - `update_lock` is allways `None`.
+/// * `Dynamic`: permitted unless the optional `update_lock` is currently
+///   active.
 /// * `Locked`: always aborts, because `update_lock` is pinned to
 ///   `TimeLock::UntilDestroyed`.
+/// * Shared-Notarizations: always aborts; use
+///   `shared_notarization::update_state`.
 ///
 /// Aborts with:
+/// * `ECapabilityRequired` when `self` is a Shared-Notarization.
 /// * `EUpdateWhileLocked` when `is_update_locked` is `true`.
 ///
 /// Emits a `NotarizationUpdated` event on success.
@@ -359,6 +570,21 @@ public fun update_state<D: store + drop + copy>(
     self: &mut Notarization<D>,
     new_state: State<D>,
     clock: &Clock,
+) {
+    assert!(!self.is_shared(), ECapabilityRequired);
+
+    update_state_internal(self, new_state, clock);
+}
+
+/// Replaces the `state` of `self` with `new_state` without checking whether
+/// `self` is a Shared-Notarization.
+///
+/// Authorization is the responsibility of the calling module; see
+/// `update_state` for the remaining contract.
+public(package) fun update_state_internal<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    new_state: State<D>,
+    clock: &Clock,
 ) {
     assert!(!self.is_update_locked(clock), EUpdateWhileLocked);
 
@@ -379,15 +605,45 @@ public fun update_state<D: store + drop + copy>(
 /// the process; the gating check `is_destroy_allowed` ensures that no
 /// `UnlockAt` lock is still active.
 ///
+/// Shared-Notarizations can only be destroyed via
+/// `shared_notarization::destroy`.
+///
 /// Aborts with:
+/// * `ECapabilityRequired` when `self` is a Shared-Notarization.
 /// * `EDestroyWhileLocked` when `is_destroy_allowed` is `false`.
 /// * `iota_notarization::timelock::ETimelockNotExpired` when any `UnlockAt`
 ///   lock is destroyed before it expires.
 ///
 /// Emits a `NotarizationDestroyed` event on success.
 public fun destroy<D: drop + store + copy>(self: Notarization<D>, clock: &Clock) {
+    assert!(!self.is_shared(), ECapabilityRequired);
+
+    destroy_internal(self, clock);
+}
+
+/// Destroys `self` without checking whether it is a Shared-Notarization.
+///
+/// Authorization is the responsibility of the calling module; see `destroy`
+/// for the remaining contract.
+public(package) fun destroy_internal<D: drop + store + copy>(
+    mut self: Notarization<D>,
+    clock: &Clock,
+) {
     assert!(self.is_destroy_allowed(clock), EDestroyWhileLocked);
 
+    lock_at::detach(&mut self.id);
+    let is_shared = self.is_shared();
+    if (is_shared) {
+        let LockMetadata { update_lock, delete_lock, transfer_lock } = dynamic_field::remove(
+            &mut self.id,
+            shared_lock_metadata_key(),
+        );
+
+        timelock::destroy(update_lock, clock);
+        timelock::destroy(delete_lock, clock);
+        timelock::destroy(transfer_lock, clock);
+    };
+
     let Notarization {
         id,
         state: _,
@@ -407,12 +663,19 @@ public fun destroy<D: drop + store + copy>(self: Notarization<D>, clock: &Clock)
             locking,
         );
 
-        // destroy the locks
-        timelock::destroy(update_lock, clock);
-        timelock::destroy(delete_lock, clock);
-        timelock::destroy(transfer_lock, clock);
+        if (is_shared) {
+            // Only guard the object against older package versions; the
+            // locks of the Shared-Notarization were destroyed above
+            timelock::destroy_superseded(update_lock);
+            timelock::destroy_superseded(delete_lock);
+            timelock::destroy_superseded(transfer_lock);
+        } else {
+            timelock::destroy(update_lock, clock);
+            timelock::destroy(delete_lock, clock);
+            timelock::destroy(transfer_lock, clock);
+        }
     } else {
-        // We know Dynamic-Notarizations have no lock metadata
+        // Only Dynamic-Notarizations can have no lock metadata
         option::destroy_none(locking);
     };
 
@@ -433,6 +696,141 @@ public(package) fun transfer_notarization<D: store + drop + copy>(
     transfer::transfer(self, recipient);
 }
 
+/// Turns `self` into a shared object using the IOTA `share_object` primitive.
+///
+/// This helper exists only so that the `shared_notarization` module can share
+/// the object without having direct access to the private fields of
+/// `Notarization`.
+#[allow(lint(share_owned))]
+public(package) fun share_notarization<D: store + drop + copy>(self: Notarization<D>) {
+    transfer::share_object(self);
+}
+
+/// Converts the Dynamic-Notarization `self` into a Locked-Notarization.
+///
+/// Replaces the `LockMetadata` of `self` with one whose `update_lock` and
+/// `transfer_lock` are `TimeLock::UntilDestroyed` and whose `delete_lock` is
+/// `delete_lock`, and switches the Notarization Method to `Locked`. The object
+/// id, `state`, `updatable_metadata`, `created_at`, `last_state_change_at`
+/// and `state_version_count` are preserved.
+///
+/// Sealing counts as a final update: it is rejected while the `update_lock`
+/// is active. A `lock_at::LockAt` attached to `self` is detached, as it is
+/// superseded by the `TimeLock::UntilDestroyed` `update_lock`. Active `UnlockAt` `delete_lock` and `transfer_lock` locks of
+/// `self` currently prevent its destruction, so the new `delete_lock` must not
+/// unlock before them.
+///
+/// Aborts with:
+/// * `ENotDynamicNotarization` when `self` is not a Dynamic-Notarization.
+/// * `EUpdateWhileLocked` when `is_update_locked` is `true`.
+/// * `ESealShortensRetention` when the new `delete_lock` unlocks before an
+///   active `UnlockAt` `delete_lock` or `transfer_lock` of `self`.
+/// * any error documented by `new_lock_metadata`.
+public(package) fun seal_notarization<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    delete_lock: TimeLock,
+    clock: &Clock,
+) {
+    assert!(self.method.is_dynamic(), ENotDynamicNotarization);
+    assert!(!self.is_update_locked(clock), EUpdateWhileLocked);
+
+    if (self.immutable_metadata.locking.is_some()) {
+        let current_locks = option::borrow(&self.immutable_metadata.locking);
+
+        assert_retained_by(&delete_lock, &current_locks.delete_lock, clock);
+        assert_retained_by(&delete_lock, &current_locks.transfer_lock, clock);
+    };
+
+    let lock_metadata = new_lock_metadata(
+        timelock::until_destroyed(),
+        delete_lock,
+        timelock::until_destroyed(),
+    );
+    let previous_locking = self.immutable_metadata.locking.swap_or_fill(lock_metadata);
+
+    if (previous_locking.is_some()) {
+        // The previous locks are superseded by the at least as restrictive
+        // locks of the Locked-Notarization
+        let LockMetadata {
+            update_lock: previous_update_lock,
+            delete_lock: previous_delete_lock,
+            transfer_lock: previous_transfer_lock,
+        } = option::destroy_some(previous_locking);
+
+        timelock::destroy_superseded(previous_update_lock);
+        timelock::destroy_superseded(previous_delete_lock);
+        timelock::destroy_superseded(previous_transfer_lock);
+    } else {
+        option::destroy_none(previous_locking);
+    };
+
+    lock_at::detach(&mut self.id);
+    self.method = new_locked();
+    assert_method_specific_invariants(self);
+}
+
+/// Freezes the Shared-Notarization `self`.
+///
+/// Replaces the `shared_lock_metadata` of `self` with one whose `update_lock`
+/// and `transfer_lock` are `TimeLock::UntilDestroyed` and whose `delete_lock`
+/// is `delete_lock`. `self` stays a shared object and keeps its capabilities,
+/// but no capability can update its `state` or `updatable_metadata` anymore.
+///
+/// Like `seal_notarization`, sealing counts as a final update: it is rejected
+/// while the `update_lock` is active, and the new `delete_lock` must not
+/// unlock before an active `UnlockAt` `delete_lock` of `self`.
+///
+/// Authorization is the responsibility of the calling module.
+///
+/// Aborts with:
+/// * `ENotSharedNotarization` when `self` is not a Shared-Notarization.
+/// * `EUpdateWhileLocked` when `is_update_locked` is `true`.
+/// * `ESealShortensRetention` when the new `delete_lock` unlocks before an
+///   active `UnlockAt` `delete_lock` of `self`.
+/// * any error documented by `new_lock_metadata`.
+public(package) fun seal_shared_notarization<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    delete_lock: TimeLock,
+    clock: &Clock,
+) {
+    assert!(self.is_shared(), ENotSharedNotarization);
+    assert!(!self.is_update_locked(clock), EUpdateWhileLocked);
+    assert_retained_by(&delete_lock, &self.shared_lock_metadata().delete_lock, clock);
+
+    let lock_metadata = new_lock_metadata(
+        timelock::until_destroyed(),
+        delete_lock,
+        timelock::until_destroyed(),
+    );
+    let LockMetadata {
+        update_lock: previous_update_lock,
+        delete_lock: previous_delete_lock,
+        transfer_lock: previous_transfer_lock,
+    } = dynamic_field::remove(&mut self.id, shared_lock_metadata_key());
+
+    // The previous locks are superseded by the at least as restrictive locks
+    // of the sealed Shared-Notarization
+    timelock::destroy_superseded(previous_update_lock);
+    timelock::destroy_superseded(previous_delete_lock);
+    timelock::destroy_superseded(previous_transfer_lock);
+
+    dynamic_field::add(&mut self.id, shared_lock_metadata_key(), lock_metadata);
+    assert_method_specific_invariants(self);
+}
+
+/// Asserts that `delete_lock` does not unlock before `current_lock`, if
+/// `current_lock` is an active `UnlockAt` lock.
+fun assert_retained_by(delete_lock: &TimeLock, current_lock: &TimeLock, clock: &Clock) {
+    if (timelock::is_timelocked_unlock_at(current_lock, clock)) {
+        assert!(
+            delete_lock.is_unlock_at() &&
+            delete_lock.get_unlock_time().destroy_some() >=
+            current_lock.get_unlock_time().destroy_some(),
+            ESealShortensRetention,
+        );
+    };
+}
+
 // ===== Metadata Management Functions =====
 /// Replaces `updatable_metadata` with `new_metadata`.
 ///
@@ -440,26 +838,58 @@ public(package) fun transfer_notarization<D: store + drop + copy>(
 /// The `immutable_metadata.description` field is unaffected.
 ///
 /// Behaviour depends on the Notarization Method:
-/// * `Dynamic`: permitted - `update_lock` is always `None`.
+/// * `Dynamic`: permitted unless the optional `update_lock` is currently
+///   active.
 /// * `Locked`: always aborts, because `update_lock` is pinned to
 ///   `TimeLock::UntilDestroyed`.
+/// * Shared-Notarizations: always aborts; use
+///   `shared_notarization::update_metadata`.
 ///
 /// Aborts with:
+/// * `ECapabilityRequired` when `self` is a Shared-Notarization.
 /// * `EUpdateWhileLocked` when `is_update_locked` is `true`.
+///
+/// Emits a `NotarizationMetadataUpdated` event on success.
 public fun update_metadata<D: store + drop + copy>(
     self: &mut Notarization<D>,
     new_metadata: Option<String>,
     clock: &Clock,
+) {
+    assert!(!self.is_shared(), ECapabilityRequired);
+
+    update_metadata_internal(self, new_metadata, clock);
+}
+
+/// Replaces `updatable_metadata` with `new_metadata` without checking whether
+/// `self` is a Shared-Notarization.
+///
+/// Authorization is the responsibility of the calling module; see
+/// `update_metadata` for the remaining contract.
+public(package) fun update_metadata_internal<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    new_metadata: Option<String>,
+    clock: &Clock,
 ) {
     assert!(!self.is_update_locked(clock), EUpdateWhileLocked);
 
     self.updatable_metadata = new_metadata;
+
+    event::emit(NotarizationMetadataUpdated {
+        notarization_id: object::uid_to_inner(&self.id),
+        updated_metadata: new_metadata,
+    });
 }
 
 // ===== Getter Functions =====
 /// Returns a reference to the object id of `self`.
 public fun id<D: store + drop + copy>(self: &Notarization<D>): &UID { &self.id }
 
+/// Returns a mutable reference to the object id of `self`, e.g. to attach
+/// dynamic fields.
+public(package) fun id_mut<D: store + drop + copy>(self: &mut Notarization<D>): &mut UID {
+    &mut self.id
+}
+
 /// Returns a reference to the current `State<D>` of `self`.
 public fun state<D: store + drop + copy>(self: &Notarization<D>): &State<D> { &self.state }
 
@@ -490,33 +920,79 @@ public fun updatable_metadata<D: store + drop + copy>(self: &Notarization<D>): O
 }
 
 /// Returns the Notarization Method of `self`.
+///
+/// Shared-Notarizations use the `Locked` Notarization Method; see
+/// `is_shared`.
 public fun notarization_method<D: store + drop + copy>(self: &Notarization<D>): NotarizationMethod {
     self.method
 }
 
+/// Returns `true` when `self` is a Shared-Notarization, i.e. when it stores
+/// its `LockMetadata` as a dynamic field.
+public fun is_shared<D: store + drop + copy>(self: &Notarization<D>): bool {
+    dynamic_field::exists_with_type<String, LockMetadata>(&self.id, shared_lock_metadata_key())
+}
+
 // ===== Lock-Related Getter Functions =====
 /// Returns a reference to the optional `LockMetadata` of `self`.
+///
+/// For Shared-Notarizations this is the `LockMetadata` guarding the object
+/// against package versions unaware of Shared-Notarizations; see
+/// `shared_lock_metadata` for the locks of the Shared-Notarization.
 public fun lock_metadata<D: store + drop + copy>(self: &Notarization<D>): &Option<LockMetadata> {
     &self.immutable_metadata.locking
 }
 
+/// Returns a reference to the `LockMetadata` of the Shared-Notarization
+/// `self`.
+///
+/// Aborts if `self` is not a Shared-Notarization.
+public fun shared_lock_metadata<D: store + drop + copy>(self: &Notarization<D>): &LockMetadata {
+    dynamic_field::borrow(&self.id, shared_lock_metadata_key())
+}
+
+/// Returns the `lock_at::LockAt` freezing the Dynamic-Notarization `self`,
+/// if any.
+public fun update_lock_at<D: store + drop + copy>(self: &Notarization<D>): Option<LockAt> {
+    if (lock_at::is_attached(&self.id)) {
+        option::some(*lock_at::borrow(&self.id))
+    } else {
+        option::none()
+    }
+}
+
 /// Checks whether `self` is currently locked against `state` and
 /// `updatable_metadata` updates.
 ///
 /// The result depends on the Notarization Method:
-/// * `Dynamic`: always returns `false`.
+/// * `Dynamic`: returns whether the attached `lock_at::LockAt` is currently
+///   locked when `self` carries one, `false` when `self` has no
+///   `LockMetadata`, and whether `LockMetadata.update_lock` is currently
+///   timelocked according to `clock` otherwise.
 /// * `Locked`: returns whether `LockMetadata.update_lock` is currently
 ///   timelocked according to `clock`.
+/// * Shared-Notarizations: returns whether the `update_lock` of
+///   `shared_lock_metadata` is currently timelocked according to `clock`.
 ///
 /// Aborts with:
 /// * `EDynamicNotarizationInvariants` when the invariants of the `Dynamic`
 ///   Notarization Method are violated.
 /// * `ELockedNotarizationInvariants` when the invariants of the `Locked`
 ///   Notarization Method are violated.
+/// * `ESharedNotarizationInvariants` when the invariants of
+///   Shared-Notarizations are violated.
 public fun is_update_locked<D: store + drop + copy>(self: &Notarization<D>, clock: &Clock): bool {
     assert_method_specific_invariants(self);
-    if (self.method.is_dynamic()) {
-        false
+    if (self.is_shared()) {
+        timelock::is_timelocked(&self.shared_lock_metadata().update_lock, clock)
+    } else if (self.method.is_dynamic()) {
+        if (lock_at::is_attached(&self.id)) {
+            lock_at::borrow(&self.id).is_locked(clock)
+        } else {
+            option::is_some_and!(&self.immutable_metadata.locking, |lock_metadata| {
+                timelock::is_timelocked(&lock_metadata.update_lock, clock)
+            })
+        }
     } else {
         let lock_metadata = option::borrow(&self.immutable_metadata.locking);
 
@@ -527,20 +1003,30 @@ public fun is_update_locked<D: store + drop + copy>(self: &Notarization<D>, cloc
 /// Checks whether `self` is currently locked against destruction.
 ///
 /// The result depends on the Notarization Method:
-/// * `Dynamic`: always returns `false`.
+/// * `Dynamic`: returns `false` when `self` has no `LockMetadata`, and
+///   whether `LockMetadata.delete_lock` is currently timelocked according to
+///   `clock` otherwise.
 /// * `Locked`: returns whether `LockMetadata.delete_lock` is currently
 ///   timelocked according to `clock`.
+/// * Shared-Notarizations: returns whether the `delete_lock` of
+///   `shared_lock_metadata` is currently timelocked according to `clock`.
 ///
 /// Aborts with:
 /// * `EDynamicNotarizationInvariants` when the invariants of the `Dynamic`
 ///   Notarization Method are violated.
 /// * `ELockedNotarizationInvariants` when the invariants of the `Locked`
 ///   Notarization Method are violated.
+/// * `ESharedNotarizationInvariants` when the invariants of
+///   Shared-Notarizations are violated.
 public fun is_delete_locked<D: store + drop + copy>(self: &Notarization<D>, clock: &Clock): bool {
     assert_method_specific_invariants(self);
 
-    if (self.method.is_dynamic()) {
-        false
+    if (self.is_shared()) {
+        timelock::is_timelocked(&self.shared_lock_metadata().delete_lock, clock)
+    } else if (self.method.is_dynamic()) {
+        option::is_some_and!(&self.immutable_metadata.locking, |lock_metadata| {
+            timelock::is_timelocked(&lock_metadata.delete_lock, clock)
+        })
     } else {
         let lock_metadata = option::borrow(&self.immutable_metadata.locking);
 
@@ -562,18 +1048,29 @@ public fun is_transfer_locked<D: store + drop + copy>(self: &Notarization<D>, cl
 /// Checks whether `self` is currently eligible for destruction.
 ///
 /// The result depends on the Notarization Method:
-/// * `Dynamic`: returns `false` when an `UnlockAt`
-///   `transfer_lock` has not yet expired, and `true` otherwise.
-/// * `Locked`: returns `true` only when none of `update_lock`, `delete_lock`,
-///   or `transfer_lock` is currently an unexpired `UnlockAt` lock.
+/// * `Dynamic`: returns `false` when an `UnlockAt` `update_lock`,
+///   `delete_lock` or `transfer_lock` has not yet expired, and `true`
+///   otherwise.
+/// * `Locked`: returns `true` only when none of `update_lock`,
+///   `delete_lock`, or `transfer_lock` is currently an unexpired `UnlockAt`
+///   lock.
+/// * Shared-Notarizations: like `Locked`, for the locks of
+///   `shared_lock_metadata`.
 public fun is_destroy_allowed<D: store + drop + copy>(self: &Notarization<D>, clock: &Clock): bool {
-    if (self.method.is_dynamic()) {
+    if (self.is_shared()) {
+        let lock_metadata = self.shared_lock_metadata();
+
+        !(
+            timelock::is_timelocked_unlock_at(&lock_metadata.update_lock, clock) ||
+        timelock::is_timelocked_unlock_at(&lock_metadata.delete_lock, clock) ||
+        timelock::is_timelocked_unlock_at(&lock_metadata.transfer_lock, clock),
+        )
+    } else if (self.method.is_dynamic()) {
         !option::is_some_and!(
             &self.immutable_metadata.locking,
-            |lock_metadata| timelock::is_timelocked_unlock_at(
-                &lock_metadata.transfer_lock,
-                clock,
-            ),
+            |lock_metadata| timelock::is_timelocked_unlock_at(&lock_metadata.update_lock, clock) ||
+            timelock::is_timelocked_unlock_at(&lock_metadata.delete_lock, clock) ||
+            timelock::is_timelocked_unlock_at(&lock_metadata.transfer_lock, clock),
         )
     } else {
         let lock_metadata = option::borrow(&self.immutable_metadata.locking);
@@ -591,19 +1088,26 @@ public fun is_destroy_allowed<D: store + drop + copy>(self: &Notarization<D>, cl
 ///
 /// The rule set is selected by `self.method`:
 /// * `Dynamic`: see `are_dynamic_notarization_invariants_ok`.
-/// * `Locked`: see `are_locked_notarization_invariants_ok`.
+/// * `Locked`: see `are_locked_notarization_invariants_ok`; additionally
+///   `are_shared_notarization_invariants_ok` for the `shared_lock_metadata`
+///   of Shared-Notarizations.
 ///
 /// Aborts with:
 /// * `EDynamicNotarizationInvariants` when `self.method` is `Dynamic` and the
 ///   invariants of the `Dynamic` Notarization Method are violated.
 /// * `ELockedNotarizationInvariants` when `self.method` is `Locked` and the
 ///   invariants of the `Locked` Notarization Method are violated.
+/// * `ESharedNotarizationInvariants` when `self` is a Shared-Notarization
+///   and the invariants of Shared-Notarizations are violated.
 public(package) fun assert_method_specific_invariants<D: store + drop + copy>(
     self: &Notarization<D>,
 ) {
     if (self.method.is_dynamic()) {
         assert!(
-            are_dynamic_notarization_invariants_ok(&self.immutable_metadata),
+            are_dynamic_notarization_invariants_ok(
+                &self.immutable_metadata,
+                lock_at::is_attached(&self.id),
+            ),
             EDynamicNotarizationInvariants,
         );
     } else if (self.method.is_locked()) {
@@ -611,6 +1115,13 @@ public(package) fun assert_method_specific_invariants<D: store + drop + copy>(
             are_locked_notarization_invariants_ok(&self.immutable_metadata),
             ELockedNotarizationInvariants,
         );
+
+        if (self.is_shared()) {
+            assert!(
+                are_shared_notarization_invariants_ok(self.shared_lock_metadata()),
+                ESharedNotarizationInvariants,
+            );
+        }
     }
 }
 
@@ -633,25 +1144,53 @@ public(package) fun are_locked_notarization_invariants_ok(
 /// Checks whether `immutable_metadata` satisfies the invariants required by
 /// the `Dynamic` Notarization Method.
 ///
-/// These invariants permit two shapes:
-/// * `locking` is `option::none()`, i.e. the Dynamic-Notarization carries no
-///   transfer lock; or
-/// * `locking` is `option::some(_)` with `update_lock` and `delete_lock` both
-///   `TimeLock::None` and `transfer_lock` anything other than `TimeLock::None`.
+/// `has_update_lock_at` tells whether a `lock_at::LockAt` is attached to the
+/// Dynamic-Notarization. These invariants permit three shapes:
+/// * `locking` is `option::none()` and no `LockAt` is attached, i.e. the
+///   Dynamic-Notarization carries no locks; or
+/// * `locking` is `option::some(_)` and no `LockAt` is attached, with
+///   `update_lock` not being `TimeLock::UntilDestroyed` and at least one of
+///   `update_lock`, `delete_lock` and `transfer_lock` being anything other
+///   than `TimeLock::None`; or
+/// * `locking` is `option::some(_)` and a `LockAt` is attached, with
+///   `update_lock` being `TimeLock::UntilDestroyed`.
 public(package) fun are_dynamic_notarization_invariants_ok(
     immutable_metadata: &ImmutableMetadata,
+    has_update_lock_at: bool,
 ): bool {
     if (immutable_metadata.locking.is_some()) {
         let lock_metadata = option::borrow(&immutable_metadata.locking);
 
-        timelock::is_none(&lock_metadata.delete_lock) &&
-        timelock::is_none(&lock_metadata.update_lock) &&
-        !timelock::is_none(&lock_metadata.transfer_lock)
+        if (has_update_lock_at) {
+            timelock::is_until_destroyed(&lock_metadata.update_lock)
+        } else {
+            !timelock::is_until_destroyed(&lock_metadata.update_lock) &&
+            (
+                !timelock::is_none(&lock_metadata.update_lock) ||
+                !timelock::is_none(&lock_metadata.delete_lock) ||
+                !timelock::is_none(&lock_metadata.transfer_lock)
+            )
+        }
     } else {
-        true
+        !has_update_lock_at
     }
 }
 
+/// Checks whether `lock_metadata` satisfies the invariants required for the
+/// `shared_lock_metadata` of Shared-Notarizations.
+///
+/// These invariants require that `transfer_lock` is
+/// `TimeLock::UntilDestroyed`. `update_lock` is `TimeLock::UntilDestroyed`
+/// only after the Shared-Notarization was sealed via
+/// `seal_shared_notarization`.
+public(package) fun are_shared_notarization_invariants_ok(lock_metadata: &LockMetadata): bool {
+    timelock::is_until_destroyed(&lock_metadata.transfer_lock)
+}
+
+fun shared_lock_metadata_key(): String {
+    SHARED_LOCK_METADATA_KEY.to_string()
+}
+
 // ===== Test-only Functions =====
 #[test_only]
 public(package) fun destroy_lock_metadata(lock_metadata: LockMetadata, clock: &Clock) {
@@ -0,0 +1,355 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Public entry surface for Shared-Notarizations: shared `Notarization<D>`
+/// objects whose `state` and `updatable_metadata` can be updated by several
+/// parties. Shared-Notarizations use the `Locked` Notarization Method and
+/// keep their own `LockMetadata` in a dynamic field; see
+/// `notarization::new_shared_notarization`.
+///
+/// Updates are authorized by `Capability` objects issued for a
+/// Shared-Notarization. A `Capability` with the `Admin` role is transferred
+/// to the creator; it permits updates, issuing and revoking capabilities,
+/// sealing and destroying the notarization. A `Capability` with the `Writer`
+/// role only permits updates. The IDs of all capabilities that have been
+/// issued and not revoked are stored in a dynamic field of the notarization.
+#[allow(lint(self_transfer))]
+module iota_notarization::shared_notarization;
+
+use iota::{clock::Clock, dynamic_field, event, vec_set::{Self, VecSet}};
+use iota_notarization::{notarization::{Self, Notarization, State}, timelock::TimeLock};
+use std::string::String;
+
+// ===== Constants =====
+/// Raised when a `Capability` was not issued for the notarization or has
+/// been revoked.
+const EInvalidCapability: u64 = 0;
+/// Raised when an operation reserved to the `Admin` role is called with a
+/// `Capability` of another role.
+const EAdminRoleRequired: u64 = 1;
+/// Raised when `revoke_capability` is called for a capability that is not
+/// valid for the notarization.
+const ECapabilityNotFound: u64 = 2;
+
+// ===== Capabilities =====
+/// Role granted by a `Capability`.
+public enum Role has copy, drop, store {
+    /// May update `state` and `updatable_metadata`, issue and revoke
+    /// capabilities, and seal and destroy the notarization.
+    Admin,
+    /// May update `state` and `updatable_metadata`.
+    Writer,
+}
+
+/// Grants its holder `role` on the Shared-Notarization `notarization_id`.
+public struct Capability has key, store {
+    id: UID,
+    /// Id of the `Notarization` object this capability was issued for.
+    notarization_id: ID,
+    /// Role granted by this capability.
+    role: Role,
+}
+
+/// Dynamic field key under which a Shared-Notarization stores the
+/// `VecSet<ID>` of its valid capabilities.
+public struct CapabilitiesKey has copy, drop, store {}
+
+// ===== Event Types =====
+/// Emitted by `create` after a Shared-Notarization is created and shared.
+public struct SharedNotarizationCreated has copy, drop {
+    /// Id of the newly created `Notarization` object.
+    notarization_id: ID,
+    /// Id of the `Admin` `Capability` transferred to the sender.
+    capability_id: ID,
+}
+
+/// Emitted by `issue_capability` after a `Capability` was issued.
+public struct CapabilityIssued has copy, drop {
+    /// Id of the `Notarization` object the capability was issued for.
+    notarization_id: ID,
+    /// Id of the issued `Capability`.
+    capability_id: ID,
+    /// Address the capability was transferred to.
+    recipient: address,
+}
+
+/// Emitted by `seal` after a Shared-Notarization was frozen.
+public struct NotarizationSealed has copy, drop {
+    /// Id of the sealed `Notarization` object.
+    notarization_id: ID,
+    /// Value of `state_version_count` of the sealed `state`.
+    state_version_count: u64,
+}
+
+/// Emitted by `revoke_capability` after a `Capability` was revoked.
+public struct CapabilityRevoked has copy, drop {
+    /// Id of the `Notarization` object the capability was issued for.
+    notarization_id: ID,
+    /// Id of the revoked `Capability`.
+    capability_id: ID,
+}
+
+// ===== Role Functions =====
+/// Returns the `Admin` role.
+public fun new_admin_role(): Role {
+    Role::Admin
+}
+
+/// Returns the `Writer` role.
+public fun new_writer_role(): Role {
+    Role::Writer
+}
+
+/// Returns `true` when `role` is `Admin`.
+public fun is_admin(role: &Role): bool {
+    match (role) {
+        Role::Admin => true,
+        Role::Writer => false,
+    }
+}
+
+// ===== Notarization Functions =====
+/// Creates a new Shared-Notarization `Notarization<D>`, shares it and
+/// transfers a `Capability` with the `Admin` role to the transaction sender.
+///
+/// `update_lock` restricts the window in which `state` and
+/// `updatable_metadata` can be updated, `delete_lock` gates destruction; see
+/// `notarization::new_shared_notarization` for the full contract.
+///
+/// Aborts with:
+/// * any error documented by `notarization::new_shared_notarization`.
+///
+/// Emits a `SharedNotarizationCreated` event on success.
+public fun create<D: store + drop + copy>(
+    state: State<D>,
+    immutable_description: Option<String>,
+    updatable_metadata: Option<String>,
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    let mut notarization = notarization::new_shared_notarization(
+        state,
+        immutable_description,
+        updatable_metadata,
+        update_lock,
+        delete_lock,
+        clock,
+        ctx,
+    );
+    let notarization_id = object::uid_to_inner(notarization.id());
+
+    let capability = new_capability(notarization_id, Role::Admin, ctx);
+    let capability_id = object::id(&capability);
+    dynamic_field::add(notarization.id_mut(), CapabilitiesKey {}, vec_set::singleton(capability_id));
+
+    event::emit(SharedNotarizationCreated { notarization_id, capability_id });
+
+    transfer::transfer(capability, tx_context::sender(ctx));
+    notarization::share_notarization(notarization);
+}
+
+/// Replaces the `state` of `self` with `new_state`, authorized by `cap`.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * any error documented by `notarization::update_state`.
+///
+/// Emits a `notarization::NotarizationUpdated` event on success.
+public fun update_state<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    cap: &Capability,
+    new_state: State<D>,
+    clock: &Clock,
+) {
+    assert!(is_capability_valid(self, cap), EInvalidCapability);
+
+    notarization::update_state_internal(self, new_state, clock);
+}
+
+/// Replaces the `updatable_metadata` of `self` with `new_metadata`,
+/// authorized by `cap`.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * any error documented by `notarization::update_metadata`.
+///
+/// Emits a `notarization::NotarizationMetadataUpdated` event on success.
+public fun update_metadata<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    cap: &Capability,
+    new_metadata: Option<String>,
+    clock: &Clock,
+) {
+    assert!(is_capability_valid(self, cap), EInvalidCapability);
+
+    notarization::update_metadata_internal(self, new_metadata, clock);
+}
+
+/// Freezes `self`, authorized by the `Admin` capability `cap`.
+///
+/// Afterwards `state` and `updatable_metadata` can no longer be updated and
+/// the destruction of `self` is gated by `delete_lock`; see
+/// `notarization::seal_shared_notarization` for the full contract.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * `EAdminRoleRequired` when `cap` does not grant the `Admin` role.
+/// * any error documented by `notarization::seal_shared_notarization`.
+///
+/// Emits a `NotarizationSealed` event on success.
+public fun seal<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    cap: &Capability,
+    delete_lock: TimeLock,
+    clock: &Clock,
+) {
+    assert_admin(self, cap);
+
+    notarization::seal_shared_notarization(self, delete_lock, clock);
+
+    event::emit(NotarizationSealed {
+        notarization_id: cap.notarization_id,
+        state_version_count: self.version_count(),
+    });
+}
+
+/// Destroys `self`, authorized by the `Admin` capability `cap`.
+///
+/// Capabilities issued for `self` are not destroyed; their holders can
+/// dispose of them via `destroy_capability`.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * `EAdminRoleRequired` when `cap` does not grant the `Admin` role.
+/// * any error documented by `notarization::destroy`.
+///
+/// Emits a `notarization::NotarizationDestroyed` event on success.
+public fun destroy<D: store + drop + copy>(
+    mut self: Notarization<D>,
+    cap: &Capability,
+    clock: &Clock,
+) {
+    assert_admin(&self, cap);
+
+    let _: VecSet<ID> = dynamic_field::remove(self.id_mut(), CapabilitiesKey {});
+    notarization::destroy_internal(self, clock);
+}
+
+// ===== Capability Administration =====
+/// Issues a new `Capability` granting `role` on `self` and transfers it to
+/// `recipient`, authorized by the `Admin` capability `cap`.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * `EAdminRoleRequired` when `cap` does not grant the `Admin` role.
+///
+/// Emits a `CapabilityIssued` event on success.
+public fun issue_capability<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    cap: &Capability,
+    role: Role,
+    recipient: address,
+    ctx: &mut TxContext,
+) {
+    assert_admin(self, cap);
+
+    let capability = new_capability(cap.notarization_id, role, ctx);
+    let capability_id = object::id(&capability);
+    capabilities_mut(self).insert(capability_id);
+
+    event::emit(CapabilityIssued {
+        notarization_id: cap.notarization_id,
+        capability_id,
+        recipient,
+    });
+
+    transfer::transfer(capability, recipient);
+}
+
+/// Revokes the `Capability` `capability_id` of `self`, authorized by the
+/// `Admin` capability `cap`.
+///
+/// A revoked capability can no longer authorize any operation. Revoking the
+/// last `Admin` capability makes the capabilities of `self` immutable and
+/// `self` indestructible.
+///
+/// Aborts with:
+/// * `EInvalidCapability` when `is_capability_valid` is `false`.
+/// * `EAdminRoleRequired` when `cap` does not grant the `Admin` role.
+/// * `ECapabilityNotFound` when `capability_id` is not a valid capability of
+///   `self`.
+///
+/// Emits a `CapabilityRevoked` event on success.
+public fun revoke_capability<D: store + drop + copy>(
+    self: &mut Notarization<D>,
+    cap: &Capability,
+    capability_id: ID,
+) {
+    assert_admin(self, cap);
+
+    let capabilities = capabilities_mut(self);
+    assert!(capabilities.contains(&capability_id), ECapabilityNotFound);
+    capabilities.remove(&capability_id);
+
+    event::emit(CapabilityRevoked {
+        notarization_id: cap.notarization_id,
+        capability_id,
+    });
+}
+
+/// Destroys `cap`.
+///
+/// Lets holders dispose of capabilities that were revoked or whose
+/// notarization was destroyed. Destroying a valid capability does not revoke
+/// it, but no object can use it anymore.
+public fun destroy_capability(cap: Capability) {
+    let Capability { id, notarization_id: _, role: _ } = cap;
+
+    object::delete(id);
+}
+
+// ===== Getter Functions =====
+/// Returns the id of the `Notarization` object `cap` was issued for.
+public fun capability_notarization_id(cap: &Capability): ID {
+    cap.notarization_id
+}
+
+/// Returns the role granted by `cap`.
+public fun capability_role(cap: &Capability): Role {
+    cap.role
+}
+
+/// Checks whether `cap` currently authorizes updates of `self`.
+///
+/// Returns `true` when `cap` was issued for `self` and has not been revoked.
+/// Always returns `false` for notarizations other than Shared-Notarizations.
+public fun is_capability_valid<D: store + drop + copy>(
+    self: &Notarization<D>,
+    cap: &Capability,
+): bool {
+    cap.notarization_id == object::uid_to_inner(self.id()) &&
+    dynamic_field::exists_(self.id(), CapabilitiesKey {}) &&
+    dynamic_field::borrow<CapabilitiesKey, VecSet<ID>>(self.id(), CapabilitiesKey {}).contains(
+        &object::id(cap),
+    )
+}
+
+// ===== Internal Functions =====
+fun new_capability(notarization_id: ID, role: Role, ctx: &mut TxContext): Capability {
+    Capability {
+        id: object::new(ctx),
+        notarization_id,
+        role,
+    }
+}
+
+fun assert_admin<D: store + drop + copy>(self: &Notarization<D>, cap: &Capability) {
+    assert!(is_capability_valid(self, cap), EInvalidCapability);
+    assert!(cap.role.is_admin(), EAdminRoleRequired);
+}
+
+fun capabilities_mut<D: store + drop + copy>(self: &mut Notarization<D>): &mut VecSet<ID> {
+    dynamic_field::borrow_mut(self.id_mut(), CapabilitiesKey {})
+}
@@ -20,6 +20,10 @@ const EPastTimestamp: u64 = 0;
 /// Error when attempting to destroy a timelock that is still locked
 const ETimelockNotExpired: u64 = 1;
 
+// ===== Constants =====
+/// Largest Unix timestamp representable by an `UnlockAt` lock.
+const MAX_UNLOCK_TIME: u32 = 4294967295;
+
 /// Represents different types of time-based locks that can be applied to
 /// notarizations.
 public enum TimeLock has store {
@@ -50,6 +54,12 @@ public fun none(): TimeLock {
     TimeLock::None
 }
 
+/// Creates a new time lock that unlocks at the largest representable Unix
+/// timestamp, i.e. one that never expires in practice.
+public(package) fun unlock_at_max(): TimeLock {
+    TimeLock::UnlockAt(MAX_UNLOCK_TIME)
+}
+
 /// Checks if the provided lock time is an UntilDestroyed lock.
 public fun is_until_destroyed(lock_time: &TimeLock): bool {
     match (lock_time) {
@@ -94,6 +104,18 @@ public fun destroy(condition: TimeLock, clock: &Clock) {
     }
 }
 
+/// Destroys a TimeLock regardless of whether it is still locked.
+///
+/// Only to be used when the lock is superseded by a lock that is at least as
+/// restrictive, e.g. when a Dynamic-Notarization is sealed.
+public(package) fun destroy_superseded(condition: TimeLock) {
+    match (condition) {
+        TimeLock::UnlockAt(_) => {},
+        TimeLock::UntilDestroyed => {},
+        TimeLock::None => {},
+    }
+}
+
 /// Checks if a timelock condition is currently active (locked).
 ///
 /// This function evaluates whether a given TimeLock instance is currently in a locked state
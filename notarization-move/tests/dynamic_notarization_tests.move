@@ -6,7 +6,7 @@
 module iota_notarization::dynamic_notarization_tests;
 
 use iota::{clock, test_scenario::{Self as ts, ctx}};
-use iota_notarization::{dynamic_notarization, notarization, timelock};
+use iota_notarization::{dynamic_notarization, lock_at, notarization, timelock};
 use std::string;
 
 const ADMIN_ADDRESS: address = @0x01;
@@ -408,3 +408,500 @@ public fun test_dynamic_notarization_with_none_lock() {
 
     scenario.end();
 }
+
+#[test]
+public fun test_dynamic_notarization_with_delete_lock() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    // Create a simple state
+    let data = string::utf8(b"Initial Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    // Create a delete lock that unlocks at timestamp 2000
+    let delete_lock = timelock::unlock_at(2000, &clock);
+
+    // Create a dynamic notarization with delete lock but without transfer lock
+    dynamic_notarization::create_with_delete_lock(
+        state,
+        std::option::none(),
+        std::option::none(),
+        delete_lock,
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    notarization::assert_method_specific_invariants(&notarization);
+
+    // Delete locked, but still updatable and transferable
+    assert!(notarization.lock_metadata().is_some(), 0);
+    assert!(notarization::is_delete_locked(&notarization, &clock), 0);
+    assert!(!notarization::is_destroy_allowed(&notarization, &clock), 0);
+    assert!(!notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(dynamic_notarization::is_transferable(&notarization, &clock), 0);
+
+    let new_data = string::utf8(b"Updated Data");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+    assert!(notarization::version_count(&notarization) == 1, 0);
+
+    // Advance time past the lock
+    clock::increment_for_testing(&mut clock, 1000000);
+    assert!(!notarization::is_delete_locked(&notarization, &clock), 0);
+    assert!(notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    // Clean up
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EDestroyWhileLocked)]
+public fun test_destroy_dynamic_notarization_before_delete_unlock() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    // Create a simple state
+    let data = string::utf8(b"Test Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    // Create a delete lock that unlocks at timestamp 2000
+    let delete_lock = timelock::unlock_at(2000, &clock);
+
+    // Create a dynamic notarization with delete lock
+    dynamic_notarization::create_with_delete_lock(
+        state,
+        std::option::none(),
+        std::option::none(),
+        delete_lock,
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+
+    // Try to destroy before lock expires - should fail
+    notarization::destroy(notarization, &clock);
+
+    // Clean up - we won't reach this due to expected failure
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::ELockTimeNotSatisfied)]
+public fun test_dynamic_notarization_delete_lock_before_transfer_lock() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Test Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    // The delete lock must not expire before the transfer lock
+    let delete_lock = timelock::unlock_at(2000, &clock);
+    let transfer_lock = timelock::unlock_at(3000, &clock);
+
+    dynamic_notarization::create_with_delete_lock(
+        state,
+        std::option::none(),
+        std::option::none(),
+        delete_lock,
+        transfer_lock,
+        &clock,
+        ctx,
+    );
+
+    // Clean up - we won't reach this due to expected failure
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EUntilDestroyedLockNotAllowed)]
+public fun test_dynamic_notarization_until_destroyed_delete_lock() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let clock = clock::create_for_testing(ctx);
+
+    let data = string::utf8(b"Test Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create_with_delete_lock(
+        state,
+        std::option::none(),
+        std::option::none(),
+        timelock::until_destroyed(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    // Clean up - we won't reach this due to expected failure
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_dynamic_notarization_with_update_embargo() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Initial Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    // Embargo updates until timestamp 2000
+    let update_lock = timelock::unlock_at(2000, &clock);
+
+    dynamic_notarization::create_with_locks(
+        state,
+        std::option::none(),
+        std::option::none(),
+        update_lock,
+        timelock::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    notarization::assert_method_specific_invariants(&notarization);
+
+    // Update locked during the embargo, but still transferable
+    assert!(notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(!notarization::is_destroy_allowed(&notarization, &clock), 0);
+    assert!(dynamic_notarization::is_transferable(&notarization, &clock), 0);
+
+    // Advance time past the embargo
+    clock::increment_for_testing(&mut clock, 1000000);
+    assert!(!notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    let new_data = string::utf8(b"Updated Data");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+    assert!(notarization::version_count(&notarization) == 1, 0);
+
+    // Clean up
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EUpdateWhileLocked)]
+public fun test_update_dynamic_notarization_during_embargo() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Initial Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create_with_locks(
+        state,
+        std::option::none(),
+        std::option::none(),
+        timelock::unlock_at(2000, &clock),
+        timelock::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+
+    // Try to update before the embargo ends - should fail
+    notarization::update_metadata(
+        &mut notarization,
+        std::option::some(string::utf8(b"Too early")),
+        &clock,
+    );
+
+    // Clean up - we won't reach this due to expected failure
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EUpdateWhileLocked)]
+public fun test_update_dynamic_notarization_after_freeze() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Initial Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    // Freeze the state from timestamp 2000 on
+    dynamic_notarization::create_with_lock_at(
+        state,
+        std::option::none(),
+        std::option::none(),
+        lock_at::new(2000, &clock),
+        timelock::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    assert!(notarization::update_lock_at(&notarization).destroy_some().lock_time() == 2000, 0);
+
+    // Updatable and destroyable before the freeze
+    assert!(!notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    let new_data = string::utf8(b"Final Data");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+
+    // Advance time past the freeze
+    clock::increment_for_testing(&mut clock, 1000000);
+    assert!(notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    let new_data = string::utf8(b"Too late");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+
+    // Clean up - we won't reach this due to expected failure
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_seal_dynamic_notarization_before_freeze() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Test Data");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create_with_lock_at(
+        state,
+        std::option::none(),
+        std::option::none(),
+        lock_at::new(2000, &clock),
+        timelock::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+
+    // Sealing supersedes the freeze
+    dynamic_notarization::seal(&mut notarization, timelock::none(), &clock);
+    assert!(notarization::update_lock_at(&notarization).is_none(), 0);
+    assert!(notarization::is_update_locked(&notarization, &clock), 0);
+
+    // Clean up
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_seal_dynamic_notarization() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Draft Contract");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create(
+        state,
+        std::option::some(string::utf8(b"Contract")),
+        std::option::none(),
+        timelock::unlock_at(1500, &clock),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    let id = object::uid_to_inner(notarization.id());
+    let created_at = notarization::created_at(&notarization);
+
+    let new_data = string::utf8(b"Final Contract");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+
+    // Seal with a delete lock that unlocks at timestamp 2000
+    dynamic_notarization::seal(&mut notarization, timelock::unlock_at(2000, &clock), &clock);
+    notarization::assert_method_specific_invariants(&notarization);
+
+    // Id, creation time and version count are preserved
+    assert!(object::uid_to_inner(notarization.id()) == id, 0);
+    assert!(notarization::created_at(&notarization) == created_at, 0);
+    assert!(notarization::version_count(&notarization) == 1, 0);
+    let method = notarization::notarization_method(&notarization);
+    assert!(method.is_locked(), 0);
+
+    // Sealed notarizations are neither updatable nor transferable
+    assert!(notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(!dynamic_notarization::is_transferable(&notarization, &clock), 0);
+    assert!(!notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    // Advance time past the delete lock
+    clock::increment_for_testing(&mut clock, 1000000);
+    assert!(notarization::is_update_locked(&notarization, &clock), 0);
+    assert!(notarization::is_destroy_allowed(&notarization, &clock), 0);
+
+    // Clean up
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EUpdateWhileLocked)]
+public fun test_update_sealed_notarization() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Draft Contract");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create(
+        state,
+        std::option::none(),
+        std::option::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    dynamic_notarization::seal(&mut notarization, timelock::none(), &clock);
+
+    // Try to update the sealed notarization - should fail
+    let new_data = string::utf8(b"Amended Contract");
+    let new_state = notarization::new_state_from_string(new_data, std::option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+
+    // Clean up - we won't reach this due to expected failure
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::ENotDynamicNotarization)]
+public fun test_seal_sealed_notarization() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Draft Contract");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create(
+        state,
+        std::option::none(),
+        std::option::none(),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+    dynamic_notarization::seal(&mut notarization, timelock::none(), &clock);
+
+    // Try to seal the notarization again - should fail
+    dynamic_notarization::seal(&mut notarization, timelock::none(), &clock);
+
+    // Clean up - we won't reach this due to expected failure
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::ESealShortensRetention)]
+public fun test_seal_shortens_delete_lock() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let ctx = scenario.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let data = string::utf8(b"Retained Record");
+    let state = notarization::new_state_from_string(data, std::option::none());
+
+    dynamic_notarization::create_with_delete_lock(
+        state,
+        std::option::none(),
+        std::option::none(),
+        timelock::unlock_at(3000, &clock),
+        timelock::none(),
+        &clock,
+        ctx,
+    );
+
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_from_sender<notarization::Notarization<string::String>>();
+
+    // The new delete lock must not unlock before the current one - should fail
+    dynamic_notarization::seal(&mut notarization, timelock::unlock_at(2000, &clock), &clock);
+
+    // Clean up - we won't reach this due to expected failure
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module provides tests for the endorsement module
+#[test_only]
+module iota_notarization::endorsement_tests;
+
+use iota::{clock, test_scenario as ts};
+use iota_notarization::{
+    dynamic_notarization,
+    endorsement::{Self, Endorsement},
+    locked_notarization,
+    notarization::{Self, Notarization},
+    timelock
+};
+use std::{bcs, hash, string};
+
+const OWNER_ADDRESS: address = @0x01;
+const ENDORSER_ADDRESS: address = @0x02;
+
+#[test]
+public fun test_endorse_notarization() {
+    let mut scenario = ts::begin(OWNER_ADDRESS);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let state = notarization::new_state_from_string(string::utf8(b"Contract v1"), option::none());
+    let notarization = dynamic_notarization::new(
+        state,
+        option::none(),
+        option::none(),
+        timelock::none(),
+        &clock,
+        scenario.ctx(),
+    );
+    let notarization_id = object::id(&notarization);
+    let state_hash = hash::sha2_256(bcs::to_bytes(notarization::state(&notarization)));
+    assert!(endorsement::hash_state(&notarization) == state_hash, 0);
+    notarization::transfer_notarization(notarization, OWNER_ADDRESS);
+
+    scenario.next_tx(OWNER_ADDRESS);
+    endorsement::endorse(notarization_id, 0, state_hash, &clock, scenario.ctx());
+
+    scenario.next_tx(OWNER_ADDRESS);
+    let endorsement = scenario.take_from_address<Endorsement>(notarization_id.to_address());
+
+    assert!(endorsement::notarization_id(&endorsement) == notarization_id, 0);
+    assert!(endorsement::endorser(&endorsement) == OWNER_ADDRESS, 0);
+    assert!(endorsement::state_version_count(&endorsement) == 0, 0);
+    assert!(endorsement::state_hash(&endorsement) == state_hash, 0);
+    assert!(endorsement::endorsed_at(&endorsement) == 1000000, 0);
+
+    ts::return_to_address(notarization_id.to_address(), endorsement);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_endorse_notarization_owned_by_other_address() {
+    let mut scenario = ts::begin(OWNER_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    let state = notarization::new_state_from_string(string::utf8(b"Contract v1"), option::none());
+    locked_notarization::create(
+        state,
+        option::none(),
+        option::none(),
+        timelock::none(),
+        &clock,
+        scenario.ctx(),
+    );
+
+    scenario.next_tx(OWNER_ADDRESS);
+    let notarization = scenario.take_from_sender<Notarization<string::String>>();
+    let notarization_id = object::id(&notarization);
+    let state_hash = endorsement::hash_state(&notarization);
+    scenario.return_to_sender(notarization);
+
+    // Endorse as a party that cannot access the owned notarization
+    scenario.next_tx(ENDORSER_ADDRESS);
+    endorsement::endorse(notarization_id, 0, state_hash, &clock, scenario.ctx());
+
+    scenario.next_tx(ENDORSER_ADDRESS);
+    let endorsement = scenario.take_from_address<Endorsement>(notarization_id.to_address());
+
+    assert!(endorsement::notarization_id(&endorsement) == notarization_id, 0);
+    assert!(endorsement::endorser(&endorsement) == ENDORSER_ADDRESS, 0);
+    assert!(endorsement::state_version_count(&endorsement) == 0, 0);
+    assert!(endorsement::state_hash(&endorsement) == state_hash, 0);
+
+    ts::return_to_address(notarization_id.to_address(), endorsement);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = endorsement::EInvalidStateHash)]
+public fun test_endorse_rejects_invalid_state_hash() {
+    let mut scenario = ts::begin(ENDORSER_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+    let notarization_id = object::id_from_address(@0x42);
+
+    // This should fail because the state hash is not 32 bytes long
+    endorsement::endorse(notarization_id, 0, b"not a hash", &clock, scenario.ctx());
+
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
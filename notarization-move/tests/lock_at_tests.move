@@ -0,0 +1,83 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module provides tests for the lock_at module
+#[test_only]
+module iota_notarization::lock_at_tests;
+
+use iota::{clock, test_scenario::{Self as ts, ctx}};
+use iota_notarization::lock_at;
+
+const ADMIN_ADDRESS: address = @0x01;
+
+#[test]
+public fun test_new_lock_at() {
+    let mut ts = ts::begin(ADMIN_ADDRESS);
+    let ctx = ts.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let lock = lock_at::new(1001, &clock);
+
+    assert!(lock.lock_time() == 1001);
+    assert!(!lock.is_locked(&clock));
+
+    // Advance time by setting a new timestamp
+    clock::increment_for_testing(&mut clock, 1000);
+
+    assert!(lock.is_locked(&clock));
+
+    // Once locked, the lock stays locked
+    clock::increment_for_testing(&mut clock, 1000000);
+    assert!(lock.is_locked(&clock));
+
+    clock::destroy_for_testing(clock);
+
+    ts.end();
+}
+
+#[test]
+#[expected_failure(abort_code = lock_at::EPastTimestamp)]
+public fun test_new_lock_at_past_time() {
+    let mut ts = ts::begin(ADMIN_ADDRESS);
+    let ctx = ts.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    // Try to create a LockAt with a timestamp in the past
+    let _lock = lock_at::new(999, &clock);
+
+    // This should never be reached
+    clock::destroy_for_testing(clock);
+
+    ts.end();
+}
+
+#[test]
+public fun test_attach_and_detach() {
+    let mut ts = ts::begin(ADMIN_ADDRESS);
+    let ctx = ts.ctx();
+
+    let mut clock = clock::create_for_testing(ctx);
+    clock::set_for_testing(&mut clock, 1000000);
+
+    let mut id = object::new(ctx);
+    assert!(!lock_at::is_attached(&id));
+
+    lock_at::attach(&mut id, lock_at::new(2000, &clock));
+    assert!(lock_at::is_attached(&id));
+    assert!(lock_at::borrow(&id).lock_time() == 2000);
+
+    lock_at::detach(&mut id);
+    assert!(!lock_at::is_attached(&id));
+
+    // Detaching without an attached LockAt is a no-op
+    lock_at::detach(&mut id);
+
+    object::delete(id);
+    clock::destroy_for_testing(clock);
+
+    ts.end();
+}
@@ -462,7 +462,8 @@ public fun test_notarization_invariants() {
         std::option::none(),
     );
 
-    assert!(notarization::are_dynamic_notarization_invariants_ok(&immutable_metadata2), 0);
+    assert!(notarization::are_dynamic_notarization_invariants_ok(&immutable_metadata2, false), 0);
+    assert!(!notarization::are_dynamic_notarization_invariants_ok(&immutable_metadata2, true), 0);
 
     notarization::destroy_immutable_metadata(immutable_metadata, &clock);
     notarization::destroy_immutable_metadata(immutable_metadata2, &clock);
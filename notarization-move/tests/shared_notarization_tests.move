@@ -0,0 +1,334 @@
+// Copyright (c) 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module provides tests for the shared_notarization module
+#[test_only]
+module iota_notarization::shared_notarization_tests;
+
+use iota::{clock, test_scenario as ts};
+use iota_notarization::{
+    notarization::{Self, Notarization},
+    shared_notarization::{Self, Capability},
+    timelock
+};
+use std::string::{Self, String};
+
+const ADMIN_ADDRESS: address = @0x01;
+const WRITER_ADDRESS: address = @0x02;
+
+fun create_shared_notarization(scenario: &mut ts::Scenario, clock: &clock::Clock) {
+    let state = notarization::new_state_from_string(string::utf8(b"Status: pending"), option::none());
+
+    shared_notarization::create(
+        state,
+        option::some(string::utf8(b"Shipment status")),
+        option::none(),
+        timelock::none(),
+        timelock::none(),
+        clock,
+        scenario.ctx(),
+    );
+}
+
+#[test]
+public fun test_create_shared_notarization() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock::set_for_testing(&mut clock, 1000000);
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let cap = scenario.take_from_sender<Capability>();
+
+    let method = notarization::notarization_method(&notarization);
+    assert!(method.is_locked(), 0);
+    assert!(notarization.is_shared(), 0);
+    assert!(notarization.is_destroy_allowed(&clock), 0);
+    assert!(shared_notarization::is_capability_valid(&notarization, &cap), 0);
+    assert!(shared_notarization::capability_role(&cap).is_admin(), 0);
+    assert!(notarization.is_transfer_locked(&clock), 0);
+    assert!(!notarization.is_update_locked(&clock), 0);
+
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: shipped"), option::none());
+    shared_notarization::update_state(&mut notarization, &cap, new_state, &clock);
+    shared_notarization::update_metadata(
+        &mut notarization,
+        &cap,
+        option::some(string::utf8(b"Carrier: ACME")),
+        &clock,
+    );
+
+    assert!(notarization::version_count(&notarization) == 1, 0);
+    assert!(notarization::state(&notarization) == &new_state, 0);
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_writer_capability_updates_and_destroy() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    // Issue a writer capability
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::issue_capability(
+        &mut notarization,
+        &admin_cap,
+        shared_notarization::new_writer_role(),
+        WRITER_ADDRESS,
+        scenario.ctx(),
+    );
+    ts::return_shared(notarization);
+    scenario.return_to_sender(admin_cap);
+
+    // Update with the writer capability
+    scenario.next_tx(WRITER_ADDRESS);
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let writer_cap = scenario.take_from_sender<Capability>();
+    assert!(!shared_notarization::capability_role(&writer_cap).is_admin(), 0);
+
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: delivered"), option::none());
+    shared_notarization::update_state(&mut notarization, &writer_cap, new_state, &clock);
+    assert!(notarization::version_count(&notarization) == 1, 0);
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(writer_cap);
+
+    // Destroy with the admin capability
+    scenario.next_tx(ADMIN_ADDRESS);
+    let notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::destroy(notarization, &admin_cap, &clock);
+
+    shared_notarization::destroy_capability(admin_cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = shared_notarization::EAdminRoleRequired)]
+public fun test_writer_cannot_issue_capability() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::issue_capability(
+        &mut notarization,
+        &admin_cap,
+        shared_notarization::new_writer_role(),
+        WRITER_ADDRESS,
+        scenario.ctx(),
+    );
+    ts::return_shared(notarization);
+    scenario.return_to_sender(admin_cap);
+
+    scenario.next_tx(WRITER_ADDRESS);
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let writer_cap = scenario.take_from_sender<Capability>();
+
+    // This should fail because writers cannot issue capabilities
+    shared_notarization::issue_capability(
+        &mut notarization,
+        &writer_cap,
+        shared_notarization::new_admin_role(),
+        WRITER_ADDRESS,
+        scenario.ctx(),
+    );
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(writer_cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = shared_notarization::EInvalidCapability)]
+public fun test_revoked_capability_cannot_update() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::issue_capability(
+        &mut notarization,
+        &admin_cap,
+        shared_notarization::new_writer_role(),
+        WRITER_ADDRESS,
+        scenario.ctx(),
+    );
+    ts::return_shared(notarization);
+    scenario.return_to_sender(admin_cap);
+
+    // Revoke the writer capability
+    scenario.next_tx(ADMIN_ADDRESS);
+    let writer_cap_id = ts::most_recent_id_for_address<Capability>(WRITER_ADDRESS).destroy_some();
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::revoke_capability(&mut notarization, &admin_cap, writer_cap_id);
+    ts::return_shared(notarization);
+    scenario.return_to_sender(admin_cap);
+
+    scenario.next_tx(WRITER_ADDRESS);
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let writer_cap = scenario.take_from_sender<Capability>();
+    assert!(!shared_notarization::is_capability_valid(&notarization, &writer_cap), 0);
+
+    // This should fail because the capability was revoked
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: lost"), option::none());
+    shared_notarization::update_state(&mut notarization, &writer_cap, new_state, &clock);
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(writer_cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::ECapabilityRequired)]
+public fun test_update_shared_notarization_without_capability() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(WRITER_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+
+    // This should fail because Shared-Notarizations require a capability
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: lost"), option::none());
+    notarization::update_state(&mut notarization, new_state, &clock);
+
+    ts::return_shared(notarization);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+public fun test_admin_seals_shared_notarization() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock::set_for_testing(&mut clock, 1000000);
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let cap = scenario.take_from_sender<Capability>();
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: delivered"), option::none());
+    shared_notarization::update_state(&mut notarization, &cap, new_state, &clock);
+
+    shared_notarization::seal(&mut notarization, &cap, timelock::unlock_at(2000, &clock), &clock);
+
+    assert!(notarization.is_shared(), 0);
+    assert!(notarization.is_update_locked(&clock), 0);
+    assert!(notarization.is_delete_locked(&clock), 0);
+    assert!(!notarization.is_destroy_allowed(&clock), 0);
+    assert!(notarization::version_count(&notarization) == 1, 0);
+    assert!(notarization::state(&notarization) == &new_state, 0);
+    assert!(shared_notarization::is_capability_valid(&notarization, &cap), 0);
+
+    // The admin can still destroy the sealed notarization once its delete lock expired
+    clock::set_for_testing(&mut clock, 2000000);
+    shared_notarization::destroy(notarization, &cap, &clock);
+
+    shared_notarization::destroy_capability(cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::EUpdateWhileLocked)]
+public fun test_sealed_shared_notarization_cannot_be_updated() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let cap = scenario.take_from_sender<Capability>();
+    shared_notarization::seal(&mut notarization, &cap, timelock::none(), &clock);
+
+    // This should fail because the notarization is sealed
+    let new_state = notarization::new_state_from_string(string::utf8(b"Status: lost"), option::none());
+    shared_notarization::update_state(&mut notarization, &cap, new_state, &clock);
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = shared_notarization::EAdminRoleRequired)]
+public fun test_writer_cannot_seal() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    create_shared_notarization(&mut scenario, &clock);
+    scenario.next_tx(ADMIN_ADDRESS);
+
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let admin_cap = scenario.take_from_sender<Capability>();
+    shared_notarization::issue_capability(
+        &mut notarization,
+        &admin_cap,
+        shared_notarization::new_writer_role(),
+        WRITER_ADDRESS,
+        scenario.ctx(),
+    );
+    ts::return_shared(notarization);
+    scenario.return_to_sender(admin_cap);
+
+    scenario.next_tx(WRITER_ADDRESS);
+    let mut notarization = scenario.take_shared<Notarization<String>>();
+    let writer_cap = scenario.take_from_sender<Capability>();
+
+    // This should fail because only the admin can seal
+    shared_notarization::seal(&mut notarization, &writer_cap, timelock::none(), &clock);
+
+    ts::return_shared(notarization);
+    scenario.return_to_sender(writer_cap);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = notarization::ENotSharedNotarization)]
+public fun test_seal_shared_rejects_other_notarizations() {
+    let mut scenario = ts::begin(ADMIN_ADDRESS);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    let state = notarization::new_state_from_string(string::utf8(b"Status: pending"), option::none());
+    let mut notarization = notarization::new_locked_notarization(
+        state,
+        option::none(),
+        option::none(),
+        timelock::none(),
+        &clock,
+        scenario.ctx(),
+    );
+
+    // This should fail because the notarization is not shared
+    notarization::seal_shared_notarization(&mut notarization, timelock::none(), &clock);
+
+    notarization::destroy(notarization, &clock);
+    clock::destroy_for_testing(clock);
+    scenario.end();
+}
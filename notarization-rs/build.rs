@@ -1,7 +1,9 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::path::PathBuf;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
 
 use product_common::move_history_manager::MoveHistoryManager;
 
@@ -26,4 +28,54 @@ fn main() {
 
     // Tell Cargo to rerun this build script if the Move.lock file changes.
     println!("cargo::rerun-if-changed={move_lock_path}");
+
+    let move_sources_path = "../notarization-move/sources";
+    write_abort_codes(Path::new(move_sources_path));
+    println!("cargo::rerun-if-changed={move_sources_path}");
+}
+
+/// Writes the `u64` error constants declared in the Move modules of `sources` to `abort_codes.rs` in `OUT_DIR`, so
+/// that abort codes are always resolved with the values of the package sources.
+fn write_abort_codes(sources: &Path) {
+    let mut paths: Vec<PathBuf> = fs::read_dir(sources)
+        .expect("Move sources are readable")
+        .map(|entry| entry.expect("Move sources are readable").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "move"))
+        .collect();
+    paths.sort();
+
+    let mut codes = String::new();
+    for path in paths {
+        let source = fs::read_to_string(&path).expect("Move sources are readable");
+        let Some(module) = source
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))
+            .and_then(|module| module.split("::").nth(1))
+            .map(|module| module.trim_end_matches([';', '{', ' ']))
+        else {
+            continue;
+        };
+        for (name, code) in source.lines().filter_map(error_constant) {
+            writeln!(codes, "    ({module:?}, {code}, {name:?}),").expect("writing to a string succeeds");
+        }
+    }
+
+    let generated = format!(
+        "/// The abort codes of the Notarization Package as `(module, code, name)`, generated from its Move sources.\n\
+         const ABORT_CODES: &[(&str, u64, &str)] = &[\n{codes}];\n"
+    );
+    let out_dir = env::var("OUT_DIR").expect("Cargo sets OUT_DIR");
+    fs::write(Path::new(&out_dir).join("abort_codes.rs"), generated).expect("OUT_DIR is writable");
+}
+
+/// Parses a declaration like `const EUpdateWhileLocked: u64 = 0;` into the name and value of the constant.
+fn error_constant(line: &str) -> Option<(&str, u64)> {
+    let (name, value) = line.trim().strip_prefix("const ")?.split_once(':')?;
+    let value = value.trim().strip_prefix("u64")?.trim().strip_prefix('=')?;
+    let name = name.trim();
+
+    name.starts_with('E')
+        .then(|| value.trim().trim_end_matches(';').trim().parse().ok())
+        .flatten()
+        .map(|code| (name, code))
 }
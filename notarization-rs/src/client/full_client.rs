@@ -8,7 +8,8 @@
 //! ## Overview
 //!
 //! This client extends [`NotarizationClientReadOnly`] with transaction capabilities,
-//! allowing you to create, update, transfer, and destroy notarizations.
+//! allowing you to create, update, transfer, seal, endorse, and destroy notarizations, and
+//! to administer the capabilities of Shared-Notarizations.
 //!
 //! ## Transaction Flow
 //!
@@ -88,9 +89,12 @@ use product_common::transaction::transaction_builder::TransactionBuilder;
 use secret_storage::Signer;
 
 use super::read_only::NotarizationClientReadOnly;
-use crate::core::builder::{Dynamic, Locked, NotarizationBuilder};
-use crate::core::transactions::{DestroyNotarization, TransferNotarization, UpdateMetadata, UpdateState};
-use crate::core::types::State;
+use crate::core::builder::{Dynamic, Locked, NotarizationBuilder, Shared};
+use crate::core::transactions::{
+    CreateNotarizationBatch, DestroyNotarization, EndorseNotarization, IssueCapability, RevokeCapability,
+    SealNotarization, TransferNotarization, UpdateMetadata, UpdateState,
+};
+use crate::core::types::{OnChainNotarization, Role, State, StateData, TimeLock};
 use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
@@ -189,7 +193,7 @@ impl<S> NotarizationClient<S> {
     ///
     /// See [`NotarizationBuilder<Locked>`] for configuration options.
     pub fn create_locked_notarization(&self) -> NotarizationBuilder<Locked> {
-        NotarizationBuilder::locked()
+        NotarizationBuilder::locked().with_retry_policy(*self.retry_policy())
     }
 
     /// Creates a builder for a Dynamic-Notarization.
@@ -198,7 +202,10 @@ impl<S> NotarizationClient<S> {
     /// `updatable_metadata` can be replaced via
     /// [`Self::update_state`] and [`Self::update_metadata`], and ownership
     /// can be transferred via [`Self::transfer_notarization`] when the
-    /// configured `transfer_lock` permits it.
+    /// configured `transfer_lock` permits it. An optional `delete_lock`
+    /// retains the notarization for a period while keeping it updatable, and
+    /// an optional `update_lock` restricts the window in which it is
+    /// updatable.
     ///
     /// On execution the resulting transaction transfers the new `Notarization`
     /// object to the sender and emits a `DynamicNotarizationCreated` event.
@@ -223,7 +230,91 @@ impl<S> NotarizationClient<S> {
     ///
     /// See [`NotarizationBuilder<Dynamic>`] for configuration options.
     pub fn create_dynamic_notarization(&self) -> NotarizationBuilder<Dynamic> {
-        NotarizationBuilder::dynamic()
+        NotarizationBuilder::dynamic().with_retry_policy(*self.retry_policy())
+    }
+
+    /// Creates a builder for a Shared-Notarization.
+    ///
+    /// A Shared-Notarization is a shared object whose `state` and
+    /// `updatable_metadata` can be updated by several parties via
+    /// [`Self::update_state_with_capability`] and
+    /// [`Self::update_metadata_with_capability`]. Every update must be
+    /// authorized by a valid capability issued for the notarization. A
+    /// Shared-Notarization cannot be transferred; an optional `update_lock`
+    /// restricts the window in which it is updatable and an optional
+    /// `delete_lock` retains it for a period.
+    ///
+    /// On execution the resulting transaction shares the new `Notarization`
+    /// object, transfers a capability with the [`Role::Admin`] role to the
+    /// sender and emits a `SharedNotarizationCreated` event.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let created = client
+    ///     .create_shared_notarization()
+    ///     .with_string_state("Shipment status: pending".to_string(), None)
+    ///     .with_immutable_description("Shipment 4711".to_string())
+    ///     .finish()
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// let admin_capability_id = created.admin_capability_id;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See [`NotarizationBuilder<Shared>`] for configuration options.
+    pub fn create_shared_notarization(&self) -> NotarizationBuilder<Shared> {
+        NotarizationBuilder::shared().with_retry_policy(*self.retry_policy())
+    }
+
+    /// Creates many notarizations at once, packing them into as few
+    /// programmable transactions as possible.
+    ///
+    /// Locked, Dynamic and Shared notarizations can be mixed in a
+    /// [`CreateNotarizationBatch`]. The batch is split into size-bounded
+    /// batches (see [`CreateNotarizationBatch::split`]); each returned
+    /// transaction outputs the [`OnChainNotarization`]s it created, in the
+    /// order they were added to the batch.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::builder::NotarizationBuilder;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let invoices = (1..=100)
+    ///     .map(|n| NotarizationBuilder::locked().with_string_state(format!("Invoice {n}"), None))
+    ///     .collect();
+    ///
+    /// for batch in client.create_batch(invoices)? {
+    ///     let notarizations = batch.build_and_execute(&client).await?.output;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if a notarization of the batch is invalid or exceeds
+    /// the transaction limits on its own, see
+    /// [`CreateNotarizationBatch::split`].
+    pub fn create_batch<D>(
+        &self,
+        batch: CreateNotarizationBatch<D>,
+    ) -> Result<Vec<TransactionBuilder<CreateNotarizationBatch<D>>>, Error>
+    where
+        D: StateData + Clone,
+    {
+        Ok(batch
+            .with_retry_policy(*self.retry_policy())
+            .split(self.read_client.package_id())?
+            .into_iter()
+            .map(TransactionBuilder::new)
+            .collect())
     }
 }
 
@@ -240,9 +331,11 @@ where
     /// Move event.
     ///
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+    /// * `Dynamic`: permitted unless the optional `update_lock` is currently active, e.g. during an embargo or after
+    ///   a freeze.
     /// * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to
     ///   `TimeLock::UntilDestroyed`.
+    /// * `Shared`: always aborts on-chain; use [`Self::update_state_with_capability`] instead.
     ///
     /// ## Parameters
     ///
@@ -269,7 +362,7 @@ where
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
     pub fn update_state(&self, new_state: State, notarization_id: ObjectId) -> TransactionBuilder<UpdateState> {
-        TransactionBuilder::new(UpdateState::new(new_state, notarization_id))
+        TransactionBuilder::new(UpdateState::new(new_state, notarization_id).with_retry_policy(*self.retry_policy()))
     }
 
     /// Destroys a notarization permanently and releases its object ID.
@@ -278,7 +371,8 @@ where
     /// destroyed in the process. The notarization must currently be
     /// destroy-allowed (see
     /// [`NotarizationClientReadOnly::is_destroy_allowed`]); otherwise the
-    /// on-chain transaction aborts.
+    /// on-chain transaction aborts. Shared-Notarizations can only be
+    /// destroyed via [`Self::destroy_with_capability`].
     ///
     /// On success the on-chain transaction emits a `NotarizationDestroyed`
     /// event.
@@ -303,7 +397,7 @@ where
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
     pub fn destroy(&self, notarization_id: ObjectId) -> TransactionBuilder<DestroyNotarization> {
-        TransactionBuilder::new(DestroyNotarization::new(notarization_id))
+        TransactionBuilder::new(DestroyNotarization::new(notarization_id).with_retry_policy(*self.retry_policy()))
     }
 
     /// Updates the `updatable_metadata` of a notarization.
@@ -313,9 +407,11 @@ where
     /// `immutable_metadata`.
     ///
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+    /// * `Dynamic`: permitted unless the optional `update_lock` is currently active, e.g. during an embargo or after
+    ///   a freeze.
     /// * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to
     ///   `TimeLock::UntilDestroyed`.
+    /// * `Shared`: always aborts on-chain; use [`Self::update_metadata_with_capability`] instead.
     ///
     /// ## Parameters
     ///
@@ -345,7 +441,7 @@ where
         metadata: Option<String>,
         notarization_id: ObjectId,
     ) -> TransactionBuilder<UpdateMetadata> {
-        TransactionBuilder::new(UpdateMetadata::new(metadata, notarization_id))
+        TransactionBuilder::new(UpdateMetadata::new(metadata, notarization_id).with_retry_policy(*self.retry_policy()))
     }
 
     /// Transfers ownership of a notarization to another address.
@@ -356,8 +452,8 @@ where
     /// Behaviour depends on the Notarization Method:
     /// * `Dynamic`: on success the notarization is transferred to `recipient`. Submitting while the configured
     ///   `transfer_lock` is engaged aborts on-chain.
-    /// * `Locked`: always aborts on-chain — Locked-Notarizations have their `transfer_lock` pinned to
-    ///   `TimeLock::UntilDestroyed` and are therefore non-transferable.
+    /// * `Locked` and `Shared`: always aborts on-chain — their `transfer_lock` is pinned to
+    ///   `TimeLock::UntilDestroyed`, so they are non-transferable.
     ///
     /// On success the on-chain transaction emits a
     /// `DynamicNotarizationTransferred` event.
@@ -388,7 +484,310 @@ where
         notarization_id: ObjectId,
         recipient: Address,
     ) -> TransactionBuilder<TransferNotarization> {
-        TransactionBuilder::new(TransferNotarization::new(recipient, notarization_id))
+        TransactionBuilder::new(
+            TransferNotarization::new(recipient, notarization_id).with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Seals a Dynamic-Notarization, converting it into a Locked-Notarization.
+    ///
+    /// The object ID, `state`, `updatable_metadata`, `created_at` and
+    /// `state_version_count` are preserved, so a drafted notarization can be
+    /// finalized without destroying and recreating it. Afterwards the
+    /// notarization can neither be updated nor transferred, and its
+    /// destruction is gated by `delete_lock`.
+    ///
+    /// Behaviour depends on the Notarization Method:
+    /// * `Dynamic`: sealed on success. Submitting while the configured `update_lock` is engaged, or with a
+    ///   `delete_lock` unlocking before an engaged `UnlockAt` `delete_lock` or `transfer_lock`, aborts on-chain.
+    /// * `Locked`: always aborts on-chain — the notarization is already sealed.
+    /// * `Shared`: always aborts on-chain, see [`Self::seal_with_capability`].
+    ///
+    /// On success the on-chain transaction emits a `NotarizationSealed`
+    /// event.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the notarization to seal
+    /// - `delete_lock`: The `delete_lock` of the sealed notarization; `TimeLock::UntilDestroyed` is not permitted
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::types::TimeLock;
+    /// # use iota_sdk_types::ObjectId;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, notarization_id: ObjectId) -> Result<(), Box<dyn std::error::Error>> {
+    /// client
+    ///     .seal(notarization_id, TimeLock::None)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn seal(&self, notarization_id: ObjectId, delete_lock: TimeLock) -> TransactionBuilder<SealNotarization> {
+        TransactionBuilder::new(
+            SealNotarization::new(notarization_id, delete_lock).with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Endorses the current `state` of a notarization on behalf of the
+    /// sender.
+    ///
+    /// The endorsement records the `state_version_count` and the hash of the
+    /// `state` the notarization holds when the transaction is built; the
+    /// notarization is not modified and not passed to the transaction, so any
+    /// address can endorse any notarization, including Locked- and
+    /// Dynamic-Notarizations owned by other addresses. The notarization must
+    /// hold a [`Data`](crate::core::types::Data) payload; use
+    /// [`Self::endorse_state_version`] for other payloads.
+    ///
+    /// The created endorsement is owned by the notarization and stays
+    /// evidence for the endorsed version after later `state` updates and after
+    /// the notarization is destroyed. Use
+    /// [`NotarizationClientReadOnly::verify_endorsements`] to check which
+    /// endorsements refer to the current `state`.
+    ///
+    /// On success the on-chain transaction emits a `NotarizationEndorsed`
+    /// event.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the notarization to endorse
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use iota_sdk_types::ObjectId;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, notarization_id: ObjectId) -> Result<(), Box<dyn std::error::Error>> {
+    /// client
+    ///     .endorse(notarization_id)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn endorse(&self, notarization_id: ObjectId) -> TransactionBuilder<EndorseNotarization> {
+        TransactionBuilder::new(EndorseNotarization::new(notarization_id).with_retry_policy(*self.retry_policy()))
+    }
+
+    /// Endorses version `state_version_count` of the `state` of a
+    /// notarization, whose hash is `state_hash`, on behalf of the sender.
+    ///
+    /// Behaves like [`Self::endorse`], but the endorsed version is passed in
+    /// instead of being read from the notarization, e.g. from a
+    /// [`StateVersion`](crate::core::types::StateVersion) the endorser
+    /// reviewed. The version is not checked on-chain; submitting with a
+    /// `state_hash` that is not 32 bytes long aborts on-chain.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the notarization to endorse
+    /// - `state_version_count`: The `state_version_count` of the endorsed `state`
+    /// - `state_hash`: The hash of the endorsed `state`, see [`State::hash`]
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn endorse_state_version(
+        &self,
+        notarization_id: ObjectId,
+        state_version_count: u64,
+        state_hash: Vec<u8>,
+    ) -> TransactionBuilder<EndorseNotarization> {
+        TransactionBuilder::new(
+            EndorseNotarization::new(notarization_id)
+                .with_state_version(state_version_count, state_hash)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Updates the state of a Shared-Notarization, authorized by a
+    /// capability.
+    ///
+    /// Behaves like [`Self::update_state`] for Dynamic-Notarizations: the
+    /// update is permitted unless the optional `update_lock` is currently
+    /// active. Submitting with a capability that was not issued for the
+    /// notarization or has been revoked aborts on-chain.
+    ///
+    /// ## Parameters
+    ///
+    /// - `new_state`: The new state to set
+    /// - `notarization_id`: The ID of the Shared-Notarization to update
+    /// - `capability_id`: The ID of the capability authorizing the update
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn update_state_with_capability(
+        &self,
+        new_state: State,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+    ) -> TransactionBuilder<UpdateState> {
+        TransactionBuilder::new(
+            UpdateState::new(new_state, notarization_id)
+                .with_capability(capability_id)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Updates the `updatable_metadata` of a Shared-Notarization, authorized
+    /// by a capability.
+    ///
+    /// Behaves like [`Self::update_metadata`] for Dynamic-Notarizations.
+    /// Submitting with a capability that was not issued for the notarization
+    /// or has been revoked aborts on-chain.
+    ///
+    /// ## Parameters
+    ///
+    /// - `metadata`: The new metadata (or `None` to clear)
+    /// - `notarization_id`: The ID of the Shared-Notarization to update
+    /// - `capability_id`: The ID of the capability authorizing the update
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn update_metadata_with_capability(
+        &self,
+        metadata: Option<String>,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+    ) -> TransactionBuilder<UpdateMetadata> {
+        TransactionBuilder::new(
+            UpdateMetadata::new(metadata, notarization_id)
+                .with_capability(capability_id)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Seals a Shared-Notarization, authorized by an admin capability.
+    ///
+    /// The `update_lock` of the notarization becomes
+    /// `TimeLock::UntilDestroyed` and its `delete_lock` is replaced by
+    /// `delete_lock`, so no capability can update it anymore. The
+    /// notarization stays shared and its method stays `Shared`; it can still
+    /// be destroyed with an admin capability once `delete_lock` allows it.
+    /// Submitting while the `update_lock` is active, or with a `delete_lock`
+    /// unlocking before an engaged `UnlockAt` `delete_lock`, aborts on-chain.
+    ///
+    /// On success the on-chain transaction emits a `NotarizationSealed`
+    /// event.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization to seal
+    /// - `capability_id`: The ID of a capability with the [`Role::Admin`] role
+    /// - `delete_lock`: The new `delete_lock`; `TimeLock::UntilDestroyed` is not permitted
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn seal_with_capability(
+        &self,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+        delete_lock: TimeLock,
+    ) -> TransactionBuilder<SealNotarization> {
+        TransactionBuilder::new(
+            SealNotarization::new(notarization_id, delete_lock)
+                .with_capability(capability_id)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Destroys a Shared-Notarization, authorized by an admin capability.
+    ///
+    /// The notarization must currently be destroy-allowed, see
+    /// [`Self::destroy`]. Capabilities issued for the notarization are not
+    /// destroyed.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization to destroy
+    /// - `capability_id`: The ID of a capability with the [`Role::Admin`] role
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn destroy_with_capability(
+        &self,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+    ) -> TransactionBuilder<DestroyNotarization> {
+        TransactionBuilder::new(
+            DestroyNotarization::new(notarization_id)
+                .with_capability(capability_id)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Issues a new capability for a Shared-Notarization and transfers it to
+    /// `recipient`.
+    ///
+    /// Must be authorized by a valid capability with the [`Role::Admin`]
+    /// role; otherwise the on-chain transaction aborts.
+    ///
+    /// On success the on-chain transaction emits a `CapabilityIssued` event,
+    /// which carries the ID of the new capability.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization
+    /// - `capability_id`: The ID of the admin capability authorizing the transaction
+    /// - `role`: The role granted by the new capability
+    /// - `recipient`: The address the new capability is transferred to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::types::Role;
+    /// # use iota_sdk_types::{Address, ObjectId};
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, notarization_id: ObjectId, admin_capability_id: ObjectId, carrier: Address) -> Result<(), Box<dyn std::error::Error>> {
+    /// let issued = client
+    ///     .issue_capability(notarization_id, admin_capability_id, Role::Writer, carrier)
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn issue_capability(
+        &self,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+        role: Role,
+        recipient: Address,
+    ) -> TransactionBuilder<IssueCapability> {
+        TransactionBuilder::new(
+            IssueCapability::new(notarization_id, capability_id, role, recipient)
+                .with_retry_policy(*self.retry_policy()),
+        )
+    }
+
+    /// Revokes a capability of a Shared-Notarization.
+    ///
+    /// Must be authorized by a valid capability with the [`Role::Admin`]
+    /// role; otherwise the on-chain transaction aborts. A revoked capability
+    /// can no longer authorize any operation.
+    ///
+    /// On success the on-chain transaction emits a `CapabilityRevoked` event.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization
+    /// - `capability_id`: The ID of the admin capability authorizing the transaction
+    /// - `revoked_capability_id`: The ID of the capability to revoke
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn revoke_capability(
+        &self,
+        notarization_id: ObjectId,
+        capability_id: ObjectId,
+        revoked_capability_id: ObjectId,
+    ) -> TransactionBuilder<RevokeCapability> {
+        TransactionBuilder::new(
+            RevokeCapability::new(notarization_id, capability_id, revoked_capability_id)
+                .with_retry_policy(*self.retry_policy()),
+        )
     }
 }
 
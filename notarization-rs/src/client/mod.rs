@@ -6,6 +6,10 @@
 //! This module provides two client types:
 //! - [`read_only`]: Read-only access to notarization data
 //! - [`full_client`]: Full read-write access with transaction capabilities
+//!
+//! Notarizations can be followed via the event streams of [`watch`], and
+//! transactions can be signed on another host via [`offline`]. Failed RPC
+//! calls are retried according to a [`retry`] policy.
 
 use iota_interaction::IotaClientTrait;
 use product_common::network_name::NetworkName;
@@ -14,10 +18,18 @@ use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
 pub mod full_client;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod offline;
 pub mod read_only;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watch;
 
 pub use full_client::*;
+pub use iota_rpc_retry as retry;
 pub use read_only::*;
+pub use retry::RetryPolicy;
+#[cfg(not(target_arch = "wasm32"))]
+pub use watch::*;
 
 /// Returns the network-id also known as chain-identifier provided by the specified iota_client
 async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Error> {
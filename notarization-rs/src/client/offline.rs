@@ -0,0 +1,339 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Offline Signing
+//!
+//! Two-phase transaction flow for signers that cannot be attached to a
+//! [`NotarizationClient`](super::NotarizationClient), e.g. keys held by an HSM
+//! on an air-gapped host.
+//!
+//! ## Overview
+//!
+//! 1. On the online host, [`OfflineTransaction::build`] turns any
+//!    [`TransactionBuilder`] into unsigned transaction data. Gas payment, gas
+//!    price and gas budget are supplied explicitly, so nothing has to be
+//!    estimated or selected on behalf of the signer. Only a
+//!    [`NotarizationClientReadOnly`](super::NotarizationClientReadOnly) is
+//!    required.
+//! 2. The [`UnsignedTransaction`] is exported with
+//!    [`UnsignedTransaction::to_json`] and moved to the signing host.
+//! 3. The signing host signs it, e.g. with [`UnsignedTransaction::sign`], and
+//!    returns the [`DetachedSignature`] as JSON.
+//! 4. Back on the online host, [`OfflineTransaction::submit`] executes the
+//!    signed transaction and applies its effects, returning the same typed
+//!    output as `build_and_execute`. If the online host was restarted in the
+//!    meantime, [`OfflineTransaction::from_unsigned`] pairs the exported
+//!    transaction data with a new [`Transaction`] of the same kind.
+//!
+//! ```rust,ignore
+//! # use notarization::client::offline::{DetachedSignature, GasConfig, OfflineTransaction};
+//! # use notarization::client::NotarizationClientReadOnly;
+//! # use notarization::core::types::State;
+//! # async fn example(client: &NotarizationClientReadOnly, notarization_id: iota_sdk_types::ObjectId, sender: iota_sdk_types::Address, gas: GasConfig) -> Result<(), Box<dyn std::error::Error>> {
+//! let builder = TransactionBuilder::new(UpdateState::new(State::from_string("v2".to_string(), None), notarization_id));
+//! let offline = OfflineTransaction::build(builder, client, sender, gas).await?;
+//! std::fs::write("update.tx.json", offline.unsigned().to_json()?)?;
+//!
+//! // ... sign `update.tx.json` on the air-gapped host ...
+//!
+//! let signature = DetachedSignature::from_json(&std::fs::read_to_string("update.sig.json")?)?;
+//! let updated = offline.submit(client, signature).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockResponseOptions};
+use iota_interaction::types::base_types::ObjectRef;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::quorum_driver_types::ExecuteTransactionRequestType;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSend, OptionalSync};
+use iota_sdk_types::Address;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Version of the JSON format of [`UnsignedTransaction`] and [`DetachedSignature`].
+pub const OFFLINE_FORMAT_VERSION: u32 = 1;
+
+/// The gas parameters of a transaction built for offline signing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasConfig {
+    /// The gas coins paying for the transaction, owned by the sender.
+    pub payment: Vec<ObjectRef>,
+    /// The gas price, e.g. the reference gas price of the current epoch.
+    pub price: u64,
+    /// The maximum amount of gas the transaction may consume.
+    pub budget: u64,
+}
+
+/// Unsigned transaction data in a portable format.
+///
+/// Serialized as JSON by [`Self::to_json`]; the embedded
+/// [`TransactionData`] is exactly the data that has to be signed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTransaction {
+    version: u32,
+    tx_data: TransactionData,
+}
+
+impl UnsignedTransaction {
+    /// Creates a new [`UnsignedTransaction`] from transaction data.
+    pub fn new(tx_data: TransactionData) -> Self {
+        Self {
+            version: OFFLINE_FORMAT_VERSION,
+            tx_data,
+        }
+    }
+
+    /// Returns the transaction data to sign.
+    pub fn tx_data(&self) -> &TransactionData {
+        &self.tx_data
+    }
+
+    /// Serializes the transaction to JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Error::InvalidArgument(format!("failed to serialize unsigned transaction: {e}")))
+    }
+
+    /// Deserializes a transaction serialized by [`Self::to_json`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `json` is malformed or was written in an
+    /// unsupported format version.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let unsigned: Self = serde_json::from_str(json)
+            .map_err(|e| Error::InvalidArgument(format!("failed to deserialize unsigned transaction: {e}")))?;
+        check_version(unsigned.version)?;
+
+        Ok(unsigned)
+    }
+
+    /// Signs the transaction with `signer`.
+    ///
+    /// Convenience for signing hosts that can run this crate; any signer
+    /// producing an IOTA user signature over the transaction data can be
+    /// used instead, see [`DetachedSignature::new`].
+    pub async fn sign<S>(&self, signer: &S) -> Result<DetachedSignature, Error>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let signature = signer
+            .sign(&self.tx_data)
+            .await
+            .map_err(|e| Error::Signing(e.to_string()))?;
+
+        Ok(DetachedSignature::new(signature))
+    }
+}
+
+/// A signature of an [`UnsignedTransaction`], created apart from the
+/// transaction data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetachedSignature {
+    version: u32,
+    signature: Signature,
+}
+
+impl DetachedSignature {
+    /// Creates a new [`DetachedSignature`].
+    pub fn new(signature: Signature) -> Self {
+        Self {
+            version: OFFLINE_FORMAT_VERSION,
+            signature,
+        }
+    }
+
+    /// Returns the signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Serializes the signature to JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Error::InvalidArgument(format!("failed to serialize signature: {e}")))
+    }
+
+    /// Deserializes a signature serialized by [`Self::to_json`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `json` is malformed or was written in an
+    /// unsupported format version.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let signature: Self = serde_json::from_str(json)
+            .map_err(|e| Error::InvalidArgument(format!("failed to deserialize signature: {e}")))?;
+        check_version(signature.version)?;
+
+        Ok(signature)
+    }
+}
+
+/// A transaction built for offline signing, together with the [`Transaction`]
+/// that applies its effects after submission.
+///
+/// The wrapped [`Transaction`] is not serialized. Keep the
+/// [`OfflineTransaction`] on the online host until the signature is
+/// available, or recreate it with [`Self::from_unsigned`] from the exported
+/// [`UnsignedTransaction`]; building it again would produce different
+/// transaction data, e.g. because object versions changed, which the
+/// signature does not match.
+pub struct OfflineTransaction<Tx> {
+    tx: Tx,
+    unsigned: UnsignedTransaction,
+}
+
+impl<Tx> OfflineTransaction<Tx>
+where
+    Tx: Transaction<Error = Error> + OptionalSend + OptionalSync,
+{
+    /// Builds the unsigned transaction data of `builder`.
+    ///
+    /// Gas settings configured on `builder` are ignored in favor of `gas`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `builder`: The transaction to build, e.g. from [`NotarizationClient::update_state`](super::NotarizationClient::update_state)
+    /// - `client`: A client used to resolve the objects referenced by the transaction
+    /// - `sender`: The address of the offline signer
+    /// - `gas`: The gas payment, price and budget of the transaction
+    pub async fn build<C>(
+        builder: TransactionBuilder<Tx>,
+        client: &C,
+        sender: Address,
+        gas: GasConfig,
+    ) -> Result<Self, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if gas.payment.is_empty() {
+            return Err(Error::InvalidArgument("at least one gas coin is required".to_string()));
+        }
+
+        let tx = builder.into_inner();
+        let programmable_tx = tx.build_programmable_transaction(client).await?;
+        let tx_data = TransactionData::new_programmable(sender, gas.payment, programmable_tx, gas.budget, gas.price);
+
+        Ok(Self {
+            tx,
+            unsigned: UnsignedTransaction::new(tx_data),
+        })
+    }
+
+    /// Pairs the exported transaction data `unsigned` with `tx`, e.g. after
+    /// the online host was restarted.
+    ///
+    /// `tx` is only used to apply the effects of the executed transaction and
+    /// is never built, so it must be of the same kind as the transaction
+    /// `unsigned` was built from, e.g. an [`UpdateState`](crate::core::transactions::UpdateState)
+    /// for an exported state update; its parameters are not compared with
+    /// `unsigned`.
+    pub fn from_unsigned(tx: Tx, unsigned: UnsignedTransaction) -> Self {
+        Self { tx, unsigned }
+    }
+
+    /// Returns the unsigned transaction data to export to the signer.
+    pub fn unsigned(&self) -> &UnsignedTransaction {
+        &self.unsigned
+    }
+
+    /// Executes the transaction with `signature` and applies its effects.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the node rejects the transaction, e.g. because
+    /// `signature` does not match the transaction data or a gas coin was
+    /// spent in the meantime, or the [`Error`] of its abort if the transaction
+    /// failed on-chain.
+    pub async fn submit<C>(self, client: &C, signature: DetachedSignature) -> Result<Tx::Output, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let response = client
+            .client_adapter()
+            .quorum_driver_api()
+            .execute_transaction_block(
+                self.unsigned.tx_data,
+                vec![signature.signature],
+                Some(IotaTransactionBlockResponseOptions::full_content()),
+                Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to execute transaction: {e}")))?;
+
+        let mut effects: IotaTransactionBlockEffects = response
+            .effects()
+            .cloned()
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("missing transaction effects".to_string()))?;
+        let mut events = response.events().cloned().unwrap_or_default();
+
+        self.tx.apply_with_events(&mut effects, &mut events, client).await
+    }
+}
+
+fn check_version(version: u32) -> Result<(), Error> {
+    if version != OFFLINE_FORMAT_VERSION {
+        return Err(Error::InvalidArgument(format!(
+            "unsupported offline transaction format version {version}, expected {OFFLINE_FORMAT_VERSION}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::random_object_ref;
+    use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+    use product_common::test_utils::InMemSigner;
+
+    use super::*;
+
+    async fn make_unsigned(signer: &InMemSigner) -> UnsignedTransaction {
+        let sender = Address::from(&signer.public_key().await.unwrap());
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        ptb.pure(42u64).unwrap();
+        let tx_data =
+            TransactionData::new_programmable(sender, vec![random_object_ref()], ptb.finish(), 10_000_000, 1000);
+
+        UnsignedTransaction::new(tx_data)
+    }
+
+    #[tokio::test]
+    async fn unsigned_transaction_round_trips_through_json() {
+        let signer = InMemSigner::new();
+        let unsigned = make_unsigned(&signer).await;
+
+        let json = unsigned.to_json().unwrap();
+
+        assert_eq!(UnsignedTransaction::from_json(&json).unwrap(), unsigned);
+    }
+
+    #[tokio::test]
+    async fn detached_signature_round_trips_through_json() {
+        let signer = InMemSigner::new();
+        let unsigned = UnsignedTransaction::from_json(&make_unsigned(&signer).await.to_json().unwrap()).unwrap();
+
+        let signature = unsigned.sign(&signer).await.unwrap();
+        let json = signature.to_json().unwrap();
+
+        assert_eq!(DetachedSignature::from_json(&json).unwrap(), signature);
+        assert_eq!(signature, unsigned.sign(&signer).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn from_json_rejects_unknown_versions() {
+        let signer = InMemSigner::new();
+        let mut json: serde_json::Value =
+            serde_json::from_str(&make_unsigned(&signer).await.to_json().unwrap()).unwrap();
+        json["version"] = serde_json::json!(OFFLINE_FORMAT_VERSION + 1);
+
+        assert!(UnsignedTransaction::from_json(&json.to_string()).is_err());
+    }
+}
@@ -7,23 +7,47 @@
 //! on the IOTA network without requiring signing capabilities.
 
 use std::ops::Deref;
+use std::str::FromStr;
 
+#[cfg(not(target_arch = "wasm32"))]
+use futures::Stream;
 #[cfg(not(target_arch = "wasm32"))]
 use iota_interaction::IotaClient;
 use iota_interaction::IotaClientTrait;
+use iota_interaction::rpc_types::{
+    DevInspectResults, EventFilter, EventPage, IotaEvent, IotaObjectDataFilter, IotaObjectDataOptions,
+    IotaObjectResponseQuery, IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponseOptions, ObjectsPage,
+};
+use iota_interaction::types::IOTA_CLOCK_OBJECT_ID;
+use iota_interaction::types::event::EventID;
+use iota_interaction::types::id::UID;
 #[cfg(target_arch = "wasm32")]
 use iota_interaction_ts::bindings::WasmIotaClient;
-use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction, TransactionKind};
+use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction, StructTag, TransactionKind};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::network_name::NetworkName;
 use product_common::package_registry::Env;
+use serde::Deserialize;
 use serde::de::DeserializeOwned;
 
 use super::network_id;
+use super::retry::RetryPolicy;
+#[cfg(not(target_arch = "wasm32"))]
+use super::watch::{self, WatchOptions, WatchedEvent};
+use crate::core::builder::NotarizationBuilder;
 use crate::core::move_utils;
 use crate::core::operations::{NotarizationImpl, NotarizationOperations};
-use crate::core::transactions::get_notarization_with_owner;
-use crate::core::types::{Data, LockMetadata, NotarizationMethod, OnChainNotarization, State};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::stream::{self, HashProgress};
+use crate::core::transactions::{
+    get_notarization_with_owner, notarization_from_object_data, resolve_all_locks, resolve_lock_fields,
+};
+use crate::core::types::event::is_notarization_updated;
+use crate::core::types::state::DataType;
+use crate::core::types::{
+    Data, Endorsement, LockMetadata, LockStatus, NotarizationFilter, NotarizationMethod, OnChainNotarization, State,
+    StateData, StateVersion, VerifiedEndorsement, event_notarization_id,
+};
 use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
 use crate::package;
@@ -41,9 +65,13 @@ pub struct NotarizationClientReadOnly {
     /// The [`ObjectId`] of the deployed Notarization Package (smart contract).
     /// All interactions go through this package ID.
     notarization_pkg_id: ObjectId,
+    /// The IDs of all versions of the Notarization Package, oldest first.
+    package_versions: Vec<ObjectId>,
     /// The name of the network this client is connected to (e.g., "mainnet", "testnet").
     network: NetworkName,
     chain_id: String,
+    /// The policy for retrying failed reads.
+    retry_policy: RetryPolicy,
 }
 
 impl Deref for NotarizationClientReadOnly {
@@ -72,6 +100,31 @@ impl NotarizationClientReadOnly {
         &self.chain_id
     }
 
+    /// Returns the ID of the first version of the Notarization Package.
+    ///
+    /// Move types keep the ID of the package version that introduced them, so
+    /// objects and events of the types of the first version are queried by
+    /// this ID rather than by [`CoreClientReadOnly::package_id`].
+    pub(crate) fn original_package_id(&self) -> ObjectId {
+        self.package_versions[0]
+    }
+
+    /// Returns the policy for retrying failed reads.
+    pub const fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Sets the policy for retrying failed reads.
+    ///
+    /// The transactions of a [`NotarizationClient`](crate::client::NotarizationClient)
+    /// wrapping this client retry the object lookups made while building and
+    /// applying them according to the same policy. See [`RetryPolicy`] for the
+    /// calls that are never retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Attempts to create a new [`NotarizationClientReadOnly`] from a given IOTA client.
     ///
     /// # Failures
@@ -108,7 +161,7 @@ impl NotarizationClientReadOnly {
     /// * `network`: The name of the network.
     async fn new_internal(iota_client: IotaClientAdapter, network: NetworkName) -> Result<Self, Error> {
         let chain_id = network.as_ref().to_string();
-        let (network, notarization_pkg_id) = {
+        let (network, notarization_pkg_id, package_versions) = {
             let package_registry = package::notarization_package_registry().await;
             let package_id = package_registry
         .package_id(&network)
@@ -117,6 +170,10 @@ impl NotarizationClientReadOnly {
             "no information for a published `notarization` package on network {network}; try to use `NotarizationClientReadOnly::new_with_package_id`"
             ))
         })?;
+            let package_versions = package_registry
+                .history(&network)
+                .map(<[ObjectId]>::to_vec)
+                .unwrap_or_else(|| vec![package_id]);
             let network = match chain_id.as_str() {
                 product_common::package_registry::MAINNET_CHAIN_ID => {
                     NetworkName::try_from("iota").expect("valid network name")
@@ -127,13 +184,15 @@ impl NotarizationClientReadOnly {
                     .unwrap_or(network),
             };
 
-            (network, package_id)
+            (network, package_id, package_versions)
         };
         Ok(NotarizationClientReadOnly {
             iota_client,
             notarization_pkg_id,
+            package_versions,
             network,
             chain_id,
+            retry_policy: RetryPolicy::default(),
         })
     }
 
@@ -178,12 +237,153 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the [`OnChainNotarization`] or an [`Error`].
     pub async fn get_notarization_by_id(&self, notarized_object_id: ObjectId) -> Result<OnChainNotarization, Error> {
-        let (mut notarization, address) = get_notarization_with_owner(self, &notarized_object_id).await?;
+        self.get_notarization_by_id_as(notarized_object_id).await
+    }
+
+    /// Retrieves the [`OnChainNotarization`] of a notarized object holding a
+    /// payload of type `D`.
+    ///
+    /// Use this for notarizations created via
+    /// [`NotarizationBuilder::with_generic_state`](crate::core::builder::NotarizationBuilder::with_generic_state).
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`OnChainNotarization<D>`] or an [`Error`].
+    pub async fn get_notarization_by_id_as<D: StateData>(
+        &self,
+        notarized_object_id: ObjectId,
+    ) -> Result<OnChainNotarization<D>, Error> {
+        let (mut notarization, address) =
+            get_notarization_with_owner(self, &notarized_object_id, &self.retry_policy).await?;
         notarization.owner = address;
 
         Ok(notarization)
     }
 
+    /// Retrieves the [`OnChainNotarization`]s of many notarized objects.
+    ///
+    /// Instead of one request per object, the objects are fetched with
+    /// multi-get queries of up to [`MULTI_GET_LIMIT`] objects each.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_ids`: The [`ObjectId`]s of the notarized objects.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`OnChainNotarization`]s, in the order of
+    /// `notarized_object_ids`, or an [`Error`] if any of the objects cannot be fetched.
+    pub async fn get_notarizations(
+        &self,
+        notarized_object_ids: &[ObjectId],
+    ) -> Result<Vec<OnChainNotarization>, Error> {
+        self.get_notarizations_as(notarized_object_ids).await
+    }
+
+    /// Retrieves the [`OnChainNotarization`]s of many notarized objects
+    /// holding a payload of type `D`.
+    ///
+    /// See [`Self::get_notarizations`] and [`Self::get_notarization_by_id_as`].
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_ids`: The [`ObjectId`]s of the notarized objects.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`OnChainNotarization<D>`]s, in the order of
+    /// `notarized_object_ids`, or an [`Error`] if any of the objects cannot be fetched.
+    pub async fn get_notarizations_as<D: StateData>(
+        &self,
+        notarized_object_ids: &[ObjectId],
+    ) -> Result<Vec<OnChainNotarization<D>>, Error> {
+        let objects = move_utils::multi_get_objects(
+            self,
+            notarized_object_ids,
+            IotaObjectDataOptions::bcs_lossless().with_owner(),
+            &self.retry_policy,
+        )
+        .await?;
+
+        let mut notarizations = objects
+            .into_iter()
+            .map(|data| {
+                let (mut notarization, address) = notarization_from_object_data(data)?;
+                notarization.owner = address;
+                Ok(notarization)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        resolve_all_locks(self, &mut notarizations, &self.retry_policy).await?;
+
+        Ok(notarizations)
+    }
+
+    /// Lists all notarizations owned by `owner` that match `filter`.
+    ///
+    /// Pages through the objects owned by `owner`, selecting
+    /// `Notarization<vector<u8>>` and `Notarization<String>` objects. Objects
+    /// holding a generic payload (see
+    /// [`NotarizationBuilder::with_generic_state`](crate::core::builder::NotarizationBuilder::with_generic_state))
+    /// are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: The [`Address`] owning the notarizations.
+    /// * `filter`: The [`NotarizationFilter`] the returned notarizations must match.
+    ///
+    /// # Returns
+    /// A `Result` containing the matching [`OnChainNotarization`]s or an [`Error`].
+    pub async fn list_owned_notarizations(
+        &self,
+        owner: Address,
+        filter: NotarizationFilter,
+    ) -> Result<Vec<OnChainNotarization>, Error> {
+        let data_tags = [Data::Bytes(vec![]).tag(), Data::Text(String::new()).tag()];
+        let mut notarizations = Vec::new();
+
+        for data_tag in data_tags {
+            let struct_tag = StructTag::from_str(&format!(
+                "{}::notarization::Notarization<{data_tag}>",
+                self.original_package_id()
+            ))
+            .map_err(|e| Error::FailedToParseTag(e.to_string()))?;
+            let query = IotaObjectResponseQuery::new(
+                Some(IotaObjectDataFilter::StructType(struct_tag)),
+                Some(IotaObjectDataOptions::bcs_lossless().with_owner()),
+            );
+
+            let mut cursor = None;
+            loop {
+                let page = self.owned_objects_page(owner, &query, cursor).await?;
+
+                let mut page_notarizations = page
+                    .data
+                    .into_iter()
+                    .filter_map(|response| response.data)
+                    .map(|data| {
+                        let (mut notarization, address) = notarization_from_object_data(data)?;
+                        notarization.owner = address;
+                        Ok(notarization)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                resolve_all_locks(self, &mut page_notarizations, &self.retry_policy).await?;
+                notarizations.extend(
+                    page_notarizations
+                        .into_iter()
+                        .filter(|notarization| filter.matches(notarization)),
+                );
+
+                cursor = page.next_cursor;
+                if !page.has_next_page {
+                    break;
+                }
+            }
+        }
+
+        Ok(notarizations)
+    }
+
     /// Retrieves the `last_state_change_at` timestamp of a notarized object.
     ///
     /// This timestamp indicates the time of the most recent state change for the object.
@@ -195,7 +395,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the timestamp as a `u64` or an [`Error`].
     pub async fn last_state_change_ts(&self, notarized_object_id: ObjectId) -> Result<u64, Error> {
-        let tx = NotarizationImpl::last_change_ts(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::last_change_ts(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -211,7 +411,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the timestamp as a `u64` or an [`Error`].
     pub async fn created_at_ts(&self, notarized_object_id: ObjectId) -> Result<u64, Error> {
-        let tx = NotarizationImpl::created_at(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::created_at(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -227,7 +427,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the version count as a `u64` or an [`Error`].
     pub async fn state_version_count(&self, notarized_object_id: ObjectId) -> Result<u64, Error> {
-        let tx = NotarizationImpl::version_count(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::version_count(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -243,7 +443,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing an `Option<String>` or an [`Error`]. `None` if no description is set.
     pub async fn description(&self, notarized_object_id: ObjectId) -> Result<Option<String>, Error> {
-        let tx = NotarizationImpl::description(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::description(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -253,7 +453,8 @@ impl NotarizationClientReadOnly {
     /// This metadata is an optional string. Whether it can be modified after
     /// creation depends on the Notarization Method:
     /// * `Dynamic`: updatable via
-    ///   [`NotarizationClient::update_metadata`](crate::client::NotarizationClient::update_metadata).
+    ///   [`NotarizationClient::update_metadata`](crate::client::NotarizationClient::update_metadata) unless the
+    ///   optional `update_lock` is active.
     /// * `Locked`: immutable after creation.
     ///
     /// # Arguments
@@ -263,7 +464,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing an `Option<String>` or an [`Error`]. `None` if no updatable metadata is set.
     pub async fn updatable_metadata(&self, notarized_object_id: ObjectId) -> Result<Option<String>, Error> {
-        let tx = NotarizationImpl::updatable_metadata(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::updatable_metadata(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -280,8 +481,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the [`NotarizationMethod`] or an [`Error`].
     pub async fn notarization_method(&self, notarized_object_id: ObjectId) -> Result<NotarizationMethod, Error> {
-        let tx = NotarizationImpl::notarization_method(notarized_object_id, self).await?;
-        self.execute_read_only_transaction(tx).await
+        Ok(self.method_and_lock_metadata(notarized_object_id).await?.0)
     }
 
     /// Retrieves the `lock_metadata` of a notarization object by its `object_id`.
@@ -295,9 +495,24 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing an `Option<LockMetadata>` or an [`Error`]. `None` if no locks are set.
     pub async fn lock_metadata(&self, notarized_object_id: ObjectId) -> Result<Option<LockMetadata>, Error> {
-        let tx = NotarizationImpl::lock_metadata(notarized_object_id, self).await?;
+        Ok(self.method_and_lock_metadata(notarized_object_id).await?.1)
+    }
 
-        self.execute_read_only_transaction(tx).await
+    /// Retrieves the Notarization Method and the `lock_metadata` of a
+    /// notarization object, resolving the ones described by its dynamic
+    /// fields.
+    async fn method_and_lock_metadata(
+        &self,
+        notarized_object_id: ObjectId,
+    ) -> Result<(NotarizationMethod, Option<LockMetadata>), Error> {
+        let tx = NotarizationImpl::notarization_method(notarized_object_id, self, &self.retry_policy).await?;
+        let mut method: NotarizationMethod = self.execute_read_only_transaction(tx).await?;
+        let tx = NotarizationImpl::lock_metadata(notarized_object_id, self, &self.retry_policy).await?;
+        let mut locking: Option<LockMetadata> = self.execute_read_only_transaction(tx).await?;
+
+        resolve_lock_fields(self, notarized_object_id, &mut method, &mut locking, &self.retry_policy).await?;
+
+        Ok((method, locking))
     }
 
     /// Retrieves the `state` of a notarization object by its `object_id`.
@@ -316,31 +531,322 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the [`State<Data>`] or an [`Error`].
     pub async fn state(&self, notarized_object_id: ObjectId) -> Result<State, Error> {
-        let type_tag = move_utils::get_type_tag(self, &notarized_object_id).await?;
-        let type_str = type_tag.to_string();
+        let type_tag = move_utils::get_type_tag(self, &notarized_object_id, &self.retry_policy).await?;
+        let data_type = DataType::from_type_tag(&type_tag)?;
 
-        let tx = NotarizationImpl::state(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::state(notarized_object_id, self, &self.retry_policy).await?;
 
-        if type_str == "vector<u8>" {
-            let state: State<Vec<u8>> = self.execute_read_only_transaction(tx).await?;
-            Ok(State {
-                data: Data::Bytes(state.data),
-                metadata: state.metadata,
-            })
-        } else if type_str.contains("::string::String") {
-            let state: State<String> = self.execute_read_only_transaction(tx).await?;
-            Ok(State {
-                data: Data::Text(state.data),
-                metadata: state.metadata,
+        match data_type {
+            DataType::Bytes => {
+                let state: State<Vec<u8>> = self.execute_read_only_transaction(tx).await?;
+                Ok(State {
+                    data: Data::Bytes(state.data),
+                    metadata: state.metadata,
+                })
+            }
+            DataType::Text => {
+                let state: State<String> = self.execute_read_only_transaction(tx).await?;
+                Ok(State {
+                    data: Data::Text(state.data),
+                    metadata: state.metadata,
+                })
+            }
+        }
+    }
+
+    /// Verifies the content of `reader` against the digest notarized in the
+    /// current `state` of a notarized object.
+    ///
+    /// The stream is re-hashed in chunks with the algorithm recorded in the
+    /// notarized [`Digest`], so arbitrarily large files can be verified
+    /// without loading them into memory. `on_progress` is invoked after every
+    /// chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    /// * `reader`: The content to verify.
+    /// * `on_progress`: Callback receiving the [`HashProgress`] of the operation.
+    ///
+    /// # Returns
+    /// `Ok(true)` if the content matches the notarized digest, `Ok(false)` if
+    /// it does not, or an [`Error`] if the `state` does not hold a digest or
+    /// reading from `reader` fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn verify_stream<R, F>(
+        &self,
+        notarized_object_id: ObjectId,
+        reader: R,
+        on_progress: F,
+    ) -> Result<bool, Error>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+        F: FnMut(HashProgress),
+    {
+        let notarized = self.state(notarized_object_id).await?.data.as_digest()?;
+        let digest = stream::digest_async_reader(notarized.algorithm(), reader, on_progress).await?;
+
+        Ok(digest == notarized)
+    }
+
+    /// Retrieves the `state` history of a notarized object.
+    ///
+    /// Reconstructs every `state` set via `update_state` from the
+    /// `NotarizationUpdated` events emitted for the notarization by any
+    /// version of the Notarization Package and returns them, together with
+    /// the initial `state`, ordered by ascending version. Timestamps are the
+    /// ones of the checkpoints containing the events; the initial `state`
+    /// carries the `created_at` timestamp of the notarization.
+    ///
+    /// Nodes can filter events by module but not by object, so the events of
+    /// all notarizations are scanned, newest first, and events of other
+    /// notarizations are skipped by their `notarization_id` without being
+    /// decoded. The scan stops once all `state_version_count` updates were
+    /// found and never reaches events emitted before the notarization was
+    /// created, so its cost grows with the activity of the package since the
+    /// creation of the notarization. The bounds are read from the
+    /// notarization, so it must not have been destroyed.
+    ///
+    /// The initial `state` of a notarization that was never updated is its
+    /// current `state`. Otherwise it is not part of any event and is read
+    /// from the object version created by the transaction that emitted the
+    /// creation event of the notarization. Nodes pruning past object versions
+    /// cannot serve it and fail with an [`Error::InitialStateUnavailable`].
+    ///
+    /// Only reads notarizations of `vector<u8>` or `String` payloads; use
+    /// [`Self::state_history_as`] for typed payloads.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`StateVersion`]s or an [`Error`].
+    pub async fn state_history(&self, notarized_object_id: ObjectId) -> Result<Vec<StateVersion>, Error> {
+        self.state_history_as(notarized_object_id).await
+    }
+
+    /// Retrieves the `state` history of a notarized object holding a payload
+    /// of type `D`.
+    ///
+    /// Works like [`Self::state_history`], but also reads notarizations
+    /// created via
+    /// [`NotarizationBuilder::with_generic_state`](crate::core::builder::NotarizationBuilder::with_generic_state),
+    /// whose updates are decoded from the BCS content of their events.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`StateVersion<D>`]s or an [`Error`].
+    pub async fn state_history_as<D: StateData>(
+        &self,
+        notarized_object_id: ObjectId,
+    ) -> Result<Vec<StateVersion<D>>, Error> {
+        let notarization = self.get_notarization_by_id_as::<D>(notarized_object_id).await?;
+        let created_at = notarization.immutable_metadata.created_at;
+        let update_count = notarization.state_version_count;
+
+        let mut history = Vec::new();
+        if update_count > 0 {
+            self.scan_notarization_events("notarization", notarized_object_id, created_at, |event| {
+                if is_notarization_updated(&event) {
+                    let updated = D::decode_updated_event(&event)?;
+                    history.push(StateVersion {
+                        version: updated.state_version_count,
+                        timestamp_ms: event_timestamp_ms(&event)?,
+                        state: updated.updated_state,
+                    });
+                }
+                Ok(history.len() as u64 == update_count)
             })
+            .await?;
+        }
+
+        let initial_state = if update_count == 0 {
+            notarization.state
         } else {
-            Err(Error::InvalidArgument(format!("Unsupported state type: {type_str}")))
+            let mut created = None;
+            for module in CREATION_EVENT_MODULES {
+                self.scan_notarization_events(module, notarized_object_id, created_at, |event| {
+                    created = Some(event);
+                    Ok(true)
+                })
+                .await?;
+                if created.is_some() {
+                    break;
+                }
+            }
+            let created = created.ok_or_else(|| {
+                Error::UnexpectedApiResponse(format!(
+                    "missing creation event of notarization {notarized_object_id}"
+                ))
+            })?;
+
+            self.initial_state(notarized_object_id, &created).await?
+        };
+        history.push(StateVersion {
+            version: 0,
+            timestamp_ms: created_at,
+            state: initial_state,
+        });
+
+        history.sort_by_key(|state_version| state_version.version);
+
+        Ok(history)
+    }
+
+    /// Lists the endorsements of a notarized object.
+    ///
+    /// Pages through the [`Endorsement`]s owned by the notarization,
+    /// including endorsements of past versions of its `state`. The endorsed
+    /// versions are returned as recorded by the endorsers and are not
+    /// checked, see [`Self::verify_endorsements`]. Endorsements of a
+    /// destroyed notarization are still listed.
+    ///
+    /// The `Endorsement` type keeps the ID of the package version that
+    /// introduced it, starting from the original package, so the
+    /// endorsements are queried for every known version of the package.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`Endorsement`]s, ordered by `endorsed_at`, or an [`Error`].
+    pub async fn endorsements(&self, notarized_object_id: ObjectId) -> Result<Vec<Endorsement>, Error> {
+        let owner = *notarized_object_id.as_address();
+
+        let mut endorsements = Vec::new();
+        for package_id in &self.package_versions {
+            let struct_tag = StructTag::from_str(&format!("{package_id}::endorsement::Endorsement"))
+                .map_err(|e| Error::FailedToParseTag(e.to_string()))?;
+            let query = IotaObjectResponseQuery::new(
+                Some(IotaObjectDataFilter::StructType(struct_tag)),
+                Some(IotaObjectDataOptions::bcs_lossless()),
+            );
+
+            let mut cursor = None;
+            loop {
+                let page = self.owned_objects_page(owner, &query, cursor).await?;
+
+                for data in page.data.into_iter().filter_map(|response| response.data) {
+                    let move_object = data
+                        .bcs
+                        .ok_or_else(|| Error::ObjectDecoding("missing object content in data".to_string()))?
+                        .try_into_move()
+                        .ok_or_else(|| Error::ObjectDecoding("failed to convert data to move object".to_string()))?;
+                    endorsements.push(bcs::from_bytes::<Endorsement>(&move_object.bcs_bytes)?);
+                }
+
+                cursor = page.next_cursor;
+                if !page.has_next_page {
+                    break;
+                }
+            }
         }
+
+        endorsements.sort_by_key(|endorsement| endorsement.endorsed_at);
+
+        Ok(endorsements)
+    }
+
+    /// Lists the endorsements of a notarized object and checks each of them
+    /// against the current `state` of the notarization.
+    ///
+    /// An endorsement is current if its `state_version_count` and state hash
+    /// equal the ones of the notarization. Both values are passed in by the
+    /// endorser and not checked on-chain, so this comparison is what makes an
+    /// endorsement count. Endorsements of past versions stay listed, so
+    /// callers can tell which parties still have to endorse the current
+    /// `state`; check them against [`Self::state_history`] with
+    /// [`Endorsement::is_for_version`].
+    ///
+    /// Fails if the notarization has been destroyed; use
+    /// [`Self::endorsements`] to list its endorsements instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`VerifiedEndorsement`]s, ordered by `endorsed_at`, or an [`Error`].
+    pub async fn verify_endorsements(&self, notarized_object_id: ObjectId) -> Result<Vec<VerifiedEndorsement>, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let endorsements = self.endorsements(notarized_object_id).await?;
+
+        Ok(endorsements
+            .into_iter()
+            .map(|endorsement| VerifiedEndorsement {
+                is_current: endorsement.is_current_for(&notarization),
+                endorsement,
+            })
+            .collect())
+    }
+
+    /// Watches a notarized object for changes.
+    ///
+    /// Returns a stream of the [`NotarizationEvent`](crate::core::types::NotarizationEvent)s emitted for the
+    /// notarization, from its creation on or from the position given by
+    /// [`WatchOptions::cursor`]. The stream polls the node every
+    /// [`WatchOptions::poll_interval`] and ends after the notarization was
+    /// destroyed.
+    ///
+    /// Nodes can filter events by module but not by object, so the events of
+    /// all notarizations are fetched; events of other notarizations are
+    /// skipped by their `notarization_id` without being decoded. Without a
+    /// cursor the stream therefore pages through every event ever emitted by
+    /// every version of the Notarization Package before it reaches the
+    /// current ones, and the cost of starting it grows with the activity of
+    /// the whole package rather than with the history of the notarization.
+    /// Persist the [`WatchedEvent::cursor`] and pass it back to resume cheaply,
+    /// or use [`Self::state_history`] to read the past states of a single
+    /// notarization.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
+    /// * `options`: The [`WatchOptions`] of the stream.
+    ///
+    /// # Returns
+    /// A stream of [`WatchedEvent`]s or [`Error`]s.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn watch(
+        &self,
+        notarized_object_id: ObjectId,
+        options: WatchOptions,
+    ) -> impl Stream<Item = Result<WatchedEvent, Error>> + use<> {
+        watch::watch_events(
+            self.clone(),
+            self.package_versions.clone(),
+            Some(notarized_object_id),
+            options,
+        )
+    }
+
+    /// Watches all notarizations of the Notarization Package for changes.
+    ///
+    /// Like [`Self::watch`], but yields the events of every notarization and
+    /// never ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `options`: The [`WatchOptions`] of the stream.
+    ///
+    /// # Returns
+    /// A stream of [`WatchedEvent`]s or [`Error`]s.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn watch_all(&self, options: WatchOptions) -> impl Stream<Item = Result<WatchedEvent, Error>> + use<> {
+        watch::watch_events(self.clone(), self.package_versions.clone(), None, options)
     }
 
     /// Retrieves the `state` of a notarization object by its `object_id` and deserializes it into a custom type `T`.
     /// This method is useful when the state data is of a custom type.
     ///
+    /// Round-trips typed payloads created via
+    /// [`NotarizationBuilder::with_generic_state`](crate::core::builder::NotarizationBuilder::with_generic_state).
+    ///
     /// # Arguments
     ///
     /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
@@ -348,7 +854,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing the [`State<T>`] or an [`Error`].
     pub async fn state_as<T: DeserializeOwned>(&self, notarized_object_id: ObjectId) -> Result<State<T>, Error> {
-        let tx = NotarizationImpl::state(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::state(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -357,9 +863,12 @@ impl NotarizationClientReadOnly {
     /// and `updatable_metadata` updates.
     ///
     /// Result depends on the Notarization Method:
-    /// * `Dynamic`: always returns `false`.
+    /// * `Dynamic`: returns whether the optional `update_lock` is currently timelocked.
     /// * `Locked`: returns whether the configured `update_lock` is currently timelocked.
     ///
+    /// To evaluate the lock of an already fetched notarization without a
+    /// network request, use [`OnChainNotarization::is_update_locked_at`].
+    ///
     /// # Arguments
     ///
     /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
@@ -367,7 +876,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing `true` if the object is update-locked, `false` otherwise, or an [`Error`].
     pub async fn is_update_locked(&self, notarized_object_id: ObjectId) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_update_locked(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::is_update_locked(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -375,11 +884,14 @@ impl NotarizationClientReadOnly {
     /// Checks if the notarized object is currently allowed to be destroyed.
     ///
     /// Behaviour depends on the Notarization Method:
-    /// * `Dynamic`: destruction is gated only on the `transfer_lock` — the object is destroy-allowed unless
-    ///   `transfer_lock` is currently `UnlockAt`-locked.
+    /// * `Dynamic`: destruction is gated on the optional `update_lock`, `delete_lock` and `transfer_lock` — the object
+    ///   is destroy-allowed unless one of them is currently `UnlockAt`-locked.
     /// * `Locked`: destruction is gated on all of `update_lock`, `delete_lock`, and `transfer_lock` — the object is
     ///   destroy-allowed only when none of them is currently `UnlockAt`-locked.
     ///
+    /// To evaluate the locks of an already fetched notarization without a
+    /// network request, use [`OnChainNotarization::is_destroy_allowed_at`].
+    ///
     /// # Arguments
     ///
     /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
@@ -387,7 +899,7 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing `true` if the object is destroy-allowed, `false` otherwise, or an [`Error`].
     pub async fn is_destroy_allowed(&self, notarized_object_id: ObjectId) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_destroy_allowed(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::is_destroy_allowed(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -399,6 +911,9 @@ impl NotarizationClientReadOnly {
     /// timelocked. Locked-Notarizations always return `true` because their
     /// `transfer_lock` is pinned to `TimeLock::UntilDestroyed`.
     ///
+    /// To evaluate the lock of an already fetched notarization without a
+    /// network request, use [`OnChainNotarization::is_transfer_locked_at`].
+    ///
     /// # Arguments
     ///
     /// * `notarized_object_id`: The [`ObjectId`] of the notarized object.
@@ -406,10 +921,123 @@ impl NotarizationClientReadOnly {
     /// # Returns
     /// A `Result` containing `true` if the object is transfer-locked, `false` otherwise, or an [`Error`].
     pub async fn is_transfer_locked(&self, notarized_object_id: ObjectId) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_transfer_locked(notarized_object_id, self).await?;
+        let tx = NotarizationImpl::is_transfer_locked(notarized_object_id, self, &self.retry_policy).await?;
 
         self.execute_read_only_transaction(tx).await
     }
+
+    /// Evaluates the locks of many notarized objects against the on-chain clock.
+    ///
+    /// Equivalent to calling [`Self::is_update_locked`],
+    /// [`Self::is_destroy_allowed`] and [`Self::is_transfer_locked`] for every
+    /// object, but the objects are fetched with multi-get queries and the
+    /// checks of up to [`LOCK_CHECK_BATCH_SIZE`] objects are inspected in a
+    /// single programmable transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_ids`: The [`ObjectId`]s of the notarized objects.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`LockStatus`]es, in the order of
+    /// `notarized_object_ids`, or an [`Error`].
+    pub async fn lock_statuses(&self, notarized_object_ids: &[ObjectId]) -> Result<Vec<LockStatus>, Error> {
+        let notarizations = move_utils::multi_get_objects(
+            self,
+            notarized_object_ids,
+            IotaObjectDataOptions::new().with_type().with_owner(),
+            &self.retry_policy,
+        )
+        .await?
+        .into_iter()
+        .map(|data| {
+            let full_type_str = data
+                .object_type()
+                .map_err(|err| Error::ObjectDecoding(format!("missing object type in data: {err}")))?
+                .to_string();
+
+            Ok((
+                move_utils::object_arg(&data, false),
+                move_utils::type_param_tag(&full_type_str)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut statuses = Vec::with_capacity(notarizations.len());
+        for chunk in notarizations.chunks(LOCK_CHECK_BATCH_SIZE) {
+            let tx = NotarizationImpl::lock_checks(chunk, self.notarization_pkg_id)?;
+            let checks: Vec<bool> = self.execute_read_only_transaction_batch(tx).await?;
+
+            if checks.len() != 3 * chunk.len() {
+                return Err(Error::UnexpectedApiResponse(format!(
+                    "expected {} lock checks, got {}",
+                    3 * chunk.len(),
+                    checks.len()
+                )));
+            }
+
+            statuses.extend(checks.chunks_exact(3).map(|status| LockStatus {
+                update_locked: status[0],
+                destroy_allowed: status[1],
+                transfer_locked: status[2],
+            }));
+        }
+
+        Ok(statuses)
+    }
+
+    /// Retrieves the current time of the on-chain `Clock` object.
+    ///
+    /// Lock evaluation and validation on-chain use this time, which may
+    /// differ from the local system time.
+    ///
+    /// # Returns
+    /// A `Result` containing the on-chain clock timestamp, in milliseconds
+    /// since the Unix epoch, or an [`Error`].
+    pub async fn chain_timestamp_ms(&self) -> Result<u64, Error> {
+        let data = self
+            .retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .get_object_with_options(IOTA_CLOCK_OBJECT_ID, IotaObjectDataOptions::bcs_lossless())
+                    .await
+                    .map_err(|err| Error::ObjectLookup(format!("failed to get the clock object: {err}")))
+            })
+            .await?
+            .data
+            .ok_or_else(|| Error::ObjectLookup("missing data for the clock object".to_string()))?;
+
+        let move_object = data
+            .bcs
+            .ok_or_else(|| Error::ObjectDecoding("missing object content in data".to_string()))?
+            .try_into_move()
+            .ok_or_else(|| Error::ObjectDecoding("failed to convert data to move object".to_string()))?;
+        let clock: OnChainClock = bcs::from_bytes(&move_object.bcs_bytes)?;
+
+        Ok(clock.timestamp_ms)
+    }
+
+    /// Validates the locks of a [`NotarizationBuilder`] against the on-chain
+    /// clock.
+    ///
+    /// Call this before building the transaction of `builder` to detect
+    /// unlock times the network would reject, independently of the local
+    /// system time. See [`NotarizationBuilder::validate_locks_at`].
+    ///
+    /// # Arguments
+    ///
+    /// * `builder`: The [`NotarizationBuilder`] to validate.
+    ///
+    /// # Returns
+    /// A `Result` that is `Ok` if all locks unlock in the future, or an
+    /// [`Error::TimeLock`] carrying the requested unlock time and the on-chain
+    /// clock time otherwise.
+    pub async fn validate_locks<M, D>(&self, builder: &NotarizationBuilder<M, D>) -> Result<(), Error> {
+        let chain_time = self.chain_timestamp_ms().await? / 1000;
+
+        builder.validate_locks_at(chain_time)
+    }
 }
 
 impl NotarizationClientReadOnly {
@@ -431,12 +1059,7 @@ impl NotarizationClientReadOnly {
         &self,
         tx: ProgrammableTransaction,
     ) -> Result<T, Error> {
-        let inspection_result = self
-            .iota_client
-            .read_api()
-            .dev_inspect_transaction_block(Address::ZERO, TransactionKind::Programmable(tx), None, None, None)
-            .await
-            .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to inspect transaction block: {err}")))?;
+        let inspection_result = self.dev_inspect(tx).await?;
 
         let execution_results = inspection_result
             .results
@@ -453,8 +1076,236 @@ impl NotarizationClientReadOnly {
 
         Ok(deserialized_output)
     }
+
+    /// Executes a read-only transaction consisting of many commands and
+    /// deserializes the first return value of every command into `T`.
+    ///
+    /// See [`Self::execute_read_only_transaction`].
+    async fn execute_read_only_transaction_batch<T: DeserializeOwned>(
+        &self,
+        tx: ProgrammableTransaction,
+    ) -> Result<Vec<T>, Error> {
+        let inspection_result = self.dev_inspect(tx).await?;
+
+        inspection_result
+            .results
+            .ok_or_else(|| Error::UnexpectedApiResponse("DevInspectResults missing 'results' field".to_string()))?
+            .iter()
+            .map(|execution_result| {
+                let (return_value_bytes, _) = execution_result
+                    .return_values
+                    .first()
+                    .ok_or_else(|| Error::InvalidArgument("should have at least one return value".to_string()))?;
+
+                Ok(bcs::from_bytes::<T>(return_value_bytes)?)
+            })
+            .collect()
+    }
+
+    /// Inspects `tx` with the zero address as sender, retrying according to
+    /// the retry policy of the client.
+    async fn dev_inspect(&self, tx: ProgrammableTransaction) -> Result<DevInspectResults, Error> {
+        self.retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .dev_inspect_transaction_block(
+                        Address::ZERO,
+                        TransactionKind::Programmable(tx.clone()),
+                        None,
+                        None,
+                        None,
+                    )
+                    .await
+                    .map_err(|err| Error::RpcError(format!("Failed to inspect transaction block: {err}")))
+            })
+            .await
+    }
+
+    /// Queries a page of the objects owned by `owner` matching `query`,
+    /// retrying according to the retry policy of the client.
+    async fn owned_objects_page(
+        &self,
+        owner: Address,
+        query: &IotaObjectResponseQuery,
+        cursor: Option<ObjectId>,
+    ) -> Result<ObjectsPage, Error> {
+        self.retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .get_owned_objects(owner, Some(query.clone()), cursor, Some(OBJECT_PAGE_SIZE))
+                    .await
+                    .map_err(|err| Error::RpcError(format!("Failed to query owned objects: {err}")))
+            })
+            .await
+    }
+
+    /// Queries the events matching `filter`, in ascending order, starting
+    /// after `cursor`, retrying according to the retry policy of the client.
+    pub(crate) async fn query_event_page(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+    ) -> Result<EventPage, Error> {
+        self.query_event_page_in_order(filter, cursor, limit, false).await
+    }
+
+    /// Queries the events matching `filter`, starting after `cursor`, newest
+    /// first if `descending_order` is set.
+    async fn query_event_page_in_order(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<EventPage, Error> {
+        self.retry_policy
+            .run(|| async {
+                self.iota_client
+                    .event_api()
+                    .query_events(filter.clone(), cursor, Some(limit), descending_order)
+                    .await
+                    .map_err(|err| Error::RpcError(format!("Failed to query events: {err}")))
+            })
+            .await
+    }
+
+    /// Scans the events emitted by `module` of any version of the
+    /// Notarization Package for `notarized_object_id`, newest first per
+    /// package version.
+    ///
+    /// Events emitted before `since_ms`, in milliseconds since the Unix
+    /// epoch, are not scanned. `visit` is called with every event of the
+    /// notarization and ends the scan by returning `true`.
+    async fn scan_notarization_events(
+        &self,
+        module: &'static str,
+        notarized_object_id: ObjectId,
+        since_ms: u64,
+        mut visit: impl FnMut(IotaEvent) -> Result<bool, Error>,
+    ) -> Result<(), Error> {
+        for package_id in self.package_versions.iter().rev() {
+            let filter = EventFilter::MoveEventModule {
+                package: *package_id,
+                module: module.into(),
+            };
+
+            let mut cursor = None;
+            'pages: loop {
+                let page = self
+                    .query_event_page_in_order(filter.clone(), cursor, EVENT_PAGE_SIZE, true)
+                    .await?;
+                for event in page.data {
+                    if event.timestamp_ms.is_some_and(|timestamp_ms| timestamp_ms < since_ms) {
+                        break 'pages;
+                    }
+                    if event_notarization_id(&event) == Some(notarized_object_id) && visit(event)? {
+                        return Ok(());
+                    }
+                }
+
+                cursor = page.next_cursor;
+                if !page.has_next_page {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the initial `state` of `notarized_object_id` from the object
+    /// version created by the transaction that emitted `created`.
+    async fn initial_state<D: StateData>(
+        &self,
+        notarized_object_id: ObjectId,
+        created: &IotaEvent,
+    ) -> Result<State<D>, Error> {
+        let digest = created.id.tx_digest;
+        let response = self
+            .retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .get_transaction_with_options(digest, IotaTransactionBlockResponseOptions::new().with_effects())
+                    .await
+                    .map_err(|err| Error::RpcError(format!("Failed to fetch transaction {digest}: {err}")))
+            })
+            .await?;
+        let version = response
+            .effects()
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("missing transaction effects".to_string()))?
+            .created()
+            .iter()
+            .find(|object| object.object_id() == notarized_object_id)
+            .map(|object| object.version())
+            .ok_or_else(|| {
+                Error::TransactionUnexpectedResponse(format!(
+                    "transaction {digest} did not create object {notarized_object_id}"
+                ))
+            })?;
+
+        let data = self
+            .retry_policy
+            .run(|| async {
+                self.iota_client
+                    .read_api()
+                    .try_get_parsed_past_object(
+                        notarized_object_id,
+                        version,
+                        IotaObjectDataOptions::bcs_lossless().with_owner(),
+                    )
+                    .await
+                    .map_err(|err| Error::ObjectLookup(err.to_string()))
+            })
+            .await?
+            .into_object()
+            .map_err(|err| Error::InitialStateUnavailable {
+                notarization_id: notarized_object_id,
+                reason: format!("version {version} of the object is not available: {err}"),
+            })?;
+        let (notarization, _) = notarization_from_object_data::<D>(data)?;
+
+        Ok(notarization.state)
+    }
+}
+
+/// Returns the timestamp of the checkpoint containing `event`.
+fn event_timestamp_ms(event: &IotaEvent) -> Result<u64, Error> {
+    event
+        .timestamp_ms
+        .ok_or_else(|| Error::UnexpectedApiResponse("event is missing its timestamp".to_string()))
 }
 
+/// The on-chain `0x2::clock::Clock` object.
+#[derive(Deserialize)]
+struct OnChainClock {
+    #[allow(dead_code)]
+    id: UID,
+    timestamp_ms: u64,
+}
+
+/// Number of objects requested per page when querying owned objects.
+const OBJECT_PAGE_SIZE: usize = 50;
+
+/// Number of events requested per page when querying events.
+const EVENT_PAGE_SIZE: usize = 50;
+
+/// The Move modules of the Notarization Package emitting the creation events.
+const CREATION_EVENT_MODULES: [&str; 3] = ["dynamic_notarization", "locked_notarization", "shared_notarization"];
+
+/// Maximum number of objects requested per multi-get query.
+pub const MULTI_GET_LIMIT: usize = 50;
+
+/// Maximum number of notarizations whose locks are checked in a single
+/// inspected transaction.
+///
+/// Each notarization adds three commands, staying well below the protocol's
+/// limit of 1024 commands per programmable transaction.
+pub const LOCK_CHECK_BATCH_SIZE: usize = 256;
+
 #[async_trait::async_trait]
 impl CoreClientReadOnly for NotarizationClientReadOnly {
     /// Returns the [`ObjectId`] of the Notarization Package used by this client.
@@ -0,0 +1,595 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Watching Notarizations
+//!
+//! Streams of [`NotarizationEvent`]s, driven by polling event queries.
+//!
+//! ## Overview
+//!
+//! [`NotarizationClientReadOnly::watch`] follows a single notarization,
+//! [`NotarizationClientReadOnly::watch_all`] every notarization of the
+//! Notarization Package. Both return a [`futures::Stream`] of [`WatchedEvent`]s
+//! in the order they were emitted.
+//!
+//! Every [`WatchedEvent`] carries a [`WatchCursor`] that can be persisted by the
+//! consumer. Passing it back via [`WatchOptions::cursor`] resumes the stream
+//! right after that event, e.g. after a restart.
+//!
+//! The streams are driven by an [`EventSource`], which is implemented by
+//! [`NotarizationClientReadOnly`]. Use [`watch_events`] with a custom
+//! [`EventSource`] to run them against a stand-in node.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use futures::StreamExt;
+//! use notarization::client::WatchOptions;
+//!
+//! let options = WatchOptions::default().with_cursor(load_cursor()?);
+//! let mut events = std::pin::pin!(client.watch(notarization_id, options));
+//! while let Some(watched) = events.next().await {
+//!     let watched = watched?;
+//!     println!("{:?}", watched.event);
+//!     store_cursor(&watched.cursor)?;
+//! }
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::Stream;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{EventFilter, EventPage, IotaEvent};
+use iota_interaction::types::event::EventID;
+use iota_sdk_types::ObjectId;
+use serde::{Deserialize, Serialize};
+
+use super::read_only::NotarizationClientReadOnly;
+use crate::core::types::{NotarizationEvent, event_notarization_id};
+use crate::error::Error;
+
+/// Default interval between two event queries once all known events were yielded.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of events requested per page when querying events.
+const EVENT_PAGE_SIZE: usize = 50;
+
+/// The Move modules of the Notarization Package that emit events.
+const EVENT_MODULES: [&str; 5] = [
+    "notarization",
+    "dynamic_notarization",
+    "locked_notarization",
+    "shared_notarization",
+    "endorsement",
+];
+
+/// A source of Move events, e.g. an IOTA node.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait EventSource {
+    /// Queries the events matching `filter`, in ascending order, starting after
+    /// `cursor`.
+    async fn query_events(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+    ) -> Result<EventPage, Error>;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl EventSource for NotarizationClientReadOnly {
+    async fn query_events(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+    ) -> Result<EventPage, Error> {
+        self.query_event_page(filter, cursor, limit).await
+    }
+}
+
+/// Position of a watch stream, used to resume it.
+///
+/// Holds the ID of the last yielded event per Move module of each version of
+/// the Notarization Package. The default value starts at the first event ever
+/// emitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchCursor(BTreeMap<String, EventID>);
+
+impl WatchCursor {
+    fn get(&self, module: &str) -> Option<EventID> {
+        self.0.get(module).copied()
+    }
+
+    fn set(&mut self, module: &str, event_id: EventID) {
+        self.0.insert(module.to_string(), event_id);
+    }
+}
+
+/// Options of a watch stream.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Position to resume from.
+    pub cursor: WatchCursor,
+    /// Interval between two event queries once all known events were yielded.
+    pub poll_interval: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            cursor: WatchCursor::default(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+impl WatchOptions {
+    /// Resumes the stream right after the event `cursor` was yielded with.
+    pub fn with_cursor(mut self, cursor: WatchCursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Sets the interval between two event queries.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+/// An event yielded by a watch stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchedEvent {
+    /// The decoded event.
+    pub event: NotarizationEvent,
+    /// ID of the event on the ledger.
+    pub event_id: EventID,
+    /// Timestamp of the checkpoint containing the event, in milliseconds since the Unix epoch.
+    pub timestamp_ms: Option<u64>,
+    /// Position of the stream after this event.
+    pub cursor: WatchCursor,
+}
+
+/// Returns a stream of the events emitted by the Notarization Package, as
+/// reported by `source`.
+///
+/// `package_ids` are the IDs of the versions of the package, oldest first.
+/// Move types keep the ID of the package version that introduced them, so
+/// events are queried by the modules of every version rather than of the
+/// latest one.
+///
+/// With a `notarization_id` only the events of that notarization are yielded
+/// and the stream ends after its `NotarizationDestroyed` event. Otherwise the
+/// stream never ends. Events of other notarizations are skipped by their
+/// `notarization_id` before they are decoded.
+///
+/// Events that cannot be decoded and failing queries are yielded as errors;
+/// the stream continues afterwards.
+pub fn watch_events<S>(
+    source: S,
+    package_ids: Vec<ObjectId>,
+    notarization_id: Option<ObjectId>,
+    options: WatchOptions,
+) -> impl Stream<Item = Result<WatchedEvent, Error>>
+where
+    S: EventSource + OptionalSync,
+{
+    let watcher = Watcher {
+        source,
+        package_ids,
+        notarization_id,
+        poll_interval: options.poll_interval,
+        fetched: options.cursor.clone(),
+        yielded: options.cursor,
+        pending: VecDeque::new(),
+        polled: false,
+        finished: false,
+    };
+
+    futures::stream::unfold(watcher, |mut watcher| async move {
+        let next = watcher.next().await?;
+        Some((next, watcher))
+    })
+}
+
+/// State of a watch stream.
+struct Watcher<S> {
+    source: S,
+    package_ids: Vec<ObjectId>,
+    notarization_id: Option<ObjectId>,
+    poll_interval: Duration,
+    /// Position of the event queries.
+    fetched: WatchCursor,
+    /// Position of the consumer.
+    yielded: WatchCursor,
+    /// Events that were fetched but not yet yielded, with their cursor key.
+    pending: VecDeque<(String, IotaEvent)>,
+    polled: bool,
+    finished: bool,
+}
+
+impl<S> Watcher<S>
+where
+    S: EventSource + OptionalSync,
+{
+    async fn next(&mut self) -> Option<Result<WatchedEvent, Error>> {
+        loop {
+            if self.finished {
+                return None;
+            }
+
+            let Some((key, event)) = self.pending.pop_front() else {
+                if self.polled {
+                    tokio::time::sleep(self.poll_interval).await;
+                }
+                self.polled = true;
+
+                if let Err(e) = self.poll().await {
+                    return Some(Err(e));
+                }
+                continue;
+            };
+
+            self.yielded.set(&key, event.id);
+            if self
+                .notarization_id
+                .is_some_and(|notarization_id| event_notarization_id(&event) != Some(notarization_id))
+            {
+                continue;
+            }
+
+            let decoded = match NotarizationEvent::try_from(&event) {
+                Ok(decoded) => decoded,
+                Err(e) => return Some(Err(e)),
+            };
+            if self.notarization_id.is_some() {
+                self.finished = matches!(decoded, NotarizationEvent::NotarizationDestroyed(_));
+            }
+
+            return Some(Ok(WatchedEvent {
+                event: decoded,
+                event_id: event.id,
+                timestamp_ms: event.timestamp_ms,
+                cursor: self.yielded.clone(),
+            }));
+        }
+    }
+
+    /// Queries all events emitted since the last poll and queues them by
+    /// emission time.
+    ///
+    /// Events are queried per module, so events of one transaction, which
+    /// share a timestamp, are put back into emission order by their sequence
+    /// number.
+    async fn poll(&mut self) -> Result<(), Error> {
+        let mut fetched = Vec::new();
+        let result = self.fetch_into(&mut fetched).await;
+
+        fetched.sort_by_key(|(_, event)| (event.timestamp_ms, event.id.tx_digest, event.id.event_seq));
+        self.pending.extend(fetched);
+
+        result
+    }
+
+    async fn fetch_into(&mut self, fetched: &mut Vec<(String, IotaEvent)>) -> Result<(), Error> {
+        for package in &self.package_ids {
+            for module in EVENT_MODULES {
+                let key = format!("{package}::{module}");
+                let filter = EventFilter::MoveEventModule {
+                    package: *package,
+                    module: module.into(),
+                };
+
+                loop {
+                    let page = self
+                        .source
+                        .query_events(filter.clone(), self.fetched.get(&key), EVENT_PAGE_SIZE)
+                        .await?;
+
+                    if let Some(last) = page.data.last() {
+                        self.fetched.set(&key, last.id);
+                    }
+                    fetched.extend(page.data.into_iter().map(|event| (key.clone(), event)));
+
+                    if !page.has_next_page {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::sync::{Arc, Mutex};
+
+    use futures::StreamExt;
+    use iota_interaction::rpc_types::Page;
+    use iota_interaction::types::base_types::dbg_object_id;
+    use iota_interaction::types::digests::TransactionDigest;
+    use serde_json::json;
+
+    use super::*;
+
+    const PACKAGE: &str = "0x0000000000000000000000000000000000000000000000000000000000000abc";
+    const UPGRADED_PACKAGE: &str = "0x0000000000000000000000000000000000000000000000000000000000000def";
+
+    /// A stand-in node holding the events of the versions of a package.
+    #[derive(Clone, Default)]
+    struct StandInNode {
+        events: Arc<Mutex<Vec<(ObjectId, &'static str, IotaEvent)>>>,
+    }
+
+    impl StandInNode {
+        fn emit(&self, module: &'static str, name: &str, notarization_id: ObjectId, timestamp_ms: u64) {
+            self.emit_in(PACKAGE, module, name, notarization_id, timestamp_ms);
+        }
+
+        fn emit_in(
+            &self,
+            package: &str,
+            module: &'static str,
+            name: &str,
+            notarization_id: ObjectId,
+            timestamp_ms: u64,
+        ) {
+            let event = IotaEvent::random_for_testing();
+            self.push(package, module, name, notarization_id, timestamp_ms, event.id);
+        }
+
+        /// Emits an event with the given ID, e.g. one of several events of a
+        /// single transaction.
+        fn emit_with_id(
+            &self,
+            module: &'static str,
+            name: &str,
+            notarization_id: ObjectId,
+            timestamp_ms: u64,
+            id: EventID,
+        ) {
+            self.push(PACKAGE, module, name, notarization_id, timestamp_ms, id);
+        }
+
+        fn push(
+            &self,
+            package: &str,
+            module: &'static str,
+            name: &str,
+            notarization_id: ObjectId,
+            timestamp_ms: u64,
+            id: EventID,
+        ) {
+            let mut event = IotaEvent::random_for_testing();
+            event.id = id;
+            event.type_ = format!("{package}::{module}::{name}").parse().unwrap();
+            event.parsed_json = json!({ "notarization_id": notarization_id.to_string() });
+            event.timestamp_ms = Some(timestamp_ms);
+
+            self.events
+                .lock()
+                .unwrap()
+                .push((package.parse().unwrap(), module, event));
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl EventSource for StandInNode {
+        async fn query_events(
+            &self,
+            filter: EventFilter,
+            cursor: Option<EventID>,
+            limit: usize,
+        ) -> Result<EventPage, Error> {
+            let EventFilter::MoveEventModule { package, module } = filter else {
+                panic!("unexpected filter {filter:?}");
+            };
+
+            let events = self.events.lock().unwrap();
+            let matching: Vec<_> = events
+                .iter()
+                .filter(|(p, m, _)| *p == package && *m == module.as_str())
+                .map(|(_, _, event)| event.clone())
+                .collect();
+            let start = cursor.map_or(0, |cursor| {
+                matching.iter().position(|event| event.id == cursor).unwrap() + 1
+            });
+            let total = matching.len();
+            let data: Vec<_> = matching.into_iter().skip(start).take(limit).collect();
+            let has_next_page = total > start + data.len();
+
+            Ok(Page {
+                next_cursor: data.last().map(|event| event.id),
+                data,
+                has_next_page,
+            })
+        }
+    }
+
+    fn packages() -> Vec<ObjectId> {
+        vec![PACKAGE.parse().unwrap(), UPGRADED_PACKAGE.parse().unwrap()]
+    }
+
+    fn options() -> WatchOptions {
+        WatchOptions::default().with_poll_interval(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn watch_all_yields_events_of_all_modules_in_order() {
+        let node = StandInNode::default();
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+        );
+        node.emit("locked_notarization", "LockedNotarizationCreated", dbg_object_id(2), 2);
+        node.emit("notarization", "NotarizationDestroyed", dbg_object_id(1), 3);
+
+        let watched: Vec<_> = watch_events(node, packages(), None, options())
+            .take(3)
+            .map(|watched| watched.unwrap().event)
+            .collect()
+            .await;
+
+        assert!(matches!(watched[0], NotarizationEvent::DynamicNotarizationCreated(_)));
+        assert!(matches!(watched[1], NotarizationEvent::LockedNotarizationCreated(_)));
+        assert!(matches!(watched[2], NotarizationEvent::NotarizationDestroyed(_)));
+    }
+
+    #[tokio::test]
+    async fn watch_yields_events_of_one_transaction_in_emission_order() {
+        let node = StandInNode::default();
+        let tx_digest = TransactionDigest::random();
+        // Queried first, as `notarization` precedes `dynamic_notarization`.
+        node.emit_with_id(
+            "notarization",
+            "NotarizationMetadataUpdated",
+            dbg_object_id(1),
+            1,
+            EventID { tx_digest, event_seq: 1 },
+        );
+        node.emit_with_id(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+            EventID { tx_digest, event_seq: 0 },
+        );
+
+        let watched: Vec<_> = watch_events(node, packages(), None, options())
+            .take(2)
+            .map(|watched| watched.unwrap().event)
+            .collect()
+            .await;
+
+        assert!(matches!(watched[0], NotarizationEvent::DynamicNotarizationCreated(_)));
+        assert!(matches!(watched[1], NotarizationEvent::NotarizationMetadataUpdated(_)));
+    }
+
+    #[tokio::test]
+    async fn watch_resumes_from_cursor() {
+        let node = StandInNode::default();
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+        );
+        node.emit("notarization", "NotarizationDestroyed", dbg_object_id(1), 2);
+
+        let first = pin!(watch_events(node.clone(), packages(), None, options()))
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+
+        let resumed = pin!(watch_events(
+            node,
+            packages(),
+            None,
+            options().with_cursor(first.cursor)
+        ))
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert!(matches!(resumed.event, NotarizationEvent::NotarizationDestroyed(_)));
+    }
+
+    #[tokio::test]
+    async fn watch_filters_by_notarization_and_ends_on_destroy() {
+        let node = StandInNode::default();
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+        );
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(2),
+            2,
+        );
+        node.emit("notarization", "NotarizationDestroyed", dbg_object_id(1), 3);
+
+        let watched: Vec<_> = watch_events(node, packages(), Some(dbg_object_id(1)), options())
+            .map(|watched| watched.unwrap().event.notarization_id())
+            .collect()
+            .await;
+
+        assert_eq!(watched, vec![dbg_object_id(1), dbg_object_id(1)]);
+    }
+
+    #[tokio::test]
+    async fn watch_skips_other_notarizations_before_decoding() {
+        let node = StandInNode::default();
+        node.emit("notarization", "UnknownEvent", dbg_object_id(2), 1);
+        node.emit("notarization", "NotarizationDestroyed", dbg_object_id(1), 2);
+
+        let watched: Vec<_> = watch_events(node, packages(), Some(dbg_object_id(1)), options())
+            .map(|watched| watched.unwrap().event)
+            .collect()
+            .await;
+
+        assert_eq!(watched.len(), 1);
+        assert!(matches!(watched[0], NotarizationEvent::NotarizationDestroyed(_)));
+    }
+
+    #[tokio::test]
+    async fn watch_yields_events_of_upgraded_package_versions() {
+        let node = StandInNode::default();
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+        );
+        node.emit_in(
+            UPGRADED_PACKAGE,
+            "notarization",
+            "NotarizationDestroyed",
+            dbg_object_id(1),
+            2,
+        );
+
+        let watched: Vec<_> = watch_events(node, packages(), None, options())
+            .take(2)
+            .map(|watched| watched.unwrap().event)
+            .collect()
+            .await;
+
+        assert!(matches!(watched[0], NotarizationEvent::DynamicNotarizationCreated(_)));
+        assert!(matches!(watched[1], NotarizationEvent::NotarizationDestroyed(_)));
+    }
+
+    #[tokio::test]
+    async fn watch_polls_for_new_events() {
+        let node = StandInNode::default();
+        node.emit(
+            "dynamic_notarization",
+            "DynamicNotarizationCreated",
+            dbg_object_id(1),
+            1,
+        );
+
+        let mut stream = pin!(watch_events(node.clone(), packages(), None, options()));
+        assert!(stream.next().await.unwrap().is_ok());
+
+        node.emit("notarization", "NotarizationDestroyed", dbg_object_id(1), 2);
+        let next = stream.next().await.unwrap().unwrap();
+
+        assert!(matches!(next.event, NotarizationEvent::NotarizationDestroyed(_)));
+    }
+}
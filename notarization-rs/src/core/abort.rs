@@ -0,0 +1,287 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Move Aborts
+//!
+//! Decoding of the Move aborts raised by the Notarization Package from the
+//! execution errors reported by IOTA nodes.
+//!
+//! An abort is attributed to the Notarization Package only if the address of
+//! the aborting module is one of the versions of the package. The names of
+//! its abort codes are resolved from [`ABORT_CODES`], which the build script
+//! generates from the Move sources of the package, and mapped to dedicated
+//! [`Error`] variants, e.g. [`Error::UpdateWhileLocked`]; all other aborts,
+//! including aborts of other packages with colliding module names, are
+//! returned as [`Error::MoveAbort`].
+
+use std::str::FromStr;
+
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaExecutionStatus, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI};
+use iota_sdk_types::ObjectId;
+use product_common::core_client::CoreClientReadOnly;
+
+use super::types::NotarizationMethod;
+use crate::error::{Error, MoveAbort};
+use crate::package;
+
+include!(concat!(env!("OUT_DIR"), "/abort_codes.rs"));
+
+/// Returns the name of the abort constant `code` of the Notarization Package
+/// module `module`.
+pub(crate) fn abort_name(module: &str, code: u64) -> Option<&'static str> {
+    ABORT_CODES
+        .iter()
+        .find(|(abort_module, abort_code, _)| *abort_module == module && *abort_code == code)
+        .map(|(_, _, name)| *name)
+}
+
+/// Returns the value of the abort constant `name` of the Notarization Package
+/// module `module`.
+#[cfg(all(any(test, feature = "test-utils"), not(target_arch = "wasm32")))]
+pub(crate) fn abort_code(module: &str, name: &str) -> Option<u64> {
+    ABORT_CODES
+        .iter()
+        .find(|(abort_module, _, abort_name)| *abort_module == module && *abort_name == name)
+        .map(|(_, code, _)| *code)
+}
+
+/// An abort parsed from an execution error, before its name is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AbortLocation {
+    pub(crate) address: Option<ObjectId>,
+    pub(crate) module: String,
+    pub(crate) function: Option<String>,
+    pub(crate) code: u64,
+}
+
+/// Decodes the [`MoveAbort`] of an execution error.
+///
+/// Returns the abort together with whether it was raised by one of
+/// `package_versions`; the name of the abort code is only resolved for
+/// aborts of the Notarization Package. Returns `None` if `error` is not a
+/// Move abort.
+pub(crate) fn decode_move_abort(error: &str, package_versions: &[ObjectId]) -> Option<(MoveAbort, bool)> {
+    let location = parse_abort_location(error)?;
+    let is_package_abort = location
+        .address
+        .is_some_and(|address| package_versions.contains(&address));
+    let name = is_package_abort
+        .then(|| abort_name(&location.module, location.code))
+        .flatten()
+        .map(str::to_string);
+
+    Some((
+        MoveAbort {
+            module: location.module,
+            function: location.function,
+            code: location.code,
+            name,
+        },
+        is_package_abort,
+    ))
+}
+
+/// Parses the location and code of an execution error of the form
+/// `MoveAbort(MoveLocation { module: ModuleId { address: <address>, name: Identifier("<module>") }, function: ..,
+/// instruction: .., function_name: Some("<function>") }, <code>) in command <index>`.
+pub(crate) fn parse_abort_location(error: &str) -> Option<AbortLocation> {
+    let abort = &error[error.find("MoveAbort(")?..];
+    let abort = abort.find(") in command").map_or(abort, |end| &abort[..end]);
+
+    let address = between(abort, "address: ", ",").and_then(|address| {
+        let address = address.trim().trim_start_matches("0x");
+        ObjectId::from_str(&format!("0x{address}")).ok()
+    });
+    let module = between(abort, "name: Identifier(\"", "\")")?.to_string();
+    let function = between(abort, "function_name: Some(\"", "\")").map(str::to_string);
+    let code = abort[abort.rfind("}, ")? + 3..]
+        .trim_end_matches(')')
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(AbortLocation {
+        address,
+        module,
+        function,
+        code,
+    })
+}
+
+/// Maps a decoded [`MoveAbort`] of the Notarization Package to the dedicated
+/// [`Error`] variant of its constant, or to [`Error::MoveAbort`] if there is
+/// none.
+pub(crate) fn abort_error(abort: MoveAbort) -> Error {
+    match (abort.module.as_str(), abort.name.as_deref()) {
+        ("notarization", Some("EUpdateWhileLocked")) => Error::UpdateWhileLocked,
+        ("notarization", Some("EDestroyWhileLocked")) => Error::DestroyWhileLocked,
+        ("notarization", Some("ELockTimeNotSatisfied")) => Error::LockTimeNotSatisfied,
+        ("notarization", Some("EUntilDestroyedLockNotAllowed")) => Error::UntilDestroyedLockNotAllowed,
+        ("notarization", Some("EDynamicNotarizationInvariants")) => {
+            Error::MethodInvariantsViolated(NotarizationMethod::Dynamic)
+        }
+        ("notarization", Some("ELockedNotarizationInvariants")) => {
+            Error::MethodInvariantsViolated(NotarizationMethod::Locked)
+        }
+        ("notarization", Some("ENotDynamicNotarization")) => Error::NotDynamicNotarization,
+        ("notarization", Some("ESealShortensRetention")) => Error::SealShortensRetention,
+        ("notarization", Some("ENotSharedNotarization")) => Error::NotSharedNotarization,
+        ("notarization", Some("ECapabilityRequired")) => Error::CapabilityRequired,
+        ("notarization", Some("ESharedNotarizationInvariants")) => {
+            Error::MethodInvariantsViolated(NotarizationMethod::Shared)
+        }
+        ("dynamic_notarization", Some("ECannotTransferLocked")) => Error::CannotTransferLocked,
+        ("timelock" | "lock_at", Some("EPastTimestamp")) => Error::PastTimestamp,
+        ("timelock", Some("ETimelockNotExpired")) => Error::TimelockNotExpired,
+        ("shared_notarization", Some("EInvalidCapability")) => Error::InvalidCapability,
+        ("shared_notarization", Some("EAdminRoleRequired")) => Error::AdminRoleRequired,
+        ("shared_notarization", Some("ECapabilityNotFound")) => Error::CapabilityNotFound,
+        ("endorsement", Some("EInvalidStateHash")) => Error::InvalidStateHash,
+        _ => Error::MoveAbort(abort),
+    }
+}
+
+/// Converts the execution error of a failed transaction into an [`Error`],
+/// attributing aborts to the Notarization Package if raised by one of
+/// `package_versions`.
+pub(crate) fn package_execution_error(error: &str, package_versions: &[ObjectId]) -> Error {
+    match decode_move_abort(error, package_versions) {
+        Some((abort, true)) => abort_error(abort),
+        Some((abort, false)) => Error::MoveAbort(abort),
+        None => Error::TransactionUnexpectedResponse(format!("transaction failed: {error}")),
+    }
+}
+
+/// Converts the execution error of a failed transaction into an [`Error`].
+pub(crate) async fn execution_error<C>(client: &C, error: &str) -> Error
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    package_execution_error(error, &package::package_versions(client).await)
+}
+
+/// Returns the [`Error`] of a transaction that failed on-chain.
+///
+/// Called first by the `apply_with_events` implementations, so aborted
+/// transactions surface their abort instead of a missing event.
+pub(crate) async fn check_execution_status<C>(effects: &IotaTransactionBlockEffects, client: &C) -> Result<(), Error>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    match effects.status() {
+        IotaExecutionStatus::Failure { error } => Err(execution_error(client, error).await),
+        IotaExecutionStatus::Success => Ok(()),
+    }
+}
+
+/// Returns the part of `text` between the first `start` and the following `end`.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+
+    Some(&text[from..to])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGE: &str = "0x1b3f4c0e0d8a6c7d2b6e1c3a9f8e7d6c5b4a39281706f5e4d3c2b1a098765432";
+
+    const ABORT: &str = "MoveAbort(MoveLocation { module: ModuleId { address: \
+                         1b3f4c0e0d8a6c7d2b6e1c3a9f8e7d6c5b4a39281706f5e4d3c2b1a098765432, name: \
+                         Identifier(\"notarization\") }, function: 12, instruction: 10, function_name: \
+                         Some(\"update_state\") }, 0) in command 0";
+
+    fn package_versions() -> Vec<ObjectId> {
+        vec![ObjectId::from_str(PACKAGE).unwrap()]
+    }
+
+    #[test]
+    fn decode_move_abort_decodes_location_and_code() {
+        assert_eq!(
+            decode_move_abort(ABORT, &package_versions()),
+            Some((
+                MoveAbort {
+                    module: "notarization".to_string(),
+                    function: Some("update_state".to_string()),
+                    code: 0,
+                    name: Some("EUpdateWhileLocked".to_string()),
+                },
+                true
+            ))
+        );
+    }
+
+    #[test]
+    fn decode_move_abort_keeps_unknown_codes() {
+        let abort = ABORT
+            .replace("}, 0)", "}, 42)")
+            .replace("Some(\"update_state\")", "None");
+
+        assert_eq!(
+            decode_move_abort(&abort, &package_versions()),
+            Some((
+                MoveAbort {
+                    module: "notarization".to_string(),
+                    function: None,
+                    code: 42,
+                    name: None,
+                },
+                true
+            ))
+        );
+    }
+
+    #[test]
+    fn decode_move_abort_ignores_names_of_other_packages() {
+        let (abort, is_package_abort) = decode_move_abort(ABORT, &[ObjectId::ZERO]).unwrap();
+
+        assert!(!is_package_abort);
+        assert_eq!(abort.name, None);
+        assert!(matches!(
+            package_execution_error(ABORT, &[ObjectId::ZERO]),
+            Error::MoveAbort(MoveAbort { code: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn execution_error_maps_known_aborts_to_variants() {
+        let versions = package_versions();
+        assert!(matches!(
+            package_execution_error(ABORT, &versions),
+            Error::UpdateWhileLocked
+        ));
+
+        let transfer = ABORT
+            .replace("Identifier(\"notarization\")", "Identifier(\"dynamic_notarization\")")
+            .replace("Some(\"update_state\")", "Some(\"transfer\")");
+        assert!(matches!(
+            package_execution_error(&transfer, &versions),
+            Error::CannotTransferLocked
+        ));
+
+        let invariants = ABORT.replace("}, 0)", "}, 9)");
+        assert!(matches!(
+            package_execution_error(&invariants, &versions),
+            Error::MethodInvariantsViolated(NotarizationMethod::Shared)
+        ));
+
+        let unknown = ABORT.replace("}, 0)", "}, 42)");
+        assert!(matches!(
+            package_execution_error(&unknown, &versions),
+            Error::MoveAbort(MoveAbort { code: 42, .. })
+        ));
+        assert!(matches!(
+            package_execution_error("InsufficientGas", &versions),
+            Error::TransactionUnexpectedResponse(_)
+        ));
+    }
+
+    #[test]
+    fn parse_abort_location_rejects_other_errors() {
+        assert_eq!(parse_abort_location("InsufficientGas"), None);
+        assert_eq!(parse_abort_location("MoveAbort(garbage"), None);
+    }
+}
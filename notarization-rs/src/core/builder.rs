@@ -12,8 +12,12 @@
 //! mutation and destruction rules of the resulting object:
 //!
 //! - `Locked` — state and updatable metadata are immutable after creation; destruction is gated by a `delete_lock`.
-//! - `Dynamic` — state and updatable metadata can be updated after creation; ownership may optionally be
-//!   transfer-locked.
+//! - `Dynamic` — state and updatable metadata can be updated after creation, optionally only within a window set by
+//!   an `update_lock`; ownership may optionally be transfer-locked and destruction may optionally be gated by a
+//!   `delete_lock`.
+//! - `Shared` — the notarization is a shared object whose state and updatable metadata can be updated by every holder
+//!   of a valid capability, optionally only within a window set by an `update_lock`; it cannot be transferred and
+//!   destruction may optionally be gated by a `delete_lock`.
 //!
 //! ## Examples
 //!
@@ -42,14 +46,32 @@
 //!     .with_transfer_lock(TimeLock::None) // Can be transferred freely
 //!     .finish();
 //! ```
+//!
+//! ### Creating a Shared Notarization
+//!
+//! ```rust,ignore
+//! use notarization::core::builder::NotarizationBuilder;
+//!
+//! let builder = NotarizationBuilder::shared()
+//!     .with_string_state("Shipment status: pending".to_string(), None)
+//!     .with_immutable_description("Shipment 4711".to_string())
+//!     .finish();
+//! ```
 
 use std::marker::PhantomData;
 
+use iota_interaction::{OptionalSend, OptionalSync};
 use product_common::transaction::transaction_builder::TransactionBuilder;
 
-use super::transactions::CreateNotarization;
-use super::types::{NotarizationMethod, State, TimeLock};
-use crate::error::Error;
+use super::merkle::MerkleTree;
+#[cfg(not(target_arch = "wasm32"))]
+use super::stream::{self, HashProgress};
+use super::transactions::{CreateNotarization, CreateSharedNotarization};
+use super::types::{
+    Data, Digest, DigestAlgorithm, GenericData, NotarizationMethod, State, StateData, TimeLock, UpdateLock,
+};
+use crate::client::retry::RetryPolicy;
+use crate::error::{Error, TimeLockError};
 
 /// Marker type for the `Locked` Notarization Method.
 #[derive(Clone)]
@@ -59,15 +81,22 @@ pub struct Locked;
 #[derive(Clone)]
 pub struct Dynamic;
 
+/// Marker type for the `Shared` Notarization Method.
+#[derive(Clone)]
+pub struct Shared;
+
 /// A builder for constructing notarization transactions.
 ///
 /// The type parameter `M` selects the Notarization Method and enforces the
-/// associated configuration constraints at compile time. The two supported
-/// markers are [`Locked`] and [`Dynamic`].
+/// associated configuration constraints at compile time. The supported
+/// markers are [`Locked`], [`Dynamic`] and [`Shared`].
+///
+/// The type parameter `D` is the type of the notarized payload, defaults to
+/// [`Data`], and is changed by [`Self::with_generic_state`].
 #[derive(Debug, Clone)]
-pub struct NotarizationBuilder<M> {
+pub struct NotarizationBuilder<M, D = Data> {
     /// The notarized payload and its optional state-associated metadata.
-    pub state: Option<State>,
+    pub state: Option<State<D>>,
     /// A permanent description set at creation.
     pub immutable_description: Option<String>,
     /// Initial updatable metadata.
@@ -75,16 +104,22 @@ pub struct NotarizationBuilder<M> {
     /// Mutability after creation depends on the Notarization Method:
     /// * `Dynamic`: updatable after creation via
     ///   [`NotarizationClient::update_metadata`](crate::client::NotarizationClient::update_metadata).
+    /// * `Shared`: updatable after creation via
+    ///   [`NotarizationClient::update_metadata_with_capability`](crate::client::NotarizationClient::update_metadata_with_capability).
     /// * `Locked`: immutable after creation.
     pub updatable_metadata: Option<String>,
-    /// Time restriction for destruction. Only configurable for the `Locked`
-    /// Notarization Method.
+    /// Time restriction for updates of `state` and `updatable_metadata`.
+    /// Only configurable for the `Dynamic` and `Shared` Notarization Methods.
+    pub update_lock: Option<UpdateLock>,
+    /// Time restriction for destruction.
     pub delete_lock: Option<TimeLock>,
     /// Time restriction for ownership transfer. Only configurable for the
     /// `Dynamic` Notarization Method.
     pub transfer_lock: Option<TimeLock>,
     /// The Notarization Method.
     pub method: NotarizationMethod,
+    /// The policy for retrying the lookups of the created notarization.
+    pub(crate) retry_policy: RetryPolicy,
     _marker: PhantomData<M>,
 }
 
@@ -108,13 +143,17 @@ impl NotarizationBuilder<Locked> {
             state: None,
             immutable_description: None,
             updatable_metadata: None,
+            update_lock: None,
             delete_lock: None,
             transfer_lock: None,
             method: NotarizationMethod::Locked,
+            retry_policy: RetryPolicy::default(),
             _marker: PhantomData,
         }
     }
+}
 
+impl<D> NotarizationBuilder<Locked, D> {
     /// Sets when the notarization can be destroyed.
     ///
     /// By default, Locked-Notarizations can be destroyed freely. Use this
@@ -154,7 +193,10 @@ impl NotarizationBuilder<Locked> {
     ///     .finish()?;
     /// # Ok::<(), notarization::Error>(())
     /// ```
-    pub fn finish(self) -> Result<TransactionBuilder<CreateNotarization<Locked>>, Error> {
+    pub fn finish(self) -> Result<TransactionBuilder<CreateNotarization<Locked, D>>, Error>
+    where
+        D: StateData + Clone + OptionalSend + OptionalSync,
+    {
         Ok(TransactionBuilder::new(CreateNotarization::new(self)))
     }
 }
@@ -178,13 +220,17 @@ impl NotarizationBuilder<Dynamic> {
             state: None,
             immutable_description: None,
             updatable_metadata: None,
+            update_lock: None,
             delete_lock: None,
             transfer_lock: None,
             method: NotarizationMethod::Dynamic,
+            retry_policy: RetryPolicy::default(),
             _marker: PhantomData,
         }
     }
+}
 
+impl<D> NotarizationBuilder<Dynamic, D> {
     /// Sets restrictions on when the notarization can be transferred.
     ///
     /// By default, Dynamic-Notarizations can be transferred freely. Use this
@@ -213,6 +259,68 @@ impl NotarizationBuilder<Dynamic> {
         self
     }
 
+    /// Sets a retention period during which the notarization cannot be
+    /// destroyed.
+    ///
+    /// By default, Dynamic-Notarizations can be destroyed freely. Unlike for
+    /// Locked-Notarizations, the `delete_lock` does not prevent updates of
+    /// `state` and `updatable_metadata`.
+    ///
+    /// ## Parameters
+    ///
+    /// `lock`: The time-based restriction for deletion. See [`TimeLock`] for more details.
+    /// - `TimeLock::None`: Can be destroyed anytime (default)
+    /// - `TimeLock::UnlockAt(timestamp)`: Can be destroyed after a specific timestamp, which must not be earlier
+    ///   than an `UnlockAt` transfer lock
+    /// - `TimeLock::UntilDestroyed`: Can NOT be used for `delete_lock`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    ///
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::types::TimeLock;
+    ///
+    /// // Retain the status record for 7 years
+    /// let builder = NotarizationBuilder::dynamic()
+    ///     .with_delete_lock(TimeLock::unlock_after(Duration::from_secs(7 * 365 * 24 * 60 * 60))?);
+    /// ```
+    pub fn with_delete_lock(mut self, lock: TimeLock) -> Self {
+        self.delete_lock = Some(lock);
+        self
+    }
+
+    /// Sets the window in which `state` and `updatable_metadata` can be
+    /// updated.
+    ///
+    /// By default, Dynamic-Notarizations can be updated at any time.
+    ///
+    /// ## Parameters
+    ///
+    /// `lock`: The time-based restriction for updates. See [`UpdateLock`] for more details.
+    /// - `TimeLock::None`: Can be updated anytime (default)
+    /// - `TimeLock::UnlockAt(timestamp)`: Embargo — can only be updated after a specific timestamp. A delete lock must
+    ///   not unlock before it.
+    /// - `UpdateLock::LockAt(timestamp)`: Freeze — can only be updated before a specific timestamp
+    /// - `TimeLock::UntilDestroyed`: Can NOT be used for the `update_lock` of Dynamic-Notarizations; use a
+    ///   Locked-Notarization instead
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::types::UpdateLock;
+    ///
+    /// // Accept corrections until the filing deadline, then freeze the record
+    /// let builder = NotarizationBuilder::dynamic()
+    ///     .with_update_lock(UpdateLock::lock_at_datetime("2030-04-15T00:00:00Z".parse()?)?);
+    /// ```
+    pub fn with_update_lock(mut self, lock: impl Into<UpdateLock>) -> Self {
+        self.update_lock = Some(lock.into());
+        self
+    }
+
     /// Finalizes the builder and creates a transaction builder.
     ///
     /// Unlike Locked-Notarizations, Dynamic-Notarizations have no required
@@ -228,12 +336,101 @@ impl NotarizationBuilder<Dynamic> {
     ///     .with_immutable_description("Status Monitor")
     ///     .finish();
     /// ```
-    pub fn finish(self) -> TransactionBuilder<CreateNotarization<Dynamic>> {
+    pub fn finish(self) -> TransactionBuilder<CreateNotarization<Dynamic, D>>
+    where
+        D: StateData + Clone + OptionalSend + OptionalSync,
+    {
         TransactionBuilder::new(CreateNotarization::new(self))
     }
 }
 
-// Shared methods for both types
+impl NotarizationBuilder<Shared> {
+    /// Creates a new builder for a Shared-Notarization.
+    ///
+    /// Shared-Notarizations are shared objects that can be updated by
+    /// several parties. The creator receives a capability with the
+    /// [`Role::Admin`](super::types::Role::Admin) role, which permits updates
+    /// and issuing further capabilities via
+    /// [`NotarizationClient::issue_capability`](crate::client::NotarizationClient::issue_capability).
+    /// Shared-Notarizations cannot be transferred.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::shared().with_string_state("Initial state".to_string(), None);
+    /// ```
+    pub fn shared() -> Self {
+        Self {
+            state: None,
+            immutable_description: None,
+            updatable_metadata: None,
+            update_lock: None,
+            delete_lock: None,
+            transfer_lock: None,
+            method: NotarizationMethod::Shared,
+            retry_policy: RetryPolicy::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<D> NotarizationBuilder<Shared, D> {
+    /// Sets a retention period during which the notarization cannot be
+    /// destroyed.
+    ///
+    /// By default, Shared-Notarizations can be destroyed freely by the holder
+    /// of an admin capability.
+    ///
+    /// ## Parameters
+    ///
+    /// `lock`: The time-based restriction for deletion. See [`TimeLock`] for more details.
+    /// - `TimeLock::None`: Can be destroyed anytime (default)
+    /// - `TimeLock::UnlockAt(timestamp)`: Can be destroyed after a specific timestamp, which must not be earlier
+    ///   than an `UnlockAt` update lock
+    /// - `TimeLock::UntilDestroyed`: Can NOT be used for `delete_lock`
+    pub fn with_delete_lock(mut self, lock: TimeLock) -> Self {
+        self.delete_lock = Some(lock);
+        self
+    }
+
+    /// Sets the window in which `state` and `updatable_metadata` can be
+    /// updated.
+    ///
+    /// By default, Shared-Notarizations can be updated at any time.
+    ///
+    /// ## Parameters
+    ///
+    /// `lock`: The time-based restriction for updates. See [`TimeLock`] for more details.
+    /// - `TimeLock::None`: Can be updated anytime (default)
+    /// - `TimeLock::UnlockAt(timestamp)`: Embargo — can only be updated after a specific timestamp
+    /// - `TimeLock::UntilDestroyed`: Can NOT be used for the `update_lock` of Shared-Notarizations; seal the
+    ///   notarization with `NotarizationClient::seal_with_capability` to freeze it
+    pub fn with_update_lock(mut self, lock: TimeLock) -> Self {
+        self.update_lock = Some(lock.into());
+        self
+    }
+
+    /// Finalizes the builder and creates a transaction builder.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::core::builder::NotarizationBuilder;
+    /// let transaction = NotarizationBuilder::shared()
+    ///     .with_string_state("Shipment status: pending".to_string(), None)
+    ///     .finish();
+    /// ```
+    pub fn finish(self) -> TransactionBuilder<CreateSharedNotarization<D>>
+    where
+        D: StateData + Clone + OptionalSend + OptionalSync,
+    {
+        TransactionBuilder::new(CreateSharedNotarization::new(self))
+    }
+}
+
+// Shared methods for all types with `Data` payloads
 impl<M> NotarizationBuilder<M> {
     /// Sets the state (data) to be notarized.
     ///
@@ -299,6 +496,171 @@ impl<M> NotarizationBuilder<M> {
         self.with_state(State::from_string(data, metadata))
     }
 
+    /// Sets the state using a [`Digest`].
+    ///
+    /// Convenience method for hash-only notarizations. The digest is stored
+    /// together with its algorithm identifier, see [`State::from_digest`].
+    ///
+    /// ## Parameters
+    ///
+    /// - `digest`: The digest to notarize
+    /// - `metadata`: Optional metadata about the digested content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::types::{Digest, DigestAlgorithm};
+    ///
+    /// let digest = Digest::compute(DigestAlgorithm::Sha256, &pdf_bytes);
+    /// let builder = NotarizationBuilder::locked().with_digest_state(digest, None);
+    /// ```
+    pub fn with_digest_state(self, digest: Digest, metadata: Option<String>) -> Self {
+        self.with_state(State::from_digest(digest, metadata))
+    }
+
+    /// Sets the state to the root of a Merkle tree.
+    ///
+    /// Anchors all payloads of `tree` with this single notarization; see
+    /// [`merkle`](super::merkle) for how individual payloads are verified.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::merkle::MerkleTree;
+    /// use notarization::core::types::DigestAlgorithm;
+    ///
+    /// let tree = MerkleTree::new(DigestAlgorithm::Sha256, &log_lines)?;
+    /// let builder = NotarizationBuilder::locked().with_merkle_root(&tree, Some("Access log".to_string()));
+    /// ```
+    pub fn with_merkle_root(self, tree: &MerkleTree, metadata: Option<String>) -> Self {
+        self.with_state(tree.to_state(metadata))
+    }
+
+    /// Sets the state to the digest of a file's content.
+    ///
+    /// Hashes `content` with `algorithm` and notarizes only the resulting
+    /// digest, see [`Self::with_digest_state`].
+    ///
+    /// ## Parameters
+    ///
+    /// - `algorithm`: The hash algorithm to use
+    /// - `content`: The file content to hash
+    /// - `metadata`: Optional metadata about the file (e.g., "Contract PDF")
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::types::DigestAlgorithm;
+    ///
+    /// let content = std::fs::read("contract.pdf")?;
+    /// let builder = NotarizationBuilder::locked().with_file_digest(
+    ///     DigestAlgorithm::Sha256,
+    ///     content,
+    ///     Some("Contract PDF".to_string()),
+    /// );
+    /// ```
+    pub fn with_file_digest(
+        self,
+        algorithm: DigestAlgorithm,
+        content: impl AsRef<[u8]>,
+        metadata: Option<String>,
+    ) -> Self {
+        self.with_digest_state(Digest::compute(algorithm, content), metadata)
+    }
+
+    /// Sets the state to the digest of everything read from `reader`.
+    ///
+    /// The input is hashed in chunks, so arbitrarily large files can be
+    /// notarized without loading them into memory. `on_progress` is invoked
+    /// after every chunk, see [`stream::digest_reader`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Io`] if reading from `reader` fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use notarization::core::types::DigestAlgorithm;
+    ///
+    /// let file = std::fs::File::open("dataset.tar")?;
+    /// let builder = NotarizationBuilder::locked().with_reader_digest(
+    ///     DigestAlgorithm::Sha256,
+    ///     file,
+    ///     Some("Dataset archive".to_string()),
+    ///     |progress| println!("hashed {} bytes", progress.bytes_processed),
+    /// )?;
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_reader_digest(
+        self,
+        algorithm: DigestAlgorithm,
+        reader: impl std::io::Read,
+        metadata: Option<String>,
+        on_progress: impl FnMut(HashProgress),
+    ) -> Result<Self, Error> {
+        let digest = stream::digest_reader(algorithm, reader, on_progress)?;
+        Ok(self.with_digest_state(digest, metadata))
+    }
+
+    /// Sets the state to the digest of everything read from the asynchronous
+    /// `reader`.
+    ///
+    /// Asynchronous counterpart of [`Self::with_reader_digest`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::Io`] if reading from `reader` fails.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn with_async_reader_digest(
+        self,
+        algorithm: DigestAlgorithm,
+        reader: impl tokio::io::AsyncRead + Unpin,
+        metadata: Option<String>,
+        on_progress: impl FnMut(HashProgress),
+    ) -> Result<Self, Error> {
+        let digest = stream::digest_async_reader(algorithm, reader, on_progress).await?;
+        Ok(self.with_digest_state(digest, metadata))
+    }
+}
+
+// Shared methods for all types and all payload types
+impl<M, D> NotarizationBuilder<M, D> {
+    /// Sets a typed payload as the state to be notarized.
+    ///
+    /// Changes the payload type of the builder to `T`. The resulting
+    /// notarization is a `Notarization<D>` where `D` is the Move type of `T`,
+    /// see [`State::from_generic`] for the supported types.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::dynamic()
+    ///     .with_generic_state(vec![21u64, 22, 23], Some("Sensor readings".to_string()));
+    /// ```
+    pub fn with_generic_state<T>(self, data: T, metadata: Option<String>) -> NotarizationBuilder<M, T>
+    where
+        T: GenericData,
+    {
+        NotarizationBuilder {
+            state: Some(State::from_generic(data, metadata)),
+            immutable_description: self.immutable_description,
+            updatable_metadata: self.updatable_metadata,
+            update_lock: self.update_lock,
+            delete_lock: self.delete_lock,
+            transfer_lock: self.transfer_lock,
+            method: self.method,
+            retry_policy: self.retry_policy,
+            _marker: PhantomData,
+        }
+    }
+
     /// Sets a permanent description for the notarization.
     ///
     /// This description is immutable and cannot be changed after creation.
@@ -322,6 +684,8 @@ impl<M> NotarizationBuilder<M> {
     /// Mutability after creation depends on the Notarization Method:
     /// * `Dynamic`: updatable via
     ///   [`NotarizationClient::update_metadata`](crate::client::NotarizationClient::update_metadata).
+    /// * `Shared`: updatable via
+    ///   [`NotarizationClient::update_metadata_with_capability`](crate::client::NotarizationClient::update_metadata_with_capability).
     /// * `Locked`: fixed at creation alongside `state`.
     ///
     /// ## Example
@@ -336,4 +700,124 @@ impl<M> NotarizationBuilder<M> {
         self.updatable_metadata = Some(metadata);
         self
     }
+
+    /// Sets the policy for retrying the lookups of the created notarization
+    /// made once the creating transaction has been executed.
+    ///
+    /// The builders returned by [`NotarizationClient`](crate::client::NotarizationClient)
+    /// use the retry policy of the client.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Checks that every `TimeLock::UnlockAt` and `UpdateLock::LockAt` lock of
+    /// the builder unlocks or locks after `now_secs`, in seconds since the Unix
+    /// epoch.
+    ///
+    /// The on-chain package rejects lock times that are not in the future,
+    /// so pass the time of the on-chain clock to detect such locks before
+    /// submitting a transaction. See
+    /// [`NotarizationClientReadOnly::validate_locks`](crate::client::NotarizationClientReadOnly::validate_locks)
+    /// for a variant reading the on-chain clock.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`TimeLockError::NotInFuture`] if a lock unlocks or locks at or
+    /// before `now_secs`.
+    pub fn validate_locks_at(&self, now_secs: u64) -> Result<(), Error> {
+        let update_lock_time = match &self.update_lock {
+            Some(UpdateLock::TimeLock(TimeLock::UnlockAt(time)) | UpdateLock::LockAt(time)) => Some(*time),
+            _ => None,
+        };
+        let lock_times = [&self.delete_lock, &self.transfer_lock]
+            .into_iter()
+            .flatten()
+            .filter_map(|lock| match lock {
+                TimeLock::UnlockAt(time) => Some(*time),
+                _ => None,
+            });
+
+        for time in update_lock_time.into_iter().chain(lock_times) {
+            if u64::from(time) <= now_secs {
+                return Err(Error::TimeLock(TimeLockError::NotInFuture {
+                    requested_time: time,
+                    chain_time: now_secs,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops the Notarization Method marker; the method is still known from
+    /// [`Self::method`].
+    pub(crate) fn into_untyped(self) -> NotarizationBuilder<(), D> {
+        NotarizationBuilder {
+            state: self.state,
+            immutable_description: self.immutable_description,
+            updatable_metadata: self.updatable_metadata,
+            update_lock: self.update_lock,
+            delete_lock: self.delete_lock,
+            transfer_lock: self.transfer_lock,
+            method: self.method,
+            retry_policy: self.retry_policy,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_locks_at_rejects_locks_not_in_future() {
+        let builder = NotarizationBuilder::locked().with_delete_lock(TimeLock::UnlockAt(1_000));
+
+        assert!(builder.validate_locks_at(999).is_ok());
+        assert!(matches!(
+            builder.validate_locks_at(1_000),
+            Err(Error::TimeLock(TimeLockError::NotInFuture {
+                requested_time: 1_000,
+                chain_time: 1_000
+            }))
+        ));
+
+        let builder = NotarizationBuilder::dynamic().with_transfer_lock(TimeLock::UnlockAt(1_000));
+        assert!(matches!(
+            builder.validate_locks_at(2_000),
+            Err(Error::TimeLock(TimeLockError::NotInFuture {
+                requested_time: 1_000,
+                chain_time: 2_000
+            }))
+        ));
+
+        let builder = NotarizationBuilder::dynamic().with_update_lock(UpdateLock::LockAt(1_000));
+        assert!(builder.validate_locks_at(999).is_ok());
+        assert!(matches!(
+            builder.validate_locks_at(1_000),
+            Err(Error::TimeLock(TimeLockError::NotInFuture {
+                requested_time: 1_000,
+                chain_time: 1_000
+            }))
+        ));
+    }
+
+    #[test]
+    fn validate_locks_at_ignores_other_locks() {
+        assert!(NotarizationBuilder::locked().validate_locks_at(u64::MAX).is_ok());
+        assert!(
+            NotarizationBuilder::locked()
+                .with_delete_lock(TimeLock::None)
+                .validate_locks_at(u64::MAX)
+                .is_ok()
+        );
+        assert!(
+            NotarizationBuilder::dynamic()
+                .with_transfer_lock(TimeLock::UntilDestroyed)
+                .validate_locks_at(u64::MAX)
+                .is_ok()
+        );
+    }
 }
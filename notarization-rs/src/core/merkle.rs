@@ -0,0 +1,365 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Merkle Anchoring
+//!
+//! This module anchors many payloads with a single notarization.
+//!
+//! ## Overview
+//!
+//! For high-volume data (log lines, sensor readings, ...) notarizing every
+//! payload on its own is too expensive. Instead a [`MerkleTree`] is built over
+//! all payloads and only its root is notarized, as a hash-only
+//! [`Digest`] state. For every payload the tree produces a [`MerkleProof`]
+//! that can be stored next to the payload and later used to show that the
+//! payload was part of the anchored batch, without revealing the other
+//! payloads.
+//!
+//! ## Tree Layout
+//!
+//! Leaves and inner nodes are hashed with distinct prefixes to rule out
+//! second-preimage attacks between the two:
+//!
+//! ```text
+//! leaf = H(0x00 || payload)
+//! node = H(0x01 || left || right)
+//! ```
+//!
+//! If a level has an odd number of nodes, the last node is promoted to the
+//! next level unchanged instead of being paired with itself.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use notarization::core::merkle::MerkleTree;
+//! use notarization::core::types::DigestAlgorithm;
+//!
+//! let readings = ["21.3 °C", "21.4 °C", "21.2 °C"];
+//! let tree = MerkleTree::new(DigestAlgorithm::Sha256, readings)?;
+//!
+//! let notarization = client
+//!     .create_locked_notarization()
+//!     .with_merkle_root(&tree, Some("Sensor readings 2025-06-01".to_string()))
+//!     .with_delete_lock(TimeLock::None)
+//!     .finish()?
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output;
+//!
+//! let proof = tree.proof(1).unwrap();
+//! assert!(notarization.verify_inclusion("21.4 °C", &proof).data_matches);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{Digest, DigestAlgorithm, DigestHasher, OnChainNotarization, State};
+use super::verification::{LocalPayload, VerificationReport, verify};
+use crate::error::Error;
+
+/// Domain separation prefix of leaf hashes.
+const LEAF_PREFIX: u8 = 0x00;
+/// Domain separation prefix of inner node hashes.
+const NODE_PREFIX: u8 = 0x01;
+
+/// A Merkle tree over a list of payloads.
+///
+/// See the [module docs](self) for the tree layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// All levels of the tree, from the leaf hashes up to the root.
+    levels: Vec<Vec<Digest>>,
+}
+
+impl MerkleTree {
+    /// Builds a Merkle tree over `payloads` using `algorithm`.
+    ///
+    /// The order of `payloads` is significant: the proof of a payload is
+    /// obtained via its index in this order.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `payloads` is empty.
+    pub fn new<I>(algorithm: DigestAlgorithm, payloads: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let leaves: Vec<Digest> = payloads
+            .into_iter()
+            .map(|payload| hash_leaf(algorithm, payload.as_ref()))
+            .collect();
+
+        if leaves.is_empty() {
+            return Err(Error::InvalidArgument(
+                "a Merkle tree must contain at least one payload".to_string(),
+            ));
+        }
+
+        let mut levels = vec![leaves];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(algorithm, left.as_slice(), right.as_slice()),
+                    [single] => single.clone(),
+                    _ => unreachable!("chunks of two"),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self { levels })
+    }
+
+    /// Returns the algorithm used to hash the tree.
+    pub fn algorithm(&self) -> DigestAlgorithm {
+        self.root().algorithm()
+    }
+
+    /// Returns the number of payloads in the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> &Digest {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns a [`State`] holding the root of the tree, ready to be
+    /// notarized.
+    pub fn to_state(&self, metadata: Option<String>) -> State {
+        State::from_digest(self.root().clone(), metadata)
+    }
+
+    /// Returns the inclusion proof of the payload at `index`, or `None` if
+    /// `index` is out of bounds.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+
+        let mut path = Vec::with_capacity(self.levels.len() - 1);
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if let Some(node) = level.get(sibling) {
+                let side = if sibling < position { Side::Left } else { Side::Right };
+                path.push(MerkleProofStep {
+                    side,
+                    hash: node.as_slice().to_vec(),
+                });
+            }
+            position /= 2;
+        }
+
+        Some(MerkleProof {
+            algorithm: self.algorithm(),
+            leaf_index: index as u64,
+            path,
+        })
+    }
+
+    /// Returns the inclusion proofs of all payloads, in payload order.
+    pub fn proofs(&self) -> impl Iterator<Item = MerkleProof> + '_ {
+        (0..self.leaf_count()).filter_map(|index| self.proof(index))
+    }
+}
+
+/// The position of a sibling node relative to the node on the path to the
+/// root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Side {
+    /// The sibling is the left child of the parent node.
+    Left,
+    /// The sibling is the right child of the parent node.
+    Right,
+}
+
+/// A single step of a [`MerkleProof`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProofStep {
+    /// The position of the sibling node.
+    pub side: Side,
+    /// The hash of the sibling node.
+    pub hash: Vec<u8>,
+}
+
+/// Proof that a payload is included in a [`MerkleTree`].
+///
+/// The proof is self-contained and serializable, so it can be stored next to
+/// its payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProof {
+    /// The algorithm used to hash the tree.
+    pub algorithm: DigestAlgorithm,
+    /// The index of the payload in the tree.
+    pub leaf_index: u64,
+    /// The sibling nodes on the path from the leaf to the root.
+    pub path: Vec<MerkleProofStep>,
+}
+
+impl MerkleProof {
+    /// Computes the root of the tree that includes `payload` according to
+    /// this proof.
+    pub fn root(&self, payload: impl AsRef<[u8]>) -> Digest {
+        self.path
+            .iter()
+            .fold(hash_leaf(self.algorithm, payload.as_ref()), |node, step| {
+                match step.side {
+                    Side::Left => hash_node(self.algorithm, &step.hash, node.as_slice()),
+                    Side::Right => hash_node(self.algorithm, node.as_slice(), &step.hash),
+                }
+            })
+    }
+}
+
+/// Verifies that `payload` is included in the Merkle tree whose root is the
+/// current `state` of `notarization`.
+///
+/// The returned report has `data_matches` set if the root computed from
+/// `payload` and `proof` equals the notarized root.
+pub fn verify_inclusion(
+    notarization: &OnChainNotarization,
+    payload: impl AsRef<[u8]>,
+    proof: &MerkleProof,
+) -> VerificationReport {
+    verify(notarization, &LocalPayload::Digest(proof.root(payload).to_bytes()))
+}
+
+impl OnChainNotarization {
+    /// Verifies that `payload` is included in the Merkle tree anchored by
+    /// this notarization.
+    ///
+    /// See [`verify_inclusion`] for details.
+    pub fn verify_inclusion(&self, payload: impl AsRef<[u8]>, proof: &MerkleProof) -> VerificationReport {
+        verify_inclusion(self, payload, proof)
+    }
+}
+
+fn hash_leaf(algorithm: DigestAlgorithm, payload: &[u8]) -> Digest {
+    let mut hasher = DigestHasher::new(algorithm);
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(payload);
+    hasher.finalize()
+}
+
+fn hash_node(algorithm: DigestAlgorithm, left: &[u8], right: &[u8]) -> Digest {
+    let mut hasher = DigestHasher::new(algorithm);
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+    use iota_interaction::types::id::UID;
+    use iota_sdk_types::Address;
+
+    use super::*;
+    use crate::core::types::{ImmutableMetadata, NotarizationMethod};
+
+    fn payloads(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("reading #{i}")).collect()
+    }
+
+    fn make_notarization(state: State) -> OnChainNotarization {
+        OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state,
+            immutable_metadata: ImmutableMetadata {
+                created_at: 1_000,
+                description: None,
+                locking: None,
+            },
+            updatable_metadata: None,
+            last_state_change_at: 1_000,
+            state_version_count: 0,
+            method: NotarizationMethod::Locked,
+            owner: Address::ZERO,
+        }
+    }
+
+    #[test]
+    fn new_rejects_empty_input() {
+        assert!(MerkleTree::new(DigestAlgorithm::Sha256, Vec::<Vec<u8>>::new()).is_err());
+    }
+
+    #[test]
+    fn single_leaf_root_is_leaf_hash() {
+        let tree = MerkleTree::new(DigestAlgorithm::Sha256, ["only"]).unwrap();
+
+        assert_eq!(tree.root(), &hash_leaf(DigestAlgorithm::Sha256, b"only"));
+        assert!(tree.proof(0).unwrap().path.is_empty());
+        assert!(tree.proof(1).is_none());
+    }
+
+    #[test]
+    fn promotes_odd_node() {
+        let tree = MerkleTree::new(DigestAlgorithm::Sha256, ["a", "b", "c"]).unwrap();
+        let algorithm = DigestAlgorithm::Sha256;
+        let ab = hash_node(
+            algorithm,
+            hash_leaf(algorithm, b"a").as_slice(),
+            hash_leaf(algorithm, b"b").as_slice(),
+        );
+        let c = hash_leaf(algorithm, b"c");
+
+        assert_eq!(tree.root(), &hash_node(algorithm, ab.as_slice(), c.as_slice()));
+        assert_eq!(tree.proof(2).unwrap().path.len(), 1);
+    }
+
+    #[test]
+    fn all_proofs_verify() {
+        for count in 1..=17 {
+            let payloads = payloads(count);
+            let tree = MerkleTree::new(DigestAlgorithm::Blake2b256, &payloads).unwrap();
+
+            for (payload, proof) in payloads.iter().zip(tree.proofs()) {
+                assert_eq!(&proof.root(payload), tree.root(), "{count} leaves, {payload}");
+            }
+        }
+    }
+
+    #[test]
+    fn tampered_inputs_do_not_verify() {
+        let payloads = payloads(5);
+        let tree = MerkleTree::new(DigestAlgorithm::Sha256, &payloads).unwrap();
+        let proof = tree.proof(3).unwrap();
+
+        assert_ne!(&proof.root("tampered"), tree.root());
+        assert_ne!(&tree.proof(2).unwrap().root(&payloads[3]), tree.root());
+
+        let mut flipped = proof.clone();
+        flipped.path[0].side = Side::Right;
+        assert_ne!(&flipped.root(&payloads[3]), tree.root());
+    }
+
+    #[test]
+    fn verify_inclusion_against_notarization() {
+        let payloads = payloads(6);
+        let tree = MerkleTree::new(DigestAlgorithm::Sha256, &payloads).unwrap();
+        let notarization = make_notarization(tree.to_state(None));
+        let proof = tree.proof(4).unwrap();
+
+        assert!(notarization.verify_inclusion(&payloads[4], &proof).data_matches);
+        assert!(!notarization.verify_inclusion(&payloads[5], &proof).data_matches);
+
+        let other = make_notarization(State::from_string("unrelated".to_string(), None));
+        assert!(!other.verify_inclusion(&payloads[4], &proof).data_matches);
+    }
+
+    #[test]
+    fn proof_serde_roundtrip() {
+        let tree = MerkleTree::new(DigestAlgorithm::Sha3_256, payloads(4)).unwrap();
+        let proof = tree.proof(2).unwrap();
+
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(serde_json::from_str::<MerkleProof>(&json).unwrap(), proof);
+    }
+}
@@ -6,8 +6,14 @@
 //! This module contains the fundamental building blocks for creating and managing
 //! notarizations, including builders, state management, and transaction operations.
 
+pub(crate) mod abort;
 pub mod builder;
+pub mod dry_run;
+pub mod merkle;
 pub(crate) mod move_utils;
 pub(crate) mod operations;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod stream;
 pub mod transactions;
 pub mod types;
+pub mod verification;
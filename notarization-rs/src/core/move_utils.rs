@@ -3,18 +3,26 @@
 
 use std::str::FromStr;
 
-use iota_interaction::rpc_types::IotaObjectDataOptions;
+use blake2::Blake2b;
+use blake2::digest::Digest as _;
+use blake2::digest::consts::U32;
+use iota_interaction::rpc_types::{IotaObjectData, IotaObjectDataOptions, IotaObjectResponse};
 use iota_interaction::types::base_types::ObjectRef;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
 use iota_interaction::types::transaction::{CallArg, SharedObjectRef};
-use iota_interaction::types::{IOTA_CLOCK_OBJECT_ID, IOTA_CLOCK_OBJECT_SHARED_VERSION};
+use iota_interaction::types::{IOTA_CLOCK_OBJECT_ID, IOTA_CLOCK_OBJECT_SHARED_VERSION, MOVE_STDLIB_PACKAGE_ID};
 use iota_interaction::{IotaClientTrait, OptionalSync};
-use iota_sdk_types::{Argument, ObjectId, TypeTag};
+use iota_sdk_types::{Argument, ObjectId, Owner, TypeTag};
 use product_common::core_client::CoreClientReadOnly;
 use serde::Serialize;
 
+use crate::client::MULTI_GET_LIMIT;
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
+/// Domain separator of the IDs of child objects, e.g. dynamic fields.
+const CHILD_OBJECT_ID_SCOPE: u8 = 0xf0;
+
 /// Adds a reference to the on-chain clock to `ptb`'s arguments.
 pub(crate) fn get_clock_ref(ptb: &mut Ptb) -> Argument {
     ptb.obj(CallArg::Shared(SharedObjectRef {
@@ -36,17 +44,38 @@ where
     })
 }
 
-/// Get the type tag of an object
-pub(crate) async fn get_type_tag<C>(client: &C, object_id: &ObjectId) -> Result<TypeTag, Error>
+/// Get the type tag of an object, retrying the lookup according to
+/// `retry_policy`
+pub(crate) async fn get_type_tag<C>(
+    client: &C,
+    object_id: &ObjectId,
+    retry_policy: &RetryPolicy,
+) -> Result<TypeTag, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
 {
-    let object_response = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*object_id, IotaObjectDataOptions::new().with_type())
-        .await
-        .map_err(|err| Error::FailedToParseTag(format!("Failed to get object: {err}")))?;
+    let full_type_str = get_object_type(client, object_id, retry_policy).await?;
+
+    type_param_tag(&full_type_str)
+}
+
+/// Get the full type of an object, e.g. `0x..::notarization::Notarization<vector<u8>>`,
+/// retrying the lookup according to `retry_policy`
+pub(crate) async fn get_object_type<C>(
+    client: &C,
+    object_id: &ObjectId,
+    retry_policy: &RetryPolicy,
+) -> Result<String, Error>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let object_response = get_object(
+        client,
+        object_id,
+        IotaObjectDataOptions::new().with_type(),
+        retry_policy,
+    )
+    .await?;
 
     let object_data = object_response
         .data
@@ -57,12 +86,25 @@ where
         .map_err(|e| Error::FailedToParseTag(format!("Failed to get object type: {e}")))?
         .to_string();
 
-    let type_param_str = parse_type(&full_type_str)?;
+    Ok(full_type_str)
+}
+
+/// Parses the package ID of a full object type string, e.g. `0x123` of
+/// `0x123::notarization::Notarization<vector<u8>>`.
+pub(crate) fn type_package_id(full_type_str: &str) -> Result<ObjectId, Error> {
+    full_type_str
+        .split_once("::")
+        .and_then(|(package, _)| ObjectId::from_str(package).ok())
+        .ok_or_else(|| Error::FailedToParseTag(format!("Could not parse package ID from {full_type_str}")))
+}
 
-    let tag = TypeTag::from_str(&type_param_str)
-        .map_err(|e| Error::FailedToParseTag(format!("Failed to parse tag '{type_param_str}': {e}")))?;
+/// Parses the type parameter of a full object type string, e.g.
+/// `0x..::notarization::Notarization<vector<u8>>`, into a [`TypeTag`].
+pub(crate) fn type_param_tag(full_type_str: &str) -> Result<TypeTag, Error> {
+    let type_param_str = parse_type(full_type_str)?;
 
-    Ok(tag)
+    TypeTag::from_str(&type_param_str)
+        .map_err(|e| Error::FailedToParseTag(format!("Failed to parse tag '{type_param_str}': {e}")))
 }
 
 /// Parses the type string to get the generic argument
@@ -84,16 +126,40 @@ pub(crate) fn parse_type(full_type: &str) -> Result<String, Error> {
     }
 }
 
+/// Fetches the object `obj` with `options`, retrying failed lookups
+/// according to `retry_policy`.
+async fn get_object(
+    iota_client: &impl CoreClientReadOnly,
+    obj: &ObjectId,
+    options: IotaObjectDataOptions,
+    retry_policy: &RetryPolicy,
+) -> Result<IotaObjectResponse, Error> {
+    retry_policy
+        .run(|| async {
+            iota_client
+                .client_adapter()
+                .read_api()
+                .get_object_with_options(*obj, options.clone())
+                .await
+                .map_err(|err| Error::ObjectLookup(format!("Failed to get object {obj}: {err}")))
+        })
+        .await
+}
+
+/// Resolves the reference of the object `obj`, retrying the lookup
+/// according to `retry_policy`.
 pub(crate) async fn get_object_ref_by_id(
     iota_client: &impl CoreClientReadOnly,
     obj: &ObjectId,
+    retry_policy: &RetryPolicy,
 ) -> Result<ObjectRef, Error> {
-    let res = iota_client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*obj, IotaObjectDataOptions::new().with_content())
-        .await
-        .map_err(|err| Error::GenericError(format!("Failed to get object: {err}")))?;
+    let res = get_object(
+        iota_client,
+        obj,
+        IotaObjectDataOptions::new().with_content(),
+        retry_policy,
+    )
+    .await?;
 
     let Some(data) = res.data else {
         return Err(Error::InvalidArgument("no data found".to_string()));
@@ -102,6 +168,124 @@ pub(crate) async fn get_object_ref_by_id(
     Ok(data.object_ref())
 }
 
+/// Resolves the argument passing the object `obj` to a Move call.
+///
+/// Shared objects, e.g. Shared-Notarizations, are passed as shared object
+/// arguments with the given mutability, all other objects by reference.
+/// The lookup is retried according to `retry_policy`.
+pub(crate) async fn get_object_arg(
+    iota_client: &impl CoreClientReadOnly,
+    obj: &ObjectId,
+    mutable: bool,
+    retry_policy: &RetryPolicy,
+) -> Result<CallArg, Error> {
+    let res = get_object(
+        iota_client,
+        obj,
+        IotaObjectDataOptions::new().with_owner(),
+        retry_policy,
+    )
+    .await?;
+
+    let Some(data) = res.data else {
+        return Err(Error::InvalidArgument("no data found".to_string()));
+    };
+
+    Ok(object_arg(&data, mutable))
+}
+
+/// Resolves the argument passing the object of `data` to a Move call, see
+/// [`get_object_arg`].
+///
+/// `data` must have been fetched with `IotaObjectDataOptions::with_owner()`.
+pub(crate) fn object_arg(data: &IotaObjectData, mutable: bool) -> CallArg {
+    match data.owner {
+        Some(Owner::Shared(initial_shared_version)) => CallArg::Shared(SharedObjectRef {
+            object_id: data.object_id,
+            initial_shared_version,
+            mutable,
+        }),
+        _ => CallArg::ImmutableOrOwned(data.object_ref()),
+    }
+}
+
+/// Fetches the objects `object_ids` with multi-get queries of up to
+/// [`MULTI_GET_LIMIT`] objects each, retrying according to `retry_policy`.
+///
+/// The returned objects are in the order of `object_ids`.
+pub(crate) async fn multi_get_objects(
+    iota_client: &impl CoreClientReadOnly,
+    object_ids: &[ObjectId],
+    options: IotaObjectDataOptions,
+    retry_policy: &RetryPolicy,
+) -> Result<Vec<IotaObjectData>, Error> {
+    multi_get_existing_objects(iota_client, object_ids, options, retry_policy)
+        .await?
+        .into_iter()
+        .zip(object_ids)
+        .map(|(data, object_id)| {
+            data.ok_or_else(|| Error::ObjectLookup(format!("missing data for object {object_id}")))
+        })
+        .collect()
+}
+
+/// Fetches the objects `object_ids` like [`multi_get_objects`], returning
+/// `None` for the ones that do not exist.
+pub(crate) async fn multi_get_existing_objects(
+    iota_client: &impl CoreClientReadOnly,
+    object_ids: &[ObjectId],
+    options: IotaObjectDataOptions,
+    retry_policy: &RetryPolicy,
+) -> Result<Vec<Option<IotaObjectData>>, Error> {
+    let mut objects = Vec::with_capacity(object_ids.len());
+    for chunk in object_ids.chunks(MULTI_GET_LIMIT) {
+        let responses = retry_policy
+            .run(|| async {
+                iota_client
+                    .client_adapter()
+                    .read_api()
+                    .multi_get_object_with_options(chunk.to_vec(), options.clone())
+                    .await
+                    .map_err(|err| Error::ObjectLookup(err.to_string()))
+            })
+            .await?;
+
+        if responses.len() != chunk.len() {
+            return Err(Error::UnexpectedApiResponse(format!(
+                "requested {} objects, got {}",
+                chunk.len(),
+                responses.len()
+            )));
+        }
+
+        objects.extend(responses.into_iter().map(|response| response.data));
+    }
+
+    Ok(objects)
+}
+
+/// Derives the ID of the dynamic field of `parent` named by the `String`
+/// `name`, like `0x2::dynamic_field::hash_type_and_key` does on-chain.
+///
+/// Lets dynamic fields be fetched by ID, e.g. with [`multi_get_objects`],
+/// instead of one `get_dynamic_field_object` query per field.
+pub(crate) fn string_dynamic_field_id(parent: ObjectId, name: &str) -> ObjectId {
+    let name_type = TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
+        .expect("the type tag of `String` is valid");
+    let name = bcs::to_bytes(name).expect("strings are serializable");
+    let hash = Blake2b::<U32>::new()
+        .chain_update([CHILD_OBJECT_ID_SCOPE])
+        .chain_update(bcs::to_bytes(&parent).expect("IDs are serializable"))
+        .chain_update((name.len() as u64).to_le_bytes())
+        .chain_update(&name)
+        .chain_update(bcs::to_bytes(&name_type).expect("type tags are serializable"))
+        .finalize();
+    let mut id = [0; 32];
+    id.copy_from_slice(&hash);
+
+    ObjectId::new(id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +324,30 @@ mod tests {
         let type_param_str = parse_type(full_type).unwrap();
         assert_eq!(type_param_str, "");
     }
+
+    #[test]
+    fn test_type_package_id() {
+        let full_type = "0x123::notarization::Notarization<vector<u8>>";
+        assert_eq!(
+            type_package_id(full_type).unwrap(),
+            ObjectId::from_str("0x123").unwrap()
+        );
+        assert!(type_package_id("vector<u8>").is_err());
+    }
+
+    #[test]
+    fn test_string_dynamic_field_id() {
+        let parent = ObjectId::from_str("0x123").unwrap();
+        let name_type = TypeTag::from_str("0x1::string::String").unwrap();
+        let name = bcs::to_bytes("update_lock_at").unwrap();
+
+        assert_eq!(
+            string_dynamic_field_id(parent, "update_lock_at"),
+            iota_mock_rpc::dynamic_field_id(parent, &name_type, &name)
+        );
+        assert_ne!(
+            string_dynamic_field_id(parent, "update_lock_at"),
+            string_dynamic_field_id(parent, "shared_lock_metadata")
+        );
+    }
 }
@@ -15,11 +15,12 @@ use async_trait::async_trait;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::types::transaction::CallArg;
 use iota_interaction::{OptionalSync, ident_str};
-use iota_sdk_types::{Address, Argument, Identifier, ObjectId, ProgrammableTransaction};
+use iota_sdk_types::{Address, Argument, Identifier, ObjectId, ProgrammableTransaction, TypeTag};
 use product_common::core_client::CoreClientReadOnly;
 
 use super::move_utils;
-use super::types::{State, TimeLock};
+use super::types::{Role, State, StateData, TimeLock, UpdateLock, timelock};
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// Internal implementation of notarization operations.
@@ -32,8 +33,10 @@ impl NotarizationImpl {
     ///
     /// # Arguments
     /// * `iota_client` - The IOTA client adapter
+    /// * `retry_policy` - The policy for retrying the object lookups
     /// * `package_id` - The package ID for the transaction
     /// * `object_id` - Optional object ID for the notarization
+    /// * `mutable` - Whether the call mutates the notarization
     /// * `method` - The method name to call
     /// * `additional_args` - Closure providing additional arguments for the transaction
     ///
@@ -48,7 +51,9 @@ impl NotarizationImpl {
     /// * Method name is invalid
     async fn build_transaction<C, F>(
         client: &C,
+        retry_policy: &RetryPolicy,
         object_id: ObjectId,
+        mutable: bool,
         method: impl AsRef<str>,
         additional_args: F,
     ) -> Result<ProgrammableTransaction, Error>
@@ -58,13 +63,13 @@ impl NotarizationImpl {
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
-        let tag = vec![move_utils::get_type_tag(client, &object_id).await?];
+        let tag = vec![move_utils::get_type_tag(client, &object_id, retry_policy).await?];
 
         let mut args = {
-            let notarization = move_utils::get_object_ref_by_id(client, &object_id).await?;
+            let notarization = move_utils::get_object_arg(client, &object_id, mutable, retry_policy).await?;
 
             vec![
-                ptb.obj(CallArg::ImmutableOrOwned(notarization))
+                ptb.obj(notarization)
                     .map_err(|e| Error::InvalidArgument(format!("Failed to create object argument: {e}")))?,
             ]
         };
@@ -89,6 +94,59 @@ impl NotarizationImpl {
 
         Ok(ptb.finish())
     }
+
+    /// Helper to build a transaction calling `method` of the
+    /// `shared_notarization` module, authorized by the capability
+    /// `capability_id`.
+    ///
+    /// The shared notarization `object_id` and the capability are passed as
+    /// the first two arguments, followed by the arguments provided by
+    /// `additional_args`.
+    ///
+    /// # Errors
+    /// Returns `Error` if:
+    /// * Tag retrieval fails
+    /// * Object argument retrieval fails
+    /// * Transaction building fails
+    async fn build_capability_transaction<C, F>(
+        client: &C,
+        retry_policy: &RetryPolicy,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        method: impl AsRef<str>,
+        additional_args: F,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        F: FnOnce(&mut ProgrammableTransactionBuilder) -> Result<Vec<Argument>, Error>,
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let tag = vec![move_utils::get_type_tag(client, &object_id, retry_policy).await?];
+
+        let notarization = move_utils::get_object_arg(client, &object_id, true, retry_policy).await?;
+        let capability = move_utils::get_object_ref_by_id(client, &capability_id, retry_policy).await?;
+        let mut args = vec![
+            ptb.obj(notarization)
+                .map_err(|e| Error::InvalidArgument(format!("Failed to create notarization argument: {e}")))?,
+            ptb.obj(CallArg::ImmutableOrOwned(capability))
+                .map_err(|e| Error::InvalidArgument(format!("Failed to create capability argument: {e}")))?,
+        ];
+        args.extend(additional_args(&mut ptb)?);
+
+        let function = Identifier::from_str(method.as_ref())
+            .map_err(|e| Error::InvalidArgument(format!("Invalid method name '{}': {}", method.as_ref(), e)))?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!("shared_notarization").as_str().into(),
+            function,
+            tag,
+            args,
+        );
+
+        Ok(ptb.finish())
+    }
 }
 
 /// Notarization operations
@@ -98,22 +156,21 @@ impl NotarizationImpl {
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 pub(crate) trait NotarizationOperations {
-    /// Build a transaction that creates a new locked notarization
-    fn new_locked(
+    /// Add a call creating a new locked notarization to `ptb`
+    fn new_locked<D: StateData>(
+        ptb: &mut ProgrammableTransactionBuilder,
         package_id: ObjectId,
-        state: State,
+        state: State<D>,
         immutable_description: Option<String>,
         updatable_metadata: Option<String>,
         delete_lock: TimeLock,
-    ) -> Result<ProgrammableTransaction, Error> {
-        let mut ptb = ProgrammableTransactionBuilder::new();
-
-        let tag = state.data.tag();
-        let clock = move_utils::get_clock_ref(&mut ptb);
-        let state_arg = state.into_ptb(&mut ptb, package_id)?;
-        let immutable_description = move_utils::ptb_pure(&mut ptb, "immutable_description", immutable_description)?;
-        let updatable_metadata = move_utils::ptb_pure(&mut ptb, "updatable_metadata", updatable_metadata)?;
-        let delete_lock = delete_lock.to_ptb(&mut ptb, package_id)?;
+    ) -> Result<(), Error> {
+        let tag = state.data.move_tag(package_id);
+        let clock = move_utils::get_clock_ref(ptb);
+        let state_arg = state.into_ptb(ptb, package_id)?;
+        let immutable_description = move_utils::ptb_pure(ptb, "immutable_description", immutable_description)?;
+        let updatable_metadata = move_utils::ptb_pure(ptb, "updatable_metadata", updatable_metadata)?;
+        let delete_lock = delete_lock.to_ptb(ptb, package_id)?;
 
         ptb.programmable_move_call(
             package_id,
@@ -123,41 +180,102 @@ pub(crate) trait NotarizationOperations {
             vec![state_arg, immutable_description, updatable_metadata, delete_lock, clock],
         );
 
-        Ok(ptb.finish())
+        Ok(())
     }
 
-    /// Build a transaction that creates a new dynamic notarization
-    fn new_dynamic(
+    /// Add a call creating a new dynamic notarization to `ptb`
+    ///
+    /// Without an `update_lock` and `delete_lock` the call targets
+    /// `dynamic_notarization::create`, which every version of the
+    /// Notarization Package provides. The other targets were added by the
+    /// package upgrade introducing update and delete locks: with only a
+    /// `delete_lock` the call targets
+    /// `dynamic_notarization::create_with_delete_lock`, with a
+    /// `UpdateLock::LockAt` `update_lock`
+    /// `dynamic_notarization::create_with_lock_at`, and otherwise
+    /// `dynamic_notarization::create_with_locks`.
+    fn new_dynamic<D: StateData>(
+        ptb: &mut ProgrammableTransactionBuilder,
         package_id: ObjectId,
-        state: State,
+        state: State<D>,
         immutable_description: Option<String>,
         updatable_metadata: Option<String>,
+        update_lock: UpdateLock,
+        delete_lock: TimeLock,
         transfer_lock: TimeLock,
-    ) -> Result<ProgrammableTransaction, Error> {
-        let mut ptb = ProgrammableTransactionBuilder::new();
-
-        let tag = state.data.tag();
-        let clock = move_utils::get_clock_ref(&mut ptb);
-        let state_arg = state.into_ptb(&mut ptb, package_id)?;
-        let immutable_description = move_utils::ptb_pure(&mut ptb, "immutable_description", immutable_description)?;
-        let updatable_metadata = move_utils::ptb_pure(&mut ptb, "updatable_metadata", updatable_metadata)?;
-        let transfer_lock = transfer_lock.to_ptb(&mut ptb, package_id)?;
+    ) -> Result<(), Error> {
+        let tag = state.data.move_tag(package_id);
+        let clock = move_utils::get_clock_ref(ptb);
+        let state_arg = state.into_ptb(ptb, package_id)?;
+        let immutable_description = move_utils::ptb_pure(ptb, "immutable_description", immutable_description)?;
+        let updatable_metadata = move_utils::ptb_pure(ptb, "updatable_metadata", updatable_metadata)?;
+        let mut arguments = vec![state_arg, immutable_description, updatable_metadata];
+
+        let function = match update_lock {
+            UpdateLock::TimeLock(TimeLock::None) if delete_lock == TimeLock::None => ident_str!("create"),
+            UpdateLock::TimeLock(TimeLock::None) => {
+                arguments.push(delete_lock.to_ptb(ptb, package_id)?);
+                ident_str!("create_with_delete_lock")
+            }
+            UpdateLock::LockAt(lock_time) => {
+                arguments.push(timelock::new_lock_at(ptb, lock_time, package_id)?);
+                arguments.push(delete_lock.to_ptb(ptb, package_id)?);
+                ident_str!("create_with_lock_at")
+            }
+            UpdateLock::TimeLock(update_lock) => {
+                arguments.push(update_lock.to_ptb(ptb, package_id)?);
+                arguments.push(delete_lock.to_ptb(ptb, package_id)?);
+                ident_str!("create_with_locks")
+            }
+        };
+        arguments.push(transfer_lock.to_ptb(ptb, package_id)?);
+        arguments.push(clock);
 
         ptb.programmable_move_call(
             package_id,
             ident_str!("dynamic_notarization").as_str().into(),
+            function.as_str().into(),
+            vec![tag],
+            arguments,
+        );
+
+        Ok(())
+    }
+
+    /// Add a call creating a new shared notarization to `ptb`
+    fn new_shared<D: StateData>(
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+        state: State<D>,
+        immutable_description: Option<String>,
+        updatable_metadata: Option<String>,
+        update_lock: TimeLock,
+        delete_lock: TimeLock,
+    ) -> Result<(), Error> {
+        let tag = state.data.move_tag(package_id);
+        let clock = move_utils::get_clock_ref(ptb);
+        let state_arg = state.into_ptb(ptb, package_id)?;
+        let immutable_description = move_utils::ptb_pure(ptb, "immutable_description", immutable_description)?;
+        let updatable_metadata = move_utils::ptb_pure(ptb, "updatable_metadata", updatable_metadata)?;
+        let update_lock = update_lock.to_ptb(ptb, package_id)?;
+        let delete_lock = delete_lock.to_ptb(ptb, package_id)?;
+
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!("shared_notarization").as_str().into(),
             ident_str!("create").as_str().into(),
             vec![tag],
             vec![
                 state_arg,
                 immutable_description,
                 updatable_metadata,
-                transfer_lock,
+                update_lock,
+                delete_lock,
                 clock,
             ],
         );
 
-        Ok(ptb.finish())
+        Ok(())
     }
 
     /// Build a transaction that updates the state of a notarization
@@ -165,11 +283,12 @@ pub(crate) trait NotarizationOperations {
         client: &C,
         object_id: ObjectId,
         new_state: State,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "update_state", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, true, "update_state", |ptb| {
             Ok(vec![
                 new_state.into_ptb(ptb, client.package_id())?,
                 move_utils::get_clock_ref(ptb),
@@ -179,11 +298,15 @@ pub(crate) trait NotarizationOperations {
     }
 
     /// Build a transaction that destroys a notarization
-    async fn destroy<C>(client: &C, object_id: ObjectId) -> Result<ProgrammableTransaction, Error>
+    async fn destroy<C>(
+        client: &C,
+        object_id: ObjectId,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "destroy", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, true, "destroy", |ptb| {
             Ok(vec![move_utils::get_clock_ref(ptb)])
         })
         .await
@@ -194,11 +317,12 @@ pub(crate) trait NotarizationOperations {
         client: &C,
         object_id: ObjectId,
         new_metadata: Option<String>,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "update_metadata", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, true, "update_metadata", |ptb| {
             Ok(vec![
                 move_utils::ptb_pure(ptb, "new_metadata", new_metadata)?,
                 move_utils::get_clock_ref(ptb),
@@ -207,119 +331,325 @@ pub(crate) trait NotarizationOperations {
         .await
     }
 
+    /// Build a transaction that updates the state of a shared notarization,
+    /// authorized by the capability `capability_id`
+    async fn update_state_with_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        new_state: State,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(
+            client,
+            retry_policy,
+            object_id,
+            capability_id,
+            "update_state",
+            |ptb| {
+                Ok(vec![
+                    new_state.into_ptb(ptb, client.package_id())?,
+                    move_utils::get_clock_ref(ptb),
+                ])
+            },
+        )
+        .await
+    }
+
+    /// Build a transaction that updates the metadata of a shared
+    /// notarization, authorized by the capability `capability_id`
+    async fn update_metadata_with_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        new_metadata: Option<String>,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(
+            client,
+            retry_policy,
+            object_id,
+            capability_id,
+            "update_metadata",
+            |ptb| {
+                Ok(vec![
+                    move_utils::ptb_pure(ptb, "new_metadata", new_metadata)?,
+                    move_utils::get_clock_ref(ptb),
+                ])
+            },
+        )
+        .await
+    }
+
+    /// Build a transaction that destroys a shared notarization, authorized
+    /// by the admin capability `capability_id`
+    async fn destroy_with_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(
+            client,
+            retry_policy,
+            object_id,
+            capability_id,
+            "destroy",
+            |ptb| Ok(vec![move_utils::get_clock_ref(ptb)]),
+        )
+        .await
+    }
+
+    /// Build a transaction that seals a shared notarization, authorized by
+    /// the admin capability `capability_id`
+    async fn seal_with_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        delete_lock: TimeLock,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(client, retry_policy, object_id, capability_id, "seal", |ptb| {
+            Ok(vec![
+                delete_lock.to_ptb(ptb, client.package_id())?,
+                move_utils::get_clock_ref(ptb),
+            ])
+        })
+        .await
+    }
+
+    /// Build a transaction that issues a capability granting `role` on a
+    /// shared notarization to `recipient`, authorized by the admin
+    /// capability `capability_id`
+    async fn issue_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        role: Role,
+        recipient: Address,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(
+            client,
+            retry_policy,
+            object_id,
+            capability_id,
+            "issue_capability",
+            |ptb| {
+                Ok(vec![
+                    role.to_ptb(ptb, client.package_id()),
+                    move_utils::ptb_pure(ptb, "recipient", recipient)?,
+                ])
+            },
+        )
+        .await
+    }
+
+    /// Build a transaction that revokes the capability
+    /// `revoked_capability_id` of a shared notarization, authorized by the
+    /// admin capability `capability_id`
+    async fn revoke_capability<C>(
+        client: &C,
+        object_id: ObjectId,
+        capability_id: ObjectId,
+        revoked_capability_id: ObjectId,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::build_capability_transaction(
+            client,
+            retry_policy,
+            object_id,
+            capability_id,
+            "revoke_capability",
+            |ptb| Ok(vec![move_utils::ptb_pure(ptb, "capability_id", revoked_capability_id)?]),
+        )
+        .await
+    }
+
     /// Build a transaction that returns the notarization method
-    async fn notarization_method<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn notarization_method<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "notarization_method", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "notarization_method", |_| {
+            Ok(vec![])
+        })
+        .await
     }
 
     /// Build a transaction that checks if the notarization is locked for update
-    async fn is_update_locked<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_update_locked<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_update_locked", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "is_update_locked", |ptb| {
             Ok(vec![move_utils::get_clock_ref(ptb)])
         })
         .await
     }
 
     /// Build a transaction that checks if the notarization is allowed to be destroyed
-    async fn is_destroy_allowed<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_destroy_allowed<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_destroy_allowed", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "is_destroy_allowed", |ptb| {
             Ok(vec![move_utils::get_clock_ref(ptb)])
         })
         .await
     }
 
     /// Build a transaction that checks if the notarization is locked for transfer
-    async fn is_transfer_locked<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_transfer_locked<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_transfer_locked", |ptb| {
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "is_transfer_locked", |ptb| {
             Ok(vec![move_utils::get_clock_ref(ptb)])
         })
         .await
     }
 
     /// Last change timestamp
-    async fn last_change_ts<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn last_change_ts<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "last_change", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "last_change", |_| Ok(vec![])).await
     }
 
     /// Version count
-    async fn version_count<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn version_count<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "version_count", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "version_count", |_| Ok(vec![]))
+            .await
     }
 
     /// Created at timestamp
-    async fn created_at<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn created_at<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "created_at", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "created_at", |_| Ok(vec![])).await
     }
 
     /// Description
-    async fn description<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn description<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "description", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "description", |_| Ok(vec![])).await
     }
 
     /// Updatable metadata
-    async fn updatable_metadata<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn updatable_metadata<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "updatable_metadata", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "updatable_metadata", |_| {
+            Ok(vec![])
+        })
+        .await
     }
 
     /// Lock metadata
-    async fn lock_metadata<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn lock_metadata<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "lock_metadata", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "lock_metadata", |_| Ok(vec![]))
+            .await
     }
 
-    async fn state<C>(object_id: ObjectId, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn state<C>(
+        object_id: ObjectId,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "state", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, retry_policy, object_id, false, "state", |_| Ok(vec![])).await
     }
 
     async fn transfer_notarization<C>(
         object_id: ObjectId,
         recipient: Address,
         client: &C,
+        retry_policy: &RetryPolicy,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
-        let tag = vec![move_utils::get_type_tag(client, &object_id).await?];
+        let tag = vec![move_utils::get_type_tag(client, &object_id, retry_policy).await?];
         let recipient = ptb
             .pure(recipient)
             .map_err(|e| Error::InvalidArgument(format!("Failed to create recipient argument: {e}")))?;
 
-        let notarization = move_utils::get_object_ref_by_id(client, &object_id).await?;
+        let notarization = move_utils::get_object_arg(client, &object_id, true, retry_policy).await?;
         let notarization = ptb
-            .obj(CallArg::ImmutableOrOwned(notarization))
+            .obj(notarization)
             .map_err(|e| Error::InvalidArgument(format!("Failed to create notarization argument: {e}")))?;
 
         let clock = move_utils::get_clock_ref(&mut ptb);
@@ -334,6 +664,96 @@ pub(crate) trait NotarizationOperations {
 
         Ok(ptb.finish())
     }
+
+    /// Build a transaction that seals a Dynamic-Notarization into a
+    /// Locked-Notarization
+    async fn seal<C>(
+        object_id: ObjectId,
+        delete_lock: TimeLock,
+        client: &C,
+        retry_policy: &RetryPolicy,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let tag = vec![move_utils::get_type_tag(client, &object_id, retry_policy).await?];
+
+        let notarization = move_utils::get_object_arg(client, &object_id, true, retry_policy).await?;
+        let notarization = ptb
+            .obj(notarization)
+            .map_err(|e| Error::InvalidArgument(format!("Failed to create notarization argument: {e}")))?;
+        let delete_lock = delete_lock.to_ptb(&mut ptb, client.package_id())?;
+        let clock = move_utils::get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!("dynamic_notarization").as_str().into(),
+            ident_str!("seal").as_str().into(),
+            tag,
+            vec![notarization, delete_lock, clock],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Build a transaction that endorses version `state_version_count` of
+    /// the `state` of a notarization, whose hash is `state_hash`, on behalf
+    /// of the sender
+    fn endorse(
+        object_id: ObjectId,
+        state_version_count: u64,
+        state_hash: Vec<u8>,
+        package_id: ObjectId,
+    ) -> Result<ProgrammableTransaction, Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let notarization_id = move_utils::ptb_pure(&mut ptb, "notarization_id", object_id)?;
+        let state_version_count = move_utils::ptb_pure(&mut ptb, "state_version_count", state_version_count)?;
+        let state_hash = move_utils::ptb_pure(&mut ptb, "state_hash", state_hash)?;
+        let clock = move_utils::get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!("endorsement").as_str().into(),
+            ident_str!("endorse").as_str().into(),
+            vec![],
+            vec![notarization_id, state_version_count, state_hash, clock],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Build a transaction that checks the locks of many notarizations.
+    ///
+    /// For every `(object_arg, tag)` in `notarizations`, calls
+    /// `is_update_locked`, `is_destroy_allowed` and `is_transfer_locked`, in
+    /// this order, so the transaction has three results per notarization.
+    fn lock_checks(
+        notarizations: &[(CallArg, TypeTag)],
+        package_id: ObjectId,
+    ) -> Result<ProgrammableTransaction, Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let clock = move_utils::get_clock_ref(&mut ptb);
+
+        for (object_arg, tag) in notarizations {
+            let notarization = ptb
+                .obj(object_arg.clone())
+                .map_err(|e| Error::InvalidArgument(format!("Failed to create notarization argument: {e}")))?;
+
+            for function in ["is_update_locked", "is_destroy_allowed", "is_transfer_locked"] {
+                ptb.programmable_move_call(
+                    package_id,
+                    ident_str!("notarization").as_str().into(),
+                    Identifier::from_str(function).expect("valid identifier"),
+                    vec![tag.clone()],
+                    vec![notarization, clock],
+                );
+            }
+        }
+
+        Ok(ptb.finish())
+    }
 }
 
 impl NotarizationOperations for NotarizationImpl {}
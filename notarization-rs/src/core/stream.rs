@@ -0,0 +1,199 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Streaming Digests
+//!
+//! Helpers for hashing large inputs in fixed-size chunks, without loading
+//! them into memory.
+//!
+//! ## Overview
+//!
+//! [`digest_reader`] consumes any [`std::io::Read`], [`digest_async_reader`]
+//! any [`tokio::io::AsyncRead`]. Both return a [`Digest`] that can be passed to
+//! [`NotarizationBuilder::with_digest_state`](super::builder::NotarizationBuilder::with_digest_state)
+//! and report a [`HashProgress`] after every chunk, so callers can render
+//! progress bars for multi-gigabyte files.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use notarization::core::stream::digest_reader;
+//! use notarization::core::types::DigestAlgorithm;
+//!
+//! let file = std::fs::File::open("dataset.tar")?;
+//! let digest = digest_reader(DigestAlgorithm::Sha256, file, |progress| {
+//!     println!("hashed {} bytes", progress.bytes_processed);
+//! })?;
+//! ```
+
+use std::io::{ErrorKind, Read};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::types::{Digest, DigestAlgorithm, DigestHasher};
+use crate::error::Error;
+
+/// Size of the chunks read from the input, in bytes.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Progress of a streaming hash operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashProgress {
+    /// Number of bytes hashed so far.
+    pub bytes_processed: u64,
+    /// Time elapsed since hashing started.
+    pub elapsed: Duration,
+}
+
+impl HashProgress {
+    /// Returns the average throughput in bytes per second.
+    pub fn bytes_per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            return 0.0;
+        }
+
+        self.bytes_processed as f64 / secs
+    }
+}
+
+/// Hashes `reader` to its end using `algorithm`.
+///
+/// The input is read in chunks of [`CHUNK_SIZE`] bytes and `on_progress` is
+/// invoked after every chunk.
+///
+/// ## Errors
+///
+/// Returns [`Error::Io`] if reading from `reader` fails.
+pub fn digest_reader<R, F>(algorithm: DigestAlgorithm, mut reader: R, mut on_progress: F) -> Result<Digest, Error>
+where
+    R: Read,
+    F: FnMut(HashProgress),
+{
+    let started = Instant::now();
+    let mut hasher = DigestHasher::new(algorithm);
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut bytes_processed = 0u64;
+
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        hasher.update(&buf[..read]);
+        bytes_processed += read as u64;
+        on_progress(HashProgress {
+            bytes_processed,
+            elapsed: started.elapsed(),
+        });
+    }
+
+    Ok(hasher.finalize())
+}
+
+/// Hashes the asynchronous `reader` to its end using `algorithm`.
+///
+/// Asynchronous counterpart of [`digest_reader`].
+///
+/// ## Errors
+///
+/// Returns [`Error::Io`] if reading from `reader` fails.
+pub async fn digest_async_reader<R, F>(
+    algorithm: DigestAlgorithm,
+    mut reader: R,
+    mut on_progress: F,
+) -> Result<Digest, Error>
+where
+    R: AsyncRead + Unpin,
+    F: FnMut(HashProgress),
+{
+    let started = Instant::now();
+    let mut hasher = DigestHasher::new(algorithm);
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut bytes_processed = 0u64;
+
+    loop {
+        let read = match reader.read(&mut buf).await {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        hasher.update(&buf[..read]);
+        bytes_processed += read as u64;
+        on_progress(HashProgress {
+            bytes_processed,
+            elapsed: started.elapsed(),
+        });
+    }
+
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn input() -> Vec<u8> {
+        (0..CHUNK_SIZE * 2 + 17).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn digest_reader_matches_compute() {
+        let data = input();
+        let mut progress = Vec::new();
+
+        let digest = digest_reader(DigestAlgorithm::Sha256, Cursor::new(&data), |p| {
+            progress.push(p.bytes_processed)
+        })
+        .unwrap();
+
+        assert_eq!(digest, Digest::compute(DigestAlgorithm::Sha256, &data));
+        assert_eq!(
+            progress,
+            vec![CHUNK_SIZE as u64, 2 * CHUNK_SIZE as u64, data.len() as u64]
+        );
+    }
+
+    #[test]
+    fn digest_reader_empty_input() {
+        let mut calls = 0;
+        let digest = digest_reader(DigestAlgorithm::Blake2b256, std::io::empty(), |_| calls += 1).unwrap();
+
+        assert_eq!(digest, Digest::compute(DigestAlgorithm::Blake2b256, []));
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn digest_reader_propagates_io_errors() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk unplugged"))
+            }
+        }
+
+        let result = digest_reader(DigestAlgorithm::Sha256, Failing, |_| ());
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn digest_async_reader_matches_compute() {
+        let data = input();
+        let mut last = 0;
+
+        let digest = digest_async_reader(DigestAlgorithm::Sha3_256, data.as_slice(), |p| last = p.bytes_processed)
+            .await
+            .unwrap();
+
+        assert_eq!(digest, Digest::compute(DigestAlgorithm::Sha3_256, &data));
+        assert_eq!(last, data.len() as u64);
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Capabilities
+//!
+//! This module defines the transactions administering the capabilities of
+//! Shared-Notarizations.
+//!
+//! ## Overview
+//!
+//! Updates of a Shared-Notarization are authorized by capability objects
+//! issued for it. Issuing and revoking capabilities must itself be
+//! authorized by a valid capability with the
+//! [`Role::Admin`](super::super::types::Role::Admin) role; both transactions
+//! abort on-chain otherwise.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::abort;
+use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{CapabilityIssued, CapabilityRevoked, NotarizationEvent, Role};
+use crate::client::retry::RetryPolicy;
+use crate::error::Error;
+
+/// A transaction that issues a new capability for a Shared-Notarization and
+/// transfers it to a recipient.
+///
+/// Must be authorized by a valid admin capability.
+///
+/// Emits a [`CapabilityIssued`] event on success, which is returned as the transaction output.
+pub struct IssueCapability {
+    notarization_id: ObjectId,
+    capability_id: ObjectId,
+    role: Role,
+    recipient: Address,
+    retry_policy: RetryPolicy,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl IssueCapability {
+    /// Creates a new issue-capability transaction.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization
+    /// - `capability_id`: The ID of the admin capability authorizing the transaction
+    /// - `role`: The role granted by the issued capability
+    /// - `recipient`: The address the issued capability is transferred to
+    pub fn new(notarization_id: ObjectId, capability_id: ObjectId, role: Role, recipient: Address) -> Self {
+        Self {
+            notarization_id,
+            capability_id,
+            role,
+            recipient,
+            retry_policy: RetryPolicy::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::issue_capability(
+            client,
+            self.notarization_id,
+            self.capability_id,
+            self.role,
+            self.recipient,
+            &self.retry_policy,
+        )
+        .await
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for IssueCapability {
+    type Error = Error;
+
+    type Output = CapabilityIssued;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::CapabilityIssued(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("CapabilityIssued event not found".to_string()))
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
+
+/// A transaction that revokes a capability of a Shared-Notarization.
+///
+/// Must be authorized by a valid admin capability. A revoked capability can
+/// no longer authorize any operation. Revoking the last admin capability
+/// makes the capabilities of the notarization immutable and the
+/// notarization indestructible.
+///
+/// Emits a [`CapabilityRevoked`] event on success, which is returned as the transaction output.
+pub struct RevokeCapability {
+    notarization_id: ObjectId,
+    capability_id: ObjectId,
+    revoked_capability_id: ObjectId,
+    retry_policy: RetryPolicy,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl RevokeCapability {
+    /// Creates a new revoke-capability transaction.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the Shared-Notarization
+    /// - `capability_id`: The ID of the admin capability authorizing the transaction
+    /// - `revoked_capability_id`: The ID of the capability to revoke
+    pub fn new(notarization_id: ObjectId, capability_id: ObjectId, revoked_capability_id: ObjectId) -> Self {
+        Self {
+            notarization_id,
+            capability_id,
+            revoked_capability_id,
+            retry_policy: RetryPolicy::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        NotarizationImpl::revoke_capability(
+            client,
+            self.notarization_id,
+            self.capability_id,
+            self.revoked_capability_id,
+            &self.retry_policy,
+        )
+        .await
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for RevokeCapability {
+    type Error = Error;
+
+    type Output = CapabilityRevoked;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::CapabilityRevoked(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("CapabilityRevoked event not found".to_string()))
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
@@ -8,35 +8,63 @@
 //! ## Overview
 //!
 //! The create-notarization transaction creates a new on-chain
-//! `Notarization<D>` object and transfers it to the transaction sender. The
-//! marker type parameter `M` selects the Notarization Method and the set of
-//! per-method invariants enforced before submission:
-//! * `Dynamic`: the resulting object has no `LockMetadata` when `transfer_lock` is `TimeLock::None`; otherwise its
-//!   `LockMetadata` has `update_lock = delete_lock = TimeLock::None` and the supplied `transfer_lock`.
+//! `Notarization<D>` object and transfers it to the transaction sender, or
+//! shares it for Shared-Notarizations. The marker type parameter
+//! `M` selects the Notarization Method and the set of per-method invariants
+//! enforced before submission:
+//! * `Dynamic`: the resulting object has no `LockMetadata` when none of `update_lock`, `delete_lock` and
+//!   `transfer_lock` is set; otherwise its `LockMetadata` carries the supplied locks. An
+//!   `UpdateLock::LockAt` `update_lock` is stored on-chain as a `lock_at::LockAt` dynamic field, with `update_lock =
+//!   TimeLock::UntilDestroyed` in the `LockMetadata`.
 //! * `Locked`: the resulting object always carries `LockMetadata` with both `update_lock` and `transfer_lock` pinned to
 //!   `TimeLock::UntilDestroyed` and `delete_lock` set to the supplied value.
+//! * `Shared`: the resulting object always carries `LockMetadata` with `transfer_lock` pinned to
+//!   `TimeLock::UntilDestroyed` and the supplied `update_lock` and `delete_lock`. On-chain it is a `Locked` object
+//!   whose `delete_lock` never expires, and the `LockMetadata` of the Shared-Notarization is stored as a dynamic
+//!   field.
 //!
 //! `state_version_count` starts at `0` and `last_state_change_at` is set to
 //! the on-chain clock timestamp at creation.
 
+use std::str::FromStr;
+
 use async_trait::async_trait;
 use iota_interaction::rpc_types::{
-    IotaData as _, IotaObjectDataOptions, IotaTransactionBlockEffects, IotaTransactionBlockEvents,
+    IotaData as _, IotaObjectData, IotaObjectDataOptions, IotaTransactionBlockEffects, IotaTransactionBlockEvents,
 };
+use iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use iota_interaction::types::dynamic_field::{DynamicFieldName, Field};
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::{IotaClientTrait, OptionalSend, OptionalSync};
-use iota_sdk_types::{Address, ObjectId, Owner, ProgrammableTransaction};
+use iota_sdk_types::{Address, ObjectId, Owner, ProgrammableTransaction, TypeTag};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
+use serde::de::DeserializeOwned;
 use tokio::sync::OnceCell;
 
+use super::super::abort;
 use super::super::builder::NotarizationBuilder;
+use super::super::move_utils;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
 use super::super::types::{
-    DynamicNotarizationCreated, Event, LockMetadata, LockedNotarizationCreated, NotarizationMethod,
-    OnChainNotarization, TimeLock,
+    Data, DynamicNotarizationCreated, Event, LockMetadata, LockedNotarizationCreated, NotarizationMethod,
+    OnChainNotarization, SharedNotarizationCreated, StateData, TimeLock, UpdateLock,
 };
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
+/// Name of the dynamic field storing the `lock_at::LockAt` of a
+/// Dynamic-Notarization.
+const UPDATE_LOCK_AT_KEY: &str = "update_lock_at";
+
+/// Name of the dynamic field storing the `LockMetadata` of a
+/// Shared-Notarization.
+const SHARED_LOCK_METADATA_KEY: &str = "shared_lock_metadata";
+
+/// The `delete_lock` of the on-chain `LockMetadata` of a Shared-Notarization,
+/// guarding it against package versions unaware of Shared-Notarizations.
+const SHARED_OBJECT_DELETE_LOCK: TimeLock = TimeLock::UnlockAt(u32::MAX);
+
 /// A transaction that creates a new notarization on-chain.
 ///
 /// On success the resulting `Notarization` object is transferred to the
@@ -44,15 +72,21 @@ use crate::error::Error;
 ///
 /// - `DynamicNotarizationCreated` for `CreateNotarization<Dynamic>`
 /// - `LockedNotarizationCreated` for `CreateNotarization<Locked>`
+/// - `SharedNotarizationCreated` for `CreateNotarization<Shared>`, whose
+///   object is shared instead; use [`CreateSharedNotarization`](super::CreateSharedNotarization)
+///   to also learn the ID of the admin capability
+///
+/// The type parameter `D` is the type of the notarized payload, see
+/// [`NotarizationBuilder::with_generic_state`].
 #[derive(Debug, Clone)]
-pub struct CreateNotarization<M> {
-    builder: NotarizationBuilder<M>,
+pub struct CreateNotarization<M, D = Data> {
+    builder: NotarizationBuilder<M, D>,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
-impl<M: Clone> CreateNotarization<M> {
+impl<M: Clone, D: StateData + Clone> CreateNotarization<M, D> {
     /// Creates a new [`CreateNotarization`] instance.
-    pub fn new(builder: NotarizationBuilder<M>) -> Self {
+    pub fn new(builder: NotarizationBuilder<M, D>) -> Self {
         Self {
             builder,
             cached_ptb: OnceCell::new(),
@@ -61,20 +95,38 @@ impl<M: Clone> CreateNotarization<M> {
 
     /// Indicates if the invariants for `NotarizationMethod::Dynamic` are satisfied:
     ///
-    /// - Dynamic notarization can only have transfer locking or no `immutable_metadata.locking`. If
-    ///   `immutable_metadata.locking` exists, all locks except `transfer_lock` must be `TimeLock::None` and the
-    ///   `transfer_lock` must not be `TimeLock::None`.
+    /// - If `immutable_metadata.locking` exists, `update_lock` must not be `TimeLock::UntilDestroyed` and at least one
+    ///   of `update_lock`, `delete_lock` and `transfer_lock` must not be `TimeLock::None`.
     fn are_dynamic_notarization_invariants_ok(locking: &Option<LockMetadata>) -> bool {
         match locking {
             Some(lock_metadata) => {
-                lock_metadata.delete_lock == TimeLock::None
-                    && lock_metadata.update_lock == TimeLock::None
-                    && lock_metadata.transfer_lock != TimeLock::None
+                lock_metadata.update_lock != TimeLock::UntilDestroyed
+                    && (lock_metadata.update_lock != TimeLock::None
+                        || lock_metadata.delete_lock != TimeLock::None
+                        || lock_metadata.transfer_lock != TimeLock::None)
             }
             None => true,
         }
     }
 
+    /// Indicates if the lock combination of `lock_metadata` is valid, as checked
+    /// by the on-chain `notarization::new_lock_metadata`:
+    ///
+    /// - `delete_lock` must not be `TimeLock::UntilDestroyed`.
+    /// - An `UnlockAt` `delete_lock` must not unlock before an `UnlockAt` `update_lock` or `transfer_lock`.
+    fn is_lock_metadata_ok(lock_metadata: &LockMetadata) -> bool {
+        match lock_metadata.delete_lock {
+            TimeLock::UntilDestroyed => false,
+            TimeLock::UnlockAt(delete_time) => {
+                let unlocks_later = |lock: &TimeLock| matches!(lock, TimeLock::UnlockAt(time) if *time > delete_time);
+
+                !matches!(&lock_metadata.update_lock, UpdateLock::TimeLock(lock) if unlocks_later(lock))
+                    && !unlocks_later(&lock_metadata.transfer_lock)
+            }
+            TimeLock::None => true,
+        }
+    }
+
     /// Indicates if the invariants for `NotarizationMethod::Locked` are satisfied:
     ///
     /// - `locking` must exist.
@@ -89,36 +141,67 @@ impl<M: Clone> CreateNotarization<M> {
         }
     }
 
+    /// Indicates if the invariants for `NotarizationMethod::Shared` are satisfied:
+    ///
+    /// - `locking` must exist.
+    /// - `transfer_lock` must be `TimeLock::UntilDestroyed`.
+    /// - `update_lock` must neither be `TimeLock::UntilDestroyed` nor `UpdateLock::LockAt`.
+    fn are_shared_notarization_invariants_ok(locking: &Option<LockMetadata>) -> bool {
+        match locking {
+            Some(lock_metadata) => {
+                lock_metadata.transfer_lock == TimeLock::UntilDestroyed
+                    && !matches!(
+                        lock_metadata.update_lock,
+                        UpdateLock::TimeLock(TimeLock::UntilDestroyed) | UpdateLock::LockAt(_)
+                    )
+            }
+            None => false,
+        }
+    }
+
     /// Makes a [`ProgrammableTransaction`] for the [`CreateNotarization`] instance.
     async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        Self::add_to_ptb(self.builder.clone(), &mut ptb, client.package_id())?;
+
+        Ok(ptb.finish())
+    }
+
+    /// Validates `builder` and adds the call creating its notarization to `ptb`.
+    pub(crate) fn add_to_ptb(
+        builder: NotarizationBuilder<M, D>,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+    ) -> Result<(), Error> {
         let NotarizationBuilder {
             state,
             immutable_description,
             updatable_metadata,
             method,
+            update_lock,
             delete_lock,
             transfer_lock,
             ..
-        } = self.builder.clone();
-
-        let package_id = client.package_id();
+        } = builder;
 
         let state = state.ok_or_else(|| Error::InvalidArgument("State is required".to_string()))?;
 
         match method {
             NotarizationMethod::Dynamic => {
-                if delete_lock.is_some() {
-                    return Err(Error::InvalidArgument(
-                        "Delete lock cannot be set for dynamic notarizations".to_string(),
-                    ));
-                }
-
-                // Construct the locking metadata for dynamic notarization
-                let locking = transfer_lock.as_ref().map(|t_lock| LockMetadata {
-                    update_lock: TimeLock::None,
-                    delete_lock: TimeLock::None,
-                    transfer_lock: t_lock.clone(),
-                });
+                let update_lock = update_lock.unwrap_or(UpdateLock::TimeLock(TimeLock::None));
+                let delete_lock = delete_lock.unwrap_or(TimeLock::None);
+
+                // Construct the locking metadata for dynamic notarization; a
+                // `TimeLock::None` update or delete lock is the default and
+                // needs none
+                let locking = (transfer_lock.is_some()
+                    || update_lock != TimeLock::None
+                    || delete_lock != TimeLock::None)
+                    .then(|| LockMetadata {
+                        update_lock: update_lock.clone(),
+                        delete_lock: delete_lock.clone(),
+                        transfer_lock: transfer_lock.clone().unwrap_or(TimeLock::None),
+                    });
 
                 // Check invariants
                 if !Self::are_dynamic_notarization_invariants_ok(&locking) {
@@ -126,16 +209,31 @@ impl<M: Clone> CreateNotarization<M> {
                         "Dynamic notarization invariants are not satisfied".to_string(),
                     ));
                 }
+                if !locking.as_ref().is_none_or(Self::is_lock_metadata_ok) {
+                    return Err(Error::InvalidArgument(
+                        "Delete lock must not be `UntilDestroyed` and must not unlock before the update or \
+                         transfer lock"
+                            .to_string(),
+                    ));
+                }
 
                 NotarizationImpl::new_dynamic(
+                    ptb,
                     package_id,
                     state,
                     immutable_description,
                     updatable_metadata,
+                    update_lock,
+                    delete_lock,
                     transfer_lock.unwrap_or(TimeLock::None),
                 )
             }
             NotarizationMethod::Locked => {
+                if update_lock.is_some() {
+                    return Err(Error::InvalidArgument(
+                        "Update lock cannot be set for locked notarizations".to_string(),
+                    ));
+                }
                 if transfer_lock.is_some() {
                     return Err(Error::InvalidArgument(
                         "Transfer lock cannot be set for locked notarizations".to_string(),
@@ -144,7 +242,7 @@ impl<M: Clone> CreateNotarization<M> {
 
                 // Construct the locking metadata for locked notarization
                 let locking = Some(LockMetadata {
-                    update_lock: TimeLock::UntilDestroyed,
+                    update_lock: TimeLock::UntilDestroyed.into(),
                     delete_lock: delete_lock.clone().unwrap_or(TimeLock::None),
                     transfer_lock: TimeLock::UntilDestroyed,
                 });
@@ -157,6 +255,7 @@ impl<M: Clone> CreateNotarization<M> {
                 }
 
                 NotarizationImpl::new_locked(
+                    ptb,
                     package_id,
                     state,
                     immutable_description,
@@ -164,16 +263,66 @@ impl<M: Clone> CreateNotarization<M> {
                     delete_lock.unwrap_or(TimeLock::None),
                 )
             }
+            NotarizationMethod::Shared => {
+                if transfer_lock.is_some() {
+                    return Err(Error::InvalidArgument(
+                        "Transfer lock cannot be set for shared notarizations".to_string(),
+                    ));
+                }
+                let update_lock = match update_lock {
+                    Some(UpdateLock::TimeLock(lock)) => lock,
+                    Some(UpdateLock::LockAt(_)) => {
+                        return Err(Error::InvalidArgument(
+                            "`LockAt` update lock cannot be set for shared notarizations".to_string(),
+                        ));
+                    }
+                    None => TimeLock::None,
+                };
+
+                // Construct the locking metadata for shared notarization
+                let lock_metadata = LockMetadata {
+                    update_lock: update_lock.clone().into(),
+                    delete_lock: delete_lock.unwrap_or(TimeLock::None),
+                    transfer_lock: TimeLock::UntilDestroyed,
+                };
+
+                // Check invariants
+                if !Self::are_shared_notarization_invariants_ok(&Some(lock_metadata.clone())) {
+                    return Err(Error::InvalidArgument(
+                        "Shared notarization invariants are not satisfied".to_string(),
+                    ));
+                }
+                if !Self::is_lock_metadata_ok(&lock_metadata) {
+                    return Err(Error::InvalidArgument(
+                        "Delete lock must not be `UntilDestroyed` and must not unlock before the update lock"
+                            .to_string(),
+                    ));
+                }
+
+                NotarizationImpl::new_shared(
+                    ptb,
+                    package_id,
+                    state,
+                    immutable_description,
+                    updatable_metadata,
+                    update_lock,
+                    lock_metadata.delete_lock,
+                )
+            }
         }
     }
 }
 
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
-impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<M> {
+impl<M, D> Transaction for CreateNotarization<M, D>
+where
+    M: Clone + OptionalSend + OptionalSync,
+    D: StateData + Clone + OptionalSend + OptionalSync,
+{
     type Error = Error;
 
-    type Output = OnChainNotarization;
+    type Output = OnChainNotarization<D>;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -184,13 +333,15 @@ impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<
 
     async fn apply_with_events<C>(
         mut self,
-        _: &mut IotaTransactionBlockEffects,
+        effects: &mut IotaTransactionBlockEffects,
         events: &mut IotaTransactionBlockEvents,
         client: &C,
     ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        abort::check_execution_status(effects, client).await?;
+
         let method = self.builder.method.clone();
         let data = events
             .data
@@ -210,11 +361,16 @@ impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<
 
                 event.data.notarization_id
             }
+            NotarizationMethod::Shared => {
+                let event: Event<SharedNotarizationCreated> = serde_json::from_value(data.parsed_json.clone())
+                    .map_err(|e| Error::TransactionUnexpectedResponse(format!("failed to parse event: {e}")))?;
+
+                event.data.notarization_id
+            }
         };
 
-        let (mut notarization, owner) = get_notarization_with_owner(client, &notarization_id)
-            .await
-            .map_err(|e| Error::ObjectLookup(e.to_string()))?;
+        let (mut notarization, owner) =
+            get_notarization_with_owner(client, &notarization_id, &self.builder.retry_policy).await?;
 
         notarization.owner = owner;
 
@@ -230,35 +386,242 @@ impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<
 }
 
 /// A helper function to get the notarization with the owner.
-pub(crate) async fn get_notarization_with_owner(
+///
+/// Failed lookups are retried according to `retry_policy`.
+pub(crate) async fn get_notarization_with_owner<D: StateData>(
     client: &impl CoreClientReadOnly,
     object_id: &ObjectId,
-) -> Result<(OnChainNotarization, Address), Error> {
-    let data = client
-        .client_adapter()
-        .read_api()
-        .get_object_with_options(*object_id, IotaObjectDataOptions::bcs_lossless().with_owner())
-        .await
-        .map_err(|err| Error::ObjectLookup(err.to_string()))?
+    retry_policy: &RetryPolicy,
+) -> Result<(OnChainNotarization<D>, Address), Error> {
+    let data = retry_policy
+        .run(|| async {
+            client
+                .client_adapter()
+                .read_api()
+                .get_object_with_options(*object_id, IotaObjectDataOptions::bcs_lossless().with_owner())
+                .await
+                .map_err(|err| Error::ObjectLookup(err.to_string()))
+        })
+        .await?
         .data
         .ok_or_else(|| Error::ObjectLookup("missing data in response".to_string()))?;
 
-    let notarization = data
+    let (mut notarization, owner) = notarization_from_object_data(data)?;
+    resolve_locks(client, &mut notarization, retry_policy).await?;
+
+    Ok((notarization, owner))
+}
+
+/// The `lock_at::LockAt` stored in the `update_lock_at` dynamic field of a
+/// Dynamic-Notarization.
+#[derive(serde::Deserialize)]
+struct LockAt {
+    lock_time: u32,
+}
+
+/// Resolves the locks `notarization` stores in dynamic fields, see
+/// [`resolve_lock_fields`].
+pub(crate) async fn resolve_locks<D>(
+    client: &impl CoreClientReadOnly,
+    notarization: &mut OnChainNotarization<D>,
+    retry_policy: &RetryPolicy,
+) -> Result<(), Error> {
+    resolve_lock_fields(
+        client,
+        *notarization.id.object_id(),
+        &mut notarization.method,
+        &mut notarization.immutable_metadata.locking,
+        retry_policy,
+    )
+    .await
+}
+
+/// Resolves the locks `notarizations` store in dynamic fields like
+/// [`resolve_locks`], fetching the dynamic fields of all of them with
+/// multi-get queries instead of one query per notarization.
+pub(crate) async fn resolve_all_locks<D>(
+    client: &impl CoreClientReadOnly,
+    notarizations: &mut [OnChainNotarization<D>],
+    retry_policy: &RetryPolicy,
+) -> Result<(), Error> {
+    let lookups: Vec<(usize, &'static str)> = notarizations
+        .iter()
+        .enumerate()
+        .filter_map(|(index, notarization)| {
+            lock_field_name(&notarization.method, notarization.immutable_metadata.locking.as_ref())
+                .map(|name| (index, name))
+        })
+        .collect();
+    if lookups.is_empty() {
+        return Ok(());
+    }
+
+    let field_ids: Vec<ObjectId> = lookups
+        .iter()
+        .map(|(index, name)| move_utils::string_dynamic_field_id(*notarizations[*index].id.object_id(), name))
+        .collect();
+    let fields =
+        move_utils::multi_get_existing_objects(client, &field_ids, IotaObjectDataOptions::bcs_lossless(), retry_policy)
+            .await?;
+
+    for ((index, name), field) in lookups.into_iter().zip(fields) {
+        let notarization = &mut notarizations[index];
+        apply_lock_field(
+            *notarization.id.object_id(),
+            name,
+            field,
+            &mut notarization.method,
+            &mut notarization.immutable_metadata.locking,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the on-chain `method` and `locking` of the notarization
+/// `object_id` by the ones its dynamic fields describe.
+///
+/// Neither `LockAt` nor Shared-Notarizations can be expressed by the types of
+/// the published package, so the object content alone does not tell them
+/// apart:
+/// * the `TimeLock::UntilDestroyed` update lock of a Dynamic-Notarization is
+///   replaced by the `UpdateLock::LockAt` stored in its `update_lock_at` dynamic
+///   field, if any;
+/// * a `Locked` object whose `delete_lock` never expires is reported as a
+///   Shared-Notarization with the `LockMetadata` stored in its
+///   `shared_lock_metadata` dynamic field, if any.
+///
+/// Other notarizations are left untouched. Failed lookups are retried
+/// according to `retry_policy`.
+pub(crate) async fn resolve_lock_fields(
+    client: &impl CoreClientReadOnly,
+    object_id: ObjectId,
+    method: &mut NotarizationMethod,
+    locking: &mut Option<LockMetadata>,
+    retry_policy: &RetryPolicy,
+) -> Result<(), Error> {
+    let Some(name) = lock_field_name(method, locking.as_ref()) else {
+        return Ok(());
+    };
+    let field = get_string_dynamic_field(client, object_id, name, retry_policy).await?;
+
+    apply_lock_field(object_id, name, field, method, locking)
+}
+
+/// Returns the name of the dynamic field that may describe the locks of a
+/// notarization with the on-chain `method` and `locking`, see
+/// [`resolve_lock_fields`].
+fn lock_field_name(method: &NotarizationMethod, locking: Option<&LockMetadata>) -> Option<&'static str> {
+    let locking = locking?;
+    match method {
+        NotarizationMethod::Dynamic if locking.update_lock == TimeLock::UntilDestroyed => Some(UPDATE_LOCK_AT_KEY),
+        NotarizationMethod::Locked if locking.delete_lock == SHARED_OBJECT_DELETE_LOCK => {
+            Some(SHARED_LOCK_METADATA_KEY)
+        }
+        _ => None,
+    }
+}
+
+/// Applies the dynamic field `name` of the notarization `object_id`, as
+/// returned by [`lock_field_name`], to its `method` and `locking`. A missing
+/// `field` leaves them untouched.
+fn apply_lock_field(
+    object_id: ObjectId,
+    name: &str,
+    field: Option<IotaObjectData>,
+    method: &mut NotarizationMethod,
+    locking: &mut Option<LockMetadata>,
+) -> Result<(), Error> {
+    let (Some(field), Some(lock_metadata)) = (field, locking.as_mut()) else {
+        return Ok(());
+    };
+
+    if name == UPDATE_LOCK_AT_KEY {
+        let lock_at: LockAt = decode_string_field(object_id, field)?;
+        lock_metadata.update_lock = UpdateLock::LockAt(lock_at.lock_time);
+    } else {
+        *lock_metadata = decode_string_field(object_id, field)?;
+        *method = NotarizationMethod::Shared;
+    }
+
+    Ok(())
+}
+
+/// Fetches the dynamic field `name` of type `String` of the object
+/// `object_id`, or `None` if the object has no such field.
+async fn get_string_dynamic_field(
+    client: &impl CoreClientReadOnly,
+    object_id: ObjectId,
+    name: &str,
+    retry_policy: &RetryPolicy,
+) -> Result<Option<IotaObjectData>, Error> {
+    let name = DynamicFieldName {
+        type_: TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
+            .expect("the type tag of `String` is valid"),
+        value: serde_json::Value::String(name.to_string()),
+    };
+    let response = retry_policy
+        .run(|| async {
+            client
+                .client_adapter()
+                .read_api()
+                .get_dynamic_field_object_v2(object_id, name.clone(), Some(IotaObjectDataOptions::bcs_lossless()))
+                .await
+                .map_err(|err| Error::ObjectLookup(err.to_string()))
+        })
+        .await?;
+
+    Ok(response.data)
+}
+
+/// Decodes the value of a dynamic field of type `String` of the object
+/// `object_id` from `data`.
+fn decode_string_field<T: DeserializeOwned>(object_id: ObjectId, data: IotaObjectData) -> Result<T, Error> {
+    let field: Field<String, T> = data
         .bcs
-        .ok_or_else(|| Error::ObjectLookup("missing object content in data".to_string()))?
+        .ok_or_else(|| Error::ObjectDecoding(format!("missing content of a dynamic field of object {object_id}")))?
         .try_into_move()
-        .ok_or_else(|| Error::ObjectLookup("failed to convert data to move object".to_string()))?
+        .ok_or_else(|| Error::ObjectDecoding(format!("a dynamic field of object {object_id} is not a move object")))?
         .deserialize()
-        .map_err(|err| Error::ObjectLookup(err.to_string()))?;
+        .map_err(|err| {
+            Error::ObjectDecoding(format!("failed to decode a dynamic field of object {object_id}: {err}"))
+        })?;
+
+    Ok(field.value)
+}
+
+/// Decodes a `Notarization<D>` object and its owner from `data`.
+///
+/// The owner of shared objects is [`Address::ZERO`]. `data` must have been
+/// fetched with
+/// `IotaObjectDataOptions::bcs_lossless().with_owner()`.
+pub(crate) fn notarization_from_object_data<D: StateData>(
+    data: IotaObjectData,
+) -> Result<(OnChainNotarization<D>, Address), Error> {
+    let full_type_str = data
+        .object_type()
+        .map_err(|err| Error::ObjectDecoding(format!("missing object type in data: {err}")))?
+        .to_string();
+    let data_type = move_utils::type_param_tag(&full_type_str)?;
+    let package_id = move_utils::type_package_id(&full_type_str)?;
+
+    let move_object = data
+        .bcs
+        .ok_or_else(|| Error::ObjectDecoding("missing object content in data".to_string()))?
+        .try_into_move()
+        .ok_or_else(|| Error::ObjectDecoding("failed to convert data to move object".to_string()))?;
+    let notarization = D::decode_notarization(&data_type, package_id, &move_object.bcs_bytes)
+        .map_err(|err| Error::ObjectDecoding(err.to_string()))?;
 
     let owner = data
         .owner
-        .ok_or_else(|| Error::ObjectLookup("missing owner in data".to_string()))?;
+        .ok_or_else(|| Error::ObjectDecoding("missing owner in data".to_string()))?;
 
     let address = match owner {
         Owner::Address(address) => address,
         Owner::Object(object_id) => *object_id.as_address(),
-        Owner::Shared(_) | Owner::Immutable => {
+        Owner::Shared(_) => Address::ZERO,
+        Owner::Immutable => {
             unreachable!("object is not owned by an address");
         }
         _ => unreachable!("non-exhaustive Owner variant"),
@@ -277,15 +640,89 @@ mod tests {
 
         assert!(are_dynamic_notarization_invariants_ok(&None));
         assert!(are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
-            update_lock: TimeLock::None,
+            update_lock: TimeLock::None.into(),
             delete_lock: TimeLock::None,
             transfer_lock: TimeLock::UntilDestroyed,
         })));
         assert!(!are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
-            update_lock: TimeLock::None,
+            update_lock: TimeLock::None.into(),
             delete_lock: TimeLock::None,
             transfer_lock: TimeLock::None,
         })));
+        assert!(are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::None.into(),
+            delete_lock: TimeLock::UnlockAt(2000),
+            transfer_lock: TimeLock::None,
+        })));
+        assert!(are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::UnlockAt(2000).into(),
+            delete_lock: TimeLock::UnlockAt(2000),
+            transfer_lock: TimeLock::None,
+        })));
+        assert!(are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: UpdateLock::LockAt(2000),
+            delete_lock: TimeLock::None,
+            transfer_lock: TimeLock::None,
+        })));
+        assert!(!are_dynamic_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::UntilDestroyed.into(),
+            delete_lock: TimeLock::UnlockAt(2000),
+            transfer_lock: TimeLock::None,
+        })));
+    }
+
+    #[test]
+    fn test_lock_metadata_validity() {
+        let is_lock_metadata_ok = CreateNotarization::<()>::is_lock_metadata_ok;
+        let lock_metadata = |delete_lock, transfer_lock| LockMetadata {
+            update_lock: TimeLock::None.into(),
+            delete_lock,
+            transfer_lock,
+        };
+
+        assert!(is_lock_metadata_ok(&lock_metadata(
+            TimeLock::None,
+            TimeLock::UnlockAt(2000)
+        )));
+        assert!(is_lock_metadata_ok(&lock_metadata(
+            TimeLock::UnlockAt(2000),
+            TimeLock::None
+        )));
+        assert!(is_lock_metadata_ok(&lock_metadata(
+            TimeLock::UnlockAt(2000),
+            TimeLock::UnlockAt(2000)
+        )));
+        assert!(is_lock_metadata_ok(&lock_metadata(
+            TimeLock::UnlockAt(2000),
+            TimeLock::UntilDestroyed
+        )));
+        assert!(!is_lock_metadata_ok(&lock_metadata(
+            TimeLock::UnlockAt(2000),
+            TimeLock::UnlockAt(3000)
+        )));
+        assert!(!is_lock_metadata_ok(&lock_metadata(
+            TimeLock::UntilDestroyed,
+            TimeLock::None
+        )));
+
+        // The delete lock must not unlock before an update embargo ends
+        let with_update_lock = |update_lock, delete_lock| LockMetadata {
+            update_lock,
+            delete_lock,
+            transfer_lock: TimeLock::None,
+        };
+        assert!(is_lock_metadata_ok(&with_update_lock(
+            TimeLock::UnlockAt(2000).into(),
+            TimeLock::UnlockAt(2000)
+        )));
+        assert!(!is_lock_metadata_ok(&with_update_lock(
+            TimeLock::UnlockAt(3000).into(),
+            TimeLock::UnlockAt(2000)
+        )));
+        assert!(is_lock_metadata_ok(&with_update_lock(
+            UpdateLock::LockAt(3000),
+            TimeLock::UnlockAt(2000)
+        )));
     }
 
     #[test]
@@ -294,14 +731,46 @@ mod tests {
 
         assert!(!are_locked_notarization_invariants_ok(&None));
         assert!(are_locked_notarization_invariants_ok(&Some(LockMetadata {
-            update_lock: TimeLock::UntilDestroyed,
+            update_lock: TimeLock::UntilDestroyed.into(),
             delete_lock: TimeLock::UntilDestroyed,
             transfer_lock: TimeLock::UntilDestroyed,
         })));
         assert!(!are_locked_notarization_invariants_ok(&Some(LockMetadata {
-            update_lock: TimeLock::UntilDestroyed,
+            update_lock: TimeLock::UntilDestroyed.into(),
             delete_lock: TimeLock::UntilDestroyed,
             transfer_lock: TimeLock::None,
         })));
     }
+
+    #[test]
+    fn test_shared_notarization_invariants() {
+        let are_shared_notarization_invariants_ok = CreateNotarization::<()>::are_shared_notarization_invariants_ok;
+
+        assert!(!are_shared_notarization_invariants_ok(&None));
+        assert!(are_shared_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::None.into(),
+            delete_lock: TimeLock::None,
+            transfer_lock: TimeLock::UntilDestroyed,
+        })));
+        assert!(are_shared_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::UnlockAt(2000).into(),
+            delete_lock: TimeLock::UnlockAt(2000),
+            transfer_lock: TimeLock::UntilDestroyed,
+        })));
+        assert!(!are_shared_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: UpdateLock::LockAt(2000),
+            delete_lock: TimeLock::UnlockAt(2000),
+            transfer_lock: TimeLock::UntilDestroyed,
+        })));
+        assert!(!are_shared_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::UntilDestroyed.into(),
+            delete_lock: TimeLock::None,
+            transfer_lock: TimeLock::UntilDestroyed,
+        })));
+        assert!(!are_shared_notarization_invariants_ok(&Some(LockMetadata {
+            update_lock: TimeLock::None.into(),
+            delete_lock: TimeLock::None,
+            transfer_lock: TimeLock::None,
+        })));
+    }
 }
@@ -0,0 +1,456 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Create Notarization Batch
+//!
+//! This module defines the batch-create transaction.
+//!
+//! ## Overview
+//!
+//! The batch-create transaction creates many notarizations in a single
+//! programmable transaction, paying gas once instead of once per
+//! notarization. Locked and Dynamic notarizations can be mixed; every entry is
+//! validated like a single [`CreateNotarization`]. Shared-Notarizations are
+//! not supported, as their admin capability would not be returned; create
+//! them with [`CreateSharedNotarization`](super::CreateSharedNotarization).
+//!
+//! A programmable transaction is bounded in size and number of commands, so
+//! large batches are split via [`CreateNotarizationBatch::split`]. Building a
+//! batch that exceeds these limits fails before it is sent to the network.
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::{IotaObjectDataOptions, IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use iota_interaction::{OptionalSend, OptionalSync};
+use iota_sdk_types::{Input, ObjectId, ProgrammableTransaction};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::builder::NotarizationBuilder;
+use super::super::types::{Data, NotarizationEvent, NotarizationMethod, OnChainNotarization, StateData};
+use super::super::{abort, move_utils};
+use super::create::{CreateNotarization, notarization_from_object_data, resolve_all_locks};
+use crate::client::retry::RetryPolicy;
+use crate::error::Error;
+
+/// Maximum number of commands in the programmable transaction of a batch.
+pub const MAX_BATCH_COMMANDS: usize = 1024;
+
+/// Maximum size of the BCS-serialized programmable transaction of a batch, in
+/// bytes.
+///
+/// Leaves room below the protocol's transaction size limit for the gas data
+/// and the rest of the transaction.
+pub const MAX_BATCH_TX_SIZE: usize = 120 * 1024;
+
+/// Maximum size of a single pure argument of a programmable transaction, in
+/// bytes, as enforced by the protocol.
+///
+/// Bounds the serialized `state` data and metadata of each notarization.
+pub const MAX_PURE_ARGUMENT_SIZE: usize = 16 * 1024;
+
+/// A transaction that creates many notarizations at once.
+///
+/// On success every `Notarization` object is transferred to the transaction
+/// sender and the on-chain transaction emits one `DynamicNotarizationCreated`
+/// or `LockedNotarizationCreated` event per notarization. The output lists the
+/// created notarizations in the order they were added to the batch.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use notarization::core::builder::NotarizationBuilder;
+/// use notarization::core::transactions::CreateNotarizationBatch;
+///
+/// let batch = CreateNotarizationBatch::new()
+///     .with(NotarizationBuilder::locked().with_string_state("Invoice 1".to_string(), None))
+///     .with(NotarizationBuilder::dynamic().with_string_state("Invoice 2".to_string(), None));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateNotarizationBatch<D = Data> {
+    builders: Vec<NotarizationBuilder<(), D>>,
+    retry_policy: RetryPolicy,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<D> Default for CreateNotarizationBatch<D> {
+    fn default() -> Self {
+        Self {
+            builders: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+}
+
+impl<D: StateData + Clone> CreateNotarizationBatch<D> {
+    /// Creates a new, empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the notarization configured by `builder` to the batch.
+    ///
+    /// Builders of Shared-Notarizations are rejected when the batch is split
+    /// or built.
+    pub fn with<M>(mut self, builder: NotarizationBuilder<M, D>) -> Self {
+        self.push(builder);
+        self
+    }
+
+    /// Adds the notarization configured by `builder` to the batch.
+    ///
+    /// Builders of Shared-Notarizations are rejected when the batch is split
+    /// or built.
+    pub fn push<M>(&mut self, builder: NotarizationBuilder<M, D>) {
+        self.builders.push(builder.into_untyped());
+        self.cached_ptb = OnceCell::new();
+    }
+
+    /// Sets the policy for retrying the lookups of the created notarizations
+    /// made once the batch has been executed.
+    ///
+    /// The retry policies of the added builders are ignored. The batches
+    /// returned by [`NotarizationClient::create_batch`](crate::client::NotarizationClient::create_batch)
+    /// use the retry policy of the client.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the number of notarizations in the batch.
+    pub fn len(&self) -> usize {
+        self.builders.len()
+    }
+
+    /// Returns `true` if the batch contains no notarizations.
+    pub fn is_empty(&self) -> bool {
+        self.builders.is_empty()
+    }
+
+    /// Splits the batch into batches that each fit into a single programmable
+    /// transaction.
+    ///
+    /// A batch is closed once adding the next notarization would exceed
+    /// [`MAX_BATCH_COMMANDS`] or [`MAX_BATCH_TX_SIZE`]. The order of the
+    /// notarizations and the retry policy are preserved.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if a notarization of the batch is invalid or shared, or if it
+    /// cannot be created by any transaction because one of its arguments
+    /// exceeds [`MAX_PURE_ARGUMENT_SIZE`] or it exceeds the batch limits on its
+    /// own.
+    pub fn split(self, package_id: ObjectId) -> Result<Vec<Self>, Error> {
+        let mut batches = Vec::new();
+        let retry_policy = self.retry_policy;
+        let mut current = Self::new().with_retry_policy(retry_policy);
+        let (mut commands, mut size) = (0, 0);
+
+        for builder in self.builders {
+            let (item_commands, item_size) = Self::measure(&builder, package_id)?;
+
+            if !current.is_empty()
+                && (commands + item_commands > MAX_BATCH_COMMANDS || size + item_size > MAX_BATCH_TX_SIZE)
+            {
+                batches.push(std::mem::replace(
+                    &mut current,
+                    Self::new().with_retry_policy(retry_policy),
+                ));
+                (commands, size) = (0, 0);
+            }
+
+            current.builders.push(builder);
+            commands += item_commands;
+            size += item_size;
+        }
+
+        if !current.is_empty() {
+            batches.push(current);
+        }
+
+        Ok(batches)
+    }
+
+    /// Returns the number of commands and the serialized size of a transaction
+    /// creating only the notarization of `builder`.
+    ///
+    /// Fails if that transaction would be rejected by the network regardless of
+    /// the other notarizations of the batch.
+    fn measure(builder: &NotarizationBuilder<(), D>, package_id: ObjectId) -> Result<(usize, usize), Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        Self::add_to_ptb(builder, &mut ptb, package_id)?;
+        let pt = ptb.finish();
+        let size = checked_size(&pt)?;
+
+        if pt.commands.len() > MAX_BATCH_COMMANDS || size > MAX_BATCH_TX_SIZE {
+            return Err(Error::InvalidArgument(format!(
+                "a notarization of {} commands and {size} bytes exceeds the batch limits",
+                pt.commands.len()
+            )));
+        }
+
+        Ok((pt.commands.len(), size))
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`CreateNotarizationBatch`] instance.
+    async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+        self.to_ptb(client.package_id())
+    }
+
+    /// Builds the [`ProgrammableTransaction`] creating all notarizations of
+    /// the batch.
+    ///
+    /// Fails if the transaction exceeds [`MAX_BATCH_COMMANDS`],
+    /// [`MAX_BATCH_TX_SIZE`] or [`MAX_PURE_ARGUMENT_SIZE`], in which case the
+    /// batch has to be [split](Self::split) first.
+    fn to_ptb(&self, package_id: ObjectId) -> Result<ProgrammableTransaction, Error> {
+        if self.builders.is_empty() {
+            return Err(Error::InvalidArgument(
+                "a batch must contain at least one notarization".to_string(),
+            ));
+        }
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        for builder in &self.builders {
+            Self::add_to_ptb(builder, &mut ptb, package_id)?;
+        }
+        let pt = ptb.finish();
+        let size = checked_size(&pt)?;
+
+        if pt.commands.len() > MAX_BATCH_COMMANDS || size > MAX_BATCH_TX_SIZE {
+            return Err(Error::InvalidArgument(format!(
+                "a batch of {} notarizations with {} commands and {size} bytes exceeds the limits of a single transaction; split it with `CreateNotarizationBatch::split`",
+                self.builders.len(),
+                pt.commands.len()
+            )));
+        }
+
+        Ok(pt)
+    }
+
+    /// Adds the commands creating the notarization of `builder` to `ptb`.
+    ///
+    /// Shared-Notarizations are rejected, as the admin capability they are
+    /// created with could not be returned by the batch.
+    fn add_to_ptb(
+        builder: &NotarizationBuilder<(), D>,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+    ) -> Result<(), Error> {
+        if builder.method == NotarizationMethod::Shared {
+            return Err(Error::InvalidArgument(
+                "Shared-Notarizations cannot be created in a batch; use CreateSharedNotarization to obtain their admin capability"
+                    .to_string(),
+            ));
+        }
+
+        CreateNotarization::add_to_ptb(builder.clone(), ptb, package_id)
+    }
+}
+
+/// Returns the BCS-serialized size of `pt`, in bytes.
+///
+/// Fails if one of the pure arguments of `pt` exceeds
+/// [`MAX_PURE_ARGUMENT_SIZE`].
+fn checked_size(pt: &ProgrammableTransaction) -> Result<usize, Error> {
+    let largest_argument = pt
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            Input::Pure { value } => Some(value.len()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or_default();
+    if largest_argument > MAX_PURE_ARGUMENT_SIZE {
+        return Err(Error::InvalidArgument(format!(
+            "a notarization argument of {largest_argument} bytes exceeds the limit of {MAX_PURE_ARGUMENT_SIZE} bytes"
+        )));
+    }
+
+    bcs::serialized_size(pt).map_err(|e| Error::InvalidArgument(format!("failed to serialize transaction: {e}")))
+}
+
+impl<M, D: StateData + Clone> FromIterator<NotarizationBuilder<M, D>> for CreateNotarizationBatch<D> {
+    fn from_iter<I: IntoIterator<Item = NotarizationBuilder<M, D>>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        batch.extend(iter);
+        batch
+    }
+}
+
+impl<M, D: StateData + Clone> Extend<NotarizationBuilder<M, D>> for CreateNotarizationBatch<D> {
+    fn extend<I: IntoIterator<Item = NotarizationBuilder<M, D>>>(&mut self, iter: I) {
+        for builder in iter {
+            self.push(builder);
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<D> Transaction for CreateNotarizationBatch<D>
+where
+    D: StateData + Clone + OptionalSend + OptionalSync,
+{
+    type Error = Error;
+
+    type Output = Vec<OnChainNotarization<D>>;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        let notarization_ids: Vec<ObjectId> = events
+            .data
+            .iter()
+            .filter_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::DynamicNotarizationCreated(event)) => Some(event.notarization_id),
+                Ok(NotarizationEvent::LockedNotarizationCreated(event)) => Some(event.notarization_id),
+                _ => None,
+            })
+            .collect();
+
+        if notarization_ids.len() != self.builders.len() {
+            return Err(Error::TransactionUnexpectedResponse(format!(
+                "expected {} creation events, found {}",
+                self.builders.len(),
+                notarization_ids.len()
+            )));
+        }
+
+        let objects = move_utils::multi_get_objects(
+            client,
+            &notarization_ids,
+            IotaObjectDataOptions::bcs_lossless().with_owner(),
+            &self.retry_policy,
+        )
+        .await?;
+
+        let mut notarizations = objects
+            .into_iter()
+            .map(|data| {
+                let (mut notarization, owner) = notarization_from_object_data(data)?;
+                notarization.owner = owner;
+                Ok(notarization)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        resolve_all_locks(client, &mut notarizations, &self.retry_policy).await?;
+
+        Ok(notarizations)
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package_id() -> ObjectId {
+        "0x0000000000000000000000000000000000000000000000000000000000000abc"
+            .parse()
+            .unwrap()
+    }
+
+    fn invoice(size: usize) -> NotarizationBuilder<(), Data> {
+        NotarizationBuilder::locked()
+            .with_bytes_state(vec![7; size], None)
+            .into_untyped()
+    }
+
+    #[test]
+    fn split_keeps_small_batches_whole() {
+        let batch: CreateNotarizationBatch = (0..3).map(|_| invoice(32)).collect();
+
+        let batches = batch.split(package_id()).unwrap();
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 3);
+    }
+
+    #[test]
+    fn split_bounds_transaction_size() {
+        let batch: CreateNotarizationBatch = (0..20).map(|_| invoice(15 * 1024)).collect();
+
+        let batches = batch.split(package_id()).unwrap();
+
+        assert_eq!(
+            batches.iter().map(CreateNotarizationBatch::len).collect::<Vec<_>>(),
+            vec![7, 7, 6]
+        );
+        assert!(batches.iter().all(|batch| {
+            batch
+                .builders
+                .iter()
+                .map(|builder| CreateNotarizationBatch::measure(builder, package_id()).unwrap().1)
+                .sum::<usize>()
+                <= MAX_BATCH_TX_SIZE
+        }));
+    }
+
+    #[test]
+    fn split_rejects_invalid_entries() {
+        let batch = CreateNotarizationBatch::new()
+            .with(invoice(32))
+            .with(NotarizationBuilder::dynamic());
+
+        assert!(batch.split(package_id()).is_err());
+    }
+
+    #[test]
+    fn split_rejects_oversized_arguments() {
+        let batch = CreateNotarizationBatch::new()
+            .with(invoice(32))
+            .with(invoice(MAX_PURE_ARGUMENT_SIZE + 1));
+
+        assert!(matches!(batch.split(package_id()), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn build_rejects_batches_exceeding_the_limits() {
+        let batch: CreateNotarizationBatch = (0..20).map(|_| invoice(15 * 1024)).collect();
+
+        assert!(matches!(batch.to_ptb(package_id()), Err(Error::InvalidArgument(_))));
+        for batch in batch.split(package_id()).unwrap() {
+            assert!(batch.to_ptb(package_id()).is_ok());
+        }
+    }
+
+    #[test]
+    fn build_rejects_oversized_arguments() {
+        let batch = CreateNotarizationBatch::new().with(invoice(MAX_PURE_ARGUMENT_SIZE + 1));
+
+        assert!(matches!(batch.to_ptb(package_id()), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn split_rejects_shared_entries() {
+        let batch = CreateNotarizationBatch::new()
+            .with(invoice(32))
+            .with(NotarizationBuilder::shared().with_string_state("Shared".to_string(), None));
+
+        assert!(matches!(batch.split(package_id()), Err(Error::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Create Shared Notarization
+//!
+//! This module defines the create-shared-notarization transaction.
+//!
+//! ## Overview
+//!
+//! The create-shared-notarization transaction creates a new on-chain
+//! `Notarization<D>` object with the `Shared` Notarization Method and shares
+//! it. A capability with the [`Role::Admin`](super::super::types::Role::Admin)
+//! role is transferred to the transaction sender; its ID is part of the
+//! transaction output, so further capabilities can be issued right away.
+//!
+//! The builder is validated like a [`CreateNotarization<Shared>`].
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use iota_interaction::{OptionalSend, OptionalSync};
+use iota_sdk_types::{ObjectId, ProgrammableTransaction};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::abort;
+use super::super::builder::{NotarizationBuilder, Shared};
+use super::super::types::{Data, NotarizationEvent, OnChainNotarization, StateData};
+use super::create::{CreateNotarization, get_notarization_with_owner};
+use crate::error::Error;
+
+/// The output of a [`CreateSharedNotarization`] transaction.
+#[derive(Debug, Clone)]
+pub struct CreatedSharedNotarization<D = Data> {
+    /// The created notarization. Its `owner` is `Address::ZERO`.
+    pub notarization: OnChainNotarization<D>,
+    /// ID of the admin capability transferred to the transaction sender.
+    pub admin_capability_id: ObjectId,
+}
+
+/// A transaction that creates a new shared notarization on-chain.
+///
+/// On success the resulting `Notarization` object is shared, an admin
+/// capability is transferred to the transaction sender, and the on-chain
+/// transaction emits a `SharedNotarizationCreated` event.
+///
+/// The type parameter `D` is the type of the notarized payload, see
+/// [`NotarizationBuilder::with_generic_state`].
+#[derive(Clone)]
+pub struct CreateSharedNotarization<D = Data> {
+    builder: NotarizationBuilder<Shared, D>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<D: StateData + Clone> CreateSharedNotarization<D> {
+    /// Creates a new [`CreateSharedNotarization`] instance.
+    pub fn new(builder: NotarizationBuilder<Shared, D>) -> Self {
+        Self {
+            builder,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`CreateSharedNotarization`] instance.
+    async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        CreateNotarization::add_to_ptb(self.builder.clone(), &mut ptb, client.package_id())?;
+
+        Ok(ptb.finish())
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<D> Transaction for CreateSharedNotarization<D>
+where
+    D: StateData + Clone + OptionalSend + OptionalSync,
+{
+    type Error = Error;
+
+    type Output = CreatedSharedNotarization<D>;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        let event = events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::SharedNotarizationCreated(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::TransactionUnexpectedResponse("SharedNotarizationCreated event not found".to_string())
+            })?;
+
+        let (notarization, _) =
+            get_notarization_with_owner(client, &event.notarization_id, &self.builder.retry_policy).await?;
+
+        Ok(CreatedSharedNotarization {
+            notarization,
+            admin_capability_id: event.capability_id,
+        })
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
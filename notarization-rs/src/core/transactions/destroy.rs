@@ -15,13 +15,16 @@
 
 use async_trait::async_trait;
 use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_sdk_types::{ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
+use super::super::abort;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{NotarizationDestroyed, NotarizationEvent};
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// A transaction that destroys a notarization on-chain and releases its
@@ -32,11 +35,16 @@ use crate::error::Error;
 /// destroyed in the process. The notarization must currently be
 /// destroy-allowed (see
 /// [`NotarizationClientReadOnly::is_destroy_allowed`](crate::client::NotarizationClientReadOnly::is_destroy_allowed));
-/// otherwise the on-chain transaction aborts.
+/// otherwise the on-chain transaction aborts. Shared-Notarizations must
+/// additionally be destroyed with an admin capability, see
+/// [`Self::with_capability`].
 ///
-/// Emits a `NotarizationDestroyed` event on success.
+/// Emits a [`NotarizationDestroyed`] event on success, which is returned as the transaction
+/// output.
 pub struct DestroyNotarization {
     notarization_id: ObjectId,
+    capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -45,15 +53,47 @@ impl DestroyNotarization {
     pub fn new(notarization_id: ObjectId) -> Self {
         Self {
             notarization_id,
+            capability_id: None,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Authorizes the destruction with the capability `capability_id`.
+    ///
+    /// Required for Shared-Notarizations, which can only be destroyed with a
+    /// valid capability with the [`Role::Admin`](super::super::types::Role::Admin)
+    /// role.
+    pub fn with_capability(mut self, capability_id: ObjectId) -> Self {
+        self.capability_id = Some(capability_id);
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::destroy(client, self.notarization_id).await
+        match self.capability_id {
+            Some(capability_id) => {
+                NotarizationImpl::destroy_with_capability(
+                    client,
+                    self.notarization_id,
+                    capability_id,
+                    &self.retry_policy,
+                )
+                .await
+            }
+            None => NotarizationImpl::destroy(client, self.notarization_id, &self.retry_policy).await,
+        }
     }
 }
 
@@ -62,7 +102,7 @@ impl DestroyNotarization {
 impl Transaction for DestroyNotarization {
     type Error = Error;
 
-    type Output = ();
+    type Output = NotarizationDestroyed;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -71,10 +111,31 @@ impl Transaction for DestroyNotarization {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::NotarizationDestroyed(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("NotarizationDestroyed event not found".to_string()))
+    }
+
     async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        unreachable!()
     }
 }
@@ -0,0 +1,155 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Endorse Notarization
+//!
+//! This module defines the endorse-notarization transaction.
+//!
+//! ## Overview
+//!
+//! The endorse-notarization transaction records that the transaction sender
+//! endorses a version of the `state` of a notarization, e.g. as a party
+//! co-signing a contract. The version is passed by its `state_version_count`
+//! and the hash of its `state` instead of the notarization object, so any
+//! address can endorse any notarization, including notarizations owned by
+//! other addresses. The created [`Endorsement`](super::super::types::Endorsement)
+//! is transferred to the notarization and can be listed and verified via
+//! [`NotarizationClientReadOnly::verify_endorsements`](crate::client::NotarizationClientReadOnly::verify_endorsements).
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_sdk_types::{ObjectId, ProgrammableTransaction};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::abort;
+use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{Data, NotarizationEndorsed, NotarizationEvent};
+use super::create::get_notarization_with_owner;
+use crate::client::retry::RetryPolicy;
+use crate::error::Error;
+
+/// A transaction that endorses a version of the `state` of a notarization on
+/// behalf of the transaction sender.
+///
+/// By default, the `state_version_count` and the hash of the `state` are read
+/// from the notarization when the transaction is built, so the endorsement
+/// refers to the `state` the endorser has seen, even if the notarization is
+/// updated before the transaction is executed. This requires the
+/// notarization to hold a [`Data`] payload; use [`Self::with_state_version`]
+/// to endorse notarizations with other payloads or a specific version.
+///
+/// The package does not check the endorsed version on-chain; readers compare
+/// it with the notarization, see
+/// [`NotarizationClientReadOnly::verify_endorsements`](crate::client::NotarizationClientReadOnly::verify_endorsements).
+///
+/// Emits a [`NotarizationEndorsed`] event on success, which is returned as the transaction output.
+pub struct EndorseNotarization {
+    notarization_id: ObjectId,
+    state_version: Option<(u64, Vec<u8>)>,
+    retry_policy: RetryPolicy,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl EndorseNotarization {
+    /// Creates a new endorse transaction for the current `state` of the
+    /// notarization.
+    ///
+    /// ## Parameters
+    ///
+    /// - `notarization_id`: The ID of the notarization to endorse
+    pub fn new(notarization_id: ObjectId) -> Self {
+        Self {
+            notarization_id,
+            state_version: None,
+            retry_policy: RetryPolicy::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Endorses version `state_version_count` of the `state`, whose hash is
+    /// `state_hash`, instead of the current `state`.
+    ///
+    /// `state_hash` is the SHA2-256 hash of the BCS of the endorsed `state`,
+    /// see [`State::hash`](super::super::types::State::hash). Submitting with
+    /// a hash that is not 32 bytes long aborts on-chain.
+    pub fn with_state_version(mut self, state_version_count: u64, state_hash: Vec<u8>) -> Self {
+        self.state_version = Some((state_version_count, state_hash));
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let (state_version_count, state_hash) = match &self.state_version {
+            Some(state_version) => state_version.clone(),
+            None => {
+                let (notarization, _) =
+                    get_notarization_with_owner::<Data>(client, &self.notarization_id, &self.retry_policy).await?;
+                (notarization.state_version_count, notarization.state.hash()?)
+            }
+        };
+
+        NotarizationImpl::endorse(
+            self.notarization_id,
+            state_version_count,
+            state_hash,
+            client.package_id(),
+        )
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for EndorseNotarization {
+    type Error = Error;
+
+    type Output = NotarizationEndorsed;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::NotarizationEndorsed(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("NotarizationEndorsed event not found".to_string()))
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
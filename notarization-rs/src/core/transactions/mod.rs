@@ -3,14 +3,24 @@
 
 //! Transaction operations for notarizations.
 
+mod capability;
 mod create;
+mod create_batch;
+mod create_shared;
 mod destroy;
+mod endorse;
+mod seal;
 mod transfer;
 mod update_metadata;
 mod update_state;
 
+pub use capability::*;
 pub use create::*;
+pub use create_batch::*;
+pub use create_shared::*;
 pub use destroy::*;
+pub use endorse::*;
+pub use seal::*;
 pub use transfer::*;
 pub use update_metadata::*;
 pub use update_state::*;
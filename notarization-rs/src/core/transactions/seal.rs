@@ -0,0 +1,156 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Seal Notarization
+//!
+//! This module defines the seal-notarization transaction.
+//!
+//! ## Overview
+//!
+//! The seal-notarization transaction converts a Dynamic-Notarization into a
+//! Locked-Notarization. The object ID, `state`, `updatable_metadata`,
+//! `created_at` and `state_version_count` are preserved; afterwards the
+//! notarization can neither be updated nor transferred, and its destruction
+//! is gated by the given `delete_lock`.
+//!
+//! Behaviour depends on the Notarization Method:
+//! * `Dynamic`: sealed, unless the configured `update_lock` is engaged, which aborts on-chain.
+//! * `Locked`: always aborts on-chain — the notarization is already sealed.
+//! * `Shared`: sealed in place when authorized by an admin capability, unless the `update_lock` is engaged. The
+//!   notarization stays shared; only its `update_lock` and `delete_lock` are replaced.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_sdk_types::{ObjectId, ProgrammableTransaction};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::abort;
+use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{NotarizationEvent, NotarizationSealed, TimeLock};
+use crate::client::retry::RetryPolicy;
+use crate::error::Error;
+
+/// A transaction that converts a Dynamic-Notarization into a
+/// Locked-Notarization.
+///
+/// `delete_lock` becomes the `delete_lock` of the Locked-Notarization and
+/// must not be `TimeLock::UntilDestroyed`. It must not unlock before an
+/// active `UnlockAt` `delete_lock` or `transfer_lock` of the
+/// Dynamic-Notarization, so sealing never shortens the retention of the
+/// notarization.
+///
+/// Submitting aborts on-chain if the notarization is not a
+/// Dynamic-Notarization or if its `update_lock` is engaged.
+///
+/// A Shared-Notarization is sealed with [`Self::with_capability`] instead:
+/// its `update_lock` becomes `TimeLock::UntilDestroyed` and its `delete_lock`
+/// is replaced by `delete_lock`, under the same retention rule. It stays
+/// shared, so its capabilities keep working for destroying it. Sealing a
+/// Shared-Notarization requires a capability with the `Admin` role.
+///
+/// Emits a [`NotarizationSealed`] event on success, which is returned as the transaction output.
+pub struct SealNotarization {
+    notarization_id: ObjectId,
+    delete_lock: TimeLock,
+    capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl SealNotarization {
+    /// Creates a new seal transaction.
+    pub fn new(notarization_id: ObjectId, delete_lock: TimeLock) -> Self {
+        Self {
+            notarization_id,
+            delete_lock,
+            capability_id: None,
+            retry_policy: RetryPolicy::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Authorizes sealing with the capability `capability_id`.
+    ///
+    /// Required for Shared-Notarizations, which can only be sealed with a
+    /// valid capability with the `Admin` role issued for them.
+    pub fn with_capability(mut self, capability_id: ObjectId) -> Self {
+        self.capability_id = Some(capability_id);
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let delete_lock = self.delete_lock.clone();
+
+        match self.capability_id {
+            Some(capability_id) => {
+                NotarizationImpl::seal_with_capability(
+                    client,
+                    self.notarization_id,
+                    capability_id,
+                    delete_lock,
+                    &self.retry_policy,
+                )
+                .await
+            }
+            None => NotarizationImpl::seal(self.notarization_id, delete_lock, client, &self.retry_policy).await,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for SealNotarization {
+    type Error = Error;
+
+    type Output = NotarizationSealed;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::NotarizationSealed(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("NotarizationSealed event not found".to_string()))
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
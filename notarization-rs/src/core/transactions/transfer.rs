@@ -19,13 +19,16 @@
 
 use async_trait::async_trait;
 use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_sdk_types::{Address, ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
+use super::super::abort;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{DynamicNotarizationTransferred, NotarizationEvent};
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// A transaction that transfers ownership of a Dynamic-Notarization to
@@ -41,10 +44,12 @@ use crate::error::Error;
 /// * `Locked`: always aborts on-chain — Locked-Notarizations have their `transfer_lock` pinned to
 ///   `TimeLock::UntilDestroyed` and are therefore non-transferable.
 ///
-/// Emits a `DynamicNotarizationTransferred` event on success.
+/// Emits a [`DynamicNotarizationTransferred`] event on success, which is returned as the transaction
+/// output.
 pub struct TransferNotarization {
     recipient: Address,
     notarization_id: ObjectId,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -54,15 +59,24 @@ impl TransferNotarization {
         Self {
             recipient,
             notarization_id,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::transfer_notarization(self.notarization_id, self.recipient, client).await
+        NotarizationImpl::transfer_notarization(self.notarization_id, self.recipient, client, &self.retry_policy).await
     }
 }
 
@@ -71,7 +85,7 @@ impl TransferNotarization {
 impl Transaction for TransferNotarization {
     type Error = Error;
 
-    type Output = ();
+    type Output = DynamicNotarizationTransferred;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -80,10 +94,33 @@ impl Transaction for TransferNotarization {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::DynamicNotarizationTransferred(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::TransactionUnexpectedResponse("DynamicNotarizationTransferred event not found".to_string())
+            })
+    }
+
     async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        unreachable!()
     }
 }
@@ -11,18 +11,23 @@
 //! existing notarization. It does not affect `state`, `state_version_count`,
 //! `last_state_change_at`, or the immutable description. Behaviour depends
 //! on the Notarization Method:
-//! * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+//! * `Dynamic`: aborts on-chain while the optional `update_lock` is active, i.e. before a `TimeLock::UnlockAt`
+//!   embargo expires or from the timestamp of an `UpdateLock::LockAt` freeze on; permitted otherwise.
 //! * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to `TimeLock::UntilDestroyed`.
+//! * `Shared`: must be authorized by a valid capability; aborts on-chain otherwise.
 
 use async_trait::async_trait;
 use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_sdk_types::{ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
+use super::super::abort;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use super::super::types::{NotarizationEvent, NotarizationMetadataUpdated};
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// A transaction that replaces the `updatable_metadata` of an existing
@@ -32,12 +37,20 @@ use crate::error::Error;
 /// or the immutable description.
 ///
 /// Behaviour depends on the Notarization Method:
-/// * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+/// * `Dynamic`: aborts on-chain while the optional `update_lock` is active, i.e. before a `TimeLock::UnlockAt`
+///   embargo expires or from the timestamp of an `UpdateLock::LockAt` freeze on; permitted otherwise.
 /// * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to `TimeLock::UntilDestroyed`.
+/// * `Shared`: must be authorized by a valid capability, see [`Self::with_capability`]; aborts on-chain
+///   otherwise.
+///
+/// Emits a [`NotarizationMetadataUpdated`] event on success, which is returned as the transaction
+/// output.
 pub struct UpdateMetadata {
     metadata: Option<String>,
     /// The ID of the notarization to update
     notarization_id: ObjectId,
+    capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -47,17 +60,57 @@ impl UpdateMetadata {
         Self {
             metadata,
             notarization_id,
+            capability_id: None,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Authorizes the update with the capability `capability_id`.
+    ///
+    /// Required for Shared-Notarizations, whose updates must be authorized by
+    /// a valid capability issued for them.
+    pub fn with_capability(mut self, capability_id: ObjectId) -> Self {
+        self.capability_id = Some(capability_id);
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     /// Builds the programmable transaction for updating the metadata of a
     /// notarization.
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::update_metadata(client, self.notarization_id, self.metadata.clone()).await
+        match self.capability_id {
+            Some(capability_id) => {
+                NotarizationImpl::update_metadata_with_capability(
+                    client,
+                    self.notarization_id,
+                    capability_id,
+                    self.metadata.clone(),
+                    &self.retry_policy,
+                )
+                .await
+            }
+            None => {
+                NotarizationImpl::update_metadata(
+                    client,
+                    self.notarization_id,
+                    self.metadata.clone(),
+                    &self.retry_policy,
+                )
+                .await
+            }
+        }
     }
 }
 
@@ -66,7 +119,7 @@ impl UpdateMetadata {
 impl Transaction for UpdateMetadata {
     type Error = Error;
 
-    type Output = ();
+    type Output = NotarizationMetadataUpdated;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -75,10 +128,33 @@ impl Transaction for UpdateMetadata {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::NotarizationMetadataUpdated(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::TransactionUnexpectedResponse("NotarizationMetadataUpdated event not found".to_string())
+            })
+    }
+
     async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        unreachable!()
     }
 }
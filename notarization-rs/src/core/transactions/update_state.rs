@@ -9,19 +9,23 @@
 //!
 //! The update-state transaction replaces the `state` of an existing
 //! notarization. Behaviour depends on the Notarization Method:
-//! * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+//! * `Dynamic`: aborts on-chain while the optional `update_lock` is active, i.e. before a `TimeLock::UnlockAt`
+//!   embargo expires or from the timestamp of an `UpdateLock::LockAt` freeze on; permitted otherwise.
 //! * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to `TimeLock::UntilDestroyed`.
+//! * `Shared`: must be authorized by a valid capability; aborts on-chain otherwise.
 
 use async_trait::async_trait;
 use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_sdk_types::{ObjectId, ProgrammableTransaction};
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
+use super::super::abort;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
-use super::super::types::State;
+use super::super::types::{NotarizationEvent, NotarizationUpdated, State};
+use crate::client::retry::RetryPolicy;
 use crate::error::Error;
 
 /// A transaction that replaces the `state` of an existing notarization.
@@ -32,10 +36,14 @@ use crate::error::Error;
 /// timestamp (in milliseconds since the Unix epoch).
 ///
 /// Behaviour depends on the Notarization Method:
-/// * `Dynamic`: always permitted — the underlying `update_lock` is fixed to `TimeLock::None`.
+/// * `Dynamic`: aborts on-chain while the optional `update_lock` is active, i.e. before a `TimeLock::UnlockAt`
+///   embargo expires or from the timestamp of an `UpdateLock::LockAt` freeze on; permitted otherwise.
 /// * `Locked`: always aborts on-chain, because the underlying `update_lock` is pinned to `TimeLock::UntilDestroyed`.
+/// * `Shared`: must be authorized by a valid capability, see [`Self::with_capability`]; aborts on-chain
+///   otherwise.
 ///
-/// Emits a `NotarizationUpdated` event on success.
+/// Emits a [`NotarizationUpdated`] event on success, which is returned as the transaction
+/// output.
 ///
 /// ## Example
 ///
@@ -58,6 +66,8 @@ use crate::error::Error;
 pub struct UpdateState {
     state: State,
     object_id: ObjectId,
+    capability_id: Option<ObjectId>,
+    retry_policy: RetryPolicy,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -72,17 +82,49 @@ impl UpdateState {
         Self {
             state,
             object_id,
+            capability_id: None,
+            retry_policy: RetryPolicy::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Authorizes the update with the capability `capability_id`.
+    ///
+    /// Required for Shared-Notarizations, whose updates must be authorized by
+    /// a valid capability issued for them.
+    pub fn with_capability(mut self, capability_id: ObjectId) -> Self {
+        self.capability_id = Some(capability_id);
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
+    /// Sets the policy for retrying the object lookups made while building
+    /// the transaction.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self.cached_ptb = OnceCell::new();
+        self
+    }
+
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         let new_state = self.state.clone();
 
-        NotarizationImpl::update_state(client, self.object_id, new_state).await
+        match self.capability_id {
+            Some(capability_id) => {
+                NotarizationImpl::update_state_with_capability(
+                    client,
+                    self.object_id,
+                    capability_id,
+                    new_state,
+                    &self.retry_policy,
+                )
+                .await
+            }
+            None => NotarizationImpl::update_state(client, self.object_id, new_state, &self.retry_policy).await,
+        }
     }
 }
 
@@ -91,7 +133,7 @@ impl UpdateState {
 impl Transaction for UpdateState {
     type Error = Error;
 
-    type Output = ();
+    type Output = NotarizationUpdated;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -100,10 +142,31 @@ impl Transaction for UpdateState {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        abort::check_execution_status(effects, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|data| match NotarizationEvent::try_from(data) {
+                Ok(NotarizationEvent::NotarizationUpdated(event)) => Some(event),
+                _ => None,
+            })
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("NotarizationUpdated event not found".to_string()))
+    }
+
     async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        unreachable!()
     }
 }
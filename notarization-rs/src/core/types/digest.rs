@@ -0,0 +1,383 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Digest
+//!
+//! This module defines typed digests for hash-only notarizations.
+//!
+//! ## Overview
+//!
+//! Instead of notarizing a (potentially large) document itself, only its
+//! digest is notarized. To keep the notarized value self-describing, the
+//! digest is stored together with an identifier of the algorithm that
+//! produced it.
+//!
+//! ## Encoding
+//!
+//! Digests are stored on-chain as `vector<u8>` using the
+//! [multihash](https://github.com/multiformats/multihash) format:
+//!
+//! ```text
+//! <varint algorithm code> <varint digest length> <digest bytes>
+//! ```
+//!
+//! Both varints are unsigned LEB128. The supported algorithms and their
+//! multicodec codes are:
+//!
+//! | Algorithm            | Code     | Length |
+//! |----------------------|----------|--------|
+//! | `SHA2-256`           | `0x12`   | 32     |
+//! | `SHA2-512`           | `0x13`   | 64     |
+//! | `SHA3-256`           | `0x16`   | 32     |
+//! | `BLAKE2b-256`        | `0xb220` | 32     |
+//!
+//! ## Example
+//!
+//! ```rust
+//! use notarization::core::types::{Digest, DigestAlgorithm, State};
+//!
+//! let digest = Digest::compute(DigestAlgorithm::Sha256, b"%PDF-1.7 ...");
+//! let state = State::from_digest(digest.clone(), Some("Signed contract PDF".to_string()));
+//!
+//! assert_eq!(state.data.as_digest().unwrap(), digest);
+//! ```
+
+use std::fmt;
+
+use blake2::Blake2b;
+use blake2::digest::consts::U32;
+use serde::{Deserialize, Serialize};
+use sha2::Digest as _;
+use sha2::{Sha256, Sha512};
+use sha3::Sha3_256;
+
+use crate::error::Error;
+
+/// Hash algorithms supported for hash-only notarizations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DigestAlgorithm {
+    /// SHA2-256, 32 byte digest.
+    Sha256,
+    /// SHA2-512, 64 byte digest.
+    Sha512,
+    /// SHA3-256, 32 byte digest.
+    Sha3_256,
+    /// BLAKE2b with a 32 byte digest.
+    Blake2b256,
+}
+
+impl DigestAlgorithm {
+    /// Returns the multicodec code identifying this algorithm.
+    pub const fn code(&self) -> u64 {
+        match self {
+            DigestAlgorithm::Sha256 => 0x12,
+            DigestAlgorithm::Sha512 => 0x13,
+            DigestAlgorithm::Sha3_256 => 0x16,
+            DigestAlgorithm::Blake2b256 => 0xb220,
+        }
+    }
+
+    /// Returns the algorithm identified by the multicodec `code`, if supported.
+    pub const fn from_code(code: u64) -> Option<Self> {
+        match code {
+            0x12 => Some(DigestAlgorithm::Sha256),
+            0x13 => Some(DigestAlgorithm::Sha512),
+            0x16 => Some(DigestAlgorithm::Sha3_256),
+            0xb220 => Some(DigestAlgorithm::Blake2b256),
+            _ => None,
+        }
+    }
+
+    /// Returns the length in bytes of digests produced by this algorithm.
+    pub const fn digest_len(&self) -> usize {
+        match self {
+            DigestAlgorithm::Sha512 => 64,
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha3_256 | DigestAlgorithm::Blake2b256 => 32,
+        }
+    }
+}
+
+impl fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DigestAlgorithm::Sha256 => "sha2-256",
+            DigestAlgorithm::Sha512 => "sha2-512",
+            DigestAlgorithm::Sha3_256 => "sha3-256",
+            DigestAlgorithm::Blake2b256 => "blake2b-256",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A digest together with the algorithm that produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Digest {
+    algorithm: DigestAlgorithm,
+    bytes: Vec<u8>,
+}
+
+impl Digest {
+    /// Creates a new [`Digest`] from an already computed digest.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the length of `bytes` does not match the digest
+    /// length of `algorithm`.
+    pub fn new(algorithm: DigestAlgorithm, bytes: Vec<u8>) -> Result<Self, Error> {
+        if bytes.len() != algorithm.digest_len() {
+            return Err(Error::InvalidArgument(format!(
+                "{algorithm} digest must be {} bytes long, got {}",
+                algorithm.digest_len(),
+                bytes.len()
+            )));
+        }
+
+        Ok(Self { algorithm, bytes })
+    }
+
+    /// Computes the digest of `data` using `algorithm`.
+    ///
+    /// Use [`DigestHasher`] to hash data that is not available at once.
+    pub fn compute(algorithm: DigestAlgorithm, data: impl AsRef<[u8]>) -> Self {
+        let mut hasher = DigestHasher::new(algorithm);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Returns the algorithm that produced this digest.
+    pub fn algorithm(&self) -> DigestAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the raw digest bytes, without the algorithm prefix.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns `true` if this digest is the digest of `data`.
+    pub fn matches(&self, data: impl AsRef<[u8]>) -> bool {
+        Self::compute(self.algorithm, data) == *self
+    }
+
+    /// Encodes this digest in the self-describing multihash format.
+    ///
+    /// See the [module docs](self) for the layout.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.bytes.len() + 4);
+        write_varint(&mut encoded, self.algorithm.code());
+        write_varint(&mut encoded, self.bytes.len() as u64);
+        encoded.extend_from_slice(&self.bytes);
+
+        encoded
+    }
+
+    /// Decodes a digest from the self-describing multihash format.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `bytes` is not a multihash of a supported
+    /// algorithm, or if its length does not match the encoded length.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (code, rest) = read_varint(bytes)?;
+        let algorithm = DigestAlgorithm::from_code(code)
+            .ok_or_else(|| Error::InvalidArgument(format!("unsupported digest algorithm code {code:#x}")))?;
+        let (len, digest) = read_varint(rest)?;
+
+        if len != digest.len() as u64 {
+            return Err(Error::InvalidArgument(format!(
+                "digest length prefix {len} does not match the {} remaining bytes",
+                digest.len()
+            )));
+        }
+
+        Self::new(algorithm, digest.to_vec())
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.algorithm)?;
+        self.bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Incremental hasher producing a [`Digest`].
+///
+/// Feed data in arbitrarily sized chunks via [`Self::update`] and obtain the
+/// digest via [`Self::finalize`]. The result is identical to calling
+/// [`Digest::compute`] on the concatenated chunks.
+#[derive(Clone)]
+pub struct DigestHasher(HasherInner);
+
+#[derive(Clone)]
+enum HasherInner {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Sha3_256(Sha3_256),
+    Blake2b256(Blake2b<U32>),
+}
+
+impl DigestHasher {
+    /// Creates a new hasher for `algorithm`.
+    pub fn new(algorithm: DigestAlgorithm) -> Self {
+        let inner = match algorithm {
+            DigestAlgorithm::Sha256 => HasherInner::Sha256(Sha256::new()),
+            DigestAlgorithm::Sha512 => HasherInner::Sha512(Sha512::new()),
+            DigestAlgorithm::Sha3_256 => HasherInner::Sha3_256(Sha3_256::new()),
+            DigestAlgorithm::Blake2b256 => HasherInner::Blake2b256(Blake2b::<U32>::new()),
+        };
+
+        Self(inner)
+    }
+
+    /// Returns the algorithm of this hasher.
+    pub fn algorithm(&self) -> DigestAlgorithm {
+        match self.0 {
+            HasherInner::Sha256(_) => DigestAlgorithm::Sha256,
+            HasherInner::Sha512(_) => DigestAlgorithm::Sha512,
+            HasherInner::Sha3_256(_) => DigestAlgorithm::Sha3_256,
+            HasherInner::Blake2b256(_) => DigestAlgorithm::Blake2b256,
+        }
+    }
+
+    /// Feeds `data` into the hasher.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        let data = data.as_ref();
+        match &mut self.0 {
+            HasherInner::Sha256(hasher) => hasher.update(data),
+            HasherInner::Sha512(hasher) => hasher.update(data),
+            HasherInner::Sha3_256(hasher) => hasher.update(data),
+            HasherInner::Blake2b256(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Consumes the hasher and returns the resulting [`Digest`].
+    pub fn finalize(self) -> Digest {
+        let algorithm = self.algorithm();
+        let bytes = match self.0 {
+            HasherInner::Sha256(hasher) => hasher.finalize().to_vec(),
+            HasherInner::Sha512(hasher) => hasher.finalize().to_vec(),
+            HasherInner::Sha3_256(hasher) => hasher.finalize().to_vec(),
+            HasherInner::Blake2b256(hasher) => hasher.finalize().to_vec(),
+        };
+
+        Digest { algorithm, bytes }
+    }
+}
+
+impl fmt::Debug for DigestHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DigestHasher").field(&self.algorithm()).finish()
+    }
+}
+
+/// Appends `value` to `buf` as unsigned LEB128.
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 value from the start of `bytes` and returns it
+/// together with the remaining bytes.
+///
+/// Only the 9 bytes allowed by the multiformats varint spec are accepted.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), Error> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, &bytes[i + 1..]));
+        }
+    }
+
+    Err(Error::InvalidArgument("truncated or overlong varint".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [DigestAlgorithm; 4] = [
+        DigestAlgorithm::Sha256,
+        DigestAlgorithm::Sha512,
+        DigestAlgorithm::Sha3_256,
+        DigestAlgorithm::Blake2b256,
+    ];
+
+    #[test]
+    fn compute_known_vectors() {
+        assert_eq!(
+            Digest::compute(DigestAlgorithm::Sha256, b"abc").to_string(),
+            "sha2-256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            Digest::compute(DigestAlgorithm::Sha3_256, b"abc").to_string(),
+            "sha3-256:3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+    }
+
+    #[test]
+    fn encoding_roundtrip() {
+        for algorithm in ALGORITHMS {
+            let digest = Digest::compute(algorithm, b"notarized document");
+            let encoded = digest.to_bytes();
+
+            assert_eq!(
+                encoded.len(),
+                algorithm.digest_len() + if algorithm.code() < 0x80 { 2 } else { 4 }
+            );
+            assert_eq!(Digest::from_bytes(&encoded).unwrap(), digest);
+        }
+    }
+
+    #[test]
+    fn encoding_prefixes() {
+        let sha256 = Digest::compute(DigestAlgorithm::Sha256, b"").to_bytes();
+        assert_eq!(&sha256[..2], &[0x12, 0x20]);
+
+        let blake2b = Digest::compute(DigestAlgorithm::Blake2b256, b"").to_bytes();
+        assert_eq!(&blake2b[..4], &[0xa0, 0xe4, 0x02, 0x20]);
+    }
+
+    #[test]
+    fn decoding_rejects_invalid_input() {
+        let mut encoded = Digest::compute(DigestAlgorithm::Sha256, b"abc").to_bytes();
+
+        assert!(Digest::from_bytes(&[]).is_err());
+        assert!(Digest::from_bytes(&encoded[..encoded.len() - 1]).is_err());
+
+        encoded[0] = 0x11;
+        assert!(Digest::from_bytes(&encoded).is_err());
+        assert!(Digest::from_bytes(b"plain text").is_err());
+    }
+
+    #[test]
+    fn new_rejects_wrong_length() {
+        assert!(Digest::new(DigestAlgorithm::Sha512, vec![0; 32]).is_err());
+        assert!(Digest::new(DigestAlgorithm::Sha512, vec![0; 64]).is_ok());
+    }
+
+    #[test]
+    fn hasher_matches_compute() {
+        let data = vec![0x5a; 10_000];
+
+        for algorithm in ALGORITHMS {
+            let mut hasher = DigestHasher::new(algorithm);
+            data.chunks(333).for_each(|chunk| hasher.update(chunk));
+
+            assert_eq!(hasher.algorithm(), algorithm);
+            assert_eq!(hasher.finalize(), Digest::compute(algorithm, &data));
+        }
+    }
+
+    #[test]
+    fn matches() {
+        let digest = Digest::compute(DigestAlgorithm::Blake2b256, b"payload");
+
+        assert!(digest.matches(b"payload"));
+        assert!(!digest.matches(b"other payload"));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Endorsement
+//!
+//! This module defines endorsements of notarizations.
+//!
+//! An endorsement records that an address endorsed a specific version of the
+//! `state` of a notarization, e.g. a counterparty co-signing a contract. The
+//! version is identified by its `state_version_count` and the hash of the
+//! `state`, both chosen by the endorser, so any address can endorse any
+//! notarization. The package does not check them on-chain: an endorsement
+//! only counts for a version if both values match that version, see
+//! [`Endorsement::is_current_for`] and [`Endorsement::is_for_version`].
+//!
+//! Endorsements are immutable objects owned by the endorsed notarization; an
+//! endorsement stays evidence for the version it refers to, even after the
+//! `state` was updated. Destroying the notarization does not destroy its
+//! endorsements: they can still be listed by the ID of the notarization, but
+//! can no longer be verified against it, and no transaction can delete them.
+
+use iota_interaction::types::id::UID;
+use iota_sdk_types::{Address, ObjectId};
+use serde::{Deserialize, Serialize};
+
+use super::notarization::OnChainNotarization;
+use super::state::{StateData, StateVersion};
+
+/// An endorsement of a version of the `state` of a notarization stored on the
+/// blockchain.
+///
+/// Endorsements are created via
+/// [`NotarizationClient::endorse`](crate::client::NotarizationClient::endorse)
+/// or
+/// [`NotarizationClient::endorse_state_version`](crate::client::NotarizationClient::endorse_state_version).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Endorsement {
+    /// The unique identifier of the endorsement.
+    pub id: UID,
+    /// ID of the endorsed notarization.
+    pub notarization_id: ObjectId,
+    /// Address that endorsed the notarization.
+    pub endorser: Address,
+    /// Value of `state_version_count` of the endorsed `state`.
+    pub state_version_count: u64,
+    /// SHA2-256 hash of the BCS of the endorsed `state`, see
+    /// [`State::hash`](super::State::hash).
+    pub state_hash: Vec<u8>,
+    /// Timestamp of the endorsement, in milliseconds since the Unix epoch.
+    pub endorsed_at: u64,
+}
+
+impl Endorsement {
+    /// Returns `true` if the endorsement refers to the current `state` of
+    /// `notarization`.
+    ///
+    /// Both the `state_version_count` and the hash of the `state` must match.
+    pub fn is_current_for<D: StateData>(&self, notarization: &OnChainNotarization<D>) -> bool {
+        self.notarization_id == *notarization.id.object_id()
+            && self.state_version_count == notarization.state_version_count
+            && notarization
+                .state
+                .hash()
+                .is_ok_and(|state_hash| state_hash == self.state_hash)
+    }
+
+    /// Returns `true` if the endorsement refers to `state_version`, e.g. a
+    /// past version returned by
+    /// [`NotarizationClientReadOnly::state_history`](crate::client::NotarizationClientReadOnly::state_history).
+    ///
+    /// Both the `state_version_count` and the hash of the `state` must match;
+    /// the caller is responsible for `state_version` belonging to the endorsed
+    /// notarization.
+    pub fn is_for_version<D: StateData>(&self, state_version: &StateVersion<D>) -> bool {
+        self.state_version_count == state_version.version
+            && state_version
+                .state
+                .hash()
+                .is_ok_and(|state_hash| state_hash == self.state_hash)
+    }
+}
+
+/// An [`Endorsement`] checked against the current `state` of the endorsed
+/// notarization.
+///
+/// See
+/// [`NotarizationClientReadOnly::verify_endorsements`](crate::client::NotarizationClientReadOnly::verify_endorsements).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VerifiedEndorsement {
+    /// The endorsement.
+    pub endorsement: Endorsement,
+    /// Whether the endorsement refers to the current `state` of the
+    /// notarization. `false` for endorsements of past versions and for
+    /// endorsements whose `state_hash` does not match the endorsed version.
+    pub is_current: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+
+    use sha2::{Digest as _, Sha256};
+
+    use super::*;
+    use crate::core::types::{ImmutableMetadata, NotarizationMethod, State};
+
+    fn make_notarization(state_version_count: u64) -> OnChainNotarization {
+        OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state: make_state("data"),
+            immutable_metadata: ImmutableMetadata {
+                created_at: 0,
+                description: None,
+                locking: None,
+            },
+            updatable_metadata: None,
+            last_state_change_at: 0,
+            state_version_count,
+            method: NotarizationMethod::Dynamic,
+            owner: Address::ZERO,
+        }
+    }
+
+    fn make_state(data: &str) -> State {
+        State::from_string(data.to_string(), None)
+    }
+
+    fn make_endorsement(notarization_id: ObjectId, state_version_count: u64, state: &State) -> Endorsement {
+        Endorsement {
+            id: UID::new(dbg_object_id(2)),
+            notarization_id,
+            endorser: Address::ZERO,
+            state_version_count,
+            state_hash: state.hash().unwrap(),
+            endorsed_at: 0,
+        }
+    }
+
+    #[test]
+    fn is_current_for_compares_notarization_version_and_state() {
+        let notarization = make_notarization(3);
+        let state = make_state("data");
+
+        assert!(make_endorsement(dbg_object_id(1), 3, &state).is_current_for(&notarization));
+        assert!(!make_endorsement(dbg_object_id(1), 2, &state).is_current_for(&notarization));
+        assert!(!make_endorsement(dbg_object_id(3), 3, &state).is_current_for(&notarization));
+        assert!(!make_endorsement(dbg_object_id(1), 3, &make_state("other")).is_current_for(&notarization));
+    }
+
+    #[test]
+    fn is_for_version_compares_version_and_state() {
+        let state = make_state("v1");
+        let state_version = StateVersion {
+            version: 1,
+            timestamp_ms: 0,
+            state: state.clone(),
+        };
+
+        assert!(make_endorsement(dbg_object_id(1), 1, &state).is_for_version(&state_version));
+        assert!(!make_endorsement(dbg_object_id(1), 2, &state).is_for_version(&state_version));
+        assert!(!make_endorsement(dbg_object_id(1), 1, &make_state("v2")).is_for_version(&state_version));
+    }
+
+    #[test]
+    fn state_hash_covers_the_bcs_of_the_move_state() {
+        let state = State::from_bytes(vec![1, 2, 3], Some("meta".to_string()));
+
+        // BCS of `State<vector<u8>>`: the data vector followed by the optional metadata.
+        let bcs = [&[3, 1, 2, 3][..], &[1, 4], b"meta"].concat();
+        assert_eq!(state.hash().unwrap(), Sha256::digest(&bcs).to_vec());
+    }
+}
@@ -1,8 +1,23 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk_types::ObjectId;
+//! # Notarization Events
+//!
+//! Typed models of the events emitted by the Notarization Package.
+//!
+//! Use [`NotarizationEvent`] to decode any of them from an [`IotaEvent`], e.g.
+//! when inspecting the events of a transaction or querying events from a node.
+
+use iota_interaction::rpc_types::IotaEvent;
+use iota_sdk_types::{Address, ObjectId, TypeTag};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
+
+use super::super::move_utils;
+use super::state::{Data, DataType, State};
+use crate::error::Error;
+
 /// An event emitted by notarization operations.
 ///
 /// Generic wrapper for different event data types.
@@ -14,12 +29,602 @@ pub struct Event<D> {
 
 /// An event that is emitted when a new dynamic notarization is created.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub(crate) struct DynamicNotarizationCreated {
+pub struct DynamicNotarizationCreated {
+    /// ID of the newly created notarization.
     pub notarization_id: ObjectId,
 }
 
 /// An event that is emitted when a new locked notarization is created.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub(crate) struct LockedNotarizationCreated {
+pub struct LockedNotarizationCreated {
+    /// ID of the newly created notarization.
+    pub notarization_id: ObjectId,
+}
+
+/// An event that is emitted when a new shared notarization is created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedNotarizationCreated {
+    /// ID of the newly created notarization.
+    pub notarization_id: ObjectId,
+    /// ID of the admin capability transferred to the creator.
+    pub capability_id: ObjectId,
+}
+
+/// An event that is emitted when a capability of a shared notarization is
+/// issued.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityIssued {
+    /// ID of the notarization the capability was issued for.
+    pub notarization_id: ObjectId,
+    /// ID of the issued capability.
+    pub capability_id: ObjectId,
+    /// Address the capability was transferred to.
+    pub recipient: Address,
+}
+
+/// An event that is emitted when a capability of a shared notarization is
+/// revoked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityRevoked {
+    /// ID of the notarization the capability was issued for.
+    pub notarization_id: ObjectId,
+    /// ID of the revoked capability.
+    pub capability_id: ObjectId,
+}
+
+/// An event that is emitted when a dynamic notarization is transferred.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicNotarizationTransferred {
+    /// ID of the transferred notarization.
+    pub notarization_id: ObjectId,
+    /// Address of the new owner.
+    pub recipient: Address,
+}
+
+/// An event that is emitted when a dynamic notarization is sealed into a
+/// locked notarization, or when a shared notarization is sealed by an admin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotarizationSealed {
+    /// ID of the sealed notarization.
+    pub notarization_id: ObjectId,
+    /// Value of `state_version_count` of the sealed `state`.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub state_version_count: u64,
+}
+
+/// An event that is emitted when the state of a notarization is updated.
+///
+/// ## Type Parameter
+///
+/// - `D`: The data type of the updated state, defaults to [`Data`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotarizationUpdated<D = Data> {
+    /// ID of the updated notarization.
+    pub notarization_id: ObjectId,
+    /// Value of `state_version_count` after the update.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub state_version_count: u64,
+    /// The new `state` after the update.
+    pub updated_state: State<D>,
+}
+
+impl<D: DeserializeOwned> NotarizationUpdated<D> {
+    /// Decodes the BCS content of a `NotarizationUpdated<D>` event.
+    pub(crate) fn from_bcs(bytes: &[u8]) -> Result<Self, Error> {
+        let RawNotarizationUpdated {
+            notarization_id,
+            state_version_count,
+            updated_state,
+        } = bcs::from_bytes(bytes)?;
+
+        Ok(Self {
+            notarization_id,
+            state_version_count,
+            updated_state,
+        })
+    }
+}
+
+/// The BCS layout of `NotarizationUpdated<D>`, whose counter is not
+/// deserializable from the string form of the parsed JSON.
+#[derive(Deserialize)]
+struct RawNotarizationUpdated<D> {
+    notarization_id: ObjectId,
+    state_version_count: u64,
+    updated_state: State<D>,
+}
+
+impl<D: Into<Data>> NotarizationUpdated<D> {
+    /// Converts the typed payload into [`Data`].
+    fn into_data(self) -> NotarizationUpdated {
+        NotarizationUpdated {
+            notarization_id: self.notarization_id,
+            state_version_count: self.state_version_count,
+            updated_state: State {
+                data: self.updated_state.data.into(),
+                metadata: self.updated_state.metadata,
+            },
+        }
+    }
+}
+
+/// A `NotarizationUpdated` event of a notarization holding a typed payload,
+/// i.e. neither `vector<u8>` nor `String`, see
+/// [`State::from_generic`](super::State::from_generic).
+///
+/// The `data` of `updated_state` is kept as rendered in the parsed JSON of the
+/// event. Use
+/// [`NotarizationClientReadOnly::state_history_as`](crate::client::NotarizationClientReadOnly::state_history_as)
+/// to read the typed states of a notarization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenericNotarizationUpdated {
+    /// ID of the updated notarization.
+    pub notarization_id: ObjectId,
+    /// Value of `state_version_count` after the update.
+    pub state_version_count: u64,
+    /// The Move type `D` of the payload.
+    pub data_type: TypeTag,
+    /// The new `state` after the update.
+    pub updated_state: State<serde_json::Value>,
+}
+
+/// An event that is emitted when the updatable metadata of a notarization is replaced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotarizationMetadataUpdated {
+    /// ID of the updated notarization.
+    pub notarization_id: ObjectId,
+    /// The new `updatable_metadata`.
+    pub updated_metadata: Option<String>,
+}
+
+/// An event that is emitted when a version of the state of a notarization is
+/// endorsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotarizationEndorsed {
+    /// ID of the created endorsement.
+    pub endorsement_id: ObjectId,
+    /// ID of the endorsed notarization.
     pub notarization_id: ObjectId,
+    /// Address that endorsed the notarization.
+    pub endorser: Address,
+    /// Value of `state_version_count` of the endorsed `state`.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub state_version_count: u64,
+    /// SHA2-256 hash of the BCS of the endorsed `state`.
+    pub state_hash: Vec<u8>,
+    /// Timestamp of the endorsement, in milliseconds since the Unix epoch.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub endorsed_at: u64,
+}
+
+/// An event that is emitted when a notarization is destroyed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotarizationDestroyed {
+    /// ID of the destroyed notarization.
+    pub notarization_id: ObjectId,
+}
+
+/// Any event emitted by the Notarization Package.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NotarizationEvent {
+    /// See [`DynamicNotarizationCreated`].
+    DynamicNotarizationCreated(DynamicNotarizationCreated),
+    /// See [`LockedNotarizationCreated`].
+    LockedNotarizationCreated(LockedNotarizationCreated),
+    /// See [`SharedNotarizationCreated`].
+    SharedNotarizationCreated(SharedNotarizationCreated),
+    /// See [`DynamicNotarizationTransferred`].
+    DynamicNotarizationTransferred(DynamicNotarizationTransferred),
+    /// See [`NotarizationSealed`].
+    NotarizationSealed(NotarizationSealed),
+    /// See [`NotarizationUpdated`].
+    NotarizationUpdated(NotarizationUpdated),
+    /// See [`GenericNotarizationUpdated`].
+    GenericNotarizationUpdated(GenericNotarizationUpdated),
+    /// See [`NotarizationMetadataUpdated`].
+    NotarizationMetadataUpdated(NotarizationMetadataUpdated),
+    /// See [`NotarizationDestroyed`].
+    NotarizationDestroyed(NotarizationDestroyed),
+    /// See [`CapabilityIssued`].
+    CapabilityIssued(CapabilityIssued),
+    /// See [`CapabilityRevoked`].
+    CapabilityRevoked(CapabilityRevoked),
+    /// See [`NotarizationEndorsed`].
+    NotarizationEndorsed(NotarizationEndorsed),
+}
+
+impl NotarizationEvent {
+    /// Returns the ID of the notarization the event was emitted for.
+    pub fn notarization_id(&self) -> ObjectId {
+        match self {
+            NotarizationEvent::DynamicNotarizationCreated(event) => event.notarization_id,
+            NotarizationEvent::LockedNotarizationCreated(event) => event.notarization_id,
+            NotarizationEvent::SharedNotarizationCreated(event) => event.notarization_id,
+            NotarizationEvent::DynamicNotarizationTransferred(event) => event.notarization_id,
+            NotarizationEvent::NotarizationSealed(event) => event.notarization_id,
+            NotarizationEvent::NotarizationUpdated(event) => event.notarization_id,
+            NotarizationEvent::GenericNotarizationUpdated(event) => event.notarization_id,
+            NotarizationEvent::NotarizationMetadataUpdated(event) => event.notarization_id,
+            NotarizationEvent::NotarizationDestroyed(event) => event.notarization_id,
+            NotarizationEvent::CapabilityIssued(event) => event.notarization_id,
+            NotarizationEvent::CapabilityRevoked(event) => event.notarization_id,
+            NotarizationEvent::NotarizationEndorsed(event) => event.notarization_id,
+        }
+    }
+
+    /// Decodes a notarization event from its Move type and its parsed JSON content.
+    ///
+    /// `event_type` is the full Move type of the event, e.g.
+    /// `0x..::notarization::NotarizationUpdated<vector<u8>>`. The package
+    /// address is not checked.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `event_type` is not a notarization event or if
+    /// `parsed_json` does not match `event_type`.
+    pub fn decode(event_type: &str, parsed_json: &serde_json::Value) -> Result<Self, Error> {
+        let event = match event_name(event_type) {
+            (Some("dynamic_notarization"), Some("DynamicNotarizationCreated")) => {
+                NotarizationEvent::DynamicNotarizationCreated(parse_event(parsed_json)?)
+            }
+            (Some("locked_notarization"), Some("LockedNotarizationCreated")) => {
+                NotarizationEvent::LockedNotarizationCreated(parse_event(parsed_json)?)
+            }
+            (Some("shared_notarization"), Some("SharedNotarizationCreated")) => {
+                NotarizationEvent::SharedNotarizationCreated(parse_event(parsed_json)?)
+            }
+            (Some("dynamic_notarization"), Some("DynamicNotarizationTransferred")) => {
+                NotarizationEvent::DynamicNotarizationTransferred(parse_event(parsed_json)?)
+            }
+            (Some("dynamic_notarization"), Some("NotarizationSealed"))
+            | (Some("shared_notarization"), Some("NotarizationSealed")) => {
+                NotarizationEvent::NotarizationSealed(parse_event(parsed_json)?)
+            }
+            (Some("notarization"), Some("NotarizationUpdated")) => {
+                let data_type = move_utils::type_param_tag(event_type)?;
+                match DataType::from_type_tag(&data_type) {
+                    Ok(DataType::Bytes) => NotarizationEvent::NotarizationUpdated(
+                        parse_event::<NotarizationUpdated<Vec<u8>>>(parsed_json)?.into_data(),
+                    ),
+                    Ok(DataType::Text) => NotarizationEvent::NotarizationUpdated(
+                        parse_event::<NotarizationUpdated<String>>(parsed_json)?.into_data(),
+                    ),
+                    Err(_) => {
+                        let updated = parse_event::<NotarizationUpdated<serde_json::Value>>(parsed_json)?;
+                        NotarizationEvent::GenericNotarizationUpdated(GenericNotarizationUpdated {
+                            notarization_id: updated.notarization_id,
+                            state_version_count: updated.state_version_count,
+                            data_type,
+                            updated_state: updated.updated_state,
+                        })
+                    }
+                }
+            }
+            (Some("notarization"), Some("NotarizationMetadataUpdated")) => {
+                NotarizationEvent::NotarizationMetadataUpdated(parse_event(parsed_json)?)
+            }
+            (Some("notarization"), Some("NotarizationDestroyed")) => {
+                NotarizationEvent::NotarizationDestroyed(parse_event(parsed_json)?)
+            }
+            (Some("shared_notarization"), Some("CapabilityIssued")) => {
+                NotarizationEvent::CapabilityIssued(parse_event(parsed_json)?)
+            }
+            (Some("shared_notarization"), Some("CapabilityRevoked")) => {
+                NotarizationEvent::CapabilityRevoked(parse_event(parsed_json)?)
+            }
+            (Some("endorsement"), Some("NotarizationEndorsed")) => {
+                NotarizationEvent::NotarizationEndorsed(parse_event(parsed_json)?)
+            }
+            _ => {
+                return Err(Error::UnexpectedApiResponse(format!(
+                    "unknown notarization event type: {event_type}"
+                )));
+            }
+        };
+
+        Ok(event)
+    }
+}
+
+impl TryFrom<&IotaEvent> for NotarizationEvent {
+    type Error = Error;
+
+    fn try_from(event: &IotaEvent) -> Result<Self, Self::Error> {
+        NotarizationEvent::decode(&event.type_.to_string(), &event.parsed_json)
+    }
+}
+
+/// Returns whether `event` is a `NotarizationUpdated` event, of any payload
+/// type.
+pub(crate) fn is_notarization_updated(event: &IotaEvent) -> bool {
+    event_name(&event.type_.to_string()) == (Some("notarization"), Some("NotarizationUpdated"))
+}
+
+/// Splits the module and the name off the Move type of an event.
+fn event_name(event_type: &str) -> (Option<&str>, Option<&str>) {
+    let path = event_type.split_once('<').map_or(event_type, |(path, _)| path);
+    let mut segments = path.rsplit("::");
+    let (name, module) = (segments.next(), segments.next());
+
+    (module, name)
+}
+
+/// Reads the `notarization_id` field every notarization event carries, without
+/// decoding the event.
+pub(crate) fn event_notarization_id(event: &IotaEvent) -> Option<ObjectId> {
+    event.parsed_json.get("notarization_id")?.as_str()?.parse().ok()
+}
+
+/// Parses the JSON content of an event into `D`.
+fn parse_event<D: DeserializeOwned>(parsed_json: &serde_json::Value) -> Result<D, Error> {
+    serde_json::from_value::<Event<D>>(parsed_json.clone())
+        .map(|event| event.data)
+        .map_err(|e| Error::UnexpectedApiResponse(format!("failed to parse event: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+    use serde_json::json;
+
+    use super::*;
+
+    const PACKAGE: &str = "0x0000000000000000000000000000000000000000000000000000000000000abc";
+
+    #[test]
+    fn notarization_updated_deserializes_from_parsed_json() {
+        let bytes_event: Event<NotarizationUpdated<Vec<u8>>> = serde_json::from_value(json!({
+            "notarization_id": dbg_object_id(1).to_string(),
+            "state_version_count": "2",
+            "updated_state": { "data": [1, 2, 3], "metadata": "v2" },
+        }))
+        .unwrap();
+
+        assert_eq!(bytes_event.data.notarization_id, dbg_object_id(1));
+        assert_eq!(bytes_event.data.state_version_count, 2);
+        assert_eq!(bytes_event.data.updated_state.data, vec![1, 2, 3]);
+        assert_eq!(bytes_event.data.updated_state.metadata, Some("v2".to_string()));
+
+        let text_event: Event<NotarizationUpdated<String>> = serde_json::from_value(json!({
+            "notarization_id": dbg_object_id(1).to_string(),
+            "state_version_count": "3",
+            "updated_state": { "data": "Müller", "metadata": null },
+        }))
+        .unwrap();
+
+        assert_eq!(text_event.data.state_version_count, 3);
+        assert_eq!(text_event.data.updated_state.data, "Müller");
+        assert_eq!(text_event.data.updated_state.metadata, None);
+    }
+
+    #[test]
+    fn decode_notarization_updated() {
+        let bytes_event = NotarizationEvent::decode(
+            &format!("{PACKAGE}::notarization::NotarizationUpdated<vector<u8>>"),
+            &json!({
+                "notarization_id": dbg_object_id(1).to_string(),
+                "state_version_count": "2",
+                "updated_state": { "data": [1, 2, 3], "metadata": "v2" },
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            bytes_event,
+            NotarizationEvent::NotarizationUpdated(NotarizationUpdated {
+                notarization_id: dbg_object_id(1),
+                state_version_count: 2,
+                updated_state: State::from_bytes(vec![1, 2, 3], Some("v2".to_string())),
+            })
+        );
+
+        let text_event = NotarizationEvent::decode(
+            &format!("{PACKAGE}::notarization::NotarizationUpdated<0x1::string::String>"),
+            &json!({
+                "notarization_id": dbg_object_id(1).to_string(),
+                "state_version_count": "3",
+                "updated_state": { "data": "Müller", "metadata": null },
+            }),
+        )
+        .unwrap();
+
+        let NotarizationEvent::NotarizationUpdated(updated) = text_event else {
+            panic!("expected NotarizationUpdated");
+        };
+        assert_eq!(updated.state_version_count, 3);
+        assert_eq!(updated.updated_state, State::from_string("Müller".to_string(), None));
+    }
+
+    #[test]
+    fn decode_generic_notarization_updated() {
+        let event = NotarizationEvent::decode(
+            &format!("{PACKAGE}::notarization::NotarizationUpdated<vector<u64>>"),
+            &json!({
+                "notarization_id": dbg_object_id(1).to_string(),
+                "state_version_count": "2",
+                "updated_state": { "data": ["21", "22"], "metadata": "readings" },
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            event,
+            NotarizationEvent::GenericNotarizationUpdated(GenericNotarizationUpdated {
+                notarization_id: dbg_object_id(1),
+                state_version_count: 2,
+                data_type: TypeTag::Vector(Box::new(TypeTag::U64)),
+                updated_state: State {
+                    data: json!(["21", "22"]),
+                    metadata: Some("readings".to_string()),
+                },
+            })
+        );
+        assert_eq!(event.notarization_id(), dbg_object_id(1));
+    }
+
+    #[test]
+    fn notarization_updated_decodes_from_bcs() {
+        let bcs = bcs::to_bytes(&(dbg_object_id(1), 2u64, vec![21u64, 22], Some("readings"))).unwrap();
+
+        let updated = NotarizationUpdated::<Vec<u64>>::from_bcs(&bcs).unwrap();
+        assert_eq!(updated.notarization_id, dbg_object_id(1));
+        assert_eq!(updated.state_version_count, 2);
+        assert_eq!(
+            updated.updated_state,
+            State::from_generic(vec![21u64, 22], Some("readings".to_string()))
+        );
+
+        assert!(NotarizationUpdated::<Vec<u64>>::from_bcs(&bcs[..40]).is_err());
+    }
+
+    #[test]
+    fn decode_non_generic_events() {
+        let id = json!({ "notarization_id": dbg_object_id(7).to_string() });
+
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::dynamic_notarization::DynamicNotarizationCreated"),
+                &id
+            )
+            .unwrap(),
+            NotarizationEvent::DynamicNotarizationCreated(DynamicNotarizationCreated {
+                notarization_id: dbg_object_id(7)
+            })
+        );
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::locked_notarization::LockedNotarizationCreated"),
+                &id
+            )
+            .unwrap()
+            .notarization_id(),
+            dbg_object_id(7)
+        );
+        assert_eq!(
+            NotarizationEvent::decode(&format!("{PACKAGE}::notarization::NotarizationDestroyed"), &id).unwrap(),
+            NotarizationEvent::NotarizationDestroyed(NotarizationDestroyed {
+                notarization_id: dbg_object_id(7)
+            })
+        );
+
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::notarization::NotarizationMetadataUpdated"),
+                &json!({ "notarization_id": dbg_object_id(7).to_string(), "updated_metadata": "v2" }),
+            )
+            .unwrap(),
+            NotarizationEvent::NotarizationMetadataUpdated(NotarizationMetadataUpdated {
+                notarization_id: dbg_object_id(7),
+                updated_metadata: Some("v2".to_string()),
+            })
+        );
+
+        let recipient = Address::ZERO;
+        let transferred = NotarizationEvent::decode(
+            &format!("{PACKAGE}::dynamic_notarization::DynamicNotarizationTransferred"),
+            &json!({ "notarization_id": dbg_object_id(7).to_string(), "recipient": recipient.to_string() }),
+        )
+        .unwrap();
+        assert_eq!(
+            transferred,
+            NotarizationEvent::DynamicNotarizationTransferred(DynamicNotarizationTransferred {
+                notarization_id: dbg_object_id(7),
+                recipient,
+            })
+        );
+
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::dynamic_notarization::NotarizationSealed"),
+                &json!({ "notarization_id": dbg_object_id(7).to_string(), "state_version_count": "4" }),
+            )
+            .unwrap(),
+            NotarizationEvent::NotarizationSealed(NotarizationSealed {
+                notarization_id: dbg_object_id(7),
+                state_version_count: 4,
+            })
+        );
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::shared_notarization::NotarizationSealed"),
+                &json!({ "notarization_id": dbg_object_id(7).to_string(), "state_version_count": "2" }),
+            )
+            .unwrap(),
+            NotarizationEvent::NotarizationSealed(NotarizationSealed {
+                notarization_id: dbg_object_id(7),
+                state_version_count: 2,
+            })
+        );
+
+        let capability = json!({
+            "notarization_id": dbg_object_id(7).to_string(),
+            "capability_id": dbg_object_id(8).to_string(),
+        });
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::shared_notarization::SharedNotarizationCreated"),
+                &capability
+            )
+            .unwrap(),
+            NotarizationEvent::SharedNotarizationCreated(SharedNotarizationCreated {
+                notarization_id: dbg_object_id(7),
+                capability_id: dbg_object_id(8),
+            })
+        );
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::shared_notarization::CapabilityRevoked"),
+                &capability
+            )
+            .unwrap()
+            .notarization_id(),
+            dbg_object_id(7)
+        );
+
+        let endorser = Address::ZERO;
+        assert_eq!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::endorsement::NotarizationEndorsed"),
+                &json!({
+                    "endorsement_id": dbg_object_id(9).to_string(),
+                    "notarization_id": dbg_object_id(7).to_string(),
+                    "endorser": endorser.to_string(),
+                    "state_version_count": "2",
+                    "state_hash": [1, 2, 3],
+                    "endorsed_at": "1700000000000",
+                }),
+            )
+            .unwrap(),
+            NotarizationEvent::NotarizationEndorsed(NotarizationEndorsed {
+                endorsement_id: dbg_object_id(9),
+                notarization_id: dbg_object_id(7),
+                endorser,
+                state_version_count: 2,
+                state_hash: vec![1, 2, 3],
+                endorsed_at: 1_700_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_rejects_unknown_or_malformed_events() {
+        let id = json!({ "notarization_id": dbg_object_id(7).to_string() });
+
+        assert!(NotarizationEvent::decode(&format!("{PACKAGE}::notarization::Unknown"), &id).is_err());
+        assert!(NotarizationEvent::decode(&format!("{PACKAGE}::other::NotarizationDestroyed"), &id).is_err());
+        assert!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::notarization::NotarizationUpdated<u64>"),
+                &json!({ "notarization_id": dbg_object_id(7).to_string(), "updated_state": null }),
+            )
+            .is_err()
+        );
+        assert!(
+            NotarizationEvent::decode(
+                &format!("{PACKAGE}::notarization::NotarizationDestroyed"),
+                &json!({ "id": 1 })
+            )
+            .is_err()
+        );
+    }
 }
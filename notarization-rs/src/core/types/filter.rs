@@ -0,0 +1,150 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::{Bound, RangeBounds};
+
+use super::{NotarizationMethod, OnChainNotarization};
+
+/// Criteria for selecting notarizations, e.g. in
+/// [`NotarizationClientReadOnly::list_owned_notarizations`](crate::client::NotarizationClientReadOnly::list_owned_notarizations).
+///
+/// All criteria are optional; a notarization matches if it satisfies every
+/// criterion that is set. The default filter matches every notarization.
+///
+/// ## Example
+///
+/// ```rust
+/// use notarization::core::types::{NotarizationFilter, NotarizationMethod};
+///
+/// let filter = NotarizationFilter::new()
+///     .with_method(NotarizationMethod::Locked)
+///     .with_description("Invoice")
+///     .with_created_at(1_735_689_600_000..);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotarizationFilter {
+    /// Only match notarizations with this Notarization Method.
+    pub method: Option<NotarizationMethod>,
+    /// Only match notarizations whose immutable description contains this
+    /// substring (case-sensitive).
+    pub description: Option<String>,
+    /// Only match notarizations created within this range, in milliseconds
+    /// since the Unix epoch.
+    pub created_at: (Bound<u64>, Bound<u64>),
+}
+
+impl Default for NotarizationFilter {
+    fn default() -> Self {
+        Self {
+            method: None,
+            description: None,
+            created_at: (Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+}
+
+impl NotarizationFilter {
+    /// Creates a filter matching every notarization.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match notarizations with the Notarization Method `method`.
+    pub fn with_method(mut self, method: NotarizationMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Only match notarizations whose immutable description contains
+    /// `description`.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Only match notarizations whose `created_at` timestamp, in milliseconds
+    /// since the Unix epoch, lies within `range`.
+    pub fn with_created_at(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.created_at = (range.start_bound().cloned(), range.end_bound().cloned());
+        self
+    }
+
+    /// Returns `true` if `notarization` satisfies every criterion of this
+    /// filter.
+    pub fn matches<D>(&self, notarization: &OnChainNotarization<D>) -> bool {
+        let metadata = &notarization.immutable_metadata;
+
+        self.method.as_ref().is_none_or(|method| *method == notarization.method)
+            && self.description.as_deref().is_none_or(|needle| {
+                metadata
+                    .description
+                    .as_deref()
+                    .is_some_and(|description| description.contains(needle))
+            })
+            && self.created_at.contains(&metadata.created_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+    use iota_interaction::types::id::UID;
+    use iota_sdk_types::Address;
+
+    use super::*;
+    use crate::core::types::{ImmutableMetadata, State};
+
+    fn make_notarization(
+        method: NotarizationMethod,
+        description: Option<&str>,
+        created_at: u64,
+    ) -> OnChainNotarization {
+        OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state: State::from_string("data".to_string(), None),
+            immutable_metadata: ImmutableMetadata {
+                created_at,
+                description: description.map(str::to_string),
+                locking: None,
+            },
+            updatable_metadata: None,
+            last_state_change_at: created_at,
+            state_version_count: 0,
+            method,
+            owner: Address::ZERO,
+        }
+    }
+
+    #[test]
+    fn default_matches_everything() {
+        let filter = NotarizationFilter::default();
+
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Dynamic, None, 0)));
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Locked, Some("x"), u64::MAX)));
+    }
+
+    #[test]
+    fn filters_by_method_and_description() {
+        let filter = NotarizationFilter::new()
+            .with_method(NotarizationMethod::Locked)
+            .with_description("Invoice");
+
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Locked, Some("Invoice #42"), 0)));
+        assert!(!filter.matches(&make_notarization(NotarizationMethod::Dynamic, Some("Invoice #42"), 0)));
+        assert!(!filter.matches(&make_notarization(NotarizationMethod::Locked, Some("invoice #42"), 0)));
+        assert!(!filter.matches(&make_notarization(NotarizationMethod::Locked, None, 0)));
+    }
+
+    #[test]
+    fn filters_by_created_at_range() {
+        let filter = NotarizationFilter::new().with_created_at(1_000..2_000);
+
+        assert!(!filter.matches(&make_notarization(NotarizationMethod::Locked, None, 999)));
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Locked, None, 1_000)));
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Locked, None, 1_999)));
+        assert!(!filter.matches(&make_notarization(NotarizationMethod::Locked, None, 2_000)));
+
+        let filter = NotarizationFilter::new().with_created_at(..=2_000);
+        assert!(filter.matches(&make_notarization(NotarizationMethod::Locked, None, 2_000)));
+    }
+}
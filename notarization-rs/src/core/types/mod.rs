@@ -3,15 +3,23 @@
 
 //! Core data types for notarization.
 
+pub mod digest;
+pub mod endorsement;
 pub mod event;
+pub mod filter;
 pub mod metadata;
 pub mod notarization;
+pub mod role;
 pub mod state;
 pub mod timelock;
 
+pub use digest::*;
+pub use endorsement::*;
 pub use event::*;
+pub use filter::*;
 pub use metadata::*;
 pub use notarization::*;
+pub use role::*;
 use serde::{Deserialize, Serialize};
 pub use state::*;
 pub use timelock::*;
@@ -22,6 +30,13 @@ pub use timelock::*;
 /// operations are permitted on the notarization afterwards. The set of
 /// Notarization Methods is closed in the current version of the package but
 /// may be extended in future versions.
+///
+/// BCS-decoding an on-chain `NotarizationMethod` never yields
+/// [`NotarizationMethod::Shared`]: the on-chain enum only knows `Dynamic` and
+/// `Locked`, and Shared-Notarizations are stored as `Locked`. The client
+/// rewrites the method to `Shared` when it resolves the `LockMetadata` of a
+/// fetched notarization, so only values returned by the client APIs carry it.
+/// Serializing `Shared` produces a variant index the package cannot decode.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum NotarizationMethod {
     /// Method whose `state` and `updatable_metadata` can be updated after
@@ -30,4 +45,12 @@ pub enum NotarizationMethod {
     /// Method whose `state` and `updatable_metadata` are immutable after
     /// creation and whose destruction is gated by a `delete_lock`.
     Locked,
+    /// Method of shared objects whose `state` and `updatable_metadata` can be
+    /// updated by every holder of a valid capability issued for them, until
+    /// an admin seals them.
+    ///
+    /// Not a variant of the on-chain `NotarizationMethod`: a Shared-Notarization
+    /// is a `Locked` object whose actual `LockMetadata` is stored in a dynamic
+    /// field, and the client reports it as `Shared` after resolving that field.
+    Shared,
 }
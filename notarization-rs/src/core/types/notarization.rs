@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_interaction::types::id::UID;
-use iota_sdk_types::Address;
+use iota_sdk_types::{Address, TypeTag};
 use serde::{Deserialize, Serialize};
 
 use super::NotarizationMethod;
 use super::metadata::ImmutableMetadata;
-use super::state::State;
+use super::state::{Data, DataType, State};
+use super::timelock::{LockMetadata, LockStatus};
+use crate::error::Error;
 
 /// A notarization record stored on the blockchain.
 ///
@@ -16,8 +18,13 @@ use super::state::State;
 /// be updated, transferred, or destroyed. The selected
 /// [`NotarizationMethod`] determines which mutations are allowed after
 /// creation.
+///
+/// ## Type Parameter
+///
+/// - `D`: The type of the notarized payload, defaults to [`Data`]. See
+///   [`StateData`](super::StateData) for typed payloads.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct OnChainNotarization {
+pub struct OnChainNotarization<D = Data> {
     /// The unique identifier of the notarization.
     pub id: UID,
     /// Notarized data and its associated state metadata.
@@ -29,7 +36,7 @@ pub struct OnChainNotarization {
     /// * `Dynamic`: updatable after creation via
     ///   [`NotarizationClient::update_state`](crate::client::NotarizationClient::update_state).
     /// * `Locked`: immutable after creation.
-    pub state: State,
+    pub state: State<D>,
     /// Provenance fixed at creation time.
     ///
     /// Carries the creation timestamp, the optional immutable description,
@@ -58,6 +65,9 @@ pub struct OnChainNotarization {
     /// this notarization.
     pub method: NotarizationMethod,
     /// The owner of the notarization.
+    ///
+    /// [`Address::ZERO`] for Shared-Notarizations, which are shared objects
+    /// without an owner.
     #[serde(skip, default = "iota_address_zero")]
     pub owner: Address,
 }
@@ -66,6 +76,105 @@ fn iota_address_zero() -> Address {
     Address::ZERO
 }
 
+impl<D> OnChainNotarization<D> {
+    /// Returns `true` if `state` and `updatable_metadata` are locked at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Evaluates the locks locally, without querying the ledger; mirrors
+    /// `notarization::is_update_locked`. Returns `false` if the notarization
+    /// has no `LockMetadata`, and whether `update_lock` is active otherwise.
+    pub fn is_update_locked_at(&self, now_secs: u64) -> bool {
+        self.lock_metadata()
+            .is_some_and(|locks| locks.is_update_locked_at(now_secs))
+    }
+
+    /// Returns `true` if this notarization is locked against destruction at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Evaluates the locks locally, without querying the ledger; mirrors
+    /// `notarization::is_delete_locked`. Returns `false` if the notarization
+    /// has no `LockMetadata`, and whether `delete_lock` is active otherwise.
+    pub fn is_delete_locked_at(&self, now_secs: u64) -> bool {
+        self.lock_metadata()
+            .is_some_and(|locks| locks.is_delete_locked_at(now_secs))
+    }
+
+    /// Returns `true` if this notarization is locked against transfer at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Evaluates the locks locally, without querying the ledger; mirrors
+    /// `notarization::is_transfer_locked`. Returns `false` if the
+    /// notarization has no `LockMetadata`.
+    pub fn is_transfer_locked_at(&self, now_secs: u64) -> bool {
+        self.lock_metadata()
+            .is_some_and(|locks| locks.is_transfer_locked_at(now_secs))
+    }
+
+    /// Returns `true` if this notarization can be destroyed at `now_secs`, in
+    /// seconds since the Unix epoch.
+    ///
+    /// Evaluates the locks locally, without querying the ledger; mirrors
+    /// `notarization::is_destroy_allowed` and returns `false` while any of the
+    /// three locks is an active `UnlockAt` lock, for both Notarization
+    /// Methods.
+    pub fn is_destroy_allowed_at(&self, now_secs: u64) -> bool {
+        self.lock_metadata().is_none_or(|locks| {
+            !(locks.update_lock.is_timelocked_unlock_at(now_secs)
+                || locks.delete_lock.is_timelocked_unlock_at(now_secs)
+                || locks.transfer_lock.is_timelocked_unlock_at(now_secs))
+        })
+    }
+
+    /// Evaluates all locks of this notarization at `now_secs`, in seconds
+    /// since the Unix epoch.
+    pub fn lock_status_at(&self, now_secs: u64) -> LockStatus {
+        LockStatus {
+            update_locked: self.is_update_locked_at(now_secs),
+            destroy_allowed: self.is_destroy_allowed_at(now_secs),
+            transfer_locked: self.is_transfer_locked_at(now_secs),
+        }
+    }
+
+    fn lock_metadata(&self) -> Option<&LockMetadata> {
+        self.immutable_metadata.locking.as_ref()
+    }
+}
+
+impl OnChainNotarization {
+    /// Decodes the BCS content of an on-chain `Notarization<D>` object.
+    ///
+    /// `data_type` is the type parameter `D` of the object and decides whether
+    /// `state.data` is decoded as [`Data::Bytes`] or [`Data::Text`]. The
+    /// returned `owner` is [`Address::ZERO`].
+    pub(crate) fn from_bcs(data_type: &TypeTag, bytes: &[u8]) -> Result<Self, Error> {
+        let notarization = match DataType::from_type_tag(data_type)? {
+            DataType::Bytes => bcs::from_bytes::<OnChainNotarization<Vec<u8>>>(bytes)?.into_data(),
+            DataType::Text => bcs::from_bytes::<OnChainNotarization<String>>(bytes)?.into_data(),
+        };
+
+        Ok(notarization)
+    }
+}
+
+impl<D: Into<Data>> OnChainNotarization<D> {
+    /// Converts the typed payload into [`Data`].
+    fn into_data(self) -> OnChainNotarization {
+        OnChainNotarization {
+            id: self.id,
+            state: State {
+                data: self.state.data.into(),
+                metadata: self.state.metadata,
+            },
+            immutable_metadata: self.immutable_metadata,
+            updatable_metadata: self.updatable_metadata,
+            last_state_change_at: self.last_state_change_at,
+            state_version_count: self.state_version_count,
+            method: self.method,
+            owner: self.owner,
+        }
+    }
+}
+
 #[cfg(feature = "irl")]
 pub mod irl_integration {
     use iota_caip::iota::{IotaAddress, IotaNetwork, IotaResourceLocator};
@@ -135,3 +244,237 @@ pub mod irl_integration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+
+    use super::*;
+    use crate::core::types::{StateData, TimeLock, UpdateLock};
+
+    fn bytes_tag() -> TypeTag {
+        Data::Bytes(vec![]).tag()
+    }
+
+    fn text_tag() -> TypeTag {
+        Data::Text(String::new()).tag()
+    }
+
+    fn encode<D: Serialize>(data: D) -> Vec<u8> {
+        bcs::to_bytes(&OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state: State {
+                data,
+                metadata: Some("meta".to_string()),
+            },
+            immutable_metadata: ImmutableMetadata {
+                created_at: 1_000,
+                description: None,
+                locking: None,
+            },
+            updatable_metadata: None,
+            last_state_change_at: 1_000,
+            state_version_count: 0,
+            method: NotarizationMethod::Dynamic,
+            owner: Address::ZERO,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn from_bcs_uses_type_parameter_to_decode_data() {
+        let text_cases = ["hello world", "Müller", "日本語", "emoji 🚀", "", "line\nbreak\t"];
+        for text in text_cases {
+            let decoded = OnChainNotarization::from_bcs(&text_tag(), &encode(text.to_string())).unwrap();
+            assert_eq!(decoded.state.data, Data::Text(text.to_string()), "text case {text:?}");
+            assert_eq!(decoded.state.metadata.as_deref(), Some("meta"));
+        }
+
+        let bytes_cases: [&[u8]; 5] = [
+            b"hello world",
+            b"{\"json\": true}",
+            b"",
+            &[0xff, 0x00, 0x7f],
+            "Müller".as_bytes(),
+        ];
+        for bytes in bytes_cases {
+            let decoded = OnChainNotarization::from_bcs(&bytes_tag(), &encode(bytes.to_vec())).unwrap();
+            assert_eq!(decoded.state.data, Data::Bytes(bytes.to_vec()), "bytes case {bytes:?}");
+        }
+    }
+
+    #[test]
+    fn from_bcs_rejects_unsupported_type_parameter() {
+        let result = OnChainNotarization::from_bcs(&TypeTag::U64, &encode(42u64));
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn typed_payloads_reject_other_type_parameters() {
+        let package_id = dbg_object_id(2);
+        let bytes = encode(b"payload".to_vec());
+
+        let decoded = Vec::<u8>::decode_notarization(&bytes_tag(), package_id, &bytes).unwrap();
+        assert_eq!(decoded.state.data, b"payload".to_vec());
+
+        let result = String::decode_notarization(&bytes_tag(), package_id, &bytes);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))), "got {result:?}");
+    }
+
+    fn with_locks(method: NotarizationMethod, locking: Option<LockMetadata>) -> OnChainNotarization {
+        OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state: State::from_string("data".to_string(), None),
+            immutable_metadata: ImmutableMetadata {
+                created_at: 1_000,
+                description: None,
+                locking,
+            },
+            updatable_metadata: None,
+            last_state_change_at: 1_000,
+            state_version_count: 0,
+            method,
+            owner: Address::ZERO,
+        }
+    }
+
+    #[test]
+    fn locked_notarization_lock_evaluation() {
+        let notarization = with_locks(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed.into(),
+                delete_lock: TimeLock::UnlockAt(2000),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+
+        assert_eq!(
+            notarization.lock_status_at(1000),
+            LockStatus {
+                update_locked: true,
+                destroy_allowed: false,
+                transfer_locked: true,
+            }
+        );
+        assert!(notarization.is_delete_locked_at(1999));
+        assert_eq!(
+            notarization.lock_status_at(2000),
+            LockStatus {
+                update_locked: true,
+                destroy_allowed: true,
+                transfer_locked: true,
+            }
+        );
+        assert!(!notarization.is_delete_locked_at(2000));
+    }
+
+    #[test]
+    fn dynamic_notarization_lock_evaluation() {
+        let unlocked = with_locks(NotarizationMethod::Dynamic, None);
+        assert_eq!(
+            unlocked.lock_status_at(1000),
+            LockStatus {
+                update_locked: false,
+                destroy_allowed: true,
+                transfer_locked: false,
+            }
+        );
+
+        let transfer_locked = with_locks(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None.into(),
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::UnlockAt(2000),
+            }),
+        );
+        assert!(!transfer_locked.is_update_locked_at(1000));
+        assert!(!transfer_locked.is_delete_locked_at(1000));
+        assert!(transfer_locked.is_transfer_locked_at(1000));
+        assert!(!transfer_locked.is_destroy_allowed_at(1000));
+        assert!(!transfer_locked.is_transfer_locked_at(2000));
+        assert!(transfer_locked.is_destroy_allowed_at(2000));
+
+        let until_destroyed = with_locks(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None.into(),
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        assert!(until_destroyed.is_transfer_locked_at(u64::from(u32::MAX)));
+        assert!(until_destroyed.is_destroy_allowed_at(1000));
+
+        let delete_locked = with_locks(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None.into(),
+                delete_lock: TimeLock::UnlockAt(2000),
+                transfer_lock: TimeLock::None,
+            }),
+        );
+        assert_eq!(
+            delete_locked.lock_status_at(1000),
+            LockStatus {
+                update_locked: false,
+                destroy_allowed: false,
+                transfer_locked: false,
+            }
+        );
+        assert!(delete_locked.is_delete_locked_at(1999));
+        assert!(!delete_locked.is_delete_locked_at(2000));
+        assert!(delete_locked.is_destroy_allowed_at(2000));
+
+        let embargoed = with_locks(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::UnlockAt(2000).into(),
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::None,
+            }),
+        );
+        assert_eq!(
+            embargoed.lock_status_at(1000),
+            LockStatus {
+                update_locked: true,
+                destroy_allowed: false,
+                transfer_locked: false,
+            }
+        );
+        assert!(!embargoed.is_update_locked_at(2000));
+        assert!(embargoed.is_destroy_allowed_at(2000));
+
+        let frozen = with_locks(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: UpdateLock::LockAt(2000),
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::None,
+            }),
+        );
+        assert!(!frozen.is_update_locked_at(1999));
+        assert!(frozen.is_update_locked_at(2000));
+        assert!(frozen.is_destroy_allowed_at(1000));
+        assert!(frozen.is_destroy_allowed_at(2000));
+    }
+
+    #[test]
+    fn data_serde_roundtrip_preserves_variant() {
+        let cases = [
+            Data::Text("Müller".to_string()),
+            Data::Text("0123".to_string()),
+            Data::Bytes(b"plain ascii".to_vec()),
+            Data::Bytes(vec![0xc3, 0xbc]),
+        ];
+
+        for data in cases {
+            let json = serde_json::to_string(&data).unwrap();
+            assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), data);
+
+            let bcs = bcs::to_bytes(&data).unwrap();
+            assert_eq!(bcs::from_bytes::<Data>(&bcs).unwrap(), data);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Role
+//!
+//! This module defines the roles granted by the capabilities of
+//! Shared-Notarizations.
+
+use std::fmt;
+
+use iota_interaction::ident_str;
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
+use iota_sdk_types::{Argument, ObjectId};
+use serde::{Deserialize, Serialize};
+
+/// The role granted by a capability of a Shared-Notarization.
+///
+/// Capabilities are issued via
+/// [`NotarizationClient::issue_capability`](crate::client::NotarizationClient::issue_capability).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// May update `state` and `updatable_metadata`, issue and revoke
+    /// capabilities, and destroy the notarization.
+    Admin,
+    /// May update `state` and `updatable_metadata`.
+    Writer,
+}
+
+impl Role {
+    /// Creates a new `Argument` from the `Role`.
+    pub(in crate::core) fn to_ptb(self, ptb: &mut Ptb, package_id: ObjectId) -> Argument {
+        let function = match self {
+            Role::Admin => ident_str!("new_admin_role"),
+            Role::Writer => ident_str!("new_writer_role"),
+        };
+
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!("shared_notarization").as_str().into(),
+            function.as_str().into(),
+            vec![],
+            vec![],
+        )
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Admin => f.write_str("admin"),
+            Role::Writer => f.write_str("writer"),
+        }
+    }
+}
@@ -18,6 +18,13 @@
 //! - **Bytes**: Raw binary data for files, images, or serialized objects
 //! - **Text**: UTF-8 encoded strings for documents or structured data
 //!
+//! Hash-only notarizations store a self-describing [`Digest`] as bytes; see
+//! the [`digest`](super::digest) module for the encoding.
+//!
+//! Beyond [`Data`], any type implementing [`GenericData`] can be notarized as
+//! a typed payload, see [`State::from_generic`] and [`StateData`]. This
+//! includes Move structs of other packages, built by their constructors.
+//!
 //! ## Examples
 //!
 //! ### Creating State from Text
@@ -39,16 +46,41 @@
 //! let pdf_content = vec![0x25, 0x50, 0x44, 0x46]; // PDF header
 //! let state = State::from_bytes(pdf_content, Some("Signed contract PDF".to_string()));
 //! ```
+//!
+//! ### Creating State from a Digest
+//!
+//! ```rust
+//! use notarization::core::types::{Digest, DigestAlgorithm, State};
+//!
+//! let digest = Digest::compute(DigestAlgorithm::Sha256, b"%PDF-1.7 ...");
+//! let state = State::from_digest(digest, Some("Signed contract PDF".to_string()));
+//! ```
+//!
+//! ### Creating State from a Typed Payload
+//!
+//! ```rust,ignore
+//! use notarization::core::types::State;
+//!
+//! // Stored on-chain as `Notarization<vector<u64>>`
+//! let readings: Vec<u64> = vec![21, 22, 23];
+//! let state = State::from_generic(readings, Some("Sensor readings".to_string()));
+//! ```
 
 use std::str::FromStr;
 
 use iota_interaction::ident_str;
+use iota_interaction::rpc_types::IotaEvent;
 use iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
-use iota_sdk_types::{Argument, ObjectId, TypeTag};
-use serde::{Deserialize, Deserializer, Serialize};
+use iota_sdk_types::{Address, Argument, ObjectId, TypeTag};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
 
 use super::super::move_utils;
+use super::digest::Digest;
+use super::event::{NotarizationEvent, NotarizationUpdated};
+use super::notarization::OnChainNotarization;
 use crate::error::Error;
 
 /// Versioned state of a notarization: the notarized `data` together with
@@ -78,7 +110,11 @@ pub struct State<T = Data> {
 }
 
 /// Represents the different types of data that can be notarized.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+///
+/// On-chain, the variant is determined by the Move type of the notarization
+/// (`Notarization<vector<u8>>` or `Notarization<String>`), never by the
+/// content of the payload.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Data {
     /// Raw binary data (e.g., files, images, serialized objects)
     Bytes(Vec<u8>),
@@ -86,23 +122,52 @@ pub enum Data {
     Text(String),
 }
 
-impl<'de> Deserialize<'de> for Data {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        // Handle both raw bytes and string representations from BCS
-        let bytes = Vec::<u8>::deserialize(deserializer)?;
-
-        if let Ok(text) = String::from_utf8(bytes.clone()) {
-            // Additional check: if it looks like actual text (not just valid UTF-8 bytes)
-            if text.chars().all(|c| c.is_ascii_graphic() || c.is_ascii_whitespace()) {
-                Ok(Data::Text(text))
-            } else {
-                Ok(Data::Bytes(bytes))
-            }
+impl From<Vec<u8>> for Data {
+    fn from(value: Vec<u8>) -> Self {
+        Data::Bytes(value)
+    }
+}
+
+impl From<String> for Data {
+    fn from(value: String) -> Self {
+        Data::Text(value)
+    }
+}
+
+/// The Move type of the `data` stored in an on-chain notarization.
+///
+/// Resolved from the type parameter `D` of `Notarization<D>` and used to
+/// decode the on-chain payload into the matching [`Data`] variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DataType {
+    /// `vector<u8>`, decoded as [`Data::Bytes`].
+    Bytes,
+    /// `std::string::String`, decoded as [`Data::Text`].
+    Text,
+}
+
+impl DataType {
+    /// Resolves the [`DataType`] from the type parameter of `Notarization<D>`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `type_tag` is neither `vector<u8>` nor `String`.
+    pub(crate) fn from_type_tag(type_tag: &TypeTag) -> Result<Self, Error> {
+        if *type_tag == DataType::Bytes.tag() {
+            Ok(DataType::Bytes)
+        } else if *type_tag == DataType::Text.tag() {
+            Ok(DataType::Text)
         } else {
-            Ok(Data::Bytes(bytes))
+            Err(Error::InvalidArgument(format!("Unsupported state type: {type_tag}")))
+        }
+    }
+
+    /// Returns the Move type tag of this data type.
+    fn tag(self) -> TypeTag {
+        match self {
+            DataType::Bytes => TypeTag::Vector(Box::new(TypeTag::U8)),
+            DataType::Text => TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
+                .expect("should be valid type tag"),
         }
     }
 }
@@ -113,9 +178,8 @@ impl Data {
     /// Used internally for blockchain transaction construction.
     pub(crate) fn tag(&self) -> TypeTag {
         match self {
-            Data::Bytes(_) => TypeTag::Vector(Box::new(TypeTag::U8)),
-            Data::Text(_) => TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
-                .expect("should be valid type tag"),
+            Data::Bytes(_) => DataType::Bytes.tag(),
+            Data::Text(_) => DataType::Text.tag(),
         }
     }
 
@@ -164,6 +228,32 @@ impl Data {
             Data::Text(data) => Ok(data),
         }
     }
+
+    /// Decodes the data as a self-describing [`Digest`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the data is text, or if the bytes are not a
+    /// digest encoded by [`Digest::to_bytes`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use notarization::core::types::{Digest, DigestAlgorithm, State};
+    /// # use notarization::error::Error;
+    /// let digest = Digest::compute(DigestAlgorithm::Sha3_256, b"document");
+    /// let state = State::from_digest(digest.clone(), None);
+    /// assert_eq!(state.data.as_digest()?, digest);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn as_digest(self) -> Result<Digest, Error> {
+        match self {
+            Data::Bytes(data) => {
+                Digest::from_bytes(&data).map_err(|e| Error::GenericError(format!("Data is not a digest: {e}")))
+            }
+            Data::Text(_) => Err(Error::GenericError("Data is not a digest".to_string())),
+        }
+    }
 }
 
 impl State {
@@ -230,6 +320,71 @@ impl State {
         }
     }
 
+    /// Creates a new state from a [`Digest`].
+    ///
+    /// Use this for hash-only notarizations, where only the digest of a
+    /// document is notarized. The digest is stored as bytes together with its
+    /// algorithm identifier and can be decoded again with
+    /// [`Data::as_digest`].
+    ///
+    /// ## Parameters
+    ///
+    /// - `digest`: The digest to store
+    /// - `metadata`: Optional description of the digested content
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::{Digest, DigestAlgorithm, State};
+    ///
+    /// let digest = Digest::compute(DigestAlgorithm::Sha512, b"large backup archive");
+    /// let state = State::from_digest(digest, Some("Nightly backup".to_string()));
+    /// ```
+    pub fn from_digest(digest: Digest, metadata: Option<String>) -> Self {
+        Self::from_bytes(digest.to_bytes(), metadata)
+    }
+}
+
+impl<T: GenericData> State<T> {
+    /// Creates a new state from a typed payload.
+    ///
+    /// The resulting notarization is a `Notarization<D>` where `D` is the
+    /// Move type returned by [`GenericData::move_type_tag`], and can be read
+    /// back via
+    /// [`NotarizationClientReadOnly::state_as`](crate::client::NotarizationClientReadOnly::state_as).
+    ///
+    /// The payload is passed to `notarization::new_state_from_generic<D>` as
+    /// the argument built by [`GenericData::to_ptb_argument`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::State;
+    ///
+    /// let state = State::from_generic(42u64, Some("Answer".to_string()));
+    /// ```
+    pub fn from_generic(data: T, metadata: Option<String>) -> Self {
+        Self { data, metadata }
+    }
+}
+
+impl<T: StateData> State<T> {
+    /// Returns the SHA2-256 hash of the BCS of the on-chain `State<D>`
+    /// holding this state.
+    ///
+    /// Equals the `state_hash` recorded by endorsements of this state, see
+    /// [`Endorsement`](super::Endorsement).
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the payload cannot be serialized.
+    pub fn hash(&self) -> Result<Vec<u8>, Error> {
+        let mut bcs = self.data.data_bcs()?;
+        bcs.extend(bcs::to_bytes(&self.metadata)?);
+
+        Ok(Sha256::digest(&bcs).to_vec())
+    }
+
     /// Creates a new `Argument` from the `State`.
     ///
     /// To be used when creating a new `Notarization` object on the ledger.
@@ -238,13 +393,316 @@ impl State {
         ptb: &mut ProgrammableTransactionBuilder,
         package_id: ObjectId,
     ) -> Result<Argument, Error> {
-        match self.data {
-            Data::Bytes(data) => state_from_bytes(ptb, data, self.metadata, package_id),
-            Data::Text(data) => state_from_string(ptb, data, self.metadata, package_id),
+        self.data.state_into_ptb(self.metadata, ptb, package_id)
+    }
+}
+
+/// A past or current version of the `state` of a notarization.
+///
+/// Reconstructed from the `NotarizationUpdated` events of the notarization, see
+/// [`NotarizationClientReadOnly::state_history`](crate::client::NotarizationClientReadOnly::state_history).
+///
+/// ## Type Parameter
+///
+/// - `D`: The data type of the `state`, defaults to [`Data`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateVersion<D = Data> {
+    /// Value of `state_version_count` while this `state` was current; `0` for
+    /// the initial `state`.
+    pub version: u64,
+    /// Timestamp at which this `state` was set, in milliseconds since the Unix
+    /// epoch.
+    pub timestamp_ms: u64,
+    /// The `state` of this version.
+    pub state: State<D>,
+}
+
+/// A payload type that can be stored in the `state` of a notarization.
+///
+/// Implemented for [`Data`], stored as `vector<u8>` or `String`, and for
+/// every type implementing [`GenericData`], stored via
+/// `notarization::new_state_from_generic<D>`.
+pub trait StateData: Sized {
+    /// Returns the Move type `D` of the `Notarization<D>` holding this payload.
+    fn move_tag(&self, package_id: ObjectId) -> TypeTag;
+
+    /// Adds a `State<D>` holding this payload and `metadata` to `ptb`.
+    fn state_into_ptb(
+        self,
+        metadata: Option<String>,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+    ) -> Result<Argument, Error>;
+
+    /// Returns the BCS of the on-chain `D` holding this payload.
+    fn data_bcs(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes the BCS content of an on-chain `Notarization<D>`, where `D`
+    /// is `data_type` and `package_id` is the package defining
+    /// `Notarization`.
+    ///
+    /// Fails if `data_type` is not a Move type of this payload.
+    fn decode_notarization(
+        data_type: &TypeTag,
+        package_id: ObjectId,
+        bytes: &[u8],
+    ) -> Result<OnChainNotarization<Self>, Error>;
+
+    /// Decodes a `NotarizationUpdated<D>` event emitted for a notarization
+    /// holding this payload.
+    ///
+    /// Fails if `D` is not a Move type of this payload.
+    fn decode_updated_event(event: &IotaEvent) -> Result<NotarizationUpdated<Self>, Error>;
+}
+
+impl StateData for Data {
+    fn move_tag(&self, _package_id: ObjectId) -> TypeTag {
+        self.tag()
+    }
+
+    fn state_into_ptb(
+        self,
+        metadata: Option<String>,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+    ) -> Result<Argument, Error> {
+        match self {
+            Data::Bytes(data) => state_from_bytes(ptb, data, metadata, package_id),
+            Data::Text(data) => state_from_string(ptb, data, metadata, package_id),
+        }
+    }
+
+    fn data_bcs(&self) -> Result<Vec<u8>, Error> {
+        Ok(match self {
+            Data::Bytes(data) => bcs::to_bytes(data)?,
+            Data::Text(data) => bcs::to_bytes(data)?,
+        })
+    }
+
+    fn decode_notarization(
+        data_type: &TypeTag,
+        _package_id: ObjectId,
+        bytes: &[u8],
+    ) -> Result<OnChainNotarization, Error> {
+        OnChainNotarization::from_bcs(data_type, bytes)
+    }
+
+    fn decode_updated_event(event: &IotaEvent) -> Result<NotarizationUpdated, Error> {
+        match NotarizationEvent::try_from(event)? {
+            NotarizationEvent::NotarizationUpdated(updated) => Ok(updated),
+            _ => Err(Error::InvalidArgument(format!(
+                "cannot decode a {} event as NotarizationUpdated of vector<u8> or String",
+                event.type_
+            ))),
         }
     }
 }
 
+impl<T: GenericData> StateData for T {
+    fn move_tag(&self, _package_id: ObjectId) -> TypeTag {
+        T::move_type_tag()
+    }
+
+    fn state_into_ptb(
+        self,
+        metadata: Option<String>,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectId,
+    ) -> Result<Argument, Error> {
+        let data = self.to_ptb_argument(ptb)?;
+        let metadata = move_utils::ptb_pure(ptb, "metadata", metadata)?;
+
+        Ok(ptb.programmable_move_call(
+            package_id,
+            ident_str!("notarization").as_str().into(),
+            ident_str!("new_state_from_generic").as_str().into(),
+            vec![T::move_type_tag()],
+            vec![data, metadata],
+        ))
+    }
+
+    fn data_bcs(&self) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    fn decode_notarization(
+        data_type: &TypeTag,
+        _package_id: ObjectId,
+        bytes: &[u8],
+    ) -> Result<OnChainNotarization<Self>, Error> {
+        check_data_type::<T>(data_type)?;
+
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    fn decode_updated_event(event: &IotaEvent) -> Result<NotarizationUpdated<Self>, Error> {
+        check_data_type::<T>(&move_utils::type_param_tag(&event.type_.to_string())?)?;
+
+        NotarizationUpdated::from_bcs(event.bcs.bytes())
+    }
+}
+
+/// A Rust type that can be stored as the typed payload of a
+/// `Notarization<D>`.
+///
+/// `D` is the Move type returned by [`Self::move_type_tag`]. Implemented for
+/// the [`PureMoveType`]s, which are passed to Move as pure arguments.
+///
+/// Move structs cannot be passed as pure arguments. To notarize a struct of
+/// your own package, implement this trait for a Rust type with the same BCS
+/// layout and build the struct in [`Self::to_ptb_argument`] by calling one of
+/// its constructors. The struct must have the `copy`, `drop` and `store`
+/// abilities.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+/// use iota_interaction::{MoveType, ident_str};
+/// use notarization::core::types::GenericData;
+/// use notarization::error::Error;
+///
+/// // Mirrors `struct Reading has copy, drop, store { sensor: address, value: u64 }`
+/// // of the `reading` module of the package `READING_PACKAGE_ID`.
+/// #[derive(Serialize, Deserialize)]
+/// struct Reading {
+///     sensor: Address,
+///     value: u64,
+/// }
+///
+/// impl MoveType for Reading {
+///     fn move_type(package: ObjectId) -> TypeTag {
+///         TypeTag::from_str(&format!("{package}::reading::Reading")).expect("should be valid type tag")
+///     }
+/// }
+///
+/// impl GenericData for Reading {
+///     fn move_type_tag() -> TypeTag {
+///         Self::move_type(READING_PACKAGE_ID)
+///     }
+///
+///     fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error> {
+///         let sensor = ptb.pure(self.sensor).map_err(|err| Error::InvalidArgument(err.to_string()))?;
+///         let value = ptb.pure(self.value).map_err(|err| Error::InvalidArgument(err.to_string()))?;
+///
+///         Ok(ptb.programmable_move_call(
+///             READING_PACKAGE_ID,
+///             ident_str!("reading").as_str().into(),
+///             ident_str!("new").as_str().into(),
+///             vec![],
+///             vec![sensor, value],
+///         ))
+///     }
+/// }
+/// ```
+pub trait GenericData: Serialize + DeserializeOwned {
+    /// Returns the Move type of this type.
+    fn move_type_tag() -> TypeTag;
+
+    /// Adds this value to `ptb` as an argument of the Move type returned by
+    /// [`Self::move_type_tag`].
+    fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error>;
+}
+
+/// A [`GenericData`] type that is passed to Move as a pure argument.
+///
+/// Implemented for `bool`, `u8`, `u16`, `u32`, `u64`, `u128`, [`String`]
+/// (`String`), [`Address`] (`address`), [`ObjectId`] (`ID`), and for [`Vec`]
+/// (`vector`) and [`Option`] of those. Sealed, as only these types can be
+/// pure arguments.
+pub trait PureMoveType: GenericData + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_pure_move_type {
+    ($($type:ty => $tag:expr),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl PureMoveType for $type {}
+
+            impl GenericData for $type {
+                fn move_type_tag() -> TypeTag {
+                    $tag
+                }
+
+                fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error> {
+                    pure_argument(ptb, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_pure_move_type! {
+    bool => TypeTag::Bool,
+    u8 => TypeTag::U8,
+    u16 => TypeTag::U16,
+    u32 => TypeTag::U32,
+    u64 => TypeTag::U64,
+    u128 => TypeTag::U128,
+    Address => TypeTag::Address,
+    String => DataType::Text.tag(),
+    ObjectId => TypeTag::from_str("0x2::object::ID").expect("should be valid type tag"),
+}
+
+impl<T: PureMoveType> sealed::Sealed for Vec<T> {}
+
+impl<T: PureMoveType> PureMoveType for Vec<T> {}
+
+impl<T: PureMoveType> GenericData for Vec<T> {
+    fn move_type_tag() -> TypeTag {
+        TypeTag::Vector(Box::new(T::move_type_tag()))
+    }
+
+    fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error> {
+        pure_argument(ptb, self)
+    }
+}
+
+impl<T: PureMoveType> sealed::Sealed for Option<T> {}
+
+impl<T: PureMoveType> PureMoveType for Option<T> {}
+
+impl<T: PureMoveType> GenericData for Option<T> {
+    fn move_type_tag() -> TypeTag {
+        TypeTag::from_str(&format!(
+            "{MOVE_STDLIB_PACKAGE_ID}::option::Option<{}>",
+            T::move_type_tag()
+        ))
+        .expect("should be valid type tag")
+    }
+
+    fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error> {
+        pure_argument(ptb, self)
+    }
+}
+
+/// Adds `value`, of the [`PureMoveType`] `T`, to `ptb` as a pure argument.
+fn pure_argument<T: PureMoveType>(ptb: &mut ProgrammableTransactionBuilder, value: &T) -> Result<Argument, Error> {
+    ptb.pure(value).map_err(|err| {
+        Error::InvalidArgument(format!(
+            "could not serialize pure value data of type {}; {err}",
+            T::move_type_tag()
+        ))
+    })
+}
+
+/// Checks that `data_type`, the type parameter `D` of a `Notarization<D>` or
+/// of one of its events, is the Move type of `T`.
+pub(crate) fn check_data_type<T: GenericData>(data_type: &TypeTag) -> Result<(), Error> {
+    let expected = T::move_type_tag();
+    if &expected != data_type {
+        return Err(Error::InvalidArgument(format!(
+            "cannot decode a Notarization<{data_type}> as Notarization<{expected}>"
+        )));
+    }
+
+    Ok(())
+}
+
 /// Helper function to create a new state from bytes.
 fn state_from_bytes(
     ptb: &mut ProgrammableTransactionBuilder,
@@ -282,3 +740,24 @@ fn state_from_string(
         vec![data, metadata],
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_type_from_type_tag() {
+        assert_eq!(
+            DataType::from_type_tag(&TypeTag::from_str("vector<u8>").unwrap()).unwrap(),
+            DataType::Bytes
+        );
+        assert_eq!(
+            DataType::from_type_tag(&TypeTag::from_str("0x1::string::String").unwrap()).unwrap(),
+            DataType::Text
+        );
+
+        assert!(DataType::from_type_tag(&TypeTag::from_str("0x2::string::String").unwrap()).is_err());
+        assert!(DataType::from_type_tag(&TypeTag::from_str("0x1::ascii::String").unwrap()).is_err());
+        assert!(DataType::from_type_tag(&TypeTag::from_str("vector<u64>").unwrap()).is_err());
+    }
+}
@@ -14,17 +14,27 @@
 //! - `UnlockAt`: The lock is unlocked at a specific time.
 //! - `UntilDestroyed`: The lock is locked until the notarization is destroyed.
 //! - `None`: The lock is not applied.
+//!
+//! The `update_lock` of a Dynamic-Notarization is an [`UpdateLock`], which is
+//! either a `TimeLock` or a `LockAt` lock, locked from a specific time on until
+//! the notarization is destroyed. On-chain, `LockAt` is not a
+//! `timelock::TimeLock` variant but a `lock_at::LockAt` stored as a dynamic
+//! field of the notarization, whose `update_lock` is then `UntilDestroyed`.
+//! The clients of this crate resolve it into `UpdateLock::LockAt` when
+//! reading a notarization.
 
+use std::fmt;
 use std::str::FromStr;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
 use iota_interaction::{MoveType, ident_str};
 use iota_sdk_types::{Argument, ObjectId, TypeTag};
 use serde::{Deserialize, Serialize};
 
 use super::super::move_utils;
-use crate::error::Error;
+use crate::error::{Error, TimeLockError};
 
 /// Bundle of three [`TimeLock`]s controlling whether a notarization can be
 /// updated, destroyed, or transferred.
@@ -35,7 +45,8 @@ use crate::error::Error;
 /// the on-chain constructor aborts otherwise.
 ///
 /// Permitted lock configurations depend on the Notarization Method:
-/// * `Dynamic`: `update_lock` is fixed to `TimeLock::None`; `transfer_lock` may carry any [`TimeLock`] variant.
+/// * `Dynamic`: `update_lock` may be `TimeLock::None`, `TimeLock::UnlockAt` or `UpdateLock::LockAt`; `transfer_lock`
+///   may carry any [`TimeLock`] variant.
 /// * `Locked`: both `update_lock` and `transfer_lock` are pinned to `TimeLock::UntilDestroyed` — Locked-Notarizations
 ///   are non-transferable and their state is immutable.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -43,9 +54,10 @@ pub struct LockMetadata {
     /// Lock guarding `update_state` and `update_metadata`.
     ///
     /// Value depends on the Notarization Method:
-    /// * `Dynamic`: fixed to `TimeLock::None`.
+    /// * `Dynamic`: an optional update window — `TimeLock::UnlockAt` embargoes updates until a time,
+    ///   `UpdateLock::LockAt` freezes the state from a time on.
     /// * `Locked`: fixed to `TimeLock::UntilDestroyed`.
-    pub update_lock: TimeLock,
+    pub update_lock: UpdateLock,
     /// Lock guarding destruction. Must not be `TimeLock::UntilDestroyed`;
     /// its unlock time must be ≥ both other locks' unlock times.
     pub delete_lock: TimeLock,
@@ -57,6 +69,47 @@ pub struct LockMetadata {
     pub transfer_lock: TimeLock,
 }
 
+impl LockMetadata {
+    /// Returns `true` if `update_lock` is active at `now_secs`, in seconds
+    /// since the Unix epoch.
+    ///
+    /// Does not take the Notarization Method into account; see
+    /// [`OnChainNotarization::is_update_locked_at`](super::OnChainNotarization::is_update_locked_at).
+    pub fn is_update_locked_at(&self, now_secs: u64) -> bool {
+        self.update_lock.is_timelocked_at(now_secs)
+    }
+
+    /// Returns `true` if `delete_lock` is active at `now_secs`, in seconds
+    /// since the Unix epoch.
+    ///
+    /// Does not take the Notarization Method into account; see
+    /// [`OnChainNotarization::is_delete_locked_at`](super::OnChainNotarization::is_delete_locked_at).
+    pub fn is_delete_locked_at(&self, now_secs: u64) -> bool {
+        self.delete_lock.is_timelocked_at(now_secs)
+    }
+
+    /// Returns `true` if `transfer_lock` is active at `now_secs`, in seconds
+    /// since the Unix epoch.
+    pub fn is_transfer_locked_at(&self, now_secs: u64) -> bool {
+        self.transfer_lock.is_timelocked_at(now_secs)
+    }
+}
+
+/// The state of the locks of a notarization, evaluated against the on-chain
+/// clock.
+///
+/// Returned by
+/// [`NotarizationClientReadOnly::lock_statuses`](crate::client::NotarizationClientReadOnly::lock_statuses).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LockStatus {
+    /// Whether `state` and `updatable_metadata` are currently locked.
+    pub update_locked: bool,
+    /// Whether the notarization can currently be destroyed.
+    pub destroy_allowed: bool,
+    /// Whether ownership transfer is currently locked.
+    pub transfer_locked: bool,
+}
+
 /// Represents different types of time-based locks that can be applied to
 /// notarizations.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -87,7 +140,96 @@ impl TimeLock {
         Ok(TimeLock::UnlockAt(unlock_time_sec))
     }
 
-    /// Creates a new `Argument` from the `TimeLock`.
+    /// Creates a new `TimeLock::UnlockAt` that unlocks once `duration` has
+    /// elapsed from now.
+    ///
+    /// The unlock time is rounded up to the next full second.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the unlock time is after 2106-02-07T06:28:15Z, the
+    /// latest unlock time representable on-chain.
+    pub fn unlock_after(duration: Duration) -> Result<Self, TimeLockError> {
+        let unlock_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("system time is before the Unix epoch")
+            .checked_add(duration)
+            .ok_or_else(|| TimeLockError::Invalid(format!("unlock time overflows after {duration:?}")))?;
+        let unlock_time_sec = unlock_time.as_secs() + u64::from(unlock_time.subsec_nanos() > 0);
+
+        Ok(TimeLock::UnlockAt(unlock_time_from_secs(
+            i64::try_from(unlock_time_sec).unwrap_or(i64::MAX),
+        )?))
+    }
+
+    /// Creates a new `TimeLock::UnlockAt` that unlocks at `datetime`.
+    ///
+    /// The unlock time is rounded up to the next full second. It is not
+    /// checked against the local clock, which may drift from the on-chain
+    /// clock; use
+    /// [`NotarizationBuilder::validate_locks_at`](crate::core::builder::NotarizationBuilder::validate_locks_at)
+    /// or
+    /// [`NotarizationClientReadOnly::validate_locks`](crate::client::NotarizationClientReadOnly::validate_locks)
+    /// to check that a lock is in the future.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `datetime` is before the Unix epoch or after
+    /// 2106-02-07T06:28:15Z, the latest unlock time representable on-chain.
+    pub fn unlock_at_datetime(datetime: DateTime<Utc>) -> Result<Self, TimeLockError> {
+        Ok(TimeLock::UnlockAt(secs_from_datetime(datetime)?))
+    }
+
+    /// Creates a new `TimeLock::UnlockAt` from an RFC 3339 timestamp, e.g.
+    /// `2030-01-01T00:00:00Z` or `2030-01-01T01:00:00+01:00`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `timestamp` cannot be parsed, if it has no UTC
+    /// offset, e.g. `2030-01-01T00:00:00` or `2030-01-01`, or for the reasons
+    /// listed in [`Self::unlock_at_datetime`].
+    pub fn from_rfc3339(timestamp: &str) -> Result<Self, TimeLockError> {
+        Self::unlock_at_datetime(parse_rfc3339(timestamp)?)
+    }
+
+    /// Returns `true` if this lock is active at `now_secs`, in seconds since
+    /// the Unix epoch.
+    ///
+    /// Mirrors `timelock::is_timelocked`: an `UnlockAt` lock is active until
+    /// its unlock time is reached, `UntilDestroyed` is always active and
+    /// `None` never is.
+    pub fn is_timelocked_at(&self, now_secs: u64) -> bool {
+        match self {
+            TimeLock::UnlockAt(unlock_time) => u64::from(*unlock_time) > now_secs,
+            TimeLock::UntilDestroyed => true,
+            TimeLock::None => false,
+        }
+    }
+
+    /// Returns `true` if this is an `UnlockAt` lock that is still active at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Mirrors `timelock::is_timelocked_unlock_at`.
+    pub fn is_timelocked_unlock_at(&self, now_secs: u64) -> bool {
+        matches!(self, TimeLock::UnlockAt(_)) && self.is_timelocked_at(now_secs)
+    }
+
+    /// Returns the time left until this lock unlocks, counted from
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Returns [`Duration::ZERO`] if the lock is not active and `None` for
+    /// `UntilDestroyed`, which never unlocks.
+    pub fn time_until_unlock(&self, now_secs: u64) -> Option<Duration> {
+        match self {
+            TimeLock::UnlockAt(unlock_time) => {
+                Some(Duration::from_secs(u64::from(*unlock_time).saturating_sub(now_secs)))
+            }
+            TimeLock::UntilDestroyed => None,
+            TimeLock::None => Some(Duration::ZERO),
+        }
+    }
+
+    /// Creates a new `timelock::TimeLock` `Argument` from the `TimeLock`.
     ///
     /// To be used when creating a new `Notarization` object on the ledger.
     pub(in crate::core) fn to_ptb(&self, ptb: &mut Ptb, package_id: ObjectId) -> Result<Argument, Error> {
@@ -99,6 +241,225 @@ impl TimeLock {
     }
 }
 
+impl fmt::Display for TimeLock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeLock::UnlockAt(unlock_time) => write!(f, "unlock at {}", rfc3339(*unlock_time)),
+            TimeLock::UntilDestroyed => f.write_str("until destroyed"),
+            TimeLock::None => f.write_str("none"),
+        }
+    }
+}
+
+impl FromStr for TimeLock {
+    type Err = TimeLockError;
+
+    /// Parses a `TimeLock` as written by its [`Display`](fmt::Display)
+    /// implementation, e.g. `unlock at 2030-01-01T00:00:00Z` or `none`.
+    ///
+    /// A bare RFC 3339 timestamp is parsed as a `TimeLock::UnlockAt`, see
+    /// [`TimeLock::from_rfc3339`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "none" => Ok(TimeLock::None),
+            "until destroyed" => Ok(TimeLock::UntilDestroyed),
+            s => Self::from_rfc3339(s.strip_prefix("unlock at ").unwrap_or(s)),
+        }
+    }
+}
+
+/// The lock guarding updates of the `state` and `updatable_metadata` of a
+/// notarization.
+///
+/// Besides the [`TimeLock`]s, the `update_lock` of a Dynamic-Notarization can
+/// be a `LockAt` lock, which freezes the notarization from a specific time on.
+/// `LockAt` is not a `timelock::TimeLock` variant; it is resolved by the
+/// clients from the `lock_at::LockAt` dynamic field of a notarization.
+///
+/// Serialized like a [`TimeLock`] with an additional `LockAt` variant, so the
+/// BCS of an `UpdateLock::TimeLock` is the BCS of the on-chain
+/// `timelock::TimeLock`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "UpdateLockRepr", into = "UpdateLockRepr")]
+pub enum UpdateLock {
+    /// A [`TimeLock`]
+    TimeLock(TimeLock),
+    /// A lock that becomes locked at a specific Unix timestamp (seconds since Unix epoch) and stays locked until the
+    /// locked object is destroyed (can only be used for Dynamic-Notarizations)
+    LockAt(u32),
+}
+
+impl UpdateLock {
+    /// Creates a new `UpdateLock::LockAt` with a specified lock time.
+    ///
+    /// The lock time is the time in seconds since the Unix epoch and must be
+    /// in the future, see [`TimeLock::new_with_ts`].
+    pub fn new_lock_at_ts(lock_time_sec: u32) -> Result<Self, Error> {
+        TimeLock::new_with_ts(lock_time_sec)?;
+
+        Ok(UpdateLock::LockAt(lock_time_sec))
+    }
+
+    /// Creates a new `UpdateLock::LockAt` that locks at `datetime`.
+    ///
+    /// The lock time is rounded up to the next full second. Unlike
+    /// [`Self::new_lock_at_ts`], the lock time is not checked against the
+    /// local clock; see [`TimeLock::unlock_at_datetime`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `datetime` is before the Unix epoch or after
+    /// 2106-02-07T06:28:15Z, the latest lock time representable on-chain.
+    pub fn lock_at_datetime(datetime: DateTime<Utc>) -> Result<Self, TimeLockError> {
+        Ok(UpdateLock::LockAt(secs_from_datetime(datetime)?))
+    }
+
+    /// Returns `true` if this lock is active at `now_secs`, in seconds since
+    /// the Unix epoch.
+    ///
+    /// Mirrors `lock_at::is_locked` for `LockAt` locks, which are active once
+    /// their lock time is reached, and [`TimeLock::is_timelocked_at`]
+    /// otherwise.
+    pub fn is_timelocked_at(&self, now_secs: u64) -> bool {
+        match self {
+            UpdateLock::TimeLock(lock) => lock.is_timelocked_at(now_secs),
+            UpdateLock::LockAt(lock_time) => u64::from(*lock_time) <= now_secs,
+        }
+    }
+
+    /// Returns `true` if this is an `UnlockAt` lock that is still active at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// See [`TimeLock::is_timelocked_unlock_at`].
+    pub fn is_timelocked_unlock_at(&self, now_secs: u64) -> bool {
+        matches!(self, UpdateLock::TimeLock(lock) if lock.is_timelocked_unlock_at(now_secs))
+    }
+
+    /// Returns the time left until this lock unlocks, counted from
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Returns `None` for active `LockAt` locks, which never unlock; see
+    /// [`TimeLock::time_until_unlock`] otherwise.
+    pub fn time_until_unlock(&self, now_secs: u64) -> Option<Duration> {
+        match self {
+            UpdateLock::TimeLock(lock) => lock.time_until_unlock(now_secs),
+            UpdateLock::LockAt(_) => (!self.is_timelocked_at(now_secs)).then_some(Duration::ZERO),
+        }
+    }
+}
+
+impl From<TimeLock> for UpdateLock {
+    fn from(lock: TimeLock) -> Self {
+        UpdateLock::TimeLock(lock)
+    }
+}
+
+impl PartialEq<TimeLock> for UpdateLock {
+    fn eq(&self, other: &TimeLock) -> bool {
+        matches!(self, UpdateLock::TimeLock(lock) if lock == other)
+    }
+}
+
+impl fmt::Display for UpdateLock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateLock::TimeLock(lock) => fmt::Display::fmt(lock, f),
+            UpdateLock::LockAt(lock_time) => write!(f, "lock at {}", rfc3339(*lock_time)),
+        }
+    }
+}
+
+impl FromStr for UpdateLock {
+    type Err = TimeLockError;
+
+    /// Parses an `UpdateLock` as written by its [`Display`](fmt::Display)
+    /// implementation, e.g. `lock at 2030-01-01T00:00:00Z`; other input is
+    /// parsed as a [`TimeLock`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().strip_prefix("lock at ") {
+            Some(timestamp) => Self::lock_at_datetime(parse_rfc3339(timestamp)?),
+            None => s.parse().map(UpdateLock::TimeLock),
+        }
+    }
+}
+
+/// The serialized form of an [`UpdateLock`]: the variants of
+/// `timelock::TimeLock`, followed by `LockAt`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TimeLock")]
+enum UpdateLockRepr {
+    UnlockAt(u32),
+    UntilDestroyed,
+    None,
+    LockAt(u32),
+}
+
+impl From<UpdateLockRepr> for UpdateLock {
+    fn from(repr: UpdateLockRepr) -> Self {
+        match repr {
+            UpdateLockRepr::UnlockAt(unlock_time) => UpdateLock::TimeLock(TimeLock::UnlockAt(unlock_time)),
+            UpdateLockRepr::UntilDestroyed => UpdateLock::TimeLock(TimeLock::UntilDestroyed),
+            UpdateLockRepr::None => UpdateLock::TimeLock(TimeLock::None),
+            UpdateLockRepr::LockAt(lock_time) => UpdateLock::LockAt(lock_time),
+        }
+    }
+}
+
+impl From<UpdateLock> for UpdateLockRepr {
+    fn from(lock: UpdateLock) -> Self {
+        match lock {
+            UpdateLock::TimeLock(TimeLock::UnlockAt(unlock_time)) => UpdateLockRepr::UnlockAt(unlock_time),
+            UpdateLock::TimeLock(TimeLock::UntilDestroyed) => UpdateLockRepr::UntilDestroyed,
+            UpdateLock::TimeLock(TimeLock::None) => UpdateLockRepr::None,
+            UpdateLock::LockAt(lock_time) => UpdateLockRepr::LockAt(lock_time),
+        }
+    }
+}
+
+/// Formats `secs` since the Unix epoch as an RFC 3339 UTC timestamp.
+fn rfc3339(secs: u32) -> String {
+    DateTime::from_timestamp(i64::from(secs), 0)
+        .expect("u32 timestamps are within the supported range")
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Parses an RFC 3339 timestamp with a UTC offset.
+fn parse_rfc3339(timestamp: &str) -> Result<DateTime<Utc>, TimeLockError> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .map_err(|e| {
+            if NaiveDateTime::from_str(timestamp).is_ok() || NaiveDate::from_str(timestamp).is_ok() {
+                TimeLockError::Invalid(format!(
+                    "timestamp {timestamp:?} has no UTC offset; append `Z` or an offset such as `+01:00`"
+                ))
+            } else {
+                TimeLockError::Invalid(format!("invalid RFC 3339 timestamp {timestamp:?}: {e}"))
+            }
+        })
+}
+
+/// Converts `datetime` into an on-chain unlock time, rounded up to the next
+/// full second.
+fn secs_from_datetime(datetime: DateTime<Utc>) -> Result<u32, TimeLockError> {
+    unlock_time_from_secs(datetime.timestamp() + i64::from(datetime.timestamp_subsec_nanos() > 0))
+}
+
+/// Converts `secs` since the Unix epoch into an on-chain unlock time.
+///
+/// Unlock times are stored on-chain as `u32`, so the latest representable
+/// unlock time is 2106-02-07T06:28:15Z.
+fn unlock_time_from_secs(secs: i64) -> Result<u32, TimeLockError> {
+    u32::try_from(secs).map_err(|_| {
+        if secs < 0 {
+            TimeLockError::Invalid(format!("unlock time {secs} is before the Unix epoch"))
+        } else {
+            TimeLockError::Invalid(format!(
+                "unlock time {secs} does not fit into a u32; the latest supported unlock time is 2106-02-07T06:28:15Z"
+            ))
+        }
+    })
+}
+
 /// Creates a new `Argument` for the `unlock_at` function.
 pub(super) fn new_unlock_at(ptb: &mut Ptb, unlock_time_sec: u32, package_id: ObjectId) -> Result<Argument, Error> {
     let clock = move_utils::get_clock_ref(ptb);
@@ -113,6 +474,24 @@ pub(super) fn new_unlock_at(ptb: &mut Ptb, unlock_time_sec: u32, package_id: Obj
     ))
 }
 
+/// Creates a new `Argument` for the `lock_at::new` function.
+pub(in crate::core) fn new_lock_at(
+    ptb: &mut Ptb,
+    lock_time_sec: u32,
+    package_id: ObjectId,
+) -> Result<Argument, Error> {
+    let clock = move_utils::get_clock_ref(ptb);
+    let lock_time_sec = move_utils::ptb_pure(ptb, "lock_time", lock_time_sec)?;
+
+    Ok(ptb.programmable_move_call(
+        package_id,
+        ident_str!("lock_at").as_str().into(),
+        ident_str!("new").as_str().into(),
+        vec![],
+        vec![lock_time_sec, clock],
+    ))
+}
+
 /// Creates a new `Argument` for the `until_destroyed` function.
 pub(super) fn new_until_destroyed(ptb: &mut Ptb, package_id: ObjectId) -> Result<Argument, Error> {
     Ok(ptb.programmable_move_call(
@@ -140,3 +519,209 @@ impl MoveType for TimeLock {
         TypeTag::from_str(format!("{package}::timelock::TimeLock").as_str()).expect("failed to create type tag")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Converts an on-chain clock timestamp to seconds, as done by the
+    /// `timelock` module.
+    fn clock_secs(timestamp_ms: u64) -> u64 {
+        timestamp_ms / 1000
+    }
+
+    // Vectors taken from `notarization-move/tests/timelock_tests.move`.
+
+    #[test]
+    fn unlock_at() {
+        let lock = TimeLock::UnlockAt(1001);
+
+        assert!(lock.is_timelocked_at(clock_secs(1_000_000)));
+        assert!(!lock.is_timelocked_at(clock_secs(1_000_000 + 1000)));
+    }
+
+    #[test]
+    fn until_destroyed() {
+        let lock = TimeLock::UntilDestroyed;
+
+        assert!(lock.is_timelocked_at(clock_secs(1_000_000)));
+        assert!(lock.is_timelocked_at(clock_secs(1_000_000 + 1_000_000)));
+        assert_eq!(lock.time_until_unlock(clock_secs(1_000_000)), None);
+    }
+
+    #[test]
+    fn none_lock() {
+        let lock = TimeLock::None;
+
+        assert!(!lock.is_timelocked_at(clock_secs(1_000_000)));
+        assert_eq!(lock.time_until_unlock(clock_secs(1_000_000)), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn lock_at() {
+        let lock = UpdateLock::LockAt(1001);
+
+        assert!(!lock.is_timelocked_at(clock_secs(1_000_000)));
+        assert!(!lock.is_timelocked_unlock_at(clock_secs(1_000_000)));
+        assert_eq!(lock.time_until_unlock(clock_secs(1_000_000)), Some(Duration::ZERO));
+
+        assert!(lock.is_timelocked_at(clock_secs(1_001_000)));
+        assert!(lock.is_timelocked_at(clock_secs(1_000_000 + 1_000_000)));
+        assert_eq!(lock.time_until_unlock(clock_secs(1_001_000)), None);
+    }
+
+    #[test]
+    fn is_timelocked_unlock_at() {
+        let now = clock_secs(1_000_000);
+
+        assert!(TimeLock::UnlockAt(2000).is_timelocked_unlock_at(now));
+        assert!(!TimeLock::UntilDestroyed.is_timelocked_unlock_at(now));
+        assert!(!TimeLock::None.is_timelocked_unlock_at(now));
+
+        assert!(!TimeLock::UnlockAt(2000).is_timelocked_unlock_at(clock_secs(1_000_000 + 1_000_000)));
+    }
+
+    #[test]
+    fn edge_cases() {
+        let one_second_future = TimeLock::UnlockAt(1001);
+        assert!(one_second_future.is_timelocked_at(clock_secs(1_000_000)));
+        assert!(!one_second_future.is_timelocked_at(clock_secs(1_001_000)));
+
+        let exact_current_time = TimeLock::UnlockAt(2001);
+        assert!(exact_current_time.is_timelocked_at(clock_secs(2_000_000)));
+        assert!(exact_current_time.is_timelocked_at(clock_secs(2_000_999)));
+        assert!(!exact_current_time.is_timelocked_at(clock_secs(2_001_000)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(TimeLock::UnlockAt(0).to_string(), "unlock at 1970-01-01T00:00:00Z");
+        assert_eq!(
+            TimeLock::UnlockAt(u32::MAX).to_string(),
+            "unlock at 2106-02-07T06:28:15Z"
+        );
+        assert_eq!(TimeLock::UntilDestroyed.to_string(), "until destroyed");
+        assert_eq!(TimeLock::None.to_string(), "none");
+        assert_eq!(UpdateLock::LockAt(0).to_string(), "lock at 1970-01-01T00:00:00Z");
+        assert_eq!(UpdateLock::from(TimeLock::None).to_string(), "none");
+    }
+
+    #[test]
+    fn from_rfc3339() {
+        assert_eq!(
+            TimeLock::from_rfc3339("2100-01-01T00:00:00Z").unwrap(),
+            TimeLock::UnlockAt(4_102_444_800)
+        );
+        assert_eq!(
+            TimeLock::from_rfc3339("2100-01-01T00:00:00+01:00").unwrap(),
+            TimeLock::UnlockAt(4_102_441_200)
+        );
+        assert_eq!(
+            TimeLock::from_rfc3339("2100-01-01T00:00:00.5Z").unwrap(),
+            TimeLock::UnlockAt(4_102_444_801)
+        );
+        assert_eq!(
+            TimeLock::from_rfc3339("2106-02-07T06:28:15Z").unwrap(),
+            TimeLock::UnlockAt(u32::MAX)
+        );
+
+        assert_eq!(
+            TimeLock::from_rfc3339("2020-01-01T00:00:00Z").unwrap(),
+            TimeLock::UnlockAt(1_577_836_800)
+        );
+        assert!(TimeLock::from_rfc3339("1969-12-31T23:59:59Z").is_err());
+        assert!(TimeLock::from_rfc3339("2100-01-01").is_err());
+        assert!(TimeLock::from_rfc3339("next tuesday").is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "2100-01-01T00:00:00Z".parse::<TimeLock>().unwrap(),
+            TimeLock::UnlockAt(4_102_444_800)
+        );
+
+        for timestamp in ["2100-01-01T00:00:00", "2100-01-01T00:00:00.5", "2100-01-01"] {
+            let Err(TimeLockError::Invalid(message)) = timestamp.parse::<TimeLock>() else {
+                panic!("expected {timestamp:?} to be rejected");
+            };
+            assert!(message.contains("no UTC offset"), "{message}");
+        }
+    }
+
+    #[test]
+    fn from_str_parses_display() {
+        for lock in [
+            TimeLock::UnlockAt(0),
+            TimeLock::UnlockAt(4_102_444_800),
+            TimeLock::UnlockAt(u32::MAX),
+            TimeLock::UntilDestroyed,
+            TimeLock::None,
+        ] {
+            assert_eq!(lock.to_string().parse::<TimeLock>().unwrap(), lock);
+
+            let lock = UpdateLock::from(lock);
+            assert_eq!(lock.to_string().parse::<UpdateLock>().unwrap(), lock);
+        }
+
+        let lock = UpdateLock::LockAt(4_102_444_800);
+        assert_eq!(lock.to_string().parse::<UpdateLock>().unwrap(), lock);
+
+        assert!("unlock at".parse::<TimeLock>().is_err());
+        assert!("lock at 2100-01-01T00:00:00Z".parse::<TimeLock>().is_err());
+        assert!("lock at 2100-01-01".parse::<UpdateLock>().is_err());
+    }
+
+    #[test]
+    fn update_lock_bcs_matches_timelock() {
+        for lock in [TimeLock::UnlockAt(2000), TimeLock::UntilDestroyed, TimeLock::None] {
+            let bcs = bcs::to_bytes(&lock).unwrap();
+            assert_eq!(bcs::to_bytes(&UpdateLock::from(lock.clone())).unwrap(), bcs);
+            assert_eq!(bcs::from_bytes::<UpdateLock>(&bcs).unwrap(), lock);
+        }
+
+        let lock = UpdateLock::LockAt(2000);
+        assert_eq!(
+            bcs::from_bytes::<UpdateLock>(&bcs::to_bytes(&lock).unwrap()).unwrap(),
+            lock
+        );
+    }
+
+    #[test]
+    fn rejects_unlock_times_after_2106() {
+        assert!(matches!(
+            TimeLock::from_rfc3339("2106-02-07T06:28:16Z"),
+            Err(TimeLockError::Invalid(_))
+        ));
+        assert!(matches!(
+            TimeLock::unlock_after(Duration::from_secs(u64::from(u32::MAX))),
+            Err(TimeLockError::Invalid(_))
+        ));
+        assert!(matches!(
+            TimeLock::unlock_after(Duration::MAX),
+            Err(TimeLockError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn unlock_after() {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let TimeLock::UnlockAt(unlock_time) = TimeLock::unlock_after(Duration::from_secs(3600)).unwrap() else {
+            panic!("expected an UnlockAt lock");
+        };
+        assert!((now + 3600..=now + 3602).contains(&u64::from(unlock_time)));
+    }
+
+    #[test]
+    fn time_until_unlock() {
+        let lock = TimeLock::UnlockAt(2001);
+
+        assert_eq!(lock.time_until_unlock(1000), Some(Duration::from_secs(1001)));
+        assert_eq!(lock.time_until_unlock(2001), Some(Duration::ZERO));
+        assert_eq!(lock.time_until_unlock(5000), Some(Duration::ZERO));
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Offline Verification
+//!
+//! This module verifies local payloads against an already-fetched
+//! [`OnChainNotarization`].
+//!
+//! ## Overview
+//!
+//! Verification never touches the network: the caller fetches the
+//! notarization once (e.g. via
+//! [`NotarizationClientReadOnly::get_notarization_by_id`](crate::client::NotarizationClientReadOnly::get_notarization_by_id))
+//! and can then verify any number of local payloads against it, also on an
+//! air-gapped host. The result is a [`VerificationReport`] that carries the
+//! match result together with the provenance fields an auditor typically
+//! needs.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use notarization::core::verification::LocalPayload;
+//!
+//! let notarization = client.get_notarization_by_id(notarization_id).await?;
+//! let report = notarization.verify(&LocalPayload::Bytes(std::fs::read("contract.pdf")?));
+//!
+//! assert!(report.data_matches);
+//! println!("verified version {} of {}", report.state_version_count, report.notarization_id);
+//! ```
+
+use iota_sdk_types::{Address, ObjectId};
+use serde::{Deserialize, Serialize};
+
+use super::types::{Data, Digest, LockMetadata, NotarizationMethod, OnChainNotarization};
+
+/// A local payload that is compared against the notarized `state.data`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocalPayload {
+    /// Raw bytes, e.g. the content of a file on disk.
+    Bytes(Vec<u8>),
+    /// UTF-8 text.
+    Text(String),
+    /// A digest computed locally, compared against a notarized digest.
+    ///
+    /// Use [`Digest::to_bytes`] to compare against a notarization created
+    /// with [`State::from_digest`](super::types::State::from_digest).
+    Digest(Vec<u8>),
+}
+
+impl LocalPayload {
+    /// Returns the byte representation used for the comparison.
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            LocalPayload::Bytes(bytes) | LocalPayload::Digest(bytes) => bytes,
+            LocalPayload::Text(text) => text.as_bytes(),
+        }
+    }
+}
+
+impl From<Vec<u8>> for LocalPayload {
+    fn from(value: Vec<u8>) -> Self {
+        LocalPayload::Bytes(value)
+    }
+}
+
+impl From<String> for LocalPayload {
+    fn from(value: String) -> Self {
+        LocalPayload::Text(value)
+    }
+}
+
+/// Structured result of verifying a [`LocalPayload`] against an
+/// [`OnChainNotarization`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// The ID of the verified notarization.
+    pub notarization_id: ObjectId,
+    /// Whether the local payload matches the notarized `state.data`.
+    pub data_matches: bool,
+    /// `state_version_count` of the verified notarization.
+    pub state_version_count: u64,
+    /// Timestamp of the most recent `state` change, in milliseconds since
+    /// the Unix epoch.
+    pub last_state_change_at: u64,
+    /// Creation timestamp, in milliseconds since the Unix epoch.
+    pub created_at: u64,
+    /// Notarization Method of the verified notarization.
+    pub method: NotarizationMethod,
+    /// Lock metadata of the verified notarization, if any.
+    pub locking: Option<LockMetadata>,
+    /// The owner of the notarization at the time it was fetched.
+    pub owner: Address,
+}
+
+impl VerificationReport {
+    /// Returns `true` if the verified notarization carries any `LockMetadata`.
+    ///
+    /// The locks may still be `None` or expired; use [`Self::is_locked_at`]
+    /// to evaluate them.
+    pub fn has_lock_metadata(&self) -> bool {
+        self.locking.is_some()
+    }
+
+    /// Returns `true` if any lock of the verified notarization is active at
+    /// `now_secs`, in seconds since the Unix epoch.
+    ///
+    /// Evaluates the locks locally, see
+    /// [`OnChainNotarization::lock_status_at`] for the individual locks.
+    pub fn is_locked_at(&self, now_secs: u64) -> bool {
+        self.locking.as_ref().is_some_and(|locks| {
+            locks.is_update_locked_at(now_secs)
+                || locks.is_delete_locked_at(now_secs)
+                || locks.is_transfer_locked_at(now_secs)
+        })
+    }
+}
+
+/// Verifies `payload` against the current `state` of `notarization`.
+///
+/// The comparison is performed on the byte representation of both sides, so a
+/// [`LocalPayload::Text`] matches a notarized [`Data::Bytes`] holding the same
+/// UTF-8 bytes and vice versa. Only the current version of the `state` is
+/// considered; `metadata` is not part of the comparison.
+///
+/// If the notarization holds a [`Digest`] (see
+/// [`State::from_digest`](super::types::State::from_digest)), a
+/// [`LocalPayload::Bytes`] or [`LocalPayload::Text`] payload is hashed with
+/// the notarized algorithm and matches if the digests are equal.
+pub fn verify(notarization: &OnChainNotarization, payload: &LocalPayload) -> VerificationReport {
+    let notarized = match &notarization.state.data {
+        Data::Bytes(bytes) => bytes.as_slice(),
+        Data::Text(text) => text.as_bytes(),
+    };
+    let data_matches = notarized == payload.as_bytes()
+        || match payload {
+            LocalPayload::Bytes(_) | LocalPayload::Text(_) => {
+                Digest::from_bytes(notarized).is_ok_and(|digest| digest.matches(payload.as_bytes()))
+            }
+            LocalPayload::Digest(_) => false,
+        };
+
+    VerificationReport {
+        notarization_id: *notarization.id.object_id(),
+        data_matches,
+        state_version_count: notarization.state_version_count,
+        last_state_change_at: notarization.last_state_change_at,
+        created_at: notarization.immutable_metadata.created_at,
+        method: notarization.method.clone(),
+        locking: notarization.immutable_metadata.locking.clone(),
+        owner: notarization.owner,
+    }
+}
+
+impl OnChainNotarization {
+    /// Verifies `payload` against the current `state` of this notarization.
+    ///
+    /// See [`verify`] for the comparison rules.
+    pub fn verify(&self, payload: &LocalPayload) -> VerificationReport {
+        verify(self, payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::dbg_object_id;
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::{DigestAlgorithm, ImmutableMetadata, State, TimeLock};
+
+    fn make_notarization(state: State) -> OnChainNotarization {
+        OnChainNotarization {
+            id: UID::new(dbg_object_id(1)),
+            state,
+            immutable_metadata: ImmutableMetadata {
+                created_at: 1_000,
+                description: Some("Contract".to_string()),
+                locking: Some(LockMetadata {
+                    update_lock: TimeLock::UntilDestroyed.into(),
+                    delete_lock: TimeLock::None,
+                    transfer_lock: TimeLock::UntilDestroyed,
+                }),
+            },
+            updatable_metadata: None,
+            last_state_change_at: 2_000,
+            state_version_count: 3,
+            method: NotarizationMethod::Locked,
+            owner: Address::ZERO,
+        }
+    }
+
+    #[test]
+    fn verify_matching_bytes() {
+        let notarization = make_notarization(State::from_bytes(vec![1, 2, 3], None));
+        let report = notarization.verify(&LocalPayload::Bytes(vec![1, 2, 3]));
+
+        assert!(report.data_matches);
+        assert!(report.has_lock_metadata());
+        assert!(report.is_locked_at(1_000));
+        assert_eq!(report.notarization_id, dbg_object_id(1));
+        assert_eq!(report.state_version_count, 3);
+        assert_eq!(report.last_state_change_at, 2_000);
+        assert_eq!(report.created_at, 1_000);
+        assert_eq!(report.method, NotarizationMethod::Locked);
+    }
+
+    #[test]
+    fn expired_locks_are_not_reported_as_locked() {
+        let mut notarization = make_notarization(State::from_bytes(vec![1, 2, 3], None));
+        notarization.immutable_metadata.locking = Some(LockMetadata {
+            update_lock: TimeLock::None.into(),
+            delete_lock: TimeLock::UnlockAt(2_000),
+            transfer_lock: TimeLock::None,
+        });
+        let report = notarization.verify(&LocalPayload::Bytes(vec![1, 2, 3]));
+
+        assert!(report.has_lock_metadata());
+        assert!(report.is_locked_at(1_999));
+        assert!(!report.is_locked_at(2_000));
+    }
+
+    #[test]
+    fn verify_mismatching_bytes() {
+        let notarization = make_notarization(State::from_bytes(vec![1, 2, 3], None));
+
+        assert!(!notarization.verify(&LocalPayload::Bytes(vec![1, 2, 4])).data_matches);
+        assert!(!notarization.verify(&LocalPayload::Bytes(vec![])).data_matches);
+    }
+
+    #[test]
+    fn verify_compares_byte_representation_across_kinds() {
+        let notarization = make_notarization(State::from_string("hello".to_string(), None));
+
+        assert!(
+            notarization
+                .verify(&LocalPayload::Text("hello".to_string()))
+                .data_matches
+        );
+        assert!(
+            notarization
+                .verify(&LocalPayload::Bytes(b"hello".to_vec()))
+                .data_matches
+        );
+        assert!(
+            !notarization
+                .verify(&LocalPayload::Text("Hello".to_string()))
+                .data_matches
+        );
+    }
+
+    #[test]
+    fn verify_digest() {
+        let digest = vec![0xAB; 32];
+        let notarization = make_notarization(State::from_bytes(digest.clone(), Some("sha256".to_string())));
+
+        assert!(notarization.verify(&LocalPayload::Digest(digest)).data_matches);
+        assert!(!notarization.verify(&LocalPayload::Digest(vec![0xCD; 32])).data_matches);
+    }
+
+    #[test]
+    fn verify_content_against_notarized_digest() {
+        let digest = Digest::compute(DigestAlgorithm::Sha256, b"contract content");
+        let notarization = make_notarization(State::from_digest(digest.clone(), None));
+
+        assert!(
+            notarization
+                .verify(&LocalPayload::Bytes(b"contract content".to_vec()))
+                .data_matches
+        );
+        assert!(
+            notarization
+                .verify(&LocalPayload::Digest(digest.to_bytes()))
+                .data_matches
+        );
+        assert!(
+            !notarization
+                .verify(&LocalPayload::Bytes(b"tampered content".to_vec()))
+                .data_matches
+        );
+    }
+}
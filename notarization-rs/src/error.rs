@@ -1,6 +1,12 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+use iota_rpc_retry::RetryableError;
+use iota_sdk_types::ObjectId;
+
+use crate::core::types::NotarizationMethod;
 use crate::iota_interaction_adapter::AdapterError;
 
 /// Errors that can occur when managing notarizations
@@ -30,7 +36,7 @@ pub enum Error {
     InvalidArgument(String),
     /// Invalid unlock time
     #[error("Invalid unlock time: {0}")]
-    TimeLock(String),
+    TimeLock(#[from] TimeLockError),
     /// The response from the IOTA node API was not in the expected format.
     #[error("unexpected API response: {0}")]
     UnexpectedApiResponse(String),
@@ -43,6 +49,144 @@ pub enum Error {
     /// Failed to get object with options
     #[error("Failed to get object with options: {0}")]
     ObjectLookup(String),
+    /// The content of a fetched object is missing or could not be decoded.
+    ///
+    /// Unlike [`Error::ObjectLookup`], this error is deterministic and never
+    /// retried.
+    #[error("failed to decode object: {0}")]
+    ObjectDecoding(String),
+    /// The initial `state` of a notarization cannot be read, e.g. because the
+    /// node prunes past object versions.
+    #[error("the initial state of notarization {notarization_id} is not available: {reason}")]
+    InitialStateUnavailable {
+        /// The ID of the notarization.
+        notarization_id: ObjectId,
+        /// Why the initial `state` is not available.
+        reason: String,
+    },
+    /// The notarization was updated while its update lock is active.
+    #[error("the notarization cannot be updated while its update lock is active")]
+    UpdateWhileLocked,
+    /// The notarization was destroyed while its delete or transfer lock is
+    /// active.
+    #[error("the notarization cannot be destroyed while its delete or transfer lock is active")]
+    DestroyWhileLocked,
+    /// The notarization was transferred while its transfer lock is active.
+    #[error("the notarization cannot be transferred while its transfer lock is active")]
+    CannotTransferLocked,
+    /// The delete lock expires before the update or transfer lock.
+    #[error("the delete lock must not expire before the update and transfer locks")]
+    LockTimeNotSatisfied,
+    /// A delete lock was given as `TimeLock::UntilDestroyed`.
+    #[error("a delete lock cannot be `UntilDestroyed`")]
+    UntilDestroyedLockNotAllowed,
+    /// The locks of a notarization violate the invariants of its
+    /// Notarization Method.
+    #[error("the locks violate the invariants of the {0:?} Notarization Method")]
+    MethodInvariantsViolated(NotarizationMethod),
+    /// A notarization that does not use the `Dynamic` Notarization Method was
+    /// sealed.
+    #[error("only Dynamic-Notarizations can be sealed")]
+    NotDynamicNotarization,
+    /// Sealing would allow destroying the notarization earlier than its
+    /// current locks do.
+    #[error("sealing must not shorten the retention of the notarization")]
+    SealShortensRetention,
+    /// A notarization other than a Shared-Notarization was sealed with a
+    /// capability.
+    #[error("only Shared-Notarizations can be sealed with a capability")]
+    NotSharedNotarization,
+    /// A Shared-Notarization was modified without a capability.
+    #[error("Shared-Notarizations can only be modified with a capability")]
+    CapabilityRequired,
+    /// The capability was not issued for the notarization or has been
+    /// revoked.
+    #[error("the capability is not valid for the notarization")]
+    InvalidCapability,
+    /// An operation reserved to the `Admin` role was called with a capability
+    /// of another role.
+    #[error("the operation requires a capability with the Admin role")]
+    AdminRoleRequired,
+    /// The revoked capability is not valid for the notarization.
+    #[error("the capability to revoke is not valid for the notarization")]
+    CapabilityNotFound,
+    /// An endorsement was created with a state hash that is not a SHA2-256
+    /// hash.
+    #[error("the endorsed state hash must be a 32-byte SHA2-256 hash")]
+    InvalidStateHash,
+    /// A time lock was created with an unlock time in the past.
+    #[error("the unlock time of the time lock is in the past")]
+    PastTimestamp,
+    /// A time lock was destroyed before it expired.
+    #[error("the time lock has not expired yet")]
+    TimelockNotExpired,
+    /// A transaction aborted in a Move function with an abort code that has
+    /// no dedicated variant.
+    #[error("{0}")]
+    MoveAbort(MoveAbort),
+    /// Failed to sign a transaction.
+    #[error("failed to sign transaction: {0}")]
+    Signing(String),
+    /// Failed to read from an input stream.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl RetryableError for Error {
+    /// RPC failures and failed object lookups are transient; objects that
+    /// cannot be decoded are not.
+    fn is_transient(&self) -> bool {
+        matches!(self, Error::RpcError(_) | Error::ObjectLookup(_))
+    }
+
+    fn timed_out(timeout: Duration) -> Self {
+        Error::RpcError(format!("call timed out after {timeout:?}"))
+    }
+}
+
+/// A Move abort raised while executing a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveAbort {
+    /// The Move module that aborted, e.g. `notarization`.
+    pub module: String,
+    /// The Move function that aborted, if reported by the node.
+    pub function: Option<String>,
+    /// The abort code.
+    pub code: u64,
+    /// The name of the abort constant, e.g. `EUpdateWhileLocked`, if the
+    /// abort was raised by the Notarization Package.
+    pub name: Option<String>,
+}
+
+impl std::fmt::Display for MoveAbort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "transaction aborted in {}", self.module)?;
+        if let Some(function) = &self.function {
+            write!(f, "::{function}")?;
+        }
+        match &self.name {
+            Some(name) => write!(f, " with {name} (code {})", self.code),
+            None => write!(f, " with code {}", self.code),
+        }
+    }
+}
+
+/// Reasons for rejecting the unlock time of a
+/// [`TimeLock`](crate::core::types::TimeLock).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum TimeLockError {
+    /// The unlock time is not after the current time of the on-chain clock.
+    #[error("unlock time {requested_time} is not after the on-chain clock time {chain_time}")]
+    NotInFuture {
+        /// The requested unlock time, in seconds since the Unix epoch.
+        requested_time: u32,
+        /// The time of the on-chain clock, in seconds since the Unix epoch.
+        chain_time: u64,
+    },
+    /// The unlock time cannot be represented or parsed.
+    #[error("{0}")]
+    Invalid(String),
 }
 
 #[cfg(target_arch = "wasm32")]
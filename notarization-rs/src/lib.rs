@@ -8,7 +8,10 @@ pub mod core;
 pub mod error;
 pub(crate) mod iota_interaction_adapter;
 pub(crate) mod package;
+#[cfg(all(any(test, feature = "test-utils"), not(target_arch = "wasm32")))]
+pub mod test_utils;
 
+pub use crate::core::merkle;
 pub use client::full_client::NotarizationClient;
 pub use client::read_only::NotarizationClientReadOnly;
 /// HTTP utilities to implement the trait [HttpClient](product_common::http_client::HttpClient).
@@ -10,6 +10,9 @@
 
 use std::sync::LazyLock;
 
+use iota_interaction::OptionalSync;
+use iota_sdk_types::ObjectId;
+use product_common::core_client::CoreClientReadOnly;
 use product_common::package_registry::PackageRegistry;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
 
@@ -57,3 +60,24 @@ pub(crate) fn try_notarization_package_registry_mut() -> Result<PackageRegistryL
 pub(crate) fn blocking_notarization_registry_mut() -> PackageRegistryLockMut {
     NOTARIZATION_PACKAGE_REGISTRY.blocking_write()
 }
+
+/// Returns the IDs of all versions of the Notarization Package known for the network
+/// of `client`, oldest first.
+///
+/// Includes the package used by `client`, also if it is missing from the
+/// registry, e.g. for clients created with a custom package ID.
+pub(crate) async fn package_versions<C>(client: &C) -> Vec<ObjectId>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let mut versions = notarization_package_registry()
+        .await
+        .history(client.network_name())
+        .map(<[ObjectId]>::to_vec)
+        .unwrap_or_default();
+    if !versions.contains(&client.package_id()) {
+        versions.push(client.package_id());
+    }
+
+    versions
+}
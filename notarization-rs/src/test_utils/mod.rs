@@ -0,0 +1,561 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Test Utilities
+//!
+//! An in-memory mock ledger for testing code built on this crate without an
+//! IOTA node.
+//!
+//! ## Overview
+//!
+//! [`MockLedger`] simulates the Move functions of the Notarization Package
+//! in-process and answers the JSON-RPC API of an IOTA node from the simulated
+//! state. As the `IotaClient` of the IOTA SDK only speaks JSON-RPC over HTTP,
+//! the requests of its clients reach the simulation through the loopback
+//! transport of [`iota_mock_rpc`]; no other network access is needed. Clients
+//! created via [`MockLedger::read_only_client`] and [`MockLedger::client`] are
+//! regular [`NotarizationClientReadOnly`]s and [`NotarizationClient`]s, so the
+//! code under test runs unchanged. [`MockLedger`] itself implements
+//! [`CoreClientReadOnly`] and can be passed wherever a read-only client is
+//! expected, e.g. to build transactions.
+//!
+//! The simulation covers the state transitions, lock checks, abort codes and
+//! events of the package, including the decoding of aborts into typed
+//! [`Error`] variants. The chain clock only moves via
+//! [`MockLedger::set_clock_ms`] and [`MockLedger::advance_clock`], which makes
+//! time locks deterministic to test. Signatures are not verified.
+//!
+//! Requires the `test-utils` feature.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use notarization::test_utils::MockLedger;
+//! use product_common::test_utils::InMemSigner;
+//!
+//! let ledger = MockLedger::start().await?;
+//! let client = ledger.client(InMemSigner::new()).await?;
+//!
+//! let notarization_id = client
+//!     .create_dynamic_notarization()
+//!     .with_string_state("draft".to_string(), None)
+//!     .finish()
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output
+//!     .id
+//!     .object_id();
+//! ledger.advance_clock(Duration::from_secs(60));
+//! ```
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iota_interaction::{IotaClient, IotaClientBuilder, IotaKeySignature, OptionalSync};
+use iota_mock_rpc::Params;
+use iota_sdk_types::{Address, ObjectId};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::network_name::NetworkName;
+use secret_storage::Signer;
+
+use crate::client::{NotarizationClient, NotarizationClientReadOnly};
+use crate::error::Error;
+use crate::iota_interaction_adapter::IotaClientAdapter;
+
+mod package;
+
+use self::package::{Ledger, NotarizationPackage};
+
+/// The [`ObjectId`] of the Notarization Package simulated by [`MockLedger`].
+pub const MOCK_PACKAGE_ID: ObjectId = ObjectId::new([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x6d, 0x6f, 0x63, 0x6b,
+]);
+
+/// The chain identifier reported by [`MockLedger`].
+pub const MOCK_CHAIN_ID: &str = "6d6f636b";
+
+/// Balance of the gas coin minted by [`MockLedger::client`], in NANOS.
+pub const DEFAULT_FUNDING: u64 = 1_000_000_000_000;
+
+/// An in-memory ledger simulating an IOTA node with the Notarization Package
+/// published.
+///
+/// Cloning a [`MockLedger`] yields another handle to the same ledger.
+#[derive(Clone)]
+pub struct MockLedger {
+    ledger: Arc<Mutex<Ledger>>,
+    address: SocketAddr,
+    iota_client: IotaClientAdapter,
+    network: NetworkName,
+}
+
+impl MockLedger {
+    /// Starts a mock ledger and serves its JSON-RPC API to the clients
+    /// created from it.
+    ///
+    /// The chain clock starts at the current system time. The transport runs
+    /// on the current Tokio runtime until the runtime shuts down.
+    pub async fn start() -> Result<Self, Error> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.as_millis() as u64);
+        let ledger = Arc::new(Mutex::new(Ledger::new(
+            NotarizationPackage,
+            MOCK_CHAIN_ID.to_string(),
+            now_ms,
+        )));
+        let handler = {
+            let ledger = ledger.clone();
+            move |method: &str, params: &Params| {
+                iota_mock_rpc::dispatch(&mut ledger.lock().expect("mock ledger is not poisoned"), method, params)
+            }
+        };
+        let address = iota_mock_rpc::serve(handler).await?;
+
+        let iota_client = IotaClientAdapter::new(connect(address).await?);
+        let network = NetworkName::try_from(MOCK_CHAIN_ID).expect("mock chain ID is a valid network name");
+
+        Ok(Self {
+            ledger,
+            address,
+            iota_client,
+            network,
+        })
+    }
+
+    /// Returns the URL of the JSON-RPC API.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates an [`IotaClient`] connected to this ledger.
+    pub async fn iota_client(&self) -> Result<IotaClient, Error> {
+        connect(self.address).await
+    }
+
+    /// Creates a [`NotarizationClientReadOnly`] connected to this ledger.
+    pub async fn read_only_client(&self) -> Result<NotarizationClientReadOnly, Error> {
+        NotarizationClientReadOnly::new_with_pkg_id(self.iota_client().await?, MOCK_PACKAGE_ID).await
+    }
+
+    /// Creates a [`NotarizationClient`] connected to this ledger and funds the
+    /// address of `signer` with [`DEFAULT_FUNDING`].
+    pub async fn client<S>(&self, signer: S) -> Result<NotarizationClient<S>, Error>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let client = NotarizationClient::new(self.read_only_client().await?, signer).await?;
+        self.fund(client.sender_address(), DEFAULT_FUNDING);
+
+        Ok(client)
+    }
+
+    /// Mints a gas coin with `amount` NANOS owned by `address`.
+    pub fn fund(&self, address: Address, amount: u64) -> ObjectId {
+        self.lock().mint_gas(address, amount)
+    }
+
+    /// Returns the time of the chain clock, in milliseconds since the Unix
+    /// epoch.
+    pub fn clock_ms(&self) -> u64 {
+        self.lock().clock_ms
+    }
+
+    /// Sets the time of the chain clock, in milliseconds since the Unix epoch.
+    pub fn set_clock_ms(&self, clock_ms: u64) {
+        self.lock().clock_ms = clock_ms;
+    }
+
+    /// Moves the chain clock forward by `duration`.
+    pub fn advance_clock(&self, duration: Duration) {
+        self.lock().clock_ms += duration.as_millis() as u64;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ledger> {
+        self.ledger.lock().expect("mock ledger is not poisoned")
+    }
+}
+
+#[async_trait::async_trait]
+impl CoreClientReadOnly for MockLedger {
+    /// Returns [`MOCK_PACKAGE_ID`].
+    fn package_id(&self) -> ObjectId {
+        MOCK_PACKAGE_ID
+    }
+
+    /// Returns the network named after [`MOCK_CHAIN_ID`].
+    fn network_name(&self) -> &NetworkName {
+        &self.network
+    }
+
+    /// Returns an [`IotaClientAdapter`] connected to this ledger.
+    fn client_adapter(&self) -> &IotaClientAdapter {
+        &self.iota_client
+    }
+}
+
+async fn connect(address: SocketAddr) -> Result<IotaClient, Error> {
+    IotaClientBuilder::default()
+        .build(format!("http://{address}"))
+        .await
+        .map_err(|e| Error::RpcError(format!("failed to connect to the mock ledger: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use futures::{StreamExt, TryStreamExt};
+    use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+    use iota_interaction::{MoveType, ident_str};
+    use iota_sdk_types::{Argument, TypeTag};
+    use product_common::test_utils::InMemSigner;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::client::offline::{GasConfig, OfflineTransaction, UnsignedTransaction};
+    use crate::client::{RetryPolicy, WatchOptions};
+    use crate::core::dry_run::DryRun;
+    use crate::core::move_utils;
+    use crate::core::transactions::UpdateState;
+    use crate::core::types::{
+        GenericData, NotarizationEvent, NotarizationMethod, NotarizationUpdated, State, TimeLock, UpdateLock,
+    };
+
+    /// Mirrors the `document::Document` struct of [`package::document`].
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Document {
+        version: u64,
+        author: Address,
+        content: Vec<u8>,
+    }
+
+    impl MoveType for Document {
+        fn move_type(package: ObjectId) -> TypeTag {
+            TypeTag::from_str(&format!("{package}::document::Document")).expect("should be valid type tag")
+        }
+    }
+
+    impl GenericData for Document {
+        fn move_type_tag() -> TypeTag {
+            Self::move_type(package::document::PACKAGE_ID)
+        }
+
+        fn to_ptb_argument(&self, ptb: &mut ProgrammableTransactionBuilder) -> Result<Argument, Error> {
+            let version = move_utils::ptb_pure(ptb, "version", self.version)?;
+            let author = move_utils::ptb_pure(ptb, "author", self.author)?;
+            let content = move_utils::ptb_pure(ptb, "content", self.content.clone())?;
+
+            Ok(ptb.programmable_move_call(
+                package::document::PACKAGE_ID,
+                ident_str!("document").as_str().into(),
+                ident_str!("new").as_str().into(),
+                vec![],
+                vec![version, author, content],
+            ))
+        }
+    }
+
+    async fn create_dynamic(
+        client: &NotarizationClient<InMemSigner>,
+        update_lock: impl Into<UpdateLock>,
+    ) -> anyhow::Result<ObjectId> {
+        Ok(client
+            .create_dynamic_notarization()
+            .with_string_state("draft".to_string(), None)
+            .with_update_lock(update_lock)
+            .finish()
+            .build_and_execute(client)
+            .await?
+            .output
+            .id
+            .object_id())
+    }
+
+    #[tokio::test]
+    async fn updates_are_read_back() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let notarization_id = create_dynamic(&client, TimeLock::None).await?;
+
+        client
+            .update_state(State::from_string("final".to_string(), None), notarization_id)
+            .build_and_execute(&client)
+            .await?;
+
+        let notarization = client.get_notarization_by_id(notarization_id).await?;
+        assert_eq!(client.package_id(), ledger.package_id());
+        assert_eq!(notarization.state_version_count, 1);
+        assert_eq!(notarization.method, NotarizationMethod::Dynamic);
+        assert_eq!(notarization.owner, client.sender_address());
+        assert_eq!(client.state(notarization_id).await?.data().clone().as_text()?, "final");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_lock_follows_the_clock() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let unlock_at = (ledger.clock_ms() / 1000 + 60) as u32;
+        let notarization_id = create_dynamic(&client, TimeLock::UnlockAt(unlock_at)).await?;
+
+        let result = client
+            .update_state(State::from_string("early".to_string(), None), notarization_id)
+            .dry_run(&client)
+            .await;
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+        assert!(client.is_update_locked(notarization_id).await?);
+
+        ledger.advance_clock(Duration::from_secs(61));
+        assert!(!client.is_update_locked(notarization_id).await?);
+        client
+            .update_state(State::from_string("late".to_string(), None), notarization_id)
+            .build_and_execute(&client)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn offline_transactions_are_submitted_from_exported_data() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let notarization_id = create_dynamic(&client, TimeLock::None).await?;
+        let gas_coin = ledger.fund(client.sender_address(), DEFAULT_FUNDING);
+        let gas = GasConfig {
+            payment: vec![move_utils::get_object_ref_by_id(&client, &gas_coin, &RetryPolicy::none()).await?],
+            price: 1000,
+            budget: 10_000_000,
+        };
+        let new_state = State::from_string("signed offline".to_string(), None);
+
+        let exported = OfflineTransaction::build(
+            client.update_state(new_state.clone(), notarization_id),
+            &client,
+            client.sender_address(),
+            gas,
+        )
+        .await?
+        .unsigned()
+        .to_json()?;
+
+        // The online host restarted: only the exported data and the signature are left
+        let unsigned = UnsignedTransaction::from_json(&exported)?;
+        let signature = unsigned.sign(client.signer()).await?;
+        let updated = OfflineTransaction::from_unsigned(UpdateState::new(new_state, notarization_id), unsigned)
+            .submit(&client, signature)
+            .await?;
+        assert_eq!(updated.state_version_count, 1);
+        assert_eq!(
+            client.state(notarization_id).await?.data().clone().as_text()?,
+            "signed offline"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batched_reads_resolve_the_locks_stored_in_dynamic_fields() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let lock_time = (ledger.clock_ms() / 1000 + 60) as u32;
+        let frozen_id = create_dynamic(&client, UpdateLock::LockAt(lock_time)).await?;
+        let open_id = create_dynamic(&client, TimeLock::None).await?;
+        let shared_id = client
+            .create_shared_notarization()
+            .with_string_state("draft".to_string(), None)
+            .with_update_lock(TimeLock::UnlockAt(lock_time))
+            .finish()
+            .build_and_execute(&client)
+            .await?
+            .output
+            .notarization
+            .id
+            .object_id();
+
+        let notarizations = client.get_notarizations(&[frozen_id, open_id, shared_id]).await?;
+        let update_locks: Vec<_> = notarizations
+            .iter()
+            .map(|notarization| {
+                let locking = notarization.immutable_metadata.locking.as_ref();
+                (
+                    notarization.method.clone(),
+                    locking.map(|locks| locks.update_lock.clone()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            update_locks,
+            [
+                (NotarizationMethod::Dynamic, Some(UpdateLock::LockAt(lock_time))),
+                (NotarizationMethod::Dynamic, None),
+                (NotarizationMethod::Shared, Some(TimeLock::UnlockAt(lock_time).into())),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shared_notarizations_are_sealed_with_the_admin_capability() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let created = client
+            .create_shared_notarization()
+            .with_string_state("draft".to_string(), None)
+            .finish()
+            .build_and_execute(&client)
+            .await?
+            .output;
+        let notarization_id = created.notarization.id.object_id();
+
+        let sealed = client
+            .seal_with_capability(notarization_id, created.admin_capability_id, TimeLock::None)
+            .build_and_execute(&client)
+            .await?
+            .output;
+        assert_eq!(sealed.notarization_id, notarization_id);
+
+        let notarization = client.get_notarization_by_id(notarization_id).await?;
+        assert_eq!(notarization.method, NotarizationMethod::Shared);
+        assert_eq!(
+            notarization.immutable_metadata.locking.map(|locks| locks.update_lock),
+            Some(TimeLock::UntilDestroyed.into())
+        );
+        let result = client
+            .update_state_with_capability(
+                State::from_string("late".to_string(), None),
+                notarization_id,
+                created.admin_capability_id,
+            )
+            .dry_run(&client)
+            .await;
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generic_notarizations_are_read_back_and_watched() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let notarization_id = client
+            .create_dynamic_notarization()
+            .with_generic_state(vec![21u64], None)
+            .finish()
+            .build_and_execute(&client)
+            .await?
+            .output
+            .id
+            .object_id();
+
+        assert_eq!(
+            client.state_as::<Vec<u64>>(notarization_id).await?,
+            State::from_generic(vec![21u64], None)
+        );
+        let result = client.state_as::<Vec<u32>>(notarization_id).await;
+        assert!(matches!(result, Err(Error::DeserializationError(_))), "got {result:?}");
+        let history = client.state_history_as::<Vec<u64>>(notarization_id).await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].state, State::from_generic(vec![21u64], None));
+
+        // `NotarizationClient::update_state` only takes `vector<u8>` and `String` payloads.
+        let (_, events) = ledger
+            .lock()
+            .transact(client.sender_address(), |session| {
+                let data = bcs::to_bytes(&vec![21u64, 22]).expect("vectors are serializable");
+                let metadata = bcs::to_bytes(&Some("second reading")).expect("options are serializable");
+                let state = package::call(
+                    session,
+                    "notarization",
+                    "new_state_from_generic",
+                    &["vector<u64>".to_string()],
+                    vec![iota_mock_rpc::Value::Pure(data), iota_mock_rpc::Value::Pure(metadata)],
+                )?;
+                package::call(
+                    session,
+                    "notarization",
+                    "update_state",
+                    &[],
+                    vec![iota_mock_rpc::Value::Object(notarization_id), state[0].clone()],
+                )
+            })
+            .map_err(|failure| anyhow::anyhow!(failure.describe(0)))?;
+        let updated = NotarizationUpdated::<Vec<u64>>::from_bcs(&events[0].bcs)?;
+        assert_eq!(updated.state_version_count, 1);
+        assert_eq!(
+            updated.updated_state,
+            State::from_generic(vec![21u64, 22], Some("second reading".to_string()))
+        );
+
+        let watched = client
+            .watch(notarization_id, WatchOptions::default())
+            .take(2)
+            .map(|watched| watched.map(|watched| watched.event))
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert!(matches!(watched[0], NotarizationEvent::DynamicNotarizationCreated(_)));
+        let NotarizationEvent::GenericNotarizationUpdated(updated) = &watched[1] else {
+            panic!("expected GenericNotarizationUpdated, got {:?}", watched[1]);
+        };
+        assert_eq!(updated.data_type, TypeTag::Vector(Box::new(TypeTag::U64)));
+        assert_eq!(updated.state_version_count, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn struct_notarizations_are_read_back() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let document = Document {
+            version: 3,
+            author: Address::new([0xd; 32]),
+            content: b"%PDF-1.7".to_vec(),
+        };
+        let notarization_id = client
+            .create_dynamic_notarization()
+            .with_generic_state(document.clone(), Some("Contract".to_string()))
+            .finish()
+            .build_and_execute(&client)
+            .await?
+            .output
+            .id
+            .object_id();
+
+        assert_eq!(
+            move_utils::get_type_tag(&client, &notarization_id, &RetryPolicy::none()).await?,
+            Document::move_type_tag()
+        );
+        let expected = State::from_generic(document, Some("Contract".to_string()));
+        assert_eq!(client.state_as::<Document>(notarization_id).await?, expected);
+        let history = client.state_history_as::<Document>(notarization_id).await?;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].state, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn locked_notarizations_cannot_be_sealed() -> anyhow::Result<()> {
+        let ledger = MockLedger::start().await?;
+        let client = ledger.client(InMemSigner::new()).await?;
+        let notarization_id = client
+            .create_locked_notarization()
+            .with_string_state("final".to_string(), None)
+            .with_delete_lock(TimeLock::None)
+            .finish()?
+            .build_and_execute(&client)
+            .await?
+            .output
+            .id
+            .object_id();
+
+        let result = client.seal(notarization_id, TimeLock::None).dry_run(&client).await;
+        assert!(matches!(result, Err(Error::NotDynamicNotarization)), "got {result:?}");
+
+        Ok(())
+    }
+}
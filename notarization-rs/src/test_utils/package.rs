@@ -0,0 +1,1951 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Package
+//!
+//! Simulation of the Move functions of the Notarization Package.
+//!
+//! The functions mirror the state transitions, abort codes and events of the
+//! `notarization`, `dynamic_notarization`, `locked_notarization`,
+//! `shared_notarization`, `timelock`, `lock_at` and `endorsement` modules, so that
+//! aborts are decoded into the same [`Error`](crate::error::Error) variants
+//! as on a real network.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use iota_mock_rpc::{DynamicField, Failure, Owner, Packages};
+use iota_sdk_types::{Address, ObjectId, TypeTag};
+use serde::de::DeserializeOwned;
+use serde_json::{Value as Json, json};
+
+use super::MOCK_PACKAGE_ID;
+use crate::core::abort;
+use crate::core::types::{ImmutableMetadata, LockMetadata, NotarizationMethod, Role, TimeLock, UpdateLock};
+
+/// A mock ledger with the Notarization Package published at
+/// [`MOCK_PACKAGE_ID`].
+pub(super) type Ledger = iota_mock_rpc::Ledger<NotarizationPackage>;
+
+type Session = iota_mock_rpc::Session<NotarizationPackage>;
+
+type Value = iota_mock_rpc::Value<PackageValue>;
+
+/// The Notarization Package, as simulated by the mock ledger.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct NotarizationPackage;
+
+/// An object of the Notarization Package.
+#[derive(Debug, Clone)]
+pub(super) enum MoveObject {
+    /// A `notarization::Notarization<D>`.
+    Notarization(Box<Notarization>),
+    /// A `shared_notarization::Capability`.
+    Capability { notarization_id: ObjectId, role: Role },
+    /// An `endorsement::Endorsement`.
+    Endorsement {
+        notarization_id: ObjectId,
+        endorser: Address,
+        state_version_count: u64,
+        state_hash: Vec<u8>,
+        endorsed_at: u64,
+    },
+}
+
+/// Name of the dynamic field storing the `lock_at::LockAt` of a
+/// Dynamic-Notarization.
+const UPDATE_LOCK_AT_KEY: &str = "update_lock_at";
+
+/// Name of the dynamic field storing the `LockMetadata` of a
+/// Shared-Notarization.
+const SHARED_LOCK_METADATA_KEY: &str = "shared_lock_metadata";
+
+/// A `notarization::Notarization<D>`.
+#[derive(Debug, Clone)]
+pub(super) struct Notarization {
+    state: State,
+    immutable_metadata: ImmutableMetadata,
+    updatable_metadata: Option<String>,
+    last_state_change_at: u64,
+    state_version_count: u64,
+    method: NotarizationMethod,
+    /// The IDs stored in the `CapabilitiesKey` dynamic field of
+    /// Shared-Notarizations.
+    capabilities: BTreeSet<ObjectId>,
+    /// The lock time of the `LockAt` stored in the `update_lock_at` dynamic
+    /// field of Dynamic-Notarizations.
+    update_lock_at: Option<u32>,
+    /// The `LockMetadata` stored in the `shared_lock_metadata` dynamic field of
+    /// Shared-Notarizations, whose own `LockMetadata` only guards them
+    /// against package versions unaware of Shared-Notarizations.
+    shared_locking: Option<LockMetadata>,
+}
+
+/// A `notarization::State<D>` with its data kept as BCS.
+#[derive(Debug, Clone)]
+pub(super) struct State {
+    type_arg: String,
+    data: Vec<u8>,
+    metadata: Option<String>,
+}
+
+/// A non-object value of the Notarization Package passed between Move calls.
+#[derive(Debug, Clone)]
+pub(super) enum PackageValue {
+    State(State),
+    TimeLock(TimeLock),
+    LockAt(u32),
+    Role(Role),
+}
+
+impl Packages for NotarizationPackage {
+    type Object = MoveObject;
+    type Value = PackageValue;
+
+    fn modules(&self) -> Vec<(ObjectId, BTreeMap<String, Vec<u8>>)> {
+        vec![(MOCK_PACKAGE_ID, BTreeMap::new())]
+    }
+
+    fn call(
+        session: &mut Session,
+        package_id: ObjectId,
+        module: &str,
+        function: &str,
+        type_arguments: &[String],
+        arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, Failure> {
+        match package_id {
+            id if id == MOCK_PACKAGE_ID => call(session, module, function, type_arguments, arguments),
+            #[cfg(test)]
+            id if id == document::PACKAGE_ID => document::call(module, function, arguments),
+            _ => Err(Failure::Other(format!("package {package_id} is not published"))),
+        }
+    }
+
+    fn object_type(&self, object: &MoveObject) -> String {
+        object.type_()
+    }
+
+    fn has_public_transfer(&self, object: &MoveObject) -> bool {
+        object.has_public_transfer()
+    }
+
+    fn object_bcs(&self, id: ObjectId, object: &MoveObject) -> Vec<u8> {
+        let mut bcs = to_bcs(&id);
+        bcs.extend(object.bcs_fields());
+        bcs
+    }
+
+    fn object_json(&self, id: ObjectId, object: &MoveObject) -> Json {
+        object.fields_json(id)
+    }
+
+    fn dynamic_fields(&self, id: ObjectId, object: &MoveObject) -> Vec<DynamicField> {
+        object.dynamic_fields(id)
+    }
+
+    fn value_type(&self, value: &PackageValue) -> String {
+        value.type_()
+    }
+
+    fn value_bcs(&self, value: &PackageValue) -> Vec<u8> {
+        value.bcs()
+    }
+}
+
+impl MoveObject {
+    /// Returns the Move type of the object.
+    fn type_(&self) -> String {
+        match self {
+            MoveObject::Notarization(notarization) => {
+                format!(
+                    "{MOCK_PACKAGE_ID}::notarization::Notarization<{}>",
+                    notarization.state.type_arg
+                )
+            }
+            MoveObject::Capability { .. } => format!("{MOCK_PACKAGE_ID}::shared_notarization::Capability"),
+            MoveObject::Endorsement { .. } => format!("{MOCK_PACKAGE_ID}::endorsement::Endorsement"),
+        }
+    }
+
+    /// Returns whether the object has the `store` ability.
+    fn has_public_transfer(&self) -> bool {
+        matches!(self, MoveObject::Capability { .. })
+    }
+
+    /// Returns the BCS of the fields following the `id` of the object.
+    fn bcs_fields(&self) -> Vec<u8> {
+        match self {
+            MoveObject::Notarization(notarization) => {
+                let mut bcs = notarization.state.bcs();
+                bcs.extend(to_bcs(&notarization.immutable_metadata));
+                bcs.extend(to_bcs(&notarization.updatable_metadata));
+                bcs.extend(to_bcs(&notarization.last_state_change_at));
+                bcs.extend(to_bcs(&notarization.state_version_count));
+                bcs.extend(to_bcs(&notarization.method));
+                bcs
+            }
+            MoveObject::Capability { notarization_id, role } => {
+                let mut bcs = to_bcs(notarization_id);
+                bcs.extend(to_bcs(role));
+                bcs
+            }
+            MoveObject::Endorsement {
+                notarization_id,
+                endorser,
+                state_version_count,
+                state_hash,
+                endorsed_at,
+            } => {
+                let mut bcs = to_bcs(notarization_id);
+                bcs.extend(to_bcs(endorser));
+                bcs.extend(to_bcs(state_version_count));
+                bcs.extend(to_bcs(state_hash));
+                bcs.extend(to_bcs(endorsed_at));
+                bcs
+            }
+        }
+    }
+
+    /// Returns the fields of the object as rendered in the parsed content of
+    /// object responses.
+    fn fields_json(&self, id: ObjectId) -> Json {
+        match self {
+            MoveObject::Notarization(notarization) => json!({
+                "id": { "id": id.to_string() },
+                "state": {
+                    "data": notarization.state.data_json(),
+                    "metadata": notarization.state.metadata,
+                },
+                "updatable_metadata": notarization.updatable_metadata,
+                "last_state_change_at": notarization.last_state_change_at.to_string(),
+                "state_version_count": notarization.state_version_count.to_string(),
+            }),
+            MoveObject::Capability { notarization_id, role } => json!({
+                "id": { "id": id.to_string() },
+                "notarization_id": notarization_id.to_string(),
+                "role": { "variant": format!("{role:?}"), "fields": {} },
+            }),
+            MoveObject::Endorsement {
+                notarization_id,
+                endorser,
+                state_version_count,
+                state_hash,
+                endorsed_at,
+            } => json!({
+                "id": { "id": id.to_string() },
+                "notarization_id": notarization_id.to_string(),
+                "endorser": endorser.to_string(),
+                "state_version_count": state_version_count.to_string(),
+                "state_hash": state_hash,
+                "endorsed_at": endorsed_at.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the dynamic fields attached to the object, whose ID is `id`.
+    fn dynamic_fields(&self, id: ObjectId) -> Vec<DynamicField> {
+        let MoveObject::Notarization(notarization) = self else {
+            return Vec::new();
+        };
+        let string_field = |name: &str, value_type: String, value: Vec<u8>| DynamicField {
+            parent: id,
+            name_type: TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
+                .expect("the type tag of `String` is valid"),
+            name: to_bcs(name),
+            value_type,
+            value,
+        };
+
+        let mut fields = Vec::new();
+        if let Some(lock_time) = notarization.update_lock_at {
+            fields.push(string_field(
+                UPDATE_LOCK_AT_KEY,
+                format!("{MOCK_PACKAGE_ID}::lock_at::LockAt"),
+                to_bcs(&lock_time),
+            ));
+        }
+        if let Some(locking) = &notarization.shared_locking {
+            fields.push(string_field(
+                SHARED_LOCK_METADATA_KEY,
+                format!("{MOCK_PACKAGE_ID}::notarization::LockMetadata"),
+                to_bcs(locking),
+            ));
+        }
+        fields
+    }
+}
+
+impl State {
+    fn bcs(&self) -> Vec<u8> {
+        let mut bcs = self.data.clone();
+        bcs.extend(to_bcs(&self.metadata));
+        bcs
+    }
+
+    /// Renders the data like the parsed JSON of events: byte vectors as
+    /// arrays of numbers, strings as strings.
+    fn data_json(&self) -> Json {
+        if self.type_arg == "vector<u8>" {
+            json!(bcs::from_bytes::<Vec<u8>>(&self.data).unwrap_or_default())
+        } else if self.type_arg.ends_with("::string::String") {
+            json!(bcs::from_bytes::<String>(&self.data).unwrap_or_default())
+        } else {
+            json!(self.data)
+        }
+    }
+}
+
+impl PackageValue {
+    /// Returns the BCS of the value.
+    fn bcs(&self) -> Vec<u8> {
+        match self {
+            PackageValue::State(state) => state.bcs(),
+            PackageValue::TimeLock(lock) => to_bcs(lock),
+            PackageValue::LockAt(lock_time) => to_bcs(lock_time),
+            PackageValue::Role(role) => to_bcs(role),
+        }
+    }
+
+    /// Returns the Move type of the value.
+    fn type_(&self) -> String {
+        match self {
+            PackageValue::State(state) => format!("{MOCK_PACKAGE_ID}::notarization::State<{}>", state.type_arg),
+            PackageValue::TimeLock(_) => format!("{MOCK_PACKAGE_ID}::timelock::TimeLock"),
+            PackageValue::LockAt(_) => format!("{MOCK_PACKAGE_ID}::lock_at::LockAt"),
+            PackageValue::Role(_) => format!("{MOCK_PACKAGE_ID}::shared_notarization::Role"),
+        }
+    }
+}
+
+impl Notarization {
+    /// Returns the `LockMetadata` the locks of the notarization are checked
+    /// against: the `shared_lock_metadata` of Shared-Notarizations, and the
+    /// object's own `LockMetadata` otherwise.
+    fn locking(&self) -> Option<&LockMetadata> {
+        self.shared_locking
+            .as_ref()
+            .or(self.immutable_metadata.locking.as_ref())
+    }
+
+    /// Mirrors `notarization::is_shared`.
+    fn is_shared(&self) -> bool {
+        self.shared_locking.is_some()
+    }
+
+    /// Mirrors `notarization::is_update_locked`; Locked- and
+    /// Shared-Notarizations always carry lock metadata.
+    fn is_update_locked(&self, now_secs: u64) -> bool {
+        match self.update_lock_at {
+            Some(lock_time) => UpdateLock::LockAt(lock_time).is_timelocked_at(now_secs),
+            None => self.locking().is_some_and(|locks| locks.is_update_locked_at(now_secs)),
+        }
+    }
+
+    fn is_delete_locked(&self, now_secs: u64) -> bool {
+        self.locking().is_some_and(|locks| locks.is_delete_locked_at(now_secs))
+    }
+
+    fn is_transfer_locked(&self, now_secs: u64) -> bool {
+        self.locking()
+            .is_some_and(|locks| locks.is_transfer_locked_at(now_secs))
+    }
+
+    fn is_destroy_allowed(&self, now_secs: u64) -> bool {
+        !self.locking().is_some_and(|locks| {
+            locks.update_lock.is_timelocked_unlock_at(now_secs)
+                || locks.delete_lock.is_timelocked_unlock_at(now_secs)
+                || locks.transfer_lock.is_timelocked_unlock_at(now_secs)
+        })
+    }
+}
+
+/// Calls the simulated Move function `module::function`.
+pub(super) fn call(
+    session: &mut Session,
+    module: &str,
+    function: &str,
+    type_arguments: &[String],
+    arguments: Vec<Value>,
+) -> Result<Vec<Value>, Failure> {
+    let mut args = Args(arguments.into_iter());
+    let now_secs = session.now_ms / 1000;
+
+    match (module, function) {
+        ("timelock", "unlock_at") => {
+            let time: u32 = args.pure()?;
+            if u64::from(time) <= now_secs {
+                return Err(abort("timelock", "unlock_at", "EPastTimestamp"));
+            }
+            Ok(vec![Value::Package(PackageValue::TimeLock(TimeLock::UnlockAt(time)))])
+        }
+        ("timelock", "until_destroyed") => Ok(vec![Value::Package(PackageValue::TimeLock(TimeLock::UntilDestroyed))]),
+        ("timelock", "none") => Ok(vec![Value::Package(PackageValue::TimeLock(TimeLock::None))]),
+        ("lock_at", "new") => {
+            let time: u32 = args.pure()?;
+            if u64::from(time) <= now_secs {
+                return Err(abort("lock_at", "new", "EPastTimestamp"));
+            }
+            Ok(vec![Value::Package(PackageValue::LockAt(time))])
+        }
+
+        ("notarization", "new_state_from_bytes" | "new_state_from_string" | "new_state_from_generic") => {
+            let data = args.pure_bytes()?;
+            let type_arg = match function {
+                "new_state_from_bytes" => {
+                    decode::<Vec<u8>>(&data)?;
+                    "vector<u8>".to_string()
+                }
+                "new_state_from_string" => {
+                    decode::<String>(&data)?;
+                    format!("{MOVE_STDLIB_PACKAGE_ID}::string::String")
+                }
+                _ => type_argument(type_arguments)?,
+            };
+            let metadata = args.pure()?;
+            Ok(vec![Value::Package(PackageValue::State(State {
+                type_arg,
+                data,
+                metadata,
+            }))])
+        }
+        ("notarization", "update_state") => {
+            let id = args.object()?;
+            let state = args.state()?;
+            if notarization(session, id)?.is_shared() {
+                return Err(abort("notarization", "update_state", "ECapabilityRequired"));
+            }
+            update_state(session, id, state)?;
+            Ok(Vec::new())
+        }
+        ("notarization", "update_metadata") => {
+            let id = args.object()?;
+            let metadata = args.pure()?;
+            if notarization(session, id)?.is_shared() {
+                return Err(abort("notarization", "update_metadata", "ECapabilityRequired"));
+            }
+            update_metadata(session, id, metadata)?;
+            Ok(Vec::new())
+        }
+        ("notarization", "destroy") => {
+            let id = args.object()?;
+            if notarization(session, id)?.is_shared() {
+                return Err(abort("notarization", "destroy", "ECapabilityRequired"));
+            }
+            destroy(session, id)?;
+            Ok(Vec::new())
+        }
+        ("notarization", getter) => {
+            let notarization = notarization(session, args.object()?)?;
+            let (bcs, type_) = match getter {
+                "is_update_locked" => (to_bcs(&notarization.is_update_locked(now_secs)), "bool".to_string()),
+                "is_delete_locked" => (to_bcs(&notarization.is_delete_locked(now_secs)), "bool".to_string()),
+                "is_transfer_locked" => (to_bcs(&notarization.is_transfer_locked(now_secs)), "bool".to_string()),
+                "is_destroy_allowed" => (to_bcs(&notarization.is_destroy_allowed(now_secs)), "bool".to_string()),
+                "is_shared" => (to_bcs(&notarization.is_shared()), "bool".to_string()),
+                "last_change" => (to_bcs(&notarization.last_state_change_at), "u64".to_string()),
+                "version_count" => (to_bcs(&notarization.state_version_count), "u64".to_string()),
+                "created_at" => (to_bcs(&notarization.immutable_metadata.created_at), "u64".to_string()),
+                "description" => (
+                    to_bcs(&notarization.immutable_metadata.description),
+                    option_string_type(),
+                ),
+                "updatable_metadata" => (to_bcs(&notarization.updatable_metadata), option_string_type()),
+                "notarization_method" => (
+                    to_bcs(&notarization.method),
+                    format!("{MOCK_PACKAGE_ID}::method::NotarizationMethod"),
+                ),
+                "lock_metadata" => (
+                    to_bcs(&notarization.immutable_metadata.locking),
+                    format!("{MOVE_STDLIB_PACKAGE_ID}::option::Option<{MOCK_PACKAGE_ID}::notarization::LockMetadata>"),
+                ),
+                "state" => return Ok(vec![Value::Package(PackageValue::State(notarization.state.clone()))]),
+                _ => return Err(unsupported(module, function)),
+            };
+            Ok(vec![Value::Move { bcs, type_ }])
+        }
+
+        (
+            "dynamic_notarization",
+            "create" | "create_with_delete_lock" | "create_with_locks" | "create_with_lock_at",
+        )
+        | ("dynamic_notarization", "new" | "new_with_delete_lock" | "new_with_locks" | "new_with_lock_at") => {
+            let state = args.state()?;
+            let description = args.pure()?;
+            let updatable_metadata = args.pure()?;
+            let (update_lock, update_lock_at) = if function.ends_with("with_locks") {
+                (args.timelock()?, None)
+            } else if function.ends_with("with_lock_at") {
+                (TimeLock::UntilDestroyed, Some(args.lock_at()?))
+            } else {
+                (TimeLock::None, None)
+            };
+            let delete_lock = if function.contains("_with_") {
+                args.timelock()?
+            } else {
+                TimeLock::None
+            };
+            let transfer_lock = args.timelock()?;
+
+            let locking = if [&update_lock, &delete_lock, &transfer_lock]
+                .iter()
+                .all(|lock| **lock == TimeLock::None)
+            {
+                None
+            } else {
+                Some(new_lock_metadata(update_lock, delete_lock, transfer_lock)?)
+            };
+            if update_lock_at.is_none()
+                && locking
+                    .as_ref()
+                    .is_some_and(|locks| locks.update_lock == TimeLock::UntilDestroyed)
+            {
+                return Err(abort(
+                    "notarization",
+                    "new_dynamic_notarization_with_locks",
+                    "EDynamicNotarizationInvariants",
+                ));
+            }
+
+            let notarization = new_notarization(
+                session,
+                state,
+                description,
+                updatable_metadata,
+                locking,
+                NotarizationMethod::Dynamic,
+            );
+            let id = session.new_id();
+            session.create(id, Owner::Address(session.sender), notarization);
+            notarization_mut(session, id)?.update_lock_at = update_lock_at;
+            if function.starts_with("create") {
+                emit(
+                    session,
+                    "dynamic_notarization",
+                    "DynamicNotarizationCreated",
+                    json!({ "notarization_id": id.to_string() }),
+                );
+                Ok(Vec::new())
+            } else {
+                Ok(vec![Value::Object(id)])
+            }
+        }
+        ("dynamic_notarization", "transfer") => {
+            let id = args.object()?;
+            let recipient: Address = args.pure()?;
+            let notarization = notarization(session, id)?;
+            if notarization.is_transfer_locked(now_secs) {
+                return Err(abort("dynamic_notarization", "transfer", "ECannotTransferLocked"));
+            }
+            session.transfer(id, Owner::Address(recipient))?;
+            emit(
+                session,
+                "dynamic_notarization",
+                "DynamicNotarizationTransferred",
+                json!({ "notarization_id": id.to_string(), "recipient": recipient.to_string() }),
+            );
+            Ok(Vec::new())
+        }
+        ("dynamic_notarization", "seal") => {
+            let id = args.object()?;
+            let delete_lock = args.timelock()?;
+            let state_version_count = seal(session, id, delete_lock)?;
+            emit(
+                session,
+                "dynamic_notarization",
+                "NotarizationSealed",
+                json!({
+                    "notarization_id": id.to_string(),
+                    "state_version_count": state_version_count.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+
+        ("locked_notarization", "create" | "new") => {
+            let state = args.state()?;
+            let description = args.pure()?;
+            let updatable_metadata = args.pure()?;
+            let delete_lock = args.timelock()?;
+            let locking = new_lock_metadata(TimeLock::UntilDestroyed, delete_lock, TimeLock::UntilDestroyed)?;
+
+            let notarization = new_notarization(
+                session,
+                state,
+                description,
+                updatable_metadata,
+                Some(locking),
+                NotarizationMethod::Locked,
+            );
+            let id = session.new_id();
+            session.create(id, Owner::Address(session.sender), notarization);
+            if function == "create" {
+                emit(
+                    session,
+                    "locked_notarization",
+                    "LockedNotarizationCreated",
+                    json!({ "notarization_id": id.to_string() }),
+                );
+                Ok(Vec::new())
+            } else {
+                Ok(vec![Value::Object(id)])
+            }
+        }
+
+        ("shared_notarization", "new_admin_role") => Ok(vec![Value::Package(PackageValue::Role(Role::Admin))]),
+        ("shared_notarization", "new_writer_role") => Ok(vec![Value::Package(PackageValue::Role(Role::Writer))]),
+        ("shared_notarization", "create") => {
+            let state = args.state()?;
+            let description = args.pure()?;
+            let updatable_metadata = args.pure()?;
+            let update_lock = args.timelock()?;
+            let delete_lock = args.timelock()?;
+            let shared_locking = new_lock_metadata(update_lock, delete_lock, TimeLock::UntilDestroyed)?;
+            if shared_locking.update_lock == TimeLock::UntilDestroyed {
+                return Err(abort(
+                    "notarization",
+                    "new_shared_notarization",
+                    "ESharedNotarizationInvariants",
+                ));
+            }
+            let locking = LockMetadata {
+                update_lock: TimeLock::UntilDestroyed.into(),
+                delete_lock: TimeLock::UnlockAt(u32::MAX),
+                transfer_lock: TimeLock::UntilDestroyed,
+            };
+
+            let notarization = new_notarization(
+                session,
+                state,
+                description,
+                updatable_metadata,
+                Some(locking),
+                NotarizationMethod::Locked,
+            );
+            let notarization_id = session.new_id();
+            session.create(notarization_id, Owner::Shared(0), notarization);
+            notarization_mut(session, notarization_id)?.shared_locking = Some(shared_locking);
+            let capability_id = session.new_id();
+            session.create(
+                capability_id,
+                Owner::Address(session.sender),
+                MoveObject::Capability {
+                    notarization_id,
+                    role: Role::Admin,
+                },
+            );
+            notarization_mut(session, notarization_id)?
+                .capabilities
+                .insert(capability_id);
+            emit(
+                session,
+                "shared_notarization",
+                "SharedNotarizationCreated",
+                json!({
+                    "notarization_id": notarization_id.to_string(),
+                    "capability_id": capability_id.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "update_state") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "update_state", false)?;
+            update_state(session, id, args.state()?)?;
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "update_metadata") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "update_metadata", false)?;
+            update_metadata(session, id, args.pure()?)?;
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "seal") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "seal", true)?;
+            let delete_lock = args.timelock()?;
+            let state_version_count = seal_shared(session, id, delete_lock)?;
+            emit(
+                session,
+                "shared_notarization",
+                "NotarizationSealed",
+                json!({
+                    "notarization_id": id.to_string(),
+                    "state_version_count": state_version_count.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "destroy") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "destroy", true)?;
+            destroy(session, id)?;
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "issue_capability") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "issue_capability", true)?;
+            let role = args.role()?;
+            let recipient: Address = args.pure()?;
+
+            let capability_id = session.new_id();
+            session.create(
+                capability_id,
+                Owner::Address(recipient),
+                MoveObject::Capability {
+                    notarization_id: id,
+                    role,
+                },
+            );
+            notarization_mut(session, id)?.capabilities.insert(capability_id);
+            emit(
+                session,
+                "shared_notarization",
+                "CapabilityIssued",
+                json!({
+                    "notarization_id": id.to_string(),
+                    "capability_id": capability_id.to_string(),
+                    "recipient": recipient.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "revoke_capability") => {
+            let id = args.object()?;
+            assert_capability(session, id, args.object()?, "revoke_capability", true)?;
+            let capability_id: ObjectId = args.pure()?;
+            if !notarization_mut(session, id)?.capabilities.remove(&capability_id) {
+                return Err(abort("shared_notarization", "revoke_capability", "ECapabilityNotFound"));
+            }
+            emit(
+                session,
+                "shared_notarization",
+                "CapabilityRevoked",
+                json!({
+                    "notarization_id": id.to_string(),
+                    "capability_id": capability_id.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+        ("shared_notarization", "destroy_capability") => {
+            let id = args.object()?;
+            match session.delete(id)? {
+                MoveObject::Capability { .. } => Ok(Vec::new()),
+                _ => Err(Failure::Other(format!("object {id} is not a capability"))),
+            }
+        }
+
+        ("endorsement", "endorse") => {
+            let notarization_id: ObjectId = args.pure()?;
+            let state_version_count: u64 = args.pure()?;
+            let state_hash: Vec<u8> = args.pure()?;
+            if state_hash.len() != 32 {
+                return Err(abort("endorsement", "endorse", "EInvalidStateHash"));
+            }
+            let endorser = session.sender;
+            let endorsed_at = session.now_ms;
+            let endorsement_id = session.new_id();
+            session.create(
+                endorsement_id,
+                Owner::Address(*notarization_id.as_address()),
+                MoveObject::Endorsement {
+                    notarization_id,
+                    endorser,
+                    state_version_count,
+                    state_hash: state_hash.clone(),
+                    endorsed_at,
+                },
+            );
+            emit(
+                session,
+                "endorsement",
+                "NotarizationEndorsed",
+                json!({
+                    "endorsement_id": endorsement_id.to_string(),
+                    "notarization_id": notarization_id.to_string(),
+                    "endorser": endorser.to_string(),
+                    "state_version_count": state_version_count.to_string(),
+                    "state_hash": state_hash,
+                    "endorsed_at": endorsed_at.to_string(),
+                }),
+            );
+            Ok(Vec::new())
+        }
+
+        _ => Err(unsupported(module, function)),
+    }
+}
+
+/// Mirrors `notarization::new_lock_metadata`.
+fn new_lock_metadata(
+    update_lock: TimeLock,
+    delete_lock: TimeLock,
+    transfer_lock: TimeLock,
+) -> Result<LockMetadata, Failure> {
+    if delete_lock == TimeLock::UntilDestroyed {
+        return Err(abort(
+            "notarization",
+            "new_lock_metadata",
+            "EUntilDestroyedLockNotAllowed",
+        ));
+    }
+    if let TimeLock::UnlockAt(delete_time) = delete_lock {
+        for lock in [&update_lock, &transfer_lock] {
+            if matches!(lock, TimeLock::UnlockAt(time) if delete_time < *time) {
+                return Err(abort("notarization", "new_lock_metadata", "ELockTimeNotSatisfied"));
+            }
+        }
+    }
+
+    Ok(LockMetadata {
+        update_lock: update_lock.into(),
+        delete_lock,
+        transfer_lock,
+    })
+}
+
+fn new_notarization(
+    session: &Session,
+    state: State,
+    description: Option<String>,
+    updatable_metadata: Option<String>,
+    locking: Option<LockMetadata>,
+    method: NotarizationMethod,
+) -> MoveObject {
+    MoveObject::Notarization(Box::new(Notarization {
+        state,
+        immutable_metadata: ImmutableMetadata {
+            created_at: session.now_ms,
+            description,
+            locking,
+        },
+        updatable_metadata,
+        last_state_change_at: session.now_ms,
+        state_version_count: 0,
+        method,
+        capabilities: BTreeSet::new(),
+        update_lock_at: None,
+        shared_locking: None,
+    }))
+}
+
+/// Mirrors `notarization::update_state_internal`.
+fn update_state(session: &mut Session, id: ObjectId, state: State) -> Result<(), Failure> {
+    let now_ms = session.now_ms;
+    let notarization = notarization_mut(session, id)?;
+    if notarization.is_update_locked(now_ms / 1000) {
+        return Err(abort("notarization", "update_state_internal", "EUpdateWhileLocked"));
+    }
+    if notarization.state.type_arg != state.type_arg {
+        return Err(Failure::Other(format!(
+            "expected a state of type {}, got {}",
+            notarization.state.type_arg, state.type_arg
+        )));
+    }
+
+    notarization.state = state;
+    notarization.last_state_change_at = now_ms;
+    notarization.state_version_count += 1;
+    let event = json!({
+        "notarization_id": id.to_string(),
+        "state_version_count": notarization.state_version_count.to_string(),
+        "updated_state": {
+            "data": notarization.state.data_json(),
+            "metadata": notarization.state.metadata,
+        },
+    });
+    let mut bcs = to_bcs(&id);
+    bcs.extend(to_bcs(&notarization.state_version_count));
+    bcs.extend(notarization.state.bcs());
+    let name = format!("NotarizationUpdated<{}>", notarization.state.type_arg);
+    session.emit_raw(MOCK_PACKAGE_ID, "notarization", &name, event, bcs);
+
+    Ok(())
+}
+
+/// Mirrors `notarization::update_metadata_internal`.
+fn update_metadata(session: &mut Session, id: ObjectId, metadata: Option<String>) -> Result<(), Failure> {
+    let now_secs = session.now_ms / 1000;
+    let notarization = notarization_mut(session, id)?;
+    if notarization.is_update_locked(now_secs) {
+        return Err(abort("notarization", "update_metadata_internal", "EUpdateWhileLocked"));
+    }
+
+    notarization.updatable_metadata = metadata.clone();
+    emit(
+        session,
+        "notarization",
+        "NotarizationMetadataUpdated",
+        json!({ "notarization_id": id.to_string(), "updated_metadata": metadata }),
+    );
+
+    Ok(())
+}
+
+/// Mirrors `notarization::destroy_internal`.
+fn destroy(session: &mut Session, id: ObjectId) -> Result<(), Failure> {
+    if !notarization(session, id)?.is_destroy_allowed(session.now_ms / 1000) {
+        return Err(abort("notarization", "destroy_internal", "EDestroyWhileLocked"));
+    }
+
+    session.delete(id)?;
+    emit(
+        session,
+        "notarization",
+        "NotarizationDestroyed",
+        json!({ "notarization_id": id.to_string() }),
+    );
+
+    Ok(())
+}
+
+/// Mirrors `notarization::seal_notarization` and returns the
+/// `state_version_count` of the sealed notarization.
+fn seal(session: &mut Session, id: ObjectId, delete_lock: TimeLock) -> Result<u64, Failure> {
+    let now_secs = session.now_ms / 1000;
+    let notarization = notarization_mut(session, id)?;
+    if notarization.method != NotarizationMethod::Dynamic {
+        return Err(abort("notarization", "seal_notarization", "ENotDynamicNotarization"));
+    }
+    if notarization.is_update_locked(now_secs) {
+        return Err(abort("notarization", "seal_notarization", "EUpdateWhileLocked"));
+    }
+    if let Some(locks) = notarization.locking() {
+        assert_retained_by(&delete_lock, &locks.delete_lock, now_secs)?;
+        assert_retained_by(&delete_lock, &locks.transfer_lock, now_secs)?;
+    }
+
+    let locking = new_lock_metadata(TimeLock::UntilDestroyed, delete_lock, TimeLock::UntilDestroyed)?;
+    notarization.immutable_metadata.locking = Some(locking);
+    notarization.update_lock_at = None;
+    notarization.method = NotarizationMethod::Locked;
+
+    Ok(notarization.state_version_count)
+}
+
+/// Mirrors `notarization::seal_shared_notarization` and returns the
+/// `state_version_count` of the sealed notarization.
+fn seal_shared(session: &mut Session, id: ObjectId, delete_lock: TimeLock) -> Result<u64, Failure> {
+    let now_secs = session.now_ms / 1000;
+    let notarization = notarization_mut(session, id)?;
+    let Some(shared_locking) = &notarization.shared_locking else {
+        return Err(abort(
+            "notarization",
+            "seal_shared_notarization",
+            "ENotSharedNotarization",
+        ));
+    };
+    if notarization.is_update_locked(now_secs) {
+        return Err(abort("notarization", "seal_shared_notarization", "EUpdateWhileLocked"));
+    }
+    assert_retained_by(&delete_lock, &shared_locking.delete_lock, now_secs)?;
+
+    let shared_locking = new_lock_metadata(TimeLock::UntilDestroyed, delete_lock, TimeLock::UntilDestroyed)?;
+    notarization.shared_locking = Some(shared_locking);
+
+    Ok(notarization.state_version_count)
+}
+
+/// Mirrors `notarization::assert_retained_by`.
+fn assert_retained_by(delete_lock: &TimeLock, current_lock: &TimeLock, now_secs: u64) -> Result<(), Failure> {
+    let retained = match (current_lock, delete_lock) {
+        (TimeLock::UnlockAt(current_time), TimeLock::UnlockAt(delete_time)) => delete_time >= current_time,
+        _ => false,
+    };
+    if current_lock.is_timelocked_unlock_at(now_secs) && !retained {
+        return Err(abort("notarization", "assert_retained_by", "ESealShortensRetention"));
+    }
+
+    Ok(())
+}
+
+/// Mirrors `shared_notarization::is_capability_valid`, and
+/// `shared_notarization::assert_admin` if `admin` is set.
+fn assert_capability(
+    session: &Session,
+    id: ObjectId,
+    capability_id: ObjectId,
+    function: &'static str,
+    admin: bool,
+) -> Result<(), Failure> {
+    let MoveObject::Capability { notarization_id, role } = session.borrow(capability_id)? else {
+        return Err(Failure::Other(format!("object {capability_id} is not a capability")));
+    };
+    if *notarization_id != id || !notarization(session, id)?.capabilities.contains(&capability_id) {
+        return Err(abort("shared_notarization", function, "EInvalidCapability"));
+    }
+    if admin && *role != Role::Admin {
+        return Err(abort("shared_notarization", function, "EAdminRoleRequired"));
+    }
+
+    Ok(())
+}
+
+fn notarization(session: &Session, id: ObjectId) -> Result<&Notarization, Failure> {
+    match session.borrow(id)? {
+        MoveObject::Notarization(notarization) => Ok(notarization),
+        _ => Err(Failure::Other(format!("object {id} is not a notarization"))),
+    }
+}
+
+fn notarization_mut(session: &mut Session, id: ObjectId) -> Result<&mut Notarization, Failure> {
+    match session.borrow_mut(id)? {
+        MoveObject::Notarization(notarization) => Ok(notarization),
+        _ => Err(Failure::Other(format!("object {id} is not a notarization"))),
+    }
+}
+
+/// Returns the abort of `module::function` with the error constant `name` of
+/// `module`, whose code is taken from the Move sources of the package.
+fn abort(module: &str, function: &str, name: &str) -> Failure {
+    let code = abort::abort_code(module, name).expect("abort constants are declared in the Move sources");
+
+    Failure::Abort {
+        package_id: MOCK_PACKAGE_ID,
+        module: module.to_string(),
+        function: function.to_string(),
+        code,
+    }
+}
+
+/// Emits the event `name` of `module`, rendered as its parsed JSON.
+fn emit(session: &mut Session, module: &str, name: &str, json: Json) {
+    session.emit_json(MOCK_PACKAGE_ID, module, name, json);
+}
+
+fn unsupported(module: &str, function: &str) -> Failure {
+    Failure::Other(format!(
+        "function {module}::{function} is not simulated by the mock ledger"
+    ))
+}
+
+fn type_argument(type_arguments: &[String]) -> Result<String, Failure> {
+    type_arguments
+        .first()
+        .cloned()
+        .ok_or_else(|| Failure::Other("missing type argument".to_string()))
+}
+
+fn option_string_type() -> String {
+    format!("{MOVE_STDLIB_PACKAGE_ID}::option::Option<{MOVE_STDLIB_PACKAGE_ID}::string::String>")
+}
+
+fn to_bcs<T: serde::Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    bcs::to_bytes(value).expect("values of the Notarization Package are serializable")
+}
+
+fn decode<T: DeserializeOwned>(bcs: &[u8]) -> Result<T, Failure> {
+    bcs::from_bytes(bcs).map_err(|e| Failure::Other(format!("invalid argument: {e}")))
+}
+
+/// The arguments of a Move call, consumed in order.
+struct Args(std::vec::IntoIter<Value>);
+
+impl Args {
+    fn next(&mut self) -> Result<Value, Failure> {
+        self.0
+            .next()
+            .ok_or_else(|| Failure::Other("missing argument".to_string()))
+    }
+
+    fn pure_bytes(&mut self) -> Result<Vec<u8>, Failure> {
+        match self.next()? {
+            Value::Pure(bcs) | Value::Move { bcs, .. } => Ok(bcs),
+            _ => Err(Failure::Other("expected a pure argument".to_string())),
+        }
+    }
+
+    fn pure<T: DeserializeOwned>(&mut self) -> Result<T, Failure> {
+        decode(&self.pure_bytes()?)
+    }
+
+    fn object(&mut self) -> Result<ObjectId, Failure> {
+        match self.next()? {
+            Value::Object(id) => Ok(id),
+            _ => Err(Failure::Other("expected an object argument".to_string())),
+        }
+    }
+
+    fn state(&mut self) -> Result<State, Failure> {
+        match self.next()? {
+            Value::Package(PackageValue::State(state)) => Ok(state),
+            _ => Err(Failure::Other("expected a state argument".to_string())),
+        }
+    }
+
+    fn timelock(&mut self) -> Result<TimeLock, Failure> {
+        match self.next()? {
+            Value::Package(PackageValue::TimeLock(lock)) => Ok(lock),
+            _ => Err(Failure::Other("expected a timelock argument".to_string())),
+        }
+    }
+
+    fn lock_at(&mut self) -> Result<u32, Failure> {
+        match self.next()? {
+            Value::Package(PackageValue::LockAt(lock_time)) => Ok(lock_time),
+            _ => Err(Failure::Other("expected a lock_at argument".to_string())),
+        }
+    }
+
+    fn role(&mut self) -> Result<Role, Failure> {
+        match self.next()? {
+            Value::Package(PackageValue::Role(role)) => Ok(role),
+            _ => Err(Failure::Other("expected a role argument".to_string())),
+        }
+    }
+}
+
+/// Simulation of a package of the tests defining the Move struct
+/// `document::Document`, notarized as a typed payload.
+#[cfg(test)]
+pub(super) mod document {
+    use super::*;
+
+    /// The [`ObjectId`] the package is published at.
+    pub(in crate::test_utils) const PACKAGE_ID: ObjectId = ObjectId::new([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x64, 0x6f, 0x63,
+    ]);
+
+    /// Calls the simulated Move function `module::function`.
+    pub(super) fn call(module: &str, function: &str, arguments: Vec<Value>) -> Result<Vec<Value>, Failure> {
+        let mut args = Args(arguments.into_iter());
+
+        match (module, function) {
+            // `document::new(version: u64, author: address, content: vector<u8>): Document`
+            ("document", "new") => {
+                let version: u64 = args.pure()?;
+                let author: Address = args.pure()?;
+                let content: Vec<u8> = args.pure()?;
+                let mut bcs = to_bcs(&version);
+                bcs.extend(to_bcs(&author));
+                bcs.extend(to_bcs(&content));
+                Ok(vec![Value::Move {
+                    bcs,
+                    type_: format!("{PACKAGE_ID}::document::Document"),
+                }])
+            }
+            _ => Err(unsupported(module, function)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_mock_rpc::{Contents, Event};
+    use sha2::{Digest as _, Sha256};
+
+    use super::super::MOCK_CHAIN_ID;
+    use super::*;
+    use crate::core::abort::package_execution_error;
+    use crate::error::Error;
+
+    const NOW_SECS: u64 = 1_750_000_000;
+    const OWNER: Address = Address::new([0xa; 32]);
+    const ALICE: Address = Address::new([0xb; 32]);
+
+    fn ledger() -> Ledger {
+        Ledger::new(NotarizationPackage, MOCK_CHAIN_ID.to_string(), NOW_SECS * 1000)
+    }
+
+    /// Runs `calls` in a transaction of `sender` and decodes aborts like the
+    /// clients of this crate.
+    fn transact<T>(
+        ledger: &mut Ledger,
+        sender: Address,
+        calls: impl FnOnce(&mut Session) -> Result<T, Failure>,
+    ) -> Result<(T, Vec<Event>), Error> {
+        ledger
+            .transact(sender, calls)
+            .map_err(|failure| package_execution_error(&failure.describe(0), &[MOCK_PACKAGE_ID]))
+    }
+
+    fn in_secs(secs: u64) -> u32 {
+        (NOW_SECS + secs) as u32
+    }
+
+    fn pure<T: serde::Serialize + ?Sized>(value: &T) -> Value {
+        Value::Pure(to_bcs(value))
+    }
+
+    fn call_one(session: &mut Session, module: &str, function: &str, arguments: Vec<Value>) -> Result<Value, Failure> {
+        call(session, module, function, &[], arguments)?
+            .pop()
+            .ok_or_else(|| Failure::Other(format!("{module}::{function} returned no value")))
+    }
+
+    fn string_state(session: &mut Session, data: &str) -> Result<Value, Failure> {
+        call_one(
+            session,
+            "notarization",
+            "new_state_from_string",
+            vec![pure(data), pure(&None::<String>)],
+        )
+    }
+
+    /// Constructs `lock` with the constructor of the `timelock` module.
+    fn timelock(session: &mut Session, lock: &TimeLock) -> Result<Value, Failure> {
+        match lock {
+            TimeLock::UnlockAt(time) => call_one(session, "timelock", "unlock_at", vec![pure(time)]),
+            TimeLock::UntilDestroyed => call_one(session, "timelock", "until_destroyed", Vec::new()),
+            TimeLock::None => call_one(session, "timelock", "none", Vec::new()),
+        }
+    }
+
+    /// Constructs `lock` with the constructor of its module.
+    fn update_lock(session: &mut Session, lock: &UpdateLock) -> Result<Value, Failure> {
+        match lock {
+            UpdateLock::TimeLock(lock) => timelock(session, lock),
+            UpdateLock::LockAt(time) => call_one(session, "lock_at", "new", vec![pure(time)]),
+        }
+    }
+
+    /// Returns the events as `module::Name`.
+    fn event_types(events: &[Event]) -> Vec<String> {
+        let prefix = format!("{MOCK_PACKAGE_ID}::");
+        events
+            .iter()
+            .map(|event| event.type_.strip_prefix(&prefix).unwrap_or(&event.type_).to_string())
+            .collect()
+    }
+
+    fn event_id(event: &Event, field: &str) -> ObjectId {
+        event.json[field]
+            .as_str()
+            .and_then(|id| id.parse().ok())
+            .expect("events reference objects by ID")
+    }
+
+    fn stored(ledger: &Ledger, id: ObjectId) -> Option<Notarization> {
+        match &ledger.object(&id)?.contents {
+            Contents::Move(MoveObject::Notarization(notarization)) => Some((**notarization).clone()),
+            _ => None,
+        }
+    }
+
+    /// Creates a notarization with `module::function`, passing a string state,
+    /// a description, no updatable metadata and `locks`.
+    fn create(ledger: &mut Ledger, module: &str, function: &str, locks: &[TimeLock]) -> Result<ObjectId, Error> {
+        let (_, events) = transact(ledger, OWNER, |session| {
+            let mut arguments = vec![
+                string_state(session, "draft")?,
+                pure(&Some("description")),
+                pure(&None::<String>),
+            ];
+            for lock in locks {
+                arguments.push(timelock(session, lock)?);
+            }
+            call(session, module, function, &[], arguments)
+        })?;
+
+        Ok(event_id(&events[0], "notarization_id"))
+    }
+
+    fn update_state(ledger: &mut Ledger, id: ObjectId, data: &str) -> Result<Vec<Event>, Error> {
+        transact(ledger, OWNER, |session| {
+            let state = string_state(session, data)?;
+            call(
+                session,
+                "notarization",
+                "update_state",
+                &[],
+                vec![Value::Object(id), state],
+            )
+        })
+        .map(|(_, events)| events)
+    }
+
+    fn run(ledger: &mut Ledger, module: &str, function: &str, arguments: Vec<Value>) -> Result<Vec<Event>, Error> {
+        transact(ledger, OWNER, |session| call(session, module, function, &[], arguments)).map(|(_, events)| events)
+    }
+
+    fn get<T: DeserializeOwned>(ledger: &mut Ledger, id: ObjectId, getter: &str) -> T {
+        let (value, _) = transact(ledger, OWNER, |session| {
+            call_one(session, "notarization", getter, vec![Value::Object(id)])
+        })
+        .expect("getters do not abort");
+        match value {
+            Value::Move { bcs, .. } => bcs::from_bytes(&bcs).expect("getters return their declared type"),
+            value => panic!("getter {getter} returned {value:?}"),
+        }
+    }
+
+    #[test]
+    fn lock_constructors_reject_past_timestamps() {
+        let mut ledger = ledger();
+
+        let (locks, _) = transact(&mut ledger, OWNER, |session| {
+            Ok([
+                timelock(session, &TimeLock::UnlockAt(in_secs(60)))?,
+                update_lock(session, &UpdateLock::LockAt(in_secs(60)))?,
+                timelock(session, &TimeLock::UntilDestroyed)?,
+                timelock(session, &TimeLock::None)?,
+            ])
+        })
+        .unwrap();
+        assert!(matches!(
+            locks,
+            [
+                Value::Package(PackageValue::TimeLock(TimeLock::UnlockAt(_))),
+                Value::Package(PackageValue::LockAt(_)),
+                Value::Package(PackageValue::TimeLock(TimeLock::UntilDestroyed)),
+                Value::Package(PackageValue::TimeLock(TimeLock::None)),
+            ]
+        ));
+
+        for lock in [TimeLock::UnlockAt(in_secs(0)).into(), UpdateLock::LockAt(in_secs(0))] {
+            let result = transact(&mut ledger, OWNER, |session| update_lock(session, &lock));
+            assert!(matches!(result, Err(Error::PastTimestamp)), "got {result:?}");
+        }
+    }
+
+    #[test]
+    fn new_state_functions_record_the_data_type() {
+        let mut ledger = ledger();
+
+        let (states, _) = transact(&mut ledger, OWNER, |session| {
+            let bytes = call_one(
+                session,
+                "notarization",
+                "new_state_from_bytes",
+                vec![pure(&vec![1u8, 2, 3]), pure(&None::<String>)],
+            )?;
+            let string = string_state(session, "text")?;
+            let generic = call(
+                session,
+                "notarization",
+                "new_state_from_generic",
+                &["u64".to_string()],
+                vec![pure(&7u64), pure(&Some("reading"))],
+            )?;
+            Ok([bytes, string, generic[0].clone()])
+        })
+        .unwrap();
+
+        let types: Vec<String> = states
+            .iter()
+            .map(|state| match state {
+                Value::Package(PackageValue::State(state)) => state.type_arg.clone(),
+                value => panic!("expected a state, got {value:?}"),
+            })
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "vector<u8>".to_string(),
+                format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"),
+                "u64".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn dynamic_notarizations_are_updated_and_destroyed() {
+        let mut ledger = ledger();
+        let id = create(&mut ledger, "dynamic_notarization", "create", &[TimeLock::None]).unwrap();
+        assert_eq!(stored(&ledger, id).unwrap().method, NotarizationMethod::Dynamic);
+        assert_eq!(ledger.object(&id).unwrap().owner, Owner::Address(OWNER));
+
+        let events = update_state(&mut ledger, id, "final").unwrap();
+        assert_eq!(
+            event_types(&events),
+            [format!(
+                "notarization::NotarizationUpdated<{MOVE_STDLIB_PACKAGE_ID}::string::String>"
+            )]
+        );
+        assert_eq!(events[0].json["state_version_count"], "1");
+        assert_eq!(events[0].json["updated_state"]["data"], "final");
+
+        let events = run(
+            &mut ledger,
+            "notarization",
+            "update_metadata",
+            vec![Value::Object(id), pure(&Some("reviewed"))],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["notarization::NotarizationMetadataUpdated"]);
+        let notarization = stored(&ledger, id).unwrap();
+        assert_eq!(notarization.updatable_metadata.as_deref(), Some("reviewed"));
+        assert_eq!(notarization.state_version_count, 1);
+
+        let events = run(&mut ledger, "notarization", "destroy", vec![Value::Object(id)]).unwrap();
+        assert_eq!(event_types(&events), ["notarization::NotarizationDestroyed"]);
+        assert!(ledger.object(&id).is_none());
+    }
+
+    #[test]
+    fn dynamic_constructors_return_the_notarization() {
+        let mut ledger = ledger();
+
+        let constructors: [(&str, Vec<UpdateLock>); 4] = [
+            ("new", vec![TimeLock::None.into()]),
+            (
+                "new_with_delete_lock",
+                vec![TimeLock::UnlockAt(in_secs(60)).into(), TimeLock::None.into()],
+            ),
+            (
+                "new_with_locks",
+                vec![
+                    TimeLock::UnlockAt(in_secs(60)).into(),
+                    TimeLock::None.into(),
+                    TimeLock::None.into(),
+                ],
+            ),
+            (
+                "new_with_lock_at",
+                vec![
+                    UpdateLock::LockAt(in_secs(60)),
+                    TimeLock::None.into(),
+                    TimeLock::None.into(),
+                ],
+            ),
+        ];
+        for (function, locks) in constructors {
+            let (values, events) = transact(&mut ledger, OWNER, |session| {
+                let mut arguments = vec![
+                    string_state(session, "draft")?,
+                    pure(&None::<String>),
+                    pure(&None::<String>),
+                ];
+                for lock in &locks {
+                    arguments.push(update_lock(session, lock)?);
+                }
+                call(session, "dynamic_notarization", function, &[], arguments)
+            })
+            .unwrap();
+
+            let [Value::Object(id)] = values.as_slice() else {
+                panic!("{function} returned {values:?}");
+            };
+            assert!(events.is_empty(), "{function} emitted {events:?}");
+            assert_eq!(stored(&ledger, *id).unwrap().method, NotarizationMethod::Dynamic);
+        }
+    }
+
+    #[test]
+    fn getters_read_the_notarization() {
+        let mut ledger = ledger();
+        let id = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_locks",
+            &[TimeLock::UnlockAt(in_secs(60)), TimeLock::None, TimeLock::None],
+        )
+        .unwrap();
+
+        assert!(get::<bool>(&mut ledger, id, "is_update_locked"));
+        assert!(!get::<bool>(&mut ledger, id, "is_delete_locked"));
+        assert!(!get::<bool>(&mut ledger, id, "is_transfer_locked"));
+        assert!(!get::<bool>(&mut ledger, id, "is_destroy_allowed"));
+        assert!(!get::<bool>(&mut ledger, id, "is_shared"));
+        assert_eq!(get::<u64>(&mut ledger, id, "version_count"), 0);
+        assert_eq!(get::<u64>(&mut ledger, id, "created_at"), NOW_SECS * 1000);
+        assert_eq!(get::<u64>(&mut ledger, id, "last_change"), NOW_SECS * 1000);
+        assert_eq!(
+            get::<Option<String>>(&mut ledger, id, "description").as_deref(),
+            Some("description")
+        );
+        assert_eq!(get::<Option<String>>(&mut ledger, id, "updatable_metadata"), None);
+        assert_eq!(
+            get::<NotarizationMethod>(&mut ledger, id, "notarization_method"),
+            NotarizationMethod::Dynamic
+        );
+        let locking = get::<Option<LockMetadata>>(&mut ledger, id, "lock_metadata").unwrap();
+        assert_eq!(locking.update_lock, TimeLock::UnlockAt(in_secs(60)));
+
+        let (state, _) = transact(&mut ledger, OWNER, |session| {
+            call_one(session, "notarization", "state", vec![Value::Object(id)])
+        })
+        .unwrap();
+        assert!(matches!(state, Value::Package(PackageValue::State(state)) if state.data == to_bcs("draft")));
+    }
+
+    #[test]
+    fn update_lock_blocks_updates_until_it_unlocks() {
+        let mut ledger = ledger();
+        let id = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_locks",
+            &[TimeLock::UnlockAt(in_secs(60)), TimeLock::None, TimeLock::None],
+        )
+        .unwrap();
+
+        let result = update_state(&mut ledger, id, "early");
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+        let result = run(
+            &mut ledger,
+            "notarization",
+            "update_metadata",
+            vec![Value::Object(id), pure(&Some("early"))],
+        );
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+
+        ledger.clock_ms += 61_000;
+        update_state(&mut ledger, id, "late").unwrap();
+        assert_eq!(stored(&ledger, id).unwrap().state_version_count, 1);
+    }
+
+    #[test]
+    fn lock_at_freezes_updates_from_its_lock_time() {
+        let mut ledger = ledger();
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let arguments = vec![
+                string_state(session, "draft")?,
+                pure(&Some("description")),
+                pure(&None::<String>),
+                update_lock(session, &UpdateLock::LockAt(in_secs(60)))?,
+                timelock(session, &TimeLock::None)?,
+                timelock(session, &TimeLock::None)?,
+            ];
+            call(session, "dynamic_notarization", "create_with_lock_at", &[], arguments)
+        })
+        .unwrap();
+        let id = event_id(&events[0], "notarization_id");
+        let notarization = stored(&ledger, id).unwrap();
+        assert_eq!(notarization.update_lock_at, Some(in_secs(60)));
+        assert_eq!(
+            notarization.immutable_metadata.locking.unwrap().update_lock,
+            TimeLock::UntilDestroyed
+        );
+
+        assert!(!get::<bool>(&mut ledger, id, "is_update_locked"));
+        update_state(&mut ledger, id, "before").unwrap();
+
+        ledger.clock_ms += 60_000;
+        assert!(get::<bool>(&mut ledger, id, "is_update_locked"));
+        let result = update_state(&mut ledger, id, "after");
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+    }
+
+    #[test]
+    fn transfer_lock_blocks_transfers_and_destruction() {
+        let mut ledger = ledger();
+        let locked = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create",
+            &[TimeLock::UnlockAt(in_secs(60))],
+        )
+        .unwrap();
+        let unlocked = create(&mut ledger, "dynamic_notarization", "create", &[TimeLock::None]).unwrap();
+
+        let result = run(
+            &mut ledger,
+            "dynamic_notarization",
+            "transfer",
+            vec![Value::Object(locked), pure(&ALICE)],
+        );
+        assert!(matches!(result, Err(Error::CannotTransferLocked)), "got {result:?}");
+        let result = run(&mut ledger, "notarization", "destroy", vec![Value::Object(locked)]);
+        assert!(matches!(result, Err(Error::DestroyWhileLocked)), "got {result:?}");
+
+        let events = run(
+            &mut ledger,
+            "dynamic_notarization",
+            "transfer",
+            vec![Value::Object(unlocked), pure(&ALICE)],
+        )
+        .unwrap();
+        assert_eq!(
+            event_types(&events),
+            ["dynamic_notarization::DynamicNotarizationTransferred"]
+        );
+        assert_eq!(events[0].json["recipient"], ALICE.to_string());
+        assert_eq!(ledger.object(&unlocked).unwrap().owner, Owner::Address(ALICE));
+    }
+
+    #[test]
+    fn lock_metadata_invariants_are_enforced() {
+        let mut ledger = ledger();
+
+        let result = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_delete_lock",
+            &[TimeLock::UnlockAt(in_secs(3600)), TimeLock::UnlockAt(in_secs(86400))],
+        );
+        assert!(matches!(result, Err(Error::LockTimeNotSatisfied)), "got {result:?}");
+
+        let result = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_delete_lock",
+            &[TimeLock::UntilDestroyed, TimeLock::None],
+        );
+        assert!(
+            matches!(result, Err(Error::UntilDestroyedLockNotAllowed)),
+            "got {result:?}"
+        );
+
+        let result = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_locks",
+            &[TimeLock::UntilDestroyed, TimeLock::None, TimeLock::None],
+        );
+        assert!(
+            matches!(
+                result,
+                Err(Error::MethodInvariantsViolated(NotarizationMethod::Dynamic))
+            ),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn sealing_locks_dynamic_notarizations() {
+        let mut ledger = ledger();
+        let id = create(
+            &mut ledger,
+            "dynamic_notarization",
+            "create_with_delete_lock",
+            &[TimeLock::UnlockAt(in_secs(3600)), TimeLock::None],
+        )
+        .unwrap();
+        update_state(&mut ledger, id, "final").unwrap();
+
+        let delete_lock = TimeLock::UnlockAt(in_secs(60));
+        let result = transact(&mut ledger, OWNER, |session| {
+            let lock = timelock(session, &delete_lock)?;
+            call(
+                session,
+                "dynamic_notarization",
+                "seal",
+                &[],
+                vec![Value::Object(id), lock],
+            )
+        });
+        assert!(matches!(result, Err(Error::SealShortensRetention)), "got {result:?}");
+
+        let delete_lock = TimeLock::UnlockAt(in_secs(7200));
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let lock = timelock(session, &delete_lock)?;
+            call(
+                session,
+                "dynamic_notarization",
+                "seal",
+                &[],
+                vec![Value::Object(id), lock],
+            )
+        })
+        .unwrap();
+        assert_eq!(event_types(&events), ["dynamic_notarization::NotarizationSealed"]);
+        assert_eq!(events[0].json["state_version_count"], "1");
+        let notarization = stored(&ledger, id).unwrap();
+        assert_eq!(notarization.method, NotarizationMethod::Locked);
+        assert_eq!(
+            notarization.immutable_metadata.locking,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed.into(),
+                delete_lock: delete_lock.clone(),
+                transfer_lock: TimeLock::UntilDestroyed,
+            })
+        );
+
+        let result = transact(&mut ledger, OWNER, |session| {
+            let lock = timelock(session, &delete_lock)?;
+            call(
+                session,
+                "dynamic_notarization",
+                "seal",
+                &[],
+                vec![Value::Object(id), lock],
+            )
+        });
+        assert!(matches!(result, Err(Error::NotDynamicNotarization)), "got {result:?}");
+    }
+
+    #[test]
+    fn locked_notarizations_cannot_be_updated_or_transferred() {
+        let mut ledger = ledger();
+        let id = create(&mut ledger, "locked_notarization", "create", &[TimeLock::None]).unwrap();
+        assert_eq!(
+            event_types(ledger.events()),
+            ["locked_notarization::LockedNotarizationCreated"]
+        );
+        assert_eq!(stored(&ledger, id).unwrap().method, NotarizationMethod::Locked);
+
+        let result = update_state(&mut ledger, id, "final");
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+        let result = run(
+            &mut ledger,
+            "dynamic_notarization",
+            "transfer",
+            vec![Value::Object(id), pure(&ALICE)],
+        );
+        assert!(matches!(result, Err(Error::CannotTransferLocked)), "got {result:?}");
+
+        let events = run(&mut ledger, "notarization", "destroy", vec![Value::Object(id)]).unwrap();
+        assert_eq!(event_types(&events), ["notarization::NotarizationDestroyed"]);
+
+        let (values, events) = transact(&mut ledger, OWNER, |session| {
+            let arguments = vec![
+                string_state(session, "draft")?,
+                pure(&None::<String>),
+                pure(&None::<String>),
+                timelock(session, &TimeLock::UnlockAt(in_secs(60)))?,
+            ];
+            call(session, "locked_notarization", "new", &[], arguments)
+        })
+        .unwrap();
+        let [Value::Object(id)] = values.as_slice() else {
+            panic!("new returned {values:?}");
+        };
+        assert!(events.is_empty());
+        let result = run(&mut ledger, "notarization", "destroy", vec![Value::Object(*id)]);
+        assert!(matches!(result, Err(Error::DestroyWhileLocked)), "got {result:?}");
+    }
+
+    #[test]
+    fn shared_notarizations_are_managed_with_capabilities() {
+        let mut ledger = ledger();
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let arguments = vec![
+                string_state(session, "draft")?,
+                pure(&None::<String>),
+                pure(&None::<String>),
+                timelock(session, &TimeLock::None)?,
+                timelock(session, &TimeLock::None)?,
+            ];
+            call(session, "shared_notarization", "create", &[], arguments)
+        })
+        .unwrap();
+        assert_eq!(event_types(&events), ["shared_notarization::SharedNotarizationCreated"]);
+        let id = event_id(&events[0], "notarization_id");
+        let admin = event_id(&events[0], "capability_id");
+        assert!(matches!(ledger.object(&id).unwrap().owner, Owner::Shared(_)));
+        assert!(get::<bool>(&mut ledger, id, "is_shared"));
+
+        let result = update_state(&mut ledger, id, "without capability");
+        assert!(matches!(result, Err(Error::CapabilityRequired)), "got {result:?}");
+
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let state = string_state(session, "final")?;
+            call(
+                session,
+                "shared_notarization",
+                "update_state",
+                &[],
+                vec![Value::Object(id), Value::Object(admin), state],
+            )
+        })
+        .unwrap();
+        assert_eq!(events[0].json["state_version_count"], "1");
+
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let role = call_one(session, "shared_notarization", "new_writer_role", Vec::new())?;
+            call(
+                session,
+                "shared_notarization",
+                "issue_capability",
+                &[],
+                vec![Value::Object(id), Value::Object(admin), role, pure(&ALICE)],
+            )
+        })
+        .unwrap();
+        assert_eq!(event_types(&events), ["shared_notarization::CapabilityIssued"]);
+        let writer = event_id(&events[0], "capability_id");
+        assert_eq!(ledger.object(&writer).unwrap().owner, Owner::Address(ALICE));
+
+        let events = run(
+            &mut ledger,
+            "shared_notarization",
+            "update_metadata",
+            vec![Value::Object(id), Value::Object(writer), pure(&Some("reviewed"))],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["notarization::NotarizationMetadataUpdated"]);
+        let result = run(
+            &mut ledger,
+            "shared_notarization",
+            "destroy",
+            vec![Value::Object(id), Value::Object(writer)],
+        );
+        assert!(matches!(result, Err(Error::AdminRoleRequired)), "got {result:?}");
+
+        let events = run(
+            &mut ledger,
+            "shared_notarization",
+            "revoke_capability",
+            vec![Value::Object(id), Value::Object(admin), pure(&writer)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["shared_notarization::CapabilityRevoked"]);
+        let result = run(
+            &mut ledger,
+            "shared_notarization",
+            "revoke_capability",
+            vec![Value::Object(id), Value::Object(admin), pure(&writer)],
+        );
+        assert!(matches!(result, Err(Error::CapabilityNotFound)), "got {result:?}");
+        let result = run(
+            &mut ledger,
+            "shared_notarization",
+            "update_metadata",
+            vec![Value::Object(id), Value::Object(writer), pure(&None::<String>)],
+        );
+        assert!(matches!(result, Err(Error::InvalidCapability)), "got {result:?}");
+
+        run(
+            &mut ledger,
+            "shared_notarization",
+            "destroy_capability",
+            vec![Value::Object(writer)],
+        )
+        .unwrap();
+        assert!(ledger.object(&writer).is_none());
+
+        let events = run(
+            &mut ledger,
+            "shared_notarization",
+            "destroy",
+            vec![Value::Object(id), Value::Object(admin)],
+        )
+        .unwrap();
+        assert_eq!(event_types(&events), ["notarization::NotarizationDestroyed"]);
+        assert!(ledger.object(&id).is_none());
+    }
+
+    #[test]
+    fn shared_notarizations_keep_the_update_lock_mutable() {
+        let mut ledger = ledger();
+
+        let result = transact(&mut ledger, OWNER, |session| {
+            let arguments = vec![
+                string_state(session, "draft")?,
+                pure(&None::<String>),
+                pure(&None::<String>),
+                timelock(session, &TimeLock::UntilDestroyed)?,
+                timelock(session, &TimeLock::None)?,
+            ];
+            call(session, "shared_notarization", "create", &[], arguments)
+        });
+        assert!(
+            matches!(result, Err(Error::MethodInvariantsViolated(NotarizationMethod::Shared))),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn shared_notarizations_are_sealed_by_admins() {
+        let mut ledger = ledger();
+        let (_, events) = transact(&mut ledger, OWNER, |session| {
+            let arguments = vec![
+                string_state(session, "draft")?,
+                pure(&None::<String>),
+                pure(&None::<String>),
+                timelock(session, &TimeLock::None)?,
+                timelock(session, &TimeLock::UnlockAt(in_secs(3600)))?,
+            ];
+            call(session, "shared_notarization", "create", &[], arguments)
+        })
+        .unwrap();
+        let id = event_id(&events[0], "notarization_id");
+        let admin = event_id(&events[0], "capability_id");
+
+        let seal = |ledger: &mut Ledger, delete_lock: TimeLock| {
+            transact(ledger, OWNER, |session| {
+                let lock = timelock(session, &delete_lock)?;
+                call(
+                    session,
+                    "shared_notarization",
+                    "seal",
+                    &[],
+                    vec![Value::Object(id), Value::Object(admin), lock],
+                )
+            })
+            .map(|(_, events)| events)
+        };
+        let result = seal(&mut ledger, TimeLock::None);
+        assert!(matches!(result, Err(Error::SealShortensRetention)), "got {result:?}");
+
+        let delete_lock = TimeLock::UnlockAt(in_secs(7200));
+        let events = seal(&mut ledger, delete_lock.clone()).unwrap();
+        assert_eq!(event_types(&events), ["shared_notarization::NotarizationSealed"]);
+        assert_eq!(events[0].json["state_version_count"], "0");
+        let notarization = stored(&ledger, id).unwrap();
+        assert_eq!(
+            notarization.shared_locking,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed.into(),
+                delete_lock,
+                transfer_lock: TimeLock::UntilDestroyed,
+            })
+        );
+        assert!(matches!(ledger.object(&id).unwrap().owner, Owner::Shared(_)));
+
+        let result = transact(&mut ledger, OWNER, |session| {
+            let state = string_state(session, "final")?;
+            call(
+                session,
+                "shared_notarization",
+                "update_state",
+                &[],
+                vec![Value::Object(id), Value::Object(admin), state],
+            )
+        });
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+        let result = seal(&mut ledger, TimeLock::UnlockAt(in_secs(9000)));
+        assert!(matches!(result, Err(Error::UpdateWhileLocked)), "got {result:?}");
+    }
+
+    #[test]
+    fn endorsements_record_the_endorsed_version() {
+        let mut ledger = ledger();
+        let id = create(&mut ledger, "locked_notarization", "create", &[TimeLock::None]).unwrap();
+        let state_hash = Sha256::digest(stored(&ledger, id).unwrap().state.bcs()).to_vec();
+
+        let endorse = |ledger: &mut Ledger, state_hash: &[u8]| {
+            transact(ledger, ALICE, |session| {
+                call(
+                    session,
+                    "endorsement",
+                    "endorse",
+                    &[],
+                    vec![pure(&id), pure(&0u64), pure(state_hash)],
+                )
+            })
+            .map(|(_, events)| events)
+        };
+        let result = endorse(&mut ledger, b"not a hash");
+        assert!(matches!(result, Err(Error::InvalidStateHash)), "got {result:?}");
+
+        let events = endorse(&mut ledger, &state_hash).unwrap();
+        assert_eq!(event_types(&events), ["endorsement::NotarizationEndorsed"]);
+        assert_eq!(events[0].json["endorser"], ALICE.to_string());
+        assert_eq!(events[0].json["state_version_count"], "0");
+
+        let endorsement_id = event_id(&events[0], "endorsement_id");
+        let endorsement = ledger.object(&endorsement_id).unwrap();
+        assert_eq!(endorsement.owner, Owner::Address(*id.as_address()));
+        let Contents::Move(MoveObject::Endorsement {
+            state_hash: endorsed_hash,
+            ..
+        }) = &endorsement.contents
+        else {
+            panic!("expected an endorsement");
+        };
+        assert_eq!(endorsed_hash, &state_hash);
+    }
+
+    #[test]
+    fn aborts_carry_the_codes_of_the_move_sources() {
+        let Failure::Abort {
+            package_id,
+            module,
+            function,
+            code,
+        } = abort("notarization", "destroy_internal", "EDestroyWhileLocked")
+        else {
+            panic!("expected an abort");
+        };
+
+        assert_eq!(package_id, MOCK_PACKAGE_ID);
+        assert_eq!(
+            (module.as_str(), function.as_str(), code),
+            ("notarization", "destroy_internal", 1)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use notarization::core::dry_run::DryRun;
+use notarization::core::types::{State, TimeLock};
+use notarization::error::Error;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn dry_run_estimates_gas_without_executing() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let update = test_client.update_state(State::from_bytes(vec![7; 4096], None), notarization_id);
+    let estimate = update.dry_run(&test_client).await?;
+    assert!(estimate.computation_cost > 0);
+    assert!(estimate.gas_budget() >= estimate.computation_cost);
+
+    // The dry run does not change the notarization
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 0);
+
+    update
+        .with_gas_budget(estimate.gas_budget())
+        .build_and_execute(&test_client)
+        .await?;
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_run_decodes_move_aborts() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("final".to_string(), None))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let result = test_client
+        .update_state(State::from_string("changed".to_string(), None), notarization_id)
+        .dry_run(&test_client)
+        .await;
+
+    assert!(
+        matches!(result, Err(Error::UpdateWhileLocked)),
+        "expected an update-lock abort, got {result:?}"
+    );
+
+    Ok(())
+}
@@ -1,10 +1,16 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use futures::{StreamExt, TryStreamExt};
 use iota_sdk_types::Address;
-use notarization::core::types::{NotarizationMethod, State, TimeLock};
+use notarization::client::WatchOptions;
+use notarization::core::builder::NotarizationBuilder;
+use notarization::core::transactions::{CreateNotarizationBatch, MAX_PURE_ARGUMENT_SIZE};
+use notarization::core::types::{
+    NotarizationEvent, NotarizationFilter, NotarizationMethod, State, TimeLock, UpdateLock,
+};
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 
 use crate::client::get_funded_test_client;
@@ -118,7 +124,9 @@ async fn test_transfer_dynamic_notarization_client_with_no_transfer_lock_works()
         .build_and_execute(&test_client)
         .await;
 
-    assert!(transfer_notarization.is_ok(), "transfer should succeed");
+    let transferred = transfer_notarization.expect("transfer should succeed").output;
+    assert_eq!(transferred.notarization_id, *notarization_id.object_id());
+    assert_eq!(transferred.recipient, alice);
 
     Ok(())
 }
@@ -144,7 +152,10 @@ async fn test_update_state_dynamic_notarization() -> anyhow::Result<()> {
         .build_and_execute(&test_client)
         .await;
 
-    assert!(update_result.is_ok(), "State update should succeed");
+    let updated = update_result.expect("State update should succeed").output;
+    assert_eq!(updated.notarization_id, *notarization_id.object_id());
+    assert_eq!(updated.state_version_count, 1);
+    assert_eq!(updated.updated_state, new_state);
 
     let retrieved_state = test_client.state(*notarization_id.object_id()).await?;
     assert_eq!(retrieved_state.data.as_text()?, "updated_state");
@@ -178,7 +189,9 @@ async fn test_update_metadata_dynamic_notarization() -> anyhow::Result<()> {
         .build_and_execute(&test_client)
         .await;
 
-    assert!(update_result.is_ok(), "Metadata update should succeed");
+    let updated = update_result.expect("Metadata update should succeed").output;
+    assert_eq!(updated.notarization_id, *notarization_id.object_id());
+    assert_eq!(updated.updated_metadata, new_metadata);
 
     let retrieved_metadata = test_client.updatable_metadata(*notarization_id.object_id()).await?;
     assert_eq!(retrieved_metadata, new_metadata);
@@ -208,10 +221,10 @@ async fn test_destroy_dynamic_notarization_no_locks() -> anyhow::Result<()> {
         .build_and_execute(&test_client)
         .await;
 
-    assert!(
-        destroy_result.is_ok(),
-        "Destroy should succeed for unlocked notarization"
-    );
+    let destroyed = destroy_result
+        .expect("Destroy should succeed for unlocked notarization")
+        .output;
+    assert_eq!(destroyed.notarization_id, *notarization_id.object_id());
 
     let res = test_client
         .get_object_ref_by_id(*notarization_id.object_id())
@@ -428,3 +441,515 @@ async fn test_bytes_state_operations() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_generic_state_roundtrip() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let readings: Vec<u64> = vec![21, 22, 23];
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_generic_state(readings.clone(), Some("Sensor readings".to_string()))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    assert_eq!(onchain_notarization.state.data, readings);
+    assert_eq!(onchain_notarization.owner, test_client.sender_address());
+
+    let notarization_id = *onchain_notarization.id.object_id();
+    let state = test_client.state_as::<Vec<u64>>(notarization_id).await?;
+    assert_eq!(state.data, readings);
+    assert_eq!(state.metadata, Some("Sensor readings".to_string()));
+    assert!(test_client.state_as::<Vec<u32>>(notarization_id).await.is_err());
+    assert!(test_client.state(notarization_id).await.is_err());
+
+    let fetched = test_client
+        .get_notarization_by_id_as::<Vec<u64>>(notarization_id)
+        .await?;
+    assert_eq!(fetched, onchain_notarization);
+
+    let history = test_client.state_history_as::<Vec<u64>>(notarization_id).await?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].state, state);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_history() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v0".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let history = test_client.state_history(notarization_id).await?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].version, 0);
+    assert_eq!(history[0].state.data.clone().as_text()?, "v0");
+
+    for (i, content) in ["v1", "Müller v2"].into_iter().enumerate() {
+        test_client
+            .update_state(
+                State::from_string(content.to_string(), Some(format!("update {}", i + 1))),
+                notarization_id,
+            )
+            .build_and_execute(&test_client)
+            .await?;
+    }
+    // Metadata updates create object versions without changing `state`.
+    test_client
+        .update_metadata(Some("described".to_string()), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let history = test_client.state_history(notarization_id).await?;
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].version, 0);
+    assert_eq!(history[0].state.data.clone().as_text()?, "v0");
+    assert_eq!(history[1].version, 1);
+    assert_eq!(history[1].state.data.clone().as_text()?, "v1");
+    assert_eq!(history[2].version, 2);
+    assert_eq!(history[2].state.data.clone().as_text()?, "Müller v2");
+    assert_eq!(history[2].state.metadata, Some("update 2".to_string()));
+    assert!(history[0].timestamp_ms <= history[2].timestamp_ms);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v0".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    test_client
+        .update_state(State::from_string("v1".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    test_client
+        .update_metadata(Some("metadata".to_string()), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    test_client
+        .destroy(notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let options = WatchOptions::default().with_poll_interval(Duration::from_millis(100));
+    let events = test_client
+        .watch(notarization_id, options)
+        .map(|watched| watched.map(|watched| watched.event))
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[0], NotarizationEvent::DynamicNotarizationCreated(_)));
+    assert!(matches!(events[1], NotarizationEvent::NotarizationUpdated(_)));
+    assert!(matches!(events[2], NotarizationEvent::NotarizationMetadataUpdated(_)));
+    assert!(matches!(events[3], NotarizationEvent::NotarizationDestroyed(_)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_batch() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let batch = CreateNotarizationBatch::new()
+        .with(NotarizationBuilder::dynamic().with_state(State::from_string("first".to_string(), None)))
+        .with(
+            NotarizationBuilder::locked()
+                .with_state(State::from_string("second".to_string(), None))
+                .with_delete_lock(TimeLock::None),
+        )
+        .with(NotarizationBuilder::dynamic().with_state(State::from_string("third".to_string(), None)));
+
+    let batches = test_client.create_batch(batch)?;
+    assert_eq!(batches.len(), 1);
+
+    let mut notarizations = Vec::new();
+    for batch in batches {
+        notarizations.extend(batch.build_and_execute(&test_client).await?.output);
+    }
+
+    assert_eq!(
+        notarizations.iter().map(|n| n.method.clone()).collect::<Vec<_>>(),
+        vec![
+            NotarizationMethod::Dynamic,
+            NotarizationMethod::Locked,
+            NotarizationMethod::Dynamic
+        ]
+    );
+    assert_eq!(
+        notarizations
+            .iter()
+            .map(|n| n.state.data.clone().as_text().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["first", "second", "third"]
+    );
+    assert!(notarizations.iter().all(|n| n.owner == test_client.sender_address()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_split_batch() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    // Each payload stays below the per-argument limit, together they exceed
+    // the size of a single transaction.
+    let payloads: Vec<Vec<u8>> = (0..12u8).map(|n| vec![n; 15 * 1024]).collect();
+    let batch: CreateNotarizationBatch = payloads
+        .iter()
+        .map(|payload| {
+            NotarizationBuilder::locked()
+                .with_bytes_state(payload.clone(), None)
+                .with_delete_lock(TimeLock::None)
+        })
+        .collect();
+
+    let batches = test_client.create_batch(batch)?;
+    assert!(batches.len() > 1);
+
+    let mut notarizations = Vec::new();
+    for batch in batches {
+        notarizations.extend(batch.build_and_execute(&test_client).await?.output);
+    }
+
+    assert_eq!(
+        notarizations
+            .into_iter()
+            .map(|n| n.state.data.as_bytes().unwrap())
+            .collect::<Vec<_>>(),
+        payloads
+    );
+
+    let oversized = CreateNotarizationBatch::new().with(
+        NotarizationBuilder::locked()
+            .with_bytes_state(vec![0; MAX_PURE_ARGUMENT_SIZE + 1], None)
+            .with_delete_lock(TimeLock::None),
+    );
+    assert!(test_client.create_batch(oversized).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_owned_notarizations() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+    let tag = format!("List test {}", SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos());
+
+    let dynamic = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("dynamic".to_string(), None))
+        .with_immutable_description(format!("{tag} dynamic"))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let locked = test_client
+        .create_locked_notarization()
+        .with_state(State::from_bytes(vec![1, 2, 3], None))
+        .with_immutable_description(format!("{tag} locked"))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    let owner = test_client.sender_address();
+    let all = test_client
+        .list_owned_notarizations(owner, NotarizationFilter::new().with_description(tag.as_str()))
+        .await?;
+    assert_eq!(all.len(), 2);
+    assert!(all.iter().all(|notarization| notarization.owner == owner));
+
+    let locked_only = test_client
+        .list_owned_notarizations(
+            owner,
+            NotarizationFilter::new()
+                .with_description(tag.as_str())
+                .with_method(NotarizationMethod::Locked),
+        )
+        .await?;
+    assert_eq!(locked_only.len(), 1);
+    assert_eq!(locked_only[0].id, locked.id);
+
+    let created_after = test_client
+        .list_owned_notarizations(
+            owner,
+            NotarizationFilter::new()
+                .with_description(tag.as_str())
+                .with_created_at(dynamic.immutable_metadata.created_at + 1..),
+        )
+        .await?;
+    assert!(created_after.iter().all(|notarization| notarization.id != dynamic.id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_notarizations_and_lock_statuses() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let batch = CreateNotarizationBatch::new()
+        .with(NotarizationBuilder::dynamic().with_state(State::from_string("unlocked".to_string(), None)))
+        .with(
+            NotarizationBuilder::dynamic()
+                .with_state(State::from_string("transfer-locked".to_string(), None))
+                .with_transfer_lock(TimeLock::UnlockAt((now_ts + 86400) as u32)),
+        )
+        .with(
+            NotarizationBuilder::locked()
+                .with_state(State::from_bytes(vec![1, 2, 3], None))
+                .with_delete_lock(TimeLock::None),
+        );
+
+    let mut ids = Vec::new();
+    for batch in test_client.create_batch(batch)? {
+        let created = batch.build_and_execute(&test_client).await?.output;
+        ids.extend(created.iter().map(|notarization| *notarization.id.object_id()));
+    }
+
+    let notarizations = test_client.get_notarizations(&ids).await?;
+    assert_eq!(notarizations.len(), ids.len());
+    for (notarization, id) in notarizations.iter().zip(&ids) {
+        assert_eq!(notarization, &test_client.get_notarization_by_id(*id).await?);
+    }
+
+    let statuses = test_client.lock_statuses(&ids).await?;
+    assert_eq!(statuses.len(), ids.len());
+    for (status, id) in statuses.iter().zip(&ids) {
+        assert_eq!(status.update_locked, test_client.is_update_locked(*id).await?);
+        assert_eq!(status.destroy_allowed, test_client.is_destroy_allowed(*id).await?);
+        assert_eq!(status.transfer_locked, test_client.is_transfer_locked(*id).await?);
+    }
+    assert!(!statuses[0].transfer_locked);
+    assert!(statuses[1].transfer_locked);
+    assert!(statuses[2].update_locked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_notarization_with_delete_lock() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let retention = Duration::from_secs(7 * 365 * 24 * 60 * 60);
+
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("status: active".to_string(), None))
+        .with_immutable_description("Retained status record".to_string())
+        .with_delete_lock(TimeLock::unlock_after(retention)?)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *onchain_notarization.id.object_id();
+
+    let locking = onchain_notarization.immutable_metadata.locking.unwrap();
+    assert!(matches!(locking.delete_lock, TimeLock::UnlockAt(_)));
+    assert_eq!(locking.update_lock, TimeLock::None);
+    assert_eq!(locking.transfer_lock, TimeLock::None);
+
+    assert!(!test_client.is_update_locked(notarization_id).await?);
+    assert!(!test_client.is_transfer_locked(notarization_id).await?);
+    assert!(!test_client.is_destroy_allowed(notarization_id).await?);
+
+    // The notarization stays updatable while it is retained
+    let updated = test_client
+        .update_state(
+            State::from_string("status: inactive".to_string(), None),
+            notarization_id,
+        )
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(updated.state_version_count, 1);
+
+    let destroy_result = test_client
+        .destroy(notarization_id)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(
+        destroy_result.is_err(),
+        "Destroy should fail while the delete lock is active"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_notarization_delete_lock_before_transfer_lock_fails() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+    let result = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UnlockAt((now_ts + 3600) as u32))
+        .with_transfer_lock(TimeLock::UnlockAt((now_ts + 86400) as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await;
+    assert!(result.is_err(), "Delete lock must not unlock before the transfer lock");
+
+    let result = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UntilDestroyed)
+        .finish()
+        .build_and_execute(&test_client)
+        .await;
+    assert!(result.is_err(), "Delete lock must not be `UntilDestroyed`");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_notarization_with_update_embargo() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("press release".to_string(), None))
+        .with_update_lock(TimeLock::unlock_after(Duration::from_secs(3600))?)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *onchain_notarization.id.object_id();
+
+    let locking = onchain_notarization.immutable_metadata.locking.unwrap();
+    assert!(matches!(
+        locking.update_lock,
+        UpdateLock::TimeLock(TimeLock::UnlockAt(_))
+    ));
+    assert_eq!(locking.delete_lock, TimeLock::None);
+    assert_eq!(locking.transfer_lock, TimeLock::None);
+
+    assert!(test_client.is_update_locked(notarization_id).await?);
+    assert!(!test_client.is_transfer_locked(notarization_id).await?);
+    assert!(!test_client.is_destroy_allowed(notarization_id).await?);
+
+    let update_result = test_client
+        .update_state(State::from_string("too early".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(update_result.is_err(), "Update should fail while the embargo is active");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dynamic_notarization_with_update_freeze() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let freeze_at = (now_ts + 3600) as u32;
+
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("draft".to_string(), None))
+        .with_update_lock(UpdateLock::LockAt(freeze_at))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *onchain_notarization.id.object_id();
+
+    let locking = onchain_notarization.immutable_metadata.locking.unwrap();
+    assert_eq!(locking.update_lock, UpdateLock::LockAt(freeze_at));
+
+    // Updatable and destroyable until the freeze
+    assert!(!test_client.is_update_locked(notarization_id).await?);
+    assert!(test_client.is_destroy_allowed(notarization_id).await?);
+
+    let updated = test_client
+        .update_state(State::from_string("final".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(updated.state_version_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_seal_dynamic_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("draft".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *onchain_notarization.id.object_id();
+
+    test_client
+        .update_state(State::from_string("final".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let sealed = test_client
+        .seal(notarization_id, TimeLock::unlock_after(Duration::from_secs(3600))?)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(sealed.notarization_id, notarization_id);
+    assert_eq!(sealed.state_version_count, 1);
+
+    let notarization = test_client.get_notarization_by_id(notarization_id).await?;
+    assert_eq!(notarization.method, NotarizationMethod::Locked);
+    assert_eq!(notarization.state.data.as_text()?, "final");
+    assert_eq!(notarization.state_version_count, 1);
+    assert_eq!(
+        notarization.immutable_metadata.created_at,
+        onchain_notarization.immutable_metadata.created_at
+    );
+
+    let locking = notarization.immutable_metadata.locking.unwrap();
+    assert_eq!(locking.update_lock, TimeLock::UntilDestroyed);
+    assert_eq!(locking.transfer_lock, TimeLock::UntilDestroyed);
+    assert!(matches!(locking.delete_lock, TimeLock::UnlockAt(_)));
+
+    assert!(test_client.is_update_locked(notarization_id).await?);
+    assert!(test_client.is_transfer_locked(notarization_id).await?);
+    assert!(!test_client.is_destroy_allowed(notarization_id).await?);
+
+    let update_result = test_client
+        .update_state(State::from_string("too late".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(update_result.is_err(), "Update of a sealed notarization should fail");
+
+    let seal_result = test_client
+        .seal(notarization_id, TimeLock::None)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(seal_result.is_err(), "Sealing a Locked-Notarization should fail");
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use notarization::core::dry_run::DryRun;
+use notarization::core::types::{State, TimeLock};
+use notarization::error::Error;
+use product_common::core_client::CoreClient;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn test_endorse_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("Contract v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let endorsed = test_client
+        .endorse(notarization_id)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(endorsed.notarization_id, notarization_id);
+    assert_eq!(endorsed.endorser, test_client.sender_address());
+    assert_eq!(endorsed.state_version_count, 0);
+    assert_eq!(
+        endorsed.state_hash,
+        State::from_string("Contract v1".to_string(), None).hash()?
+    );
+
+    let endorsements = test_client.endorsements(notarization_id).await?;
+    assert_eq!(endorsements.len(), 1);
+    assert_eq!(*endorsements[0].id.object_id(), endorsed.endorsement_id);
+    assert_eq!(endorsements[0].endorser, test_client.sender_address());
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 1);
+    assert!(verified[0].is_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_endorsement_outdated_after_update() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("Contract v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    test_client
+        .endorse(notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    test_client
+        .update_state(State::from_string("Contract v2".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 1);
+    assert!(!verified[0].is_current);
+
+    test_client
+        .endorse(notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 2);
+    let current: Vec<_> = verified.iter().filter(|verified| verified.is_current).collect();
+    assert_eq!(current.len(), 1);
+    assert_eq!(current[0].endorsement.state_version_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shared_notarization_endorsed_by_other_parties() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+    let counterparty = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_shared_notarization()
+        .with_state(State::from_string("Contract v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .notarization
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    for client in [&test_client, &counterparty] {
+        client.endorse(notarization_id).build_and_execute(client).await?;
+    }
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 2);
+    assert!(verified.iter().all(|verified| verified.is_current));
+    assert!(
+        verified
+            .iter()
+            .any(|verified| verified.endorsement.endorser == counterparty.sender_address())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owned_notarization_endorsed_by_other_parties() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+    let counterparty = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("Contract v1".to_string(), None))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let endorsed = counterparty
+        .endorse(notarization_id)
+        .build_and_execute(&counterparty)
+        .await?
+        .output;
+    assert_eq!(endorsed.endorser, counterparty.sender_address());
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 1);
+    assert!(verified[0].is_current);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_endorsement_of_wrong_state_is_not_current() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("Contract v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let forged_hash = State::from_string("Contract v2".to_string(), None).hash()?;
+    test_client
+        .endorse_state_version(notarization_id, 0, forged_hash)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let verified = test_client.verify_endorsements(notarization_id).await?;
+    assert_eq!(verified.len(), 1);
+    assert!(!verified[0].is_current);
+
+    let result = test_client
+        .endorse_state_version(notarization_id, 0, vec![0; 4])
+        .dry_run(&test_client)
+        .await;
+    assert!(matches!(result, Err(Error::InvalidStateHash)), "got {result:?}");
+
+    Ok(())
+}
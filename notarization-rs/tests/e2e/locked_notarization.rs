@@ -4,7 +4,9 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use iota_sdk_types::Address;
-use notarization::core::types::{NotarizationMethod, State, TimeLock};
+use notarization::core::merkle::MerkleTree;
+use notarization::core::types::{DigestAlgorithm, NotarizationMethod, State, TimeLock};
+use notarization::error::{Error, TimeLockError};
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 
 use crate::client::get_funded_test_client;
@@ -470,3 +472,67 @@ async fn test_notarization_owner() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_merkle_root_anchors_payloads() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let log_lines: Vec<String> = (0..10).map(|i| format!("log line {i}")).collect();
+    let tree = MerkleTree::new(DigestAlgorithm::Sha256, &log_lines)?;
+
+    let onchain_notarization = test_client
+        .create_locked_notarization()
+        .with_merkle_root(&tree, Some("Access log".to_string()))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    let proof = tree.proof(7).unwrap();
+    assert!(
+        onchain_notarization
+            .verify_inclusion(&log_lines[7], &proof)
+            .data_matches
+    );
+    assert!(
+        !onchain_notarization
+            .verify_inclusion(&log_lines[6], &proof)
+            .data_matches
+    );
+
+    let onchain_notarization = test_client
+        .get_notarization_by_id(*onchain_notarization.id.object_id())
+        .await?;
+    assert!(
+        tree.proofs()
+            .zip(&log_lines)
+            .all(|(proof, line)| onchain_notarization.verify_inclusion(line, &proof).data_matches)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_validate_locks_against_chain_clock() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let chain_time = test_client.chain_timestamp_ms().await? / 1000;
+
+    let builder = test_client
+        .create_locked_notarization()
+        .with_string_state("test".to_string(), None)
+        .with_delete_lock(TimeLock::UnlockAt(chain_time as u32));
+    let err = test_client.validate_locks(&builder).await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::TimeLock(TimeLockError::NotInFuture { requested_time, chain_time: validated_at })
+            if u64::from(requested_time) == chain_time && validated_at >= chain_time
+    ));
+
+    let builder = builder.with_delete_lock(TimeLock::UnlockAt((chain_time + 3600) as u32));
+    test_client.validate_locks(&builder).await?;
+    builder.finish()?.build_and_execute(&test_client).await?;
+
+    Ok(())
+}
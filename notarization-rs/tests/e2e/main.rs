@@ -2,5 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod client;
+mod dry_run;
 mod dynamic_notarization;
+mod endorsement;
 mod locked_notarization;
+mod shared_notarization;
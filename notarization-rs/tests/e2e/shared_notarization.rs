@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_types::Address;
+use notarization::core::types::{NotarizationMethod, Role, State, TimeLock};
+use product_common::core_client::CoreClient;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn create_shared_notarization_works() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let created = test_client
+        .create_shared_notarization()
+        .with_state(State::from_string("Status: pending".to_string(), None))
+        .with_immutable_description("Shipment status".to_string())
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization = created.notarization;
+
+    assert_eq!(notarization.method, NotarizationMethod::Shared);
+    assert_eq!(notarization.owner, Address::ZERO);
+    assert_eq!(notarization.state_version_count, 0);
+
+    let locking = notarization.immutable_metadata.locking.unwrap();
+    assert_eq!(locking.update_lock, TimeLock::None);
+    assert_eq!(locking.delete_lock, TimeLock::None);
+    assert_eq!(locking.transfer_lock, TimeLock::UntilDestroyed);
+
+    let notarization_id = *notarization.id.object_id();
+    assert!(!test_client.is_update_locked(notarization_id).await?);
+    assert!(test_client.is_transfer_locked(notarization_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shared_notarization_capabilities() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let created = test_client
+        .create_shared_notarization()
+        .with_state(State::from_string("Status: pending".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *created.notarization.id.object_id();
+    let admin_capability_id = created.admin_capability_id;
+
+    // Updates without a capability are rejected
+    let update_result = test_client
+        .update_state(State::from_string("Status: lost".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(update_result.is_err(), "Update without a capability should fail");
+
+    // Issue a writer capability and update with it
+    let issued = test_client
+        .issue_capability(
+            notarization_id,
+            admin_capability_id,
+            Role::Writer,
+            test_client.sender_address(),
+        )
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(issued.notarization_id, notarization_id);
+    assert_eq!(issued.recipient, test_client.sender_address());
+    let writer_capability_id = issued.capability_id;
+
+    test_client
+        .update_state_with_capability(
+            State::from_string("Status: shipped".to_string(), None),
+            notarization_id,
+            writer_capability_id,
+        )
+        .build_and_execute(&test_client)
+        .await?;
+    test_client
+        .update_metadata_with_capability(Some("Carrier: ACME".to_string()), notarization_id, writer_capability_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let retrieved_state = test_client.state(notarization_id).await?;
+    assert_eq!(retrieved_state.data.as_text()?, "Status: shipped");
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+    assert_eq!(
+        test_client.updatable_metadata(notarization_id).await?,
+        Some("Carrier: ACME".to_string())
+    );
+
+    // Writers cannot issue capabilities or destroy the notarization
+    let issue_result = test_client
+        .issue_capability(
+            notarization_id,
+            writer_capability_id,
+            Role::Admin,
+            test_client.sender_address(),
+        )
+        .build_and_execute(&test_client)
+        .await;
+    assert!(issue_result.is_err(), "Writers should not issue capabilities");
+
+    let destroy_result = test_client
+        .destroy_with_capability(notarization_id, writer_capability_id)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(destroy_result.is_err(), "Writers should not destroy the notarization");
+
+    // Revoked capabilities are rejected
+    let revoked = test_client
+        .revoke_capability(notarization_id, admin_capability_id, writer_capability_id)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(revoked.capability_id, writer_capability_id);
+
+    let update_result = test_client
+        .update_state_with_capability(
+            State::from_string("Status: lost".to_string(), None),
+            notarization_id,
+            writer_capability_id,
+        )
+        .build_and_execute(&test_client)
+        .await;
+    assert!(update_result.is_err(), "Update with a revoked capability should fail");
+
+    test_client
+        .destroy_with_capability(notarization_id, admin_capability_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # IOTA RPC Retry
+//!
+//! The [`RetryPolicy`] of the RPC calls of the notarization and audit-trail clients, which re-export this crate as
+//! `client::retry`.
+//!
+//! ## Overview
+//!
+//! A call failing with a transient error, as classified by the [`RetryableError`] implementation of the error type of
+//! the calling crate, is attempted again until [`RetryPolicy::max_attempts`] is reached. Between two attempts the
+//! policy waits for an exponentially growing backoff, randomized by jitter so that many clients do not retry in
+//! lockstep. Every attempt can be bounded by [`RetryPolicy::timeout`]; an attempt running out of time fails with
+//! [`RetryableError::timed_out`], which is transient as well.
+//!
+//! Only idempotent calls are run by a policy. Reads are retried at any time, writes only until they are submitted: the
+//! submission of a transaction is never retried, as a submission failing on the client side might still have been
+//! executed.
+//!
+//! On `wasm32` targets, which lack a timer, retries are disabled: every call is attempted exactly once and timeouts
+//! are not enforced.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use notarization::client::{NotarizationClientReadOnly, RetryPolicy};
+//!
+//! let policy = RetryPolicy::default().with_max_attempts(5).with_timeout(Duration::from_secs(10));
+//! let client = NotarizationClientReadOnly::new(iota_client).await?.with_retry_policy(policy);
+//! ```
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+#[cfg(not(target_arch = "wasm32"))]
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// Default number of attempts of a call, including the first one.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// Default backoff before the first retry.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+/// Default upper bound of the backoff between two attempts.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// Default factor by which the backoff grows after every retry.
+pub const DEFAULT_BACKOFF_MULTIPLIER: u32 = 2;
+
+/// An error of a call run by a [`RetryPolicy`].
+pub trait RetryableError {
+    /// Returns whether a call failing with this error may succeed if attempted again.
+    fn is_transient(&self) -> bool;
+
+    /// Returns the error of an attempt that did not complete within `timeout`.
+    fn timed_out(timeout: Duration) -> Self;
+}
+
+/// Configures how failed RPC calls are retried.
+///
+/// The default policy makes up to [`DEFAULT_MAX_ATTEMPTS`] attempts with a jittered backoff growing from
+/// [`DEFAULT_INITIAL_BACKOFF`] to [`DEFAULT_MAX_BACKOFF`], and does not bound the duration of an attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts of a call, including the first one.
+    ///
+    /// `1` disables retries. Ignored on `wasm32` targets, where every call is attempted once.
+    pub max_attempts: u32,
+    /// Backoff before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the backoff between two attempts.
+    pub max_backoff: Duration,
+    /// Factor by which the backoff grows after every retry.
+    pub multiplier: u32,
+    /// Whether a random share of up to half of every backoff is skipped.
+    pub jitter: bool,
+    /// Maximum duration of a single attempt; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            multiplier: DEFAULT_BACKOFF_MULTIPLIER,
+            jitter: true,
+            timeout: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a policy making a single attempt per call, without timeout.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of attempts of a call, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the backoff before the first retry and its upper bound.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor by which the backoff grows after every retry.
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Enables or disables the randomization of backoffs.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Bounds the duration of every attempt by `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the backoff before the `retry`-th retry, starting at 1, without jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.checked_pow(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+
+        self.initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Runs the idempotent call `call` according to this policy.
+    ///
+    /// `call` is invoked once per attempt. The result of the last attempt is returned.
+    pub async fn run<T, E, F, Fut>(&self, mut call: F) -> Result<T, E>
+    where
+        E: RetryableError,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        #[cfg(target_arch = "wasm32")]
+        return call().await;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut attempt = 1;
+            loop {
+                match self.attempt(call()).await {
+                    Err(e) if attempt < self.max_attempts && e.is_transient() => {
+                        tokio::time::sleep(self.jittered_backoff(attempt)).await;
+                        attempt += 1;
+                    }
+                    result => return result,
+                }
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn attempt<T, E: RetryableError>(&self, call: impl Future<Output = Result<T, E>>) -> Result<T, E> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, call)
+                .await
+                .unwrap_or_else(|_| Err(E::timed_out(timeout))),
+            None => call.await,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn jittered_backoff(&self, retry: u32) -> Duration {
+        let backoff = self.backoff(retry);
+        if !self.jitter {
+            return backoff;
+        }
+
+        // Randomly seeded hashers are the only source of randomness of the standard library.
+        let half = backoff / 2;
+        let random = RandomState::new().hash_one(retry);
+        let skipped = random % (half.as_nanos() as u64 + 1);
+
+        backoff - Duration::from_nanos(skipped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum TestError {
+        Transient(&'static str),
+        TimedOut,
+        Permanent,
+    }
+
+    impl RetryableError for TestError {
+        fn is_transient(&self) -> bool {
+            !matches!(self, TestError::Permanent)
+        }
+
+        fn timed_out(_timeout: Duration) -> Self {
+            TestError::TimedOut
+        }
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::default().with_backoff(Duration::ZERO, Duration::ZERO)
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_limit() {
+        let policy = RetryPolicy::default().with_backoff(Duration::from_millis(100), Duration::from_secs(1));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_secs(1));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn jitter_skips_at_most_half_of_the_backoff() {
+        let policy = RetryPolicy::default().with_backoff(Duration::from_millis(100), Duration::from_secs(1));
+
+        for retry in 1..10 {
+            let backoff = policy.jittered_backoff(retry);
+            assert!(backoff <= policy.backoff(retry));
+            assert!(backoff >= policy.backoff(retry) / 2);
+        }
+        assert_eq!(
+            policy.with_jitter(false).jittered_backoff(3),
+            Duration::from_millis(400)
+        );
+    }
+
+    #[tokio::test]
+    async fn transient_errors_are_retried() {
+        let attempts = AtomicU32::new(0);
+        let counter = &attempts;
+        let result = fast_policy()
+            .run(move || async move {
+                match counter.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(TestError::Transient("connection reset")),
+                    1 => Err(TestError::Transient("node is syncing")),
+                    _ => Ok(42),
+                }
+            })
+            .await;
+
+        assert_eq!(result, Ok(42));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+        let counter = &attempts;
+        let result: Result<(), _> = fast_policy()
+            .with_max_attempts(2)
+            .run(move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err(TestError::Transient("connection reset"))
+            })
+            .await;
+
+        assert_eq!(result, Err(TestError::Transient("connection reset")));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn permanent_errors_are_not_retried() {
+        let attempts = AtomicU32::new(0);
+        let counter = &attempts;
+        let result: Result<(), _> = fast_policy()
+            .run(move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Err(TestError::Permanent)
+            })
+            .await;
+
+        assert_eq!(result, Err(TestError::Permanent));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn attempts_running_out_of_time_are_retried() {
+        let attempts = AtomicU32::new(0);
+        let counter = &attempts;
+        let result = fast_policy()
+            .with_timeout(Duration::from_millis(10))
+            .run(move || async move {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    std::future::pending::<()>().await;
+                }
+                Ok::<_, TestError>(())
+            })
+            .await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn attempts_running_out_of_time_fail_with_the_timeout_error() {
+        let result: Result<(), _> = fast_policy()
+            .with_max_attempts(1)
+            .with_timeout(Duration::from_millis(10))
+            .run(std::future::pending)
+            .await;
+
+        assert_eq!(result, Err(TestError::TimedOut));
+    }
+}